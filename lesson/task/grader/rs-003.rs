@@ -0,0 +1,9 @@
+// title: 变量与可变性
+// chapter: 1
+// api: pub fn vars() -> i32
+// timeout: 5
+
+#[test]
+fn vars_multiplies_x_by_updated_y() {
+    assert_eq!(vars(), -605, "x = -11，y = 22 + 33，返回 x * y");
+}
@@ -0,0 +1,10 @@
+// title: 常量
+// chapter: 1
+// api: pub fn calc_area(r: f64) -> f64
+// timeout: 5
+
+#[test]
+fn calc_area_uses_pi() {
+    assert!((calc_area(10.0) - 314.0).abs() < 1e-9, "PI 取 3.14，面积为 PI * r * r");
+    assert_eq!(calc_area(0.0), 0.0);
+}
@@ -0,0 +1,16 @@
+// title: 元组
+// chapter: 2
+// api: pub fn return_tuple() -> (i32, i32, bool)
+// api: pub fn swap(t: (i32, i32)) -> (i32, i32)
+// timeout: 5
+
+#[test]
+fn return_tuple_has_three_fields() {
+    assert_eq!(return_tuple(), (1, -1, true));
+}
+
+#[test]
+fn swap_exchanges_fields() {
+    assert_eq!(swap((1, 2)), (2, 1));
+    assert_eq!(swap((-3, -3)), (-3, -3));
+}
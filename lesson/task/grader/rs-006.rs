@@ -0,0 +1,21 @@
+// title: 数组与切片
+// chapter: 2
+// api: pub fn make() -> [u32; 10]
+// api: pub fn first_2(nums: &[i32; 10]) -> &[i32]
+// api: pub fn mid_3(nums: &[i32; 10]) -> &[i32]
+// api: pub fn last_2(nums: &[i32; 10]) -> &[i32]
+// timeout: 5
+
+const NUMS: [i32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+#[test]
+fn make_sets_only_index_5() {
+    assert_eq!(make(), [0, 0, 0, 0, 0, 1, 0, 0, 0, 0]);
+}
+
+#[test]
+fn slices_cover_the_expected_ranges() {
+    assert_eq!(first_2(&NUMS), [0, 1], "前两个元素");
+    assert_eq!(mid_3(&NUMS), [4, 5, 6], "下标 4 到 6");
+    assert_eq!(last_2(&NUMS), [8, 9], "最后两个元素");
+}
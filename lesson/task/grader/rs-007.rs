@@ -0,0 +1,27 @@
+// title: String 与 &str
+// chapter: 9
+// api: pub fn str_to_string(s: &str) -> String
+// api: pub fn string_to_str(s: &String) -> &str
+// api: pub fn add(a: &str, b: &str) -> String
+// api: pub fn slice(s: &String, start: usize, len: usize) -> &str
+// timeout: 5
+
+#[test]
+fn converts_between_string_and_str() {
+    assert_eq!(str_to_string("rust"), String::from("rust"));
+    let s = String::from("crab");
+    assert_eq!(string_to_str(&s), "crab");
+}
+
+#[test]
+fn add_concatenates() {
+    assert_eq!(add("hello ", "rust"), "hello rust");
+    assert_eq!(add("", ""), "");
+}
+
+#[test]
+fn slice_takes_len_bytes_from_start() {
+    let s = String::from("hello rust");
+    assert_eq!(slice(&s, 6, 4), "rust");
+    assert_eq!(slice(&s, 0, 0), "");
+}
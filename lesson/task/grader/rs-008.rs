@@ -0,0 +1,13 @@
+// title: 枚举的变体
+// chapter: 10
+// api: pub enum Move
+// api: pub fn move_up(y: u32) -> Move
+// api: pub fn move_down(y: u32) -> Move
+// timeout: 5
+
+#[test]
+fn moves_carry_the_distance() {
+    assert_eq!(move_up(3), Move::Up(3));
+    assert_eq!(move_down(7), Move::Down(7));
+    assert_ne!(Move::Left(1), Move::Right(1));
+}
@@ -0,0 +1,29 @@
+// title: 结构体与方法函数
+// chapter: 8
+// api: pub struct Rectangle
+// api: pub fn make(top: i32, left: i32, height: u32, width: u32) -> Rectangle
+// api: pub fn move_to(rect: &mut Rectangle, top: i32, left: i32)
+// api: pub fn grow(rect: &mut Rectangle, scale_factor: u32)
+// api: pub fn area(rect: &Rectangle) -> u32
+// timeout: 5
+
+#[test]
+fn make_keeps_every_field() {
+    let rect = make(1, -2, 3, 4);
+    assert_eq!((rect.top, rect.left, rect.height, rect.width), (1, -2, 3, 4));
+}
+
+#[test]
+fn move_to_changes_only_the_position() {
+    let mut rect = make(0, 0, 3, 4);
+    move_to(&mut rect, -5, 7);
+    assert_eq!((rect.top, rect.left, rect.height, rect.width), (-5, 7, 3, 4));
+}
+
+#[test]
+fn grow_scales_both_sides() {
+    let mut rect = make(0, 0, 3, 4);
+    grow(&mut rect, 2);
+    assert_eq!((rect.height, rect.width), (6, 8));
+    assert_eq!(area(&rect), 48);
+}
@@ -0,0 +1,23 @@
+// title: 关联函数与方法
+// chapter: 8
+// api: pub struct Circle
+// api: pub fn unit() -> Self
+// api: pub fn area(&self) -> f32
+// api: pub fn shift(&mut self, dx: i32, dy: i32)
+// timeout: 5
+
+#[test]
+fn unit_circle_is_at_the_origin() {
+    let circle = Circle::unit();
+    assert_eq!((circle.center, circle.radius), ((0, 0), 1));
+    assert!((circle.area() - 3.14).abs() < 1e-5);
+}
+
+#[test]
+fn shift_moves_the_center() {
+    let mut circle = Circle { center: (1, 2), radius: 2 };
+    circle.shift(3, -4);
+    assert_eq!(circle.center, (4, -2));
+    assert_eq!(circle.radius, 2, "移动不改变半径");
+    assert!((circle.area() - 12.56).abs() < 1e-4);
+}
@@ -0,0 +1,14 @@
+// title: if 表达式
+// chapter: 4
+// api: pub fn min(x: i32, y: i32) -> i32
+// api: pub fn max(x: i32, y: i32) -> i32
+// timeout: 5
+
+#[test]
+fn min_and_max_pick_the_right_value() {
+    assert_eq!(min(-3, 5), -3);
+    assert_eq!(min(5, -3), -3);
+    assert_eq!(max(-3, 5), 5);
+    assert_eq!(max(5, -3), 5);
+    assert_eq!((min(4, 4), max(4, 4)), (4, 4));
+}
@@ -0,0 +1,28 @@
+// title: 循环
+// chapter: 4
+// api: pub fn pow(x: u32, n: u32) -> u32
+// api: pub fn sum(nums: &[i32]) -> i32
+// api: pub fn fib(n: u32) -> u32
+// timeout: 5
+
+#[test]
+fn pow_multiplies_n_times() {
+    assert_eq!(pow(2, 10), 1024);
+    assert_eq!(pow(7, 0), 1, "任何数的 0 次方都是 1");
+    assert_eq!(pow(0, 3), 0);
+}
+
+#[test]
+fn sum_adds_every_element() {
+    assert_eq!(sum(&[1, -2, 3, 4]), 6);
+    assert_eq!(sum(&[]), 0, "空切片的和是 0");
+}
+
+#[test]
+fn fib_starts_from_zero_and_one() {
+    let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34];
+    for (n, value) in expected.iter().enumerate() {
+        assert_eq!(fib(n as u32), *value, "fib({})", n);
+    }
+    assert_eq!(fib(40), 102_334_155, "fib(40) 要在时间限制内算完");
+}
@@ -0,0 +1,25 @@
+// title: match 模式匹配
+// chapter: 10
+// api: pub struct Color(pub u8, pub u8, pub u8)
+// api: pub fn color_to_string(color: Color) -> String
+// api: pub fn get_or_default(x: Option<u32>, default_val: u32) -> u32
+// api: pub fn get_ok_or_default(res: Result<u32, ()>, default_val: u32) -> u32
+// timeout: 5
+
+#[test]
+fn color_names() {
+    assert_eq!(color_to_string(Color(0, 0, 0)), "black");
+    assert_eq!(color_to_string(Color(255, 0, 0)), "red");
+    assert_eq!(color_to_string(Color(0, 255, 0)), "green");
+    assert_eq!(color_to_string(Color(0, 0, 255)), "blue");
+    assert_eq!(color_to_string(Color(255, 255, 255)), "white");
+    assert_eq!(color_to_string(Color(1, 2, 3)), "unknown");
+}
+
+#[test]
+fn defaults_for_option_and_result() {
+    assert_eq!(get_or_default(Some(1), 9), 1);
+    assert_eq!(get_or_default(None, 9), 9);
+    assert_eq!(get_ok_or_default(Ok(1), 9), 1);
+    assert_eq!(get_ok_or_default(Err(()), 9), 9);
+}
@@ -0,0 +1,10 @@
+// title: if let
+// chapter: 10
+// api: pub fn get_or_default(x: Option<u32>, default_val: u32) -> u32
+// timeout: 5
+
+#[test]
+fn get_or_default_unwraps_some() {
+    assert_eq!(get_or_default(Some(3), 0), 3);
+    assert_eq!(get_or_default(None, 42), 42);
+}
@@ -0,0 +1,11 @@
+// title: 函数
+// chapter: 3
+// api: pub fn avg(a: u32, b: u32) -> u32
+// timeout: 5
+
+#[test]
+fn avg_rounds_down() {
+    assert_eq!(avg(2, 4), 3);
+    assert_eq!(avg(1, 2), 1, "整数除法向下取整");
+    assert_eq!(avg(0, 0), 0);
+}
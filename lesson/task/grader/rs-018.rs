@@ -0,0 +1,18 @@
+// title: Option 与 Result
+// chapter: 25
+// api: pub fn div(x: u32, y: u32) -> Result<u32, String>
+// api: pub fn find_index(nums: &[i32], x: i32) -> Option<usize>
+// timeout: 5
+
+#[test]
+fn div_rejects_zero() {
+    assert_eq!(div(7, 2), Ok(3));
+    assert!(div(1, 0).is_err(), "除数为 0 时返回 Err");
+}
+
+#[test]
+fn find_index_returns_first_match() {
+    assert_eq!(find_index(&[4, 2, 2], 2), Some(1));
+    assert_eq!(find_index(&[4, 2, 2], 5), None);
+    assert_eq!(find_index(&[], 0), None);
+}
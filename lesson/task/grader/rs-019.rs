@@ -0,0 +1,24 @@
+// title: unwrap 与 expect
+// chapter: 25
+// api: pub enum MathError
+// api: pub fn div_unwrap(x: u32, y: u32) -> u32
+// api: pub fn div_expect(x: u32, y: u32) -> u32
+// timeout: 5
+
+#[test]
+fn divides_when_the_divisor_is_not_zero() {
+    assert_eq!(div_unwrap(9, 3), 3);
+    assert_eq!(div_expect(9, 2), 4);
+}
+
+#[test]
+#[should_panic]
+fn unwrap_panics_on_zero() {
+    div_unwrap(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "div error")]
+fn expect_panics_with_the_message() {
+    div_expect(1, 0);
+}
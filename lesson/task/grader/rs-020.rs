@@ -0,0 +1,18 @@
+// title: ? 运算符
+// chapter: 25
+// api: pub enum MathError
+// api: pub fn f(a: u32, b: u32, c: u32, d: u32) -> Result<u32, MathError>
+// timeout: 5
+
+#[test]
+fn computes_a_div_b_minus_c_div_d() {
+    assert_eq!(f(10, 2, 6, 3), Ok(3));
+    assert_eq!(f(10, 2, 10, 2), Ok(0));
+}
+
+#[test]
+fn propagates_errors() {
+    assert_eq!(f(1, 0, 6, 3), Err(MathError::DivByZero));
+    assert_eq!(f(6, 3, 1, 0), Err(MathError::DivByZero));
+    assert_eq!(f(2, 1, 9, 1), Err(MathError::UnderFlow), "结果为负数时返回 UnderFlow");
+}
@@ -0,0 +1,25 @@
+// title: 传播不同类型的错误
+// chapter: 25
+// api: pub fn parse_and_sum(src_path: &str) -> Result<u32, Box<dyn std::error::Error>>
+// timeout: 5
+
+fn write_numbers(name: &str, text: &str) -> String {
+    let path = std::env::temp_dir().join(format!("grade-rs-021-{}-{}", std::process::id(), name));
+    std::fs::write(&path, text).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn sums_one_number_per_line() {
+    let path = write_numbers("ok", "1\n20\n300\n");
+    assert_eq!(parse_and_sum(&path).unwrap(), 321);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn reports_missing_files_and_bad_numbers() {
+    assert!(parse_and_sum("/no/such/file.txt").is_err(), "文件不存在时返回错误");
+    let path = write_numbers("bad", "1\nx\n");
+    assert!(parse_and_sum(&path).is_err(), "无法解析的行返回错误");
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,22 @@
+// title: 引用作为参数
+// chapter: 7
+// api: pub fn max_len(a: &String, b: &String) -> usize
+// api: pub fn add(a: &mut String, b: &String)
+// timeout: 5
+
+#[test]
+fn max_len_borrows_both_strings() {
+    let (a, b) = ("rust".to_string(), "solidity".to_string());
+    assert_eq!(max_len(&a, &b), 8);
+    assert_eq!(max_len(&b, &a), 8);
+    assert_eq!(a, "rust", "借用之后仍然可以使用");
+}
+
+#[test]
+fn add_appends_in_place() {
+    let mut a = "hello ".to_string();
+    let b = "rust".to_string();
+    add(&mut a, &b);
+    assert_eq!(a, "hello rust");
+    assert_eq!(b, "rust");
+}
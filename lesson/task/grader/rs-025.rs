@@ -0,0 +1,35 @@
+// title: 切片的读写
+// chapter: 7
+// api: pub fn sum(s: &[i32]) -> i32
+// api: pub fn reverse(s: &mut [i32])
+// api: pub fn find(s: &[i32], target: i32) -> Option<usize>
+// timeout: 5
+
+#[test]
+fn sum_adds_every_element() {
+    assert_eq!(sum(&[3, -1, 5]), 7);
+    assert_eq!(sum(&[]), 0);
+}
+
+#[test]
+fn reverse_in_place() {
+    let mut odd = [1, 2, 3, 4, 5];
+    reverse(&mut odd);
+    assert_eq!(odd, [5, 4, 3, 2, 1]);
+    let mut even = [1, 2];
+    reverse(&mut even);
+    assert_eq!(even, [2, 1]);
+    let mut empty: [i32; 0] = [];
+    reverse(&mut empty);
+}
+
+#[test]
+fn find_searches_a_sorted_slice() {
+    let sorted = [-3, 0, 2, 5, 8, 13];
+    for (index, value) in sorted.iter().enumerate() {
+        assert_eq!(find(&sorted, *value), Some(index), "查找 {}", value);
+    }
+    assert_eq!(find(&sorted, 4), None);
+    assert_eq!(find(&sorted, 100), None);
+    assert_eq!(find(&[], 1), None);
+}
@@ -0,0 +1,16 @@
+// title: 所有权与函数
+// chapter: 6
+// api: pub fn print(s: &str)
+// api: pub fn append(s: &mut String)
+// api: pub fn make() -> String
+// timeout: 5
+
+#[test]
+fn make_returns_an_owned_string() {
+    let mut s = make();
+    assert_eq!(s, "rust");
+    append(&mut s);
+    assert_eq!(s, "rust!");
+    print(&s);
+    assert_eq!(s, "rust!", "打印只借用字符串");
+}
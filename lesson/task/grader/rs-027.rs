@@ -0,0 +1,22 @@
+// title: 可变引用
+// chapter: 7
+// api: pub fn inc(x: &mut i32)
+// api: pub fn write(s: &mut String)
+// api: pub fn add(x: &i32, y: &i32) -> i32
+// timeout: 5
+
+#[test]
+fn mutable_references_change_the_value() {
+    let mut x = 1;
+    inc(&mut x);
+    inc(&mut x);
+    assert_eq!(x, 3);
+    let mut s = "rust".to_string();
+    write(&mut s);
+    assert_eq!(s, "rust!");
+}
+
+#[test]
+fn add_reads_through_references() {
+    assert_eq!(add(&2, &-5), -3);
+}
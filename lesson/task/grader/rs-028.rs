@@ -0,0 +1,20 @@
+// title: Vec 的遍历
+// chapter: 9
+// api: pub fn max(v: &Vec<i32>) -> Option<i32>
+// api: pub fn reverse(v: &Vec<i32>) -> Vec<i32>
+// timeout: 5
+
+#[test]
+fn max_of_empty_vec_is_none() {
+    assert_eq!(max(&vec![]), None);
+    assert_eq!(max(&vec![3, -1, 7, 7, 2]), Some(7));
+    assert_eq!(max(&vec![i32::MIN]), Some(i32::MIN));
+}
+
+#[test]
+fn reverse_returns_a_new_vec() {
+    let v = vec![1, 2, 3];
+    assert_eq!(reverse(&v), vec![3, 2, 1]);
+    assert_eq!(v, vec![1, 2, 3], "原来的 Vec 不变");
+    assert_eq!(reverse(&vec![]), vec![]);
+}
@@ -0,0 +1,18 @@
+// title: HashMap 计分
+// chapter: 9
+// api: pub fn tally(scores: &Vec<(String, u32)>) -> HashMap<String, u32>
+// timeout: 5
+
+use std::collections::HashMap;
+
+#[test]
+fn tally_sums_scores_per_team() {
+    let scores = vec![
+        ("red".to_string(), 3),
+        ("blue".to_string(), 1),
+        ("red".to_string(), 2),
+    ];
+    let expected = HashMap::from([("red".to_string(), 5), ("blue".to_string(), 1)]);
+    assert_eq!(tally(&scores), expected);
+    assert!(tally(&vec![]).is_empty());
+}
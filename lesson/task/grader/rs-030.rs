@@ -0,0 +1,10 @@
+// title: HashSet 去重
+// chapter: 9
+// api: pub fn remove_dup(vals: Vec<i32>) -> Vec<i32>
+// timeout: 5
+
+#[test]
+fn keeps_the_first_occurrence_in_order() {
+    assert_eq!(remove_dup(vec![3, 1, 3, 2, 1]), vec![3, 1, 2]);
+    assert_eq!(remove_dup(vec![]), vec![]);
+}
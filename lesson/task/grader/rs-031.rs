@@ -0,0 +1,48 @@
+// title: 代币特征 Money
+// chapter: 11
+// api: pub enum MoneyError
+// api: pub trait Money
+// api: pub struct GoldCoin
+// api: pub fn balance_of(money: &impl Money, account: &str) -> u32
+// api: pub fn mint(money: &mut impl Money, dst: &str, amount: u32)
+// api: pub fn transfer(money: &mut impl Money, src: &str, dst: &str, amount: u32) -> Result<(), MoneyError>
+// timeout: 5
+
+use std::collections::HashMap;
+
+fn coin() -> GoldCoin {
+    GoldCoin {
+        balances: HashMap::new(),
+    }
+}
+
+#[test]
+fn unknown_accounts_have_zero_balance() {
+    assert_eq!(balance_of(&coin(), "alice"), 0);
+}
+
+#[test]
+fn mint_adds_to_the_balance() {
+    let mut coin = coin();
+    mint(&mut coin, "alice", 100);
+    mint(&mut coin, "alice", 20);
+    assert_eq!(balance_of(&coin, "alice"), 120);
+}
+
+#[test]
+fn transfer_moves_the_amount() {
+    let mut coin = coin();
+    mint(&mut coin, "alice", 100);
+    assert_eq!(transfer(&mut coin, "alice", "bob", 30), Ok(()));
+    assert_eq!(balance_of(&coin, "alice"), 70, "转出方余额应减少");
+    assert_eq!(balance_of(&coin, "bob"), 30, "转入方余额应增加");
+}
+
+#[test]
+fn transfer_rejects_insufficient_balance() {
+    let mut coin = coin();
+    mint(&mut coin, "alice", 10);
+    assert_eq!(transfer(&mut coin, "alice", "bob", 11), Err(MoneyError::InsufficientBalance));
+    assert_eq!(balance_of(&coin, "alice"), 10, "失败的转账不能改变余额");
+    assert_eq!(balance_of(&coin, "bob"), 0);
+}
@@ -0,0 +1,10 @@
+// title: 派生 Default
+// chapter: 24
+// api: pub enum Animal
+// timeout: 5
+
+#[test]
+fn default_animal_is_a_cat() {
+    assert_eq!(Animal::default(), Animal::Cat);
+    assert_ne!(Animal::Dog, Animal::Mouse);
+}
@@ -0,0 +1,29 @@
+// title: Clone 与 Copy
+// chapter: 24
+// api: pub struct Rectangle
+// api: pub struct Book
+// api: pub struct Transaction
+// api: pub struct Block
+// timeout: 5
+
+#[test]
+fn copy_types_stay_usable_after_assignment() {
+    let rect = Rectangle { top: 1, left: 2, height: 3, width: 4 };
+    let moved = rect;
+    assert_eq!((rect.top, moved.width), (1, 4));
+    let tx = Transaction { hash: [1; 32], sender: [2; 20], receiver: [3; 20], value: 5 };
+    let copy = tx;
+    assert_eq!((tx.value, copy.sender), (5, [2; 20]));
+}
+
+#[test]
+fn clone_types_copy_their_heap_data() {
+    let book = Book { id: 1, title: "Rust".to_string(), author: "Ferris".to_string() };
+    let cloned = book.clone();
+    assert_eq!((cloned.id, cloned.title.as_str(), cloned.author.as_str()), (1, "Rust", "Ferris"));
+    assert_eq!(book.title, "Rust");
+    let tx = Transaction { hash: [0; 32], sender: [0; 20], receiver: [0; 20], value: 9 };
+    let block = Block { hash: [7; 32], number: 2, timestamp: 3, transactions: vec![tx] };
+    let cloned = block.clone();
+    assert_eq!((cloned.number, cloned.transactions.len(), block.transactions[0].value), (2, 1, 9));
+}
@@ -0,0 +1,20 @@
+// title: 特征对象
+// chapter: 23
+// api: pub trait Compiler
+// api: pub enum ParseError
+// api: pub fn compile(lang: &dyn Compiler) -> String
+// api: pub fn parse(lang: &str) -> Result<Box<dyn Compiler>, ParseError>
+// timeout: 5
+
+#[test]
+fn compile_dispatches_on_the_language() {
+    assert_eq!(compile(&Rust), "rustc");
+    assert_eq!(compile(&Solidity), "solc");
+}
+
+#[test]
+fn parse_returns_boxed_compilers() {
+    assert_eq!(parse("rust").map(|lang| lang.compile()), Ok("rustc".to_string()));
+    assert_eq!(parse("solidity").map(|lang| lang.compile()), Ok("solc".to_string()));
+    assert!(matches!(parse("vyper"), Err(ParseError::InvalidLanguage)), "未知语言返回 InvalidLanguage");
+}
@@ -0,0 +1,15 @@
+// title: 特征继承
+// chapter: 11
+// api: pub trait Animal
+// api: pub trait Fly
+// api: pub struct Crow
+// api: pub trait Bird: Animal + Fly
+// timeout: 5
+
+#[test]
+fn crow_uses_the_default_method() {
+    assert_eq!(Crow.name(), "crow");
+    assert_eq!(Crow.sound(), "caw!");
+    assert_eq!(Crow.fly(), "flap wings");
+    assert_eq!(Crow.fly_and_make_sound(), "crow: caw! flap wings");
+}
@@ -0,0 +1,15 @@
+// title: 同名方法
+// chapter: 11
+// api: pub trait Color
+// api: pub trait Shape
+// api: pub struct Circle
+// api: pub fn get_color(circle: &Circle) -> &str
+// api: pub fn get_shape(circle: &Circle) -> &str
+// timeout: 5
+
+#[test]
+fn calls_each_trait_method_explicitly() {
+    let circle = Circle { color: "red".to_string(), radius: 1 };
+    assert_eq!(get_color(&circle), "red");
+    assert_eq!(get_shape(&circle), "circle");
+}
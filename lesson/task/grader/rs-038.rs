@@ -0,0 +1,23 @@
+// title: 泛型类型
+// chapter: 11
+// api: pub struct Container<T>
+// api: pub enum Either<L, R>
+// api: pub struct Graph<T>
+// timeout: 5
+
+#[test]
+fn generic_types_hold_any_type() {
+    let container = Container { value: "rust" };
+    assert_eq!(container.value, "rust");
+    let values: Vec<Either<u32, &str>> = vec![Either::Left(1), Either::Right("a")];
+    let described: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Either::Left(n) => n.to_string(),
+            Either::Right(s) => s.to_string(),
+        })
+        .collect();
+    assert_eq!(described, ["1", "a"]);
+    let graph = Graph { vertices: vec!['a', 'b'], edges: vec![('a', 'b')] };
+    assert_eq!((graph.vertices.len(), graph.edges[0]), (2, ('a', 'b')));
+}
@@ -0,0 +1,24 @@
+// title: 泛型函数
+// chapter: 11
+// api: pub fn find<T: PartialEq>(s: &[T], x: T) -> Option<usize>
+// api: pub fn get_or_default<T>(x: Option<T>, val: T) -> T
+// api: pub fn zip<A: Copy, B: Copy>(x: &[A], y: &[B]) -> Vec<(A, B)>
+// timeout: 5
+
+#[test]
+fn find_works_for_any_comparable_type() {
+    assert_eq!(find(&[1, 2, 3], 3), Some(2));
+    assert_eq!(find(&["a", "b"], "c"), None);
+}
+
+#[test]
+fn get_or_default_prefers_the_value() {
+    assert_eq!(get_or_default(Some("x"), "y"), "x");
+    assert_eq!(get_or_default(None, 5), 5);
+}
+
+#[test]
+fn zip_stops_at_the_shorter_slice() {
+    assert_eq!(zip(&[1, 2, 3], &['a', 'b']), vec![(1, 'a'), (2, 'b')]);
+    assert_eq!(zip::<u8, u8>(&[], &[1]), vec![]);
+}
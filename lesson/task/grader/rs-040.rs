@@ -0,0 +1,15 @@
+// title: 泛型方法
+// chapter: 11
+// api: pub struct Rectangle<T>
+// api: pub fn resize(&mut self, width: T, height: T)
+// timeout: 5
+
+#[test]
+fn resize_keeps_the_position() {
+    let mut rect = Rectangle { top: 1, left: 2, width: 3, height: 4 };
+    rect.resize(10, 20);
+    assert_eq!((rect.top, rect.left, rect.width, rect.height), (1, 2, 10, 20));
+    let mut rect = Rectangle { top: 0.0, left: 0.0, width: 1.0, height: 1.0 };
+    rect.resize(0.5, 2.5);
+    assert_eq!((rect.width, rect.height), (0.5, 2.5));
+}
@@ -0,0 +1,11 @@
+// title: 泛型特征
+// chapter: 11
+// api: pub trait First<T>
+// timeout: 5
+
+#[test]
+fn first_of_vec_and_tuple() {
+    assert_eq!(First::first(&vec![3, 4]), Some(&3));
+    assert_eq!(First::first(&Vec::<u8>::new()), None);
+    assert_eq!(First::first(&("a", 1, true)), Some(&"a"));
+}
@@ -0,0 +1,22 @@
+// title: From 与 Into
+// chapter: 24
+// api: pub struct Cat
+// api: pub struct Pair<A, B>(pub A, pub B)
+// timeout: 5
+
+#[test]
+fn cats_from_tuples() {
+    let cat = Cat::from(("Tom", 3, "chase mice"));
+    assert_eq!((cat.name.as_str(), cat.age, cat.hobbies.clone()), ("Tom", 3, vec!["chase mice".to_string()]));
+    let cat: Cat = ("Kitty", 1).into();
+    assert_eq!((cat.name.as_str(), cat.age), ("Kitty", 1));
+    assert_eq!(cat.hobbies, ["sleep in a box"]);
+}
+
+#[test]
+fn pairs_from_tuples_and_numbers() {
+    let pair = Pair::from(("a", 2));
+    assert_eq!((pair.0, pair.1), ("a", 2));
+    let pair: Pair<u32, u32> = 7u32.into();
+    assert_eq!((pair.0, pair.1), (7, 7));
+}
@@ -0,0 +1,28 @@
+// title: 特征约束
+// chapter: 11
+// api: pub fn sum<T: Default + Copy + AddAssign>(vals: &[T]) -> T
+// api: pub struct Graph<T>
+// api: pub fn graph_to_hash_set<T>(g: Graph<T>) -> HashMap<T, HashSet<T>>
+// timeout: 5
+
+use std::collections::HashSet;
+
+#[test]
+fn sum_of_any_addable_type() {
+    assert_eq!(sum(&[1, 2, 3]), 6);
+    assert_eq!(sum(&[0.5, 0.25]), 0.75);
+    assert_eq!(sum::<u8>(&[]), 0);
+}
+
+#[test]
+fn adjacency_sets_from_edges() {
+    let graph = Graph {
+        vertices: HashSet::from([1, 2, 3]),
+        edges: HashSet::from([(1, 2), (1, 3), (2, 3)]),
+    };
+    let map = graph_to_hash_set(graph);
+    assert_eq!(map.len(), 3, "没有出边的顶点也要出现");
+    assert_eq!(map[&1], HashSet::from([2, 3]));
+    assert_eq!(map[&2], HashSet::from([3]));
+    assert!(map[&3].is_empty());
+}
@@ -0,0 +1,14 @@
+// title: ?Sized
+// chapter: 11
+// api: pub struct Wrap<T: ?Sized>
+// api: pub fn wrap<T: ?Sized>(value: Box<T>) -> Wrap<T>
+// timeout: 5
+
+#[test]
+fn wraps_unsized_values() {
+    let wrapped: Wrap<str> = wrap(Box::from("rust"));
+    assert_eq!(&*wrapped.value, "rust");
+    let wrapped: Wrap<[u32]> = wrap(Box::from(&[1, 2][..]));
+    assert_eq!(wrapped.value.len(), 2);
+    assert_eq!(*wrap(Box::new(5)).value, 5);
+}
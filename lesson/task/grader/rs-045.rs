@@ -0,0 +1,17 @@
+// title: 静态分发与动态分发
+// chapter: 23
+// api: pub trait Animal
+// api: pub fn static_dispatch<A: Animal>(a: &A) -> &str
+// api: pub fn call_static() -> [String; 2]
+// api: pub fn dynamic_dispatch(a: &dyn Animal) -> &str
+// api: pub fn call_dynamic(animal_str: &str) -> String
+// timeout: 5
+
+#[test]
+fn both_dispatches_reach_the_right_impl() {
+    assert_eq!(static_dispatch(&Cat), "meow");
+    assert_eq!(dynamic_dispatch(&Dog), "woof");
+    assert_eq!(call_static(), ["meow".to_string(), "woof".to_string()]);
+    assert_eq!(call_dynamic("cat"), "meow");
+    assert_eq!(call_dynamic("dog"), "woof");
+}
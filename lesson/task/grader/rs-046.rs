@@ -0,0 +1,15 @@
+// title: 关联类型
+// chapter: 11
+// api: pub trait Iterator
+// api: pub struct VecIter<T>
+// api: pub fn new(v: Vec<T>) -> Self
+// timeout: 5
+
+#[test]
+fn vec_iter_yields_each_item_once() {
+    let mut iter = VecIter::new(vec![1, 2]);
+    assert_eq!(Iterator::next(&mut iter), Some(1));
+    assert_eq!(Iterator::next(&mut iter), Some(2));
+    assert_eq!(Iterator::next(&mut iter), None);
+    assert_eq!(Iterator::next(&mut iter), None, "结束后继续返回 None");
+}
@@ -0,0 +1,17 @@
+// title: 运算符重载
+// chapter: 24
+// api: pub struct C<T>
+// timeout: 5
+
+#[test]
+fn adds_and_multiplies_complex_numbers() {
+    let a = C { re: 1, im: 2 };
+    let b = C { re: 3, im: -4 };
+    let sum = a + b;
+    assert_eq!((sum.re, sum.im), (4, -2));
+    let product = a * b;
+    assert_eq!((product.re, product.im), (11, 2), "(1 + 2i)(3 - 4i) = 11 + 2i");
+    let i = C { re: 0.0, im: 1.0 };
+    let square = i * i;
+    assert_eq!((square.re, square.im), (-1.0, 0.0));
+}
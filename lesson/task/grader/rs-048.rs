@@ -0,0 +1,23 @@
+// title: 迭代器的三种形式
+// chapter: 16
+// api: pub fn key_vals(map: HashMap<String, u32>) -> Vec<(String, u32)>
+// api: pub fn repeat(v: Vec<u32>, n: u32) -> Vec<u32>
+// api: pub fn mul_by_2(mut v: Vec<u32>) -> Vec<u32>
+// timeout: 5
+
+use std::collections::HashMap;
+
+#[test]
+fn key_vals_takes_every_entry() {
+    let map = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+    let mut kvs = key_vals(map);
+    kvs.sort();
+    assert_eq!(kvs, [("a".to_string(), 1), ("b".to_string(), 2)]);
+}
+
+#[test]
+fn repeat_and_double() {
+    assert_eq!(repeat(vec![1, 2], 3), vec![1, 2, 1, 2, 1, 2]);
+    assert_eq!(repeat(vec![1], 0), vec![]);
+    assert_eq!(mul_by_2(vec![0, 4, 5]), vec![0, 8, 10]);
+}
@@ -0,0 +1,28 @@
+// title: 迭代器适配器
+// chapter: 16
+// api: pub fn plus_one(v: Vec<u32>) -> Vec<u32>
+// api: pub fn not_zero(v: Vec<u32>) -> Vec<u32>
+// api: pub struct Point
+// api: pub fn zip(xs: Vec<u32>, ys: Vec<u32>) -> Vec<Point>
+// api: pub fn factorial(n: u32) -> u32
+// timeout: 5
+
+#[test]
+fn map_and_filter() {
+    assert_eq!(plus_one(vec![0, 1, 2]), vec![1, 2, 3]);
+    assert_eq!(not_zero(vec![0, 3, 0, 4]), vec![3, 4]);
+}
+
+#[test]
+fn zip_builds_points() {
+    let points = zip(vec![1, 2, 3], vec![4, 5]);
+    assert_eq!(points.len(), 2, "元素个数取较短的一边");
+    assert_eq!((points[1].x, points[1].y), (2, 5));
+}
+
+#[test]
+fn factorial_folds_the_range() {
+    assert_eq!(factorial(0), 1);
+    assert_eq!(factorial(5), 120);
+    assert_eq!(factorial(10), 3_628_800);
+}
@@ -0,0 +1,10 @@
+// title: while let
+// chapter: 4
+// api: pub fn take_and_sum(mut v: Vec<u32>) -> u32
+// timeout: 5
+
+#[test]
+fn sums_until_empty() {
+    assert_eq!(take_and_sum(vec![1, 2, 3, 4]), 10);
+    assert_eq!(take_and_sum(vec![]), 0);
+}
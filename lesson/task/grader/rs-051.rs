@@ -0,0 +1,23 @@
+// title: 生命周期标注
+// chapter: 12
+// api: pub fn shortest_vec<'a>(x: &'a Vec<u32>, y: &'a Vec<u32>) -> &'a Vec<u32>
+// api: pub fn shortest_vec_generic<'a, T>(x: &'a Vec<T>, y: &'a Vec<T>) -> &'a Vec<T>
+// api: pub struct ServerConfig<'a>
+// timeout: 5
+
+#[test]
+fn shortest_prefers_the_first_on_ties() {
+    let (a, b) = (vec![1, 2], vec![3]);
+    assert!(std::ptr::eq(shortest_vec(&a, &b), &b));
+    let c = vec![4, 5];
+    assert!(std::ptr::eq(shortest_vec(&a, &c), &a), "长度相同时返回第一个");
+    let (x, y) = (vec!["a"], vec!["b", "c"]);
+    assert!(std::ptr::eq(shortest_vec_generic(&x, &y), &x));
+}
+
+#[test]
+fn server_config_borrows_its_strings() {
+    let host = String::from("localhost");
+    let config = ServerConfig::new(&host, 8080, "postgres://db");
+    assert_eq!((config.host, config.port, config.db_url), ("localhost", 8080, "postgres://db"));
+}
@@ -0,0 +1,23 @@
+// title: 函数指针
+// chapter: 15
+// api: pub fn add(x: u32, y: u32) -> u32
+// api: pub fn sub(x: u32, y: u32) -> u32
+// api: pub fn op(s: &str) -> Option<fn(u32, u32) -> u32>
+// api: pub fn map<T: Copy>(v: Vec<T>, f: fn(T) -> T) -> Vec<T>
+// timeout: 5
+
+#[test]
+fn op_looks_up_functions_by_name() {
+    assert_eq!(op("add").map(|f| f(5, 3)), Some(8));
+    assert_eq!(op("sub").map(|f| f(5, 3)), Some(2));
+    assert!(op("mul").is_none());
+}
+
+#[test]
+fn map_applies_the_function_pointer() {
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+    assert_eq!(map(vec![1, -2, 3], double), vec![2, -4, 6]);
+    assert_eq!(map(vec!['a'], |c: char| c.to_ascii_uppercase()), vec!['A']);
+}
@@ -0,0 +1,10 @@
+// title: 闭包作为参数
+// chapter: 15
+// api: pub fn map_plus_one(v: Vec<u32>) -> Vec<u32>
+// timeout: 5
+
+#[test]
+fn adds_one_to_each_element() {
+    assert_eq!(map_plus_one(vec![0, 1, 41]), vec![1, 2, 42]);
+    assert_eq!(map_plus_one(vec![]), vec![]);
+}
@@ -0,0 +1,15 @@
+// title: 闭包捕获变量
+// chapter: 15
+// api: pub fn borrow(s: &String) -> String
+// api: pub fn borrow_mut(s: &mut String)
+// api: pub fn take(s: String) -> String
+// timeout: 5
+
+#[test]
+fn closures_borrow_or_move() {
+    let mut s = "rust".to_string();
+    assert_eq!(borrow(&s), "hello rust");
+    borrow_mut(&mut s);
+    assert_eq!(s, "rust world");
+    assert_eq!(take(s), "rust world");
+}
@@ -0,0 +1,19 @@
+// title: Fn、FnMut 与 FnOnce
+// chapter: 15
+// api: pub fn get<F: Fn() -> usize>(f: F) -> usize
+// api: pub fn push<F: FnMut()>(mut f: F)
+// api: pub fn take<F: FnOnce()>(f: F)
+// timeout: 5
+
+#[test]
+fn calls_each_kind_of_closure() {
+    let v = vec![1, 2, 3];
+    assert_eq!(get(|| v.len()), 3);
+    let mut pushed = vec![];
+    push(|| pushed.push(1));
+    assert_eq!(pushed, [1]);
+    let owned = vec![4];
+    let mut taken = vec![];
+    take(|| taken = owned);
+    assert_eq!(taken, [4]);
+}
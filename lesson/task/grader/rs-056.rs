@@ -0,0 +1,25 @@
+// title: 返回闭包
+// chapter: 15
+// api: pub fn make_add() -> impl Fn(u32, u32) -> u32
+// api: pub fn make_append_emoji(mut s: String) -> impl FnMut() -> String
+// api: pub fn once(mut v: Vec<u32>) -> impl FnOnce() -> Vec<u32>
+// timeout: 5
+
+#[test]
+fn make_add_adds() {
+    let add = make_add();
+    assert_eq!(add(2, 3), 5);
+    assert_eq!(add(0, 0), 0);
+}
+
+#[test]
+fn append_emoji_keeps_state_between_calls() {
+    let mut append = make_append_emoji(String::from("rust"));
+    assert_eq!(append(), "rust🦀");
+    assert_eq!(append(), "rust🦀🦀", "闭包要保留上一次的结果");
+}
+
+#[test]
+fn once_pushes_one() {
+    assert_eq!(once(vec![3])(), vec![3, 1]);
+}
@@ -0,0 +1,23 @@
+// title: Box
+// chapter: 17
+// api: pub fn wrap(val: u32) -> Box<u32>
+// api: pub fn unwrap(x: Box<u32>) -> u32
+// api: pub struct Tree
+// api: pub fn make_tree() -> Tree
+// timeout: 5
+
+#[test]
+fn wrap_and_unwrap_round_trip() {
+    assert_eq!(*wrap(5), 5);
+    assert_eq!(unwrap(Box::new(6)), 6);
+}
+
+#[test]
+fn tree_has_two_leaves() {
+    let tree = make_tree();
+    assert_eq!(tree.val, 1);
+    let left = tree.left.expect("根节点有左子树");
+    let right = tree.right.expect("根节点有右子树");
+    assert_eq!((left.val, right.val), (2, 3));
+    assert!(left.left.is_none() && left.right.is_none() && right.left.is_none() && right.right.is_none());
+}
@@ -0,0 +1,17 @@
+// title: Rc 共享所有权
+// chapter: 18
+// api: pub struct Node
+// api: pub fn make() -> Vec<Rc<Node>>
+// timeout: 5
+
+use std::rc::Rc;
+
+#[test]
+fn node_0_is_shared_by_both_neighbors() {
+    let nodes = make();
+    assert_eq!(nodes.iter().map(|node| node.val).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(Rc::strong_count(&nodes[0]), 3, "节点 0 被列表和两个邻居共同持有");
+    assert!(Rc::ptr_eq(&nodes[1].neighbors[0], &nodes[0]));
+    assert!(Rc::ptr_eq(&nodes[2].neighbors[0], &nodes[0]));
+    assert!(nodes[0].neighbors.is_empty());
+}
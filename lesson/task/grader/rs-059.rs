@@ -0,0 +1,17 @@
+// title: RefCell 内部可变性
+// chapter: 18
+// api: pub struct Node
+// api: pub fn make() -> Vec<Rc<Node>>
+// timeout: 5
+
+use std::rc::Rc;
+
+#[test]
+fn neighbors_are_added_after_creation() {
+    let nodes = make();
+    let neighbors = |index: usize| nodes[index].neighbors.borrow().iter().map(|node| node.val).collect::<Vec<_>>();
+    assert_eq!(neighbors(0), [1, 2]);
+    assert_eq!(neighbors(1), [2]);
+    assert!(neighbors(2).is_empty());
+    assert_eq!(Rc::strong_count(&nodes[2]), 3);
+}
@@ -0,0 +1,23 @@
+// title: Weak 引用
+// chapter: 18
+// api: pub struct Node
+// api: pub fn make() -> Vec<Rc<Node>>
+// api: pub fn walk(mut nodes: Vec<Rc<Node>>) -> Vec<u32>
+// timeout: 5
+
+#[test]
+fn make_builds_a_weak_cycle() {
+    let nodes = make();
+    assert_eq!(nodes.len(), 3);
+    for (index, node) in nodes.iter().enumerate() {
+        assert_eq!(node.val, index as u32);
+        assert_eq!(std::rc::Rc::strong_count(node), 1, "邻居只能持有 Weak 引用");
+        let next = node.neighbors.borrow()[0].upgrade().expect("邻居还活着");
+        assert_eq!(next.val, (index as u32 + 1) % 3);
+    }
+}
+
+#[test]
+fn walk_stops_at_dropped_nodes() {
+    assert_eq!(walk(make()), vec![2, 0, 1], "节点 2 出队后就被释放，回到它的 Weak 无法升级");
+}
@@ -0,0 +1,16 @@
+// title: 创建线程
+// chapter: 19
+// api: pub fn sleep(ms: u64) -> JoinHandle<u32>
+// api: pub fn add(h1: JoinHandle<u32>, h2: JoinHandle<u32>) -> u32
+// timeout: 5
+
+use std::time::{Duration, Instant};
+
+#[test]
+fn threads_sleep_concurrently() {
+    let start = Instant::now();
+    assert_eq!(add(sleep(500), sleep(500)), 2);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(500), "要等线程结束");
+    assert!(elapsed < Duration::from_millis(990), "两个线程应该同时运行，用时 {:?}", elapsed);
+}
@@ -0,0 +1,13 @@
+// title: 作用域线程求和
+// chapter: 19
+// api: pub fn par_sum(v: &Vec<u32>) -> u32
+// timeout: 10
+
+#[test]
+fn par_sum_matches_the_sequential_sum() {
+    let v: Vec<u32> = (1..=1000).collect();
+    assert_eq!(par_sum(&v), 500_500);
+    assert_eq!(par_sum(&vec![7]), 7, "只有一个元素时也要正确");
+    assert_eq!(par_sum(&vec![]), 0);
+    assert_eq!(v.len(), 1000, "调用之后仍然可以使用原来的 Vec");
+}
@@ -0,0 +1,11 @@
+// title: 通道
+// chapter: 19
+// api: pub fn make_and_send() -> Receiver<String>
+// timeout: 5
+
+#[test]
+fn receives_messages_in_order() {
+    let rx = make_and_send();
+    let received: Vec<String> = rx.iter().collect();
+    assert_eq!(received, ["hello", "rust"], "发送方结束后通道关闭");
+}
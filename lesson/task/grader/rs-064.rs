@@ -0,0 +1,11 @@
+// title: Mutex
+// chapter: 19
+// api: pub fn count() -> u32
+// timeout: 10
+
+#[test]
+fn both_threads_increment_the_counter() {
+    for _ in 0..100 {
+        assert_eq!(count(), 2);
+    }
+}
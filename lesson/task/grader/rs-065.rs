@@ -0,0 +1,16 @@
+// title: Arc 与 Mutex
+// chapter: 19
+// api: pub fn count() -> u32
+// api: pub fn par_sum(v: Vec<u32>) -> u32
+// timeout: 10
+
+#[test]
+fn count_from_ten_threads() {
+    assert_eq!(count(), 10);
+}
+
+#[test]
+fn par_sum_shares_the_vec() {
+    assert_eq!(par_sum((1..=100).collect()), 5050);
+    assert_eq!(par_sum(vec![]), 0);
+}
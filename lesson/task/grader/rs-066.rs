@@ -0,0 +1,12 @@
+// title: Send 与 Sync
+// chapter: 19
+// api: pub fn send_and_sync() -> (Arc<Mutex<Vec<u32>>>, JoinHandle<()>)
+// timeout: 5
+
+#[test]
+fn the_thread_pushes_into_the_shared_vec() {
+    let (data, handle) = send_and_sync();
+    handle.join().unwrap();
+    assert_eq!(*data.lock().unwrap(), [1, 2, 3, 4]);
+    assert_eq!(std::sync::Arc::strong_count(&data), 1, "线程结束后只剩一份引用");
+}
@@ -37,7 +37,7 @@ fn main() {
 }
 
 // 4.1 引用与借用规则
-fn reference_and_borrowing() {
+pub(crate) fn reference_and_borrowing() {
     println!("什么是借用？");
     println!("借用允许你访问数据而不获取其所有权");
     
@@ -99,7 +99,7 @@ fn reference_scope_demo() {
 }
 
 // 4.2 验证借用规则
-fn verify_borrowing_rules() {
+pub(crate) fn verify_borrowing_rules() {
     println!("规则验证:");
     
     println!("\n规则一：不可变引用和可变引用不能同时存在");
@@ -184,7 +184,7 @@ fn reference_to_reference() {
 }
 
 // 4.3 切片
-fn slices_demo() {
+pub(crate) fn slices_demo() {
     println!("字符串切片:");
     string_slices();
     
@@ -265,7 +265,7 @@ fn mutable_slices() {
 }
 
 // 4.4 悬垂引用
-fn dangling_references() {
+pub(crate) fn dangling_references() {
     println!("什么是悬垂引用？");
     println!("悬垂引用指向的内存可能已经被分配给其他人");
     
@@ -40,7 +40,7 @@ fn main() {
 // 1. 闭包基础
 // ============================================================================
 
-fn closure_basics() {
+pub(crate) fn closure_basics() {
     println!("\n🎯 1. 闭包基础");
     println!("{}", "-".repeat(40));
     
@@ -77,7 +77,7 @@ fn closure_basics() {
 // 2. 闭包语法和类型推断
 // ============================================================================
 
-fn closure_syntax() {
+pub(crate) fn closure_syntax() {
     println!("\n📝 2. 闭包语法和类型推断");
     println!("{}", "-".repeat(40));
     
@@ -130,7 +130,7 @@ fn closure_syntax() {
 // 3. 捕获环境
 // ============================================================================
 
-fn capture_environment() {
+pub(crate) fn capture_environment() {
     println!("\n🎪 3. 捕获环境");
     println!("{}", "-".repeat(40));
     
@@ -183,7 +183,7 @@ fn capture_environment() {
 // 4. 闭包特征：Fn、FnMut、FnOnce
 // ============================================================================
 
-fn closure_traits() {
+pub(crate) fn closure_traits() {
     println!("\n🎭 4. 闭包特征：Fn、FnMut、FnOnce");
     println!("{}", "-".repeat(40));
     
@@ -224,7 +224,7 @@ fn closure_traits() {
 // 5. 闭包作为参数
 // ============================================================================
 
-fn closures_as_parameters() {
+pub(crate) fn closures_as_parameters() {
     println!("\n📤 5. 闭包作为参数");
     println!("{}", "-".repeat(40));
     
@@ -301,7 +301,7 @@ fn closures_as_parameters() {
 // 6. 返回闭包
 // ============================================================================
 
-fn returning_closures() {
+pub(crate) fn returning_closures() {
     println!("\n📥 6. 返回闭包");
     println!("{}", "-".repeat(40));
     
@@ -374,7 +374,7 @@ fn practical_examples() {
     strategy_pattern_example();
 }
 
-fn cache_example() {
+pub(crate) fn cache_example() {
     println!("\n  🔸 缓存/记忆化示例：");
     
     struct Cacher<T>
@@ -425,7 +425,7 @@ fn cache_example() {
     println!("    结果: {}", result2);
 }
 
-fn event_handling_example() {
+pub(crate) fn event_handling_example() {
     println!("\n  🔸 事件处理示例：");
     
     struct EventHandler {
@@ -476,7 +476,7 @@ fn event_handling_example() {
     event_handler.trigger_event("用户登录");
 }
 
-fn functional_programming_example() {
+pub(crate) fn functional_programming_example() {
     println!("\n  🔸 函数式编程示例：");
     
     let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
@@ -517,7 +517,7 @@ fn functional_programming_example() {
     println!("    长单词 (大写): {:?}", long_words);
 }
 
-fn strategy_pattern_example() {
+pub(crate) fn strategy_pattern_example() {
     println!("\n  🔸 策略模式示例：");
     
     struct Calculator {
@@ -33,7 +33,7 @@ fn main() {
 // 1. Cow (Clone on Write)
 // ============================================================================
 
-fn cow_demo() {
+pub(crate) fn cow_demo() {
     println!("\n🐄 1. Cow (Clone on Write)");
     println!("{}", "-".repeat(40));
     
@@ -175,7 +175,7 @@ fn cow_demo() {
 // 2. Weak 弱引用
 // ============================================================================
 
-fn weak_reference_demo() {
+pub(crate) fn weak_reference_demo() {
     println!("\n🔗 2. Weak 弱引用");
     println!("{}", "-".repeat(40));
     
@@ -337,7 +337,7 @@ fn weak_reference_demo() {
 // 3. Pin 固定指针
 // ============================================================================
 
-fn pin_demo() {
+pub(crate) fn pin_demo() {
     println!("\n📌 3. Pin 固定指针");
     println!("{}", "-".repeat(40));
     
@@ -527,7 +527,7 @@ fn practical_examples() {
     async_task_management();
 }
 
-fn config_system_example() {
+pub(crate) fn config_system_example() {
     println!("\n  🔸 配置系统示例：");
     
     use std::collections::HashMap;
@@ -578,7 +578,7 @@ fn config_system_example() {
     println!("    端口: {:?}", config.get("port"));
 }
 
-fn improved_observer_pattern() {
+pub(crate) fn improved_observer_pattern() {
     println!("\n  🔸 改进的观察者模式：");
     
     trait Observer {
@@ -647,7 +647,7 @@ fn improved_observer_pattern() {
     event_manager.cleanup();
 }
 
-fn async_task_management() {
+pub(crate) fn async_task_management() {
     println!("\n  🔸 异步任务管理示例：");
     
     use std::collections::VecDeque;
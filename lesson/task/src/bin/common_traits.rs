@@ -43,7 +43,7 @@ fn main() {
 }
 
 /// 11.1 Display 和 Debug 特征
-fn display_debug_traits() {
+pub(crate) fn display_debug_traits() {
     println!("📝 11.1 Display 和 Debug 特征");
     println!("{}", "-".repeat(30));
     
@@ -99,7 +99,7 @@ fn display_debug_traits() {
 }
 
 /// 11.2 Clone 和 Copy 特征
-fn clone_copy_traits() {
+pub(crate) fn clone_copy_traits() {
     println!("📝 11.2 Clone 和 Copy 特征");
     println!("{}", "-".repeat(30));
     
@@ -166,7 +166,7 @@ fn clone_copy_traits() {
 }
 
 /// 11.3 PartialEq 和 Eq 特征
-fn equality_traits() {
+pub(crate) fn equality_traits() {
     println!("📝 11.3 PartialEq 和 Eq 特征");
     println!("{}", "-".repeat(30));
     
@@ -223,7 +223,7 @@ fn equality_traits() {
 }
 
 /// 11.4 PartialOrd 和 Ord 特征
-fn ordering_traits() {
+pub(crate) fn ordering_traits() {
     println!("📝 11.4 PartialOrd 和 Ord 特征");
     println!("{}", "-".repeat(30));
     
@@ -287,7 +287,7 @@ fn ordering_traits() {
 }
 
 /// 11.5 Hash 特征
-fn hash_trait() {
+pub(crate) fn hash_trait() {
     println!("📝 11.5 Hash 特征");
     println!("{}", "-".repeat(30));
     
@@ -341,7 +341,7 @@ fn hash_trait() {
 }
 
 /// 11.6 Iterator 特征
-fn iterator_trait() {
+pub(crate) fn iterator_trait() {
     println!("📝 11.6 Iterator 特征");
     println!("{}", "-".repeat(30));
     
@@ -419,7 +419,7 @@ fn iterator_trait() {
 }
 
 /// 11.7 From 和 Into 特征
-fn conversion_traits() {
+pub(crate) fn conversion_traits() {
     println!("📝 11.7 From 和 Into 特征");
     println!("{}", "-".repeat(30));
     
@@ -498,7 +498,7 @@ fn conversion_traits() {
 }
 
 /// 11.8 Default 特征
-fn default_trait() {
+pub(crate) fn default_trait() {
     println!("📝 11.8 Default 特征");
     println!("{}", "-".repeat(30));
     
@@ -588,7 +588,7 @@ fn default_trait() {
 }
 
 /// 11.9 Drop 特征
-fn drop_trait() {
+pub(crate) fn drop_trait() {
     println!("📝 11.9 Drop 特征");
     println!("{}", "-".repeat(30));
     
@@ -31,7 +31,7 @@ fn main() {
 }
 
 // 6.1 Vector
-fn vector_demo() {
+pub(crate) fn vector_demo() {
     println!("创建 Vector:");
     create_vectors();
     
@@ -217,7 +217,7 @@ fn vector_slices() {
 }
 
 // 6.2 String
-fn string_demo() {
+pub(crate) fn string_demo() {
     println!("创建 String:");
     create_strings();
     
@@ -346,7 +346,7 @@ fn string_methods() {
 }
 
 // 6.3 类型比较
-fn type_comparison() {
+pub(crate) fn type_comparison() {
     println!("String vs &str:");
     string_vs_str();
     
@@ -39,7 +39,7 @@ fn main() {
 // 1. 线程基础
 // ============================================================================
 
-fn thread_basics() {
+pub(crate) fn thread_basics() {
     println!("\n🧵 1. 线程基础");
     println!("{}", "-".repeat(40));
     
@@ -127,7 +127,7 @@ fn thread_basics() {
 // 2. 消息传递
 // ============================================================================
 
-fn message_passing() {
+pub(crate) fn message_passing() {
     println!("\n📨 2. 消息传递");
     println!("{}", "-".repeat(40));
     
@@ -263,7 +263,7 @@ fn message_passing() {
 // 3. 共享状态
 // ============================================================================
 
-fn shared_state() {
+pub(crate) fn shared_state() {
     println!("\n🔒 3. 共享状态");
     println!("{}", "-".repeat(40));
     
@@ -361,7 +361,7 @@ fn shared_state() {
 // 4. 原子操作
 // ============================================================================
 
-fn atomic_operations() {
+pub(crate) fn atomic_operations() {
     println!("\n⚛️ 4. 原子操作");
     println!("{}", "-".repeat(40));
     
@@ -460,7 +460,7 @@ fn atomic_operations() {
 // 5. 条件变量
 // ============================================================================
 
-fn condition_variables() {
+pub(crate) fn condition_variables() {
     println!("\n🚦 5. 条件变量");
     println!("{}", "-".repeat(40));
     
@@ -573,7 +573,7 @@ fn practical_examples() {
     producer_consumer_queue();
 }
 
-fn parallel_computation() {
+pub(crate) fn parallel_computation() {
     println!("\n  🔸 并行计算示例：");
     
     let data: Vec<i32> = (1..=1000).collect();
@@ -601,12 +601,12 @@ fn parallel_computation() {
     println!("    结果一致: {}", total == sequential_sum);
 }
 
-fn thread_pool_example() {
+pub(crate) fn thread_pool_example() {
     println!("\n  🔸 线程池示例：");
     
     struct ThreadPool {
         workers: Vec<Worker>,
-        sender: mpsc::Sender<Job>,
+        sender: Option<mpsc::Sender<Job>>,
     }
     
     type Job = Box<dyn FnOnce() + Send + 'static>;
@@ -629,7 +629,10 @@ fn thread_pool_example() {
                 workers.push(Worker::new(id, Arc::clone(&receiver)));
             }
             
-            ThreadPool { workers, sender }
+            ThreadPool {
+                workers,
+                sender: Some(sender),
+            }
         }
         
         fn execute<F>(&self, f: F)
@@ -637,13 +640,14 @@ fn thread_pool_example() {
             F: FnOnce() + Send + 'static,
         {
             let job = Box::new(f);
-            self.sender.send(job).unwrap();
+            self.sender.as_ref().unwrap().send(job).unwrap();
         }
     }
     
     impl Drop for ThreadPool {
         fn drop(&mut self) {
-            drop(self.sender.clone());
+            // 关闭发送端，工作线程的 recv 返回错误后退出循环
+            drop(self.sender.take());
             
             for worker in &mut self.workers {
                 if let Some(thread) = worker.thread.take() {
@@ -689,7 +693,7 @@ fn thread_pool_example() {
     println!("    线程池示例完成");
 }
 
-fn producer_consumer_queue() {
+pub(crate) fn producer_consumer_queue() {
     println!("\n  🔸 生产者消费者队列：");
     
     use std::collections::VecDeque;
@@ -32,7 +32,7 @@ fn main() {
 // 1. 迭代器基础
 // ============================================================================
 
-fn iterator_basics() {
+pub(crate) fn iterator_basics() {
     println!("\n🎯 1. 迭代器基础");
     println!("{}", "-".repeat(40));
     
@@ -86,7 +86,7 @@ fn iterator_basics() {
 // 2. 迭代器适配器
 // ============================================================================
 
-fn iterator_adapters() {
+pub(crate) fn iterator_adapters() {
     println!("\n🔧 2. 迭代器适配器");
     println!("{}", "-".repeat(40));
     
@@ -153,7 +153,7 @@ fn iterator_adapters() {
 // 3. 消费适配器
 // ============================================================================
 
-fn consuming_adapters() {
+pub(crate) fn consuming_adapters() {
     println!("\n🍽️ 3. 消费适配器");
     println!("{}", "-".repeat(40));
     
@@ -237,7 +237,7 @@ fn consuming_adapters() {
 // 4. 自定义迭代器
 // ============================================================================
 
-fn custom_iterators() {
+pub(crate) fn custom_iterators() {
     println!("\n🛠️ 4. 自定义迭代器");
     println!("{}", "-".repeat(40));
     
@@ -337,7 +337,7 @@ fn practical_examples() {
     grouping_and_aggregation();
 }
 
-fn data_processing_pipeline() {
+pub(crate) fn data_processing_pipeline() {
     println!("\n  🔸 数据处理管道：");
     
     #[derive(Debug, Clone)]
@@ -386,7 +386,7 @@ fn data_processing_pipeline() {
     }
 }
 
-fn text_analysis() {
+pub(crate) fn text_analysis() {
     println!("\n  🔸 文本分析：");
     
     let text = "Rust is a systems programming language that runs blazingly fast, \
@@ -416,7 +416,7 @@ fn text_analysis() {
     println!("    首字母大写的单词: {:?}", capitalized_words);
 }
 
-fn grouping_and_aggregation() {
+pub(crate) fn grouping_and_aggregation() {
     println!("\n  🔸 分组和聚合：");
     
     #[derive(Debug)]
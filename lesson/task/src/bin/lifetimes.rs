@@ -31,7 +31,7 @@ fn main() {
 }
 
 /// 9.1 基本生命周期概念
-fn basic_lifetimes() {
+pub(crate) fn basic_lifetimes() {
     println!("📝 9.1 基本生命周期概念");
     println!("{}", "-".repeat(30));
     
@@ -63,7 +63,7 @@ fn basic_lifetimes() {
 }
 
 /// 9.2 函数中的生命周期
-fn function_lifetimes() {
+pub(crate) fn function_lifetimes() {
     println!("📝 9.2 函数中的生命周期");
     println!("{}", "-".repeat(30));
     
@@ -131,7 +131,7 @@ where
 }
 
 /// 9.3 结构体中的生命周期
-fn struct_lifetimes() {
+pub(crate) fn struct_lifetimes() {
     println!("📝 9.3 结构体中的生命周期");
     println!("{}", "-".repeat(30));
     
@@ -188,7 +188,7 @@ fn struct_lifetimes() {
 }
 
 /// 9.4 生命周期省略规则
-fn lifetime_elision() {
+pub(crate) fn lifetime_elision() {
     println!("📝 9.4 生命周期省略规则");
     println!("{}", "-".repeat(30));
     
@@ -229,7 +229,7 @@ fn lifetime_elision() {
 }
 
 /// 9.5 静态生命周期
-fn static_lifetimes() {
+pub(crate) fn static_lifetimes() {
     println!("📝 9.5 静态生命周期");
     println!("{}", "-".repeat(30));
     
@@ -260,7 +260,7 @@ fn static_lifetimes() {
 }
 
 /// 9.6 高级生命周期
-fn advanced_lifetimes() {
+pub(crate) fn advanced_lifetimes() {
     println!("📝 9.6 高级生命周期");
     println!("{}", "-".repeat(30));
     
@@ -57,7 +57,7 @@ fn main() {
 // 1. 宏与函数的区别
 // ============================================================================
 
-fn macro_vs_function_demo() {
+pub(crate) fn macro_vs_function_demo() {
     println!("\n📍 1. 宏与函数的区别");
     println!("{}", "-".repeat(40));
     
@@ -96,7 +96,7 @@ fn macro_vs_function_demo() {
 // 2. 基本宏语法
 // ============================================================================
 
-fn basic_macro_syntax_demo() {
+pub(crate) fn basic_macro_syntax_demo() {
     println!("\n📍 2. 基本宏语法");
     println!("{}", "-".repeat(40));
     
@@ -133,7 +133,7 @@ fn basic_macro_syntax_demo() {
 // 3. 宏参数类型
 // ============================================================================
 
-fn macro_parameter_types_demo() {
+pub(crate) fn macro_parameter_types_demo() {
     println!("\n📍 3. 宏参数类型");
     println!("{}", "-".repeat(40));
     
@@ -208,7 +208,7 @@ fn macro_parameter_types_demo() {
 // 4. 重复模式宏
 // ============================================================================
 
-fn repetition_patterns_demo() {
+pub(crate) fn repetition_patterns_demo() {
     println!("\n📍 4. 重复模式宏");
     println!("{}", "-".repeat(40));
     
@@ -257,7 +257,7 @@ fn repetition_patterns_demo() {
 // 5. HashMap 创建宏
 // ============================================================================
 
-fn hashmap_creation_demo() {
+pub(crate) fn hashmap_creation_demo() {
     println!("\n📍 5. HashMap 创建宏");
     println!("{}", "-".repeat(40));
     
@@ -297,7 +297,7 @@ fn hashmap_creation_demo() {
 // 6. 条件编译宏
 // ============================================================================
 
-fn conditional_compilation_demo() {
+pub(crate) fn conditional_compilation_demo() {
     println!("\n📍 6. 条件编译宏");
     println!("{}", "-".repeat(40));
     
@@ -344,7 +344,7 @@ fn conditional_compilation_demo() {
 // 7. 断言宏
 // ============================================================================
 
-fn assertion_macros_demo() {
+pub(crate) fn assertion_macros_demo() {
     println!("\n📍 7. 断言宏");
     println!("{}", "-".repeat(40));
     
@@ -391,7 +391,7 @@ fn assertion_macros_demo() {
 // 8. 日志宏
 // ============================================================================
 
-fn logging_macros_demo() {
+pub(crate) fn logging_macros_demo() {
     println!("\n📍 8. 日志宏");
     println!("{}", "-".repeat(40));
     
@@ -431,7 +431,7 @@ fn logging_macros_demo() {
 // 9. 计算宏
 // ============================================================================
 
-fn calculation_macros_demo() {
+pub(crate) fn calculation_macros_demo() {
     println!("\n📍 9. 计算宏");
     println!("{}", "-".repeat(40));
     
@@ -467,7 +467,7 @@ fn calculation_macros_demo() {
 // 10. 结构体生成宏
 // ============================================================================
 
-fn struct_generation_demo() {
+pub(crate) fn struct_generation_demo() {
     println!("\n📍 10. 结构体生成宏");
     println!("{}", "-".repeat(40));
     
@@ -551,7 +551,7 @@ fn struct_generation_demo() {
 // 11. 测试宏
 // ============================================================================
 
-fn test_macros_demo() {
+pub(crate) fn test_macros_demo() {
     println!("\n📍 11. 测试宏");
     println!("{}", "-".repeat(40));
     
@@ -611,7 +611,7 @@ fn test_macros_demo() {
 // 12. 配置宏
 // ============================================================================
 
-fn config_macros_demo() {
+pub(crate) fn config_macros_demo() {
     println!("\n📍 12. 配置宏");
     println!("{}", "-".repeat(40));
     
@@ -6,8 +6,6 @@
 // 第2章：程序与内存管理示例
 // 使用命令：cargo run --bin memory_management
 
-use task::examples;
-
 fn main() {
     println!("🦀 Rust 基础教程 - 第2章：程序与内存管理");
     println!("==========================================\n");
@@ -39,7 +37,7 @@ fn main() {
 }
 
 // 2.1 程序的基本执行流程
-fn program_execution_flow() {
+pub(crate) fn program_execution_flow() {
     // 全局变量/静态变量 - 存储在数据段
     static GLOBAL_COUNTER: i32 = 0;
     const MAX_SIZE: usize = 100;
@@ -84,7 +82,7 @@ fn multiply(x: i32, y: i32) -> i32 {
 }
 
 // 2.2 栈与堆
-fn stack_and_heap() {
+pub(crate) fn stack_and_heap() {
     println!("栈上的数据:");
     // 这些数据都存储在栈上
     let a = 5;          // i32 - 4 字节
@@ -144,7 +142,7 @@ fn stack_and_heap() {
 }
 
 // 2.3 指针类型
-fn pointer_types() {
+pub(crate) fn pointer_types() {
     println!("引用（References）:");
     let x = 5;
     let r = &x;        // r 是 x 的不可变引用
@@ -187,7 +185,7 @@ fn pointer_types() {
 }
 
 // 2.4 函数调用
-fn function_calls() {
+pub(crate) fn function_calls() {
     println!("调用栈示例:");
     let a = 10;
     let result = foo(a);
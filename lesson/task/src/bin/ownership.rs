@@ -31,7 +31,7 @@ fn main() {
 }
 
 // 3.1 目的与核心思想
-fn core_concepts() {
+pub(crate) fn core_concepts() {
     println!("所有权系统的核心思想:");
     println!("每个值都有一个所有者，并且同时只能有一个所有者");
     
@@ -72,7 +72,7 @@ fn memory_safety_demo() {
 }
 
 // 3.2 所有权规则
-fn ownership_rules() {
+pub(crate) fn ownership_rules() {
     println!("所有权的三条基本规则:");
     println!("1. 每个值都有一个所有者");
     println!("2. 值在任一时刻只能有一个所有者");
@@ -177,7 +177,7 @@ fn drop_example() {
 }
 
 // 3.3 验证规则
-fn verify_rules() {
+pub(crate) fn verify_rules() {
     println!("Copy 和 Clone:");
     copy_and_clone();
     
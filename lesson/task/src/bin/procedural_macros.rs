@@ -55,7 +55,7 @@ fn main() {
 // 1. 过程宏基本概念
 // ============================================================================
 
-fn proc_macro_concepts_demo() {
+pub(crate) fn proc_macro_concepts_demo() {
     println!("\n📍 1. 过程宏基本概念");
     println!("{}", "-".repeat(40));
     
@@ -85,7 +85,7 @@ fn proc_macro_concepts_demo() {
 // 2. 派生宏演示
 // ============================================================================
 
-fn derive_macro_demo() {
+pub(crate) fn derive_macro_demo() {
     println!("\n📍 2. 派生宏演示");
     println!("{}", "-".repeat(40));
     
@@ -130,7 +130,7 @@ fn derive_macro_demo() {
 // 3. 属性宏演示
 // ============================================================================
 
-fn attribute_macro_demo() {
+pub(crate) fn attribute_macro_demo() {
     println!("\n📍 3. 属性宏演示");
     println!("{}", "-".repeat(40));
     
@@ -172,7 +172,7 @@ fn attribute_macro_demo() {
 // 4. 函数式宏演示
 // ============================================================================
 
-fn function_like_macro_demo() {
+pub(crate) fn function_like_macro_demo() {
     println!("\n📍 4. 函数式宏演示");
     println!("{}", "-".repeat(40));
     
@@ -211,7 +211,7 @@ fn function_like_macro_demo() {
 // 5. Debug trait 自动实现演示
 // ============================================================================
 
-fn custom_debug_demo() {
+pub(crate) fn custom_debug_demo() {
     println!("\n📍 5. Debug trait 自动实现演示");
     println!("{}", "-".repeat(40));
     
@@ -278,7 +278,7 @@ fn custom_debug_demo() {
 // 6. Builder 模式演示
 // ============================================================================
 
-fn builder_pattern_demo() {
+pub(crate) fn builder_pattern_demo() {
     println!("\n📍 6. Builder 模式演示");
     println!("{}", "-".repeat(40));
     
@@ -366,7 +366,7 @@ fn builder_pattern_demo() {
 // 7. 序列化宏演示
 // ============================================================================
 
-fn serialization_demo() {
+pub(crate) fn serialization_demo() {
     println!("\n📍 7. 序列化宏演示");
     println!("{}", "-".repeat(40));
     
@@ -421,7 +421,7 @@ fn serialization_demo() {
 // 8. ORM 映射演示
 // ============================================================================
 
-fn orm_mapping_demo() {
+pub(crate) fn orm_mapping_demo() {
     println!("\n📍 8. ORM 映射演示");
     println!("{}", "-".repeat(40));
     
@@ -487,7 +487,7 @@ fn orm_mapping_demo() {
 // 9. API 路由演示
 // ============================================================================
 
-fn api_route_demo() {
+pub(crate) fn api_route_demo() {
     println!("\n📍 9. API 路由演示");
     println!("{}", "-".repeat(40));
     
@@ -571,7 +571,7 @@ fn api_route_demo() {
 // 10. 状态机演示
 // ============================================================================
 
-fn state_machine_demo() {
+pub(crate) fn state_machine_demo() {
     println!("\n📍 10. 状态机演示");
     println!("{}", "-".repeat(40));
     
@@ -658,7 +658,7 @@ fn state_machine_demo() {
 // 11. 所有权分析演示
 // ============================================================================
 
-fn ownership_analysis_demo() {
+pub(crate) fn ownership_analysis_demo() {
     println!("\n📍 11. 所有权分析演示");
     println!("{}", "-".repeat(40));
     
@@ -735,7 +735,7 @@ fn ownership_analysis_demo() {
 // 12. 内存安全检查演示
 // ============================================================================
 
-fn memory_safety_demo() {
+pub(crate) fn memory_safety_demo() {
     println!("\n📍 12. 内存安全检查演示");
     println!("{}", "-".repeat(40));
     
@@ -41,7 +41,7 @@ fn main() {
 // 1. Box<T> - 堆分配
 // ============================================================================
 
-fn box_pointer_demo() {
+pub(crate) fn box_pointer_demo() {
     println!("\n📦 1. Box<T> - 堆分配");
     println!("{}", "-".repeat(40));
     
@@ -129,7 +129,7 @@ fn box_pointer_demo() {
 // 2. Rc<T> - 引用计数
 // ============================================================================
 
-fn rc_pointer_demo() {
+pub(crate) fn rc_pointer_demo() {
     println!("\n🔄 2. Rc<T> - 引用计数");
     println!("{}", "-".repeat(40));
     
@@ -207,7 +207,7 @@ fn rc_pointer_demo() {
 // 3. RefCell<T> - 内部可变性
 // ============================================================================
 
-fn refcell_demo() {
+pub(crate) fn refcell_demo() {
     println!("\n🔄 3. RefCell<T> - 内部可变性");
     println!("{}", "-".repeat(40));
     
@@ -315,7 +315,7 @@ fn refcell_demo() {
 // 4. Rc<RefCell<T>> 组合
 // ============================================================================
 
-fn rc_refcell_combo() {
+pub(crate) fn rc_refcell_combo() {
     println!("\n🔄📦 4. Rc<RefCell<T>> 组合");
     println!("{}", "-".repeat(40));
     
@@ -413,7 +413,7 @@ fn rc_refcell_combo() {
 // 5. Arc<T> - 原子引用计数
 // ============================================================================
 
-fn arc_demo() {
+pub(crate) fn arc_demo() {
     println!("\n⚛️ 5. Arc<T> - 原子引用计数");
     println!("{}", "-".repeat(40));
     
@@ -480,7 +480,7 @@ fn arc_demo() {
 // 6. Mutex<T> - 互斥锁
 // ============================================================================
 
-fn mutex_demo() {
+pub(crate) fn mutex_demo() {
     println!("\n🔒 6. Mutex<T> - 互斥锁");
     println!("{}", "-".repeat(40));
     
@@ -559,7 +559,7 @@ fn practical_examples() {
     thread_pool_example();
 }
 
-fn cache_system_example() {
+pub(crate) fn cache_system_example() {
     println!("\n  🔸 缓存系统：");
     
     use std::collections::HashMap;
@@ -620,7 +620,7 @@ fn cache_system_example() {
     println!("    主线程读取: user:2 = {:?}", cache.get("user:2"));
 }
 
-fn observer_pattern_example() {
+pub(crate) fn observer_pattern_example() {
     println!("\n  🔸 观察者模式：");
     
     trait Observer {
@@ -673,7 +673,7 @@ fn observer_pattern_example() {
     subject.notify("第二条消息");
 }
 
-fn thread_pool_example() {
+pub(crate) fn thread_pool_example() {
     println!("\n  🔸 简单线程池：");
     
     use std::sync::mpsc;
@@ -718,7 +718,10 @@ fn thread_pool_example() {
     impl Worker {
         fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
             let thread = thread::spawn(move || loop {
-                let job = receiver.lock().unwrap().recv().unwrap();
+                // 线程池被丢弃后发送端关闭，工作线程随之退出
+                let Ok(job) = receiver.lock().unwrap().recv() else {
+                    break;
+                };
                 println!("    Worker {} 开始执行任务", id);
                 job();
             });
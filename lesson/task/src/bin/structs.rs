@@ -39,7 +39,7 @@ struct User {
 }
 
 // 5.1 定义与使用
-fn struct_definition_and_usage() {
+pub(crate) fn struct_definition_and_usage() {
     println!("基本结构体定义与使用:");
     
     // 创建结构体实例
@@ -161,7 +161,7 @@ fn struct_printing() {
 }
 
 // 5.2 关联方法与函数
-fn associated_methods() {
+pub(crate) fn associated_methods() {
     println!("方法定义:");
     methods_demo();
     
@@ -271,7 +271,7 @@ fn method_chaining() {
 }
 
 // 5.3 内存布局
-fn memory_layout() {
+pub(crate) fn memory_layout() {
     use std::mem;
     
     println!("结构体的内存布局:");
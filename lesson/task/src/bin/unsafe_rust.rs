@@ -53,7 +53,7 @@ fn main() {
 // 1. Unsafe 的基本概念
 // ============================================================================
 
-fn unsafe_basics_demo() {
+pub(crate) fn unsafe_basics_demo() {
     println!("\n📍 1. Unsafe 的基本概念");
     println!("{}", "-".repeat(40));
     
@@ -90,7 +90,7 @@ fn unsafe_basics_demo() {
 // 2. 原始指针操作
 // ============================================================================
 
-fn raw_pointers_demo() {
+pub(crate) fn raw_pointers_demo() {
     println!("\n📍 2. 原始指针操作");
     println!("{}", "-".repeat(40));
     
@@ -141,7 +141,7 @@ unsafe fn dangerous_function() {
     println!("   这是一个不安全函数");
 }
 
-fn unsafe_functions_demo() {
+pub(crate) fn unsafe_functions_demo() {
     println!("\n📍 3. 不安全函数");
     println!("{}", "-".repeat(40));
     
@@ -192,7 +192,7 @@ unsafe fn split_at_mut_unsafe(values: &mut [i32], mid: usize) -> (&mut [i32], &m
 
 static mut COUNTER: usize = 0;
 
-fn static_variables_demo() {
+pub(crate) fn static_variables_demo() {
     println!("\n📍 4. 静态变量访问");
     println!("{}", "-".repeat(40));
     
@@ -329,7 +329,7 @@ impl<T> Drop for SafeVec<T> {
     }
 }
 
-fn safe_abstractions_demo() {
+pub(crate) fn safe_abstractions_demo() {
     println!("\n📍 5. 创建安全抽象");
     println!("{}", "-".repeat(40));
     
@@ -390,7 +390,7 @@ impl<T> Drop for MyBox<T> {
     }
 }
 
-fn custom_smart_pointer_demo() {
+pub(crate) fn custom_smart_pointer_demo() {
     println!("\n📍 6. 自定义智能指针");
     println!("{}", "-".repeat(40));
     
@@ -475,7 +475,7 @@ impl<T> FastStack<T> {
     }
 }
 
-fn high_performance_structures_demo() {
+pub(crate) fn high_performance_structures_demo() {
     println!("\n📍 7. 高性能数据结构");
     println!("{}", "-".repeat(40));
     
@@ -519,7 +519,7 @@ struct PackedStruct {
     c: u16,
 }
 
-fn memory_layout_demo() {
+pub(crate) fn memory_layout_demo() {
     println!("\n📍 8. 内存对齐和布局");
     println!("{}", "-".repeat(40));
     
@@ -563,7 +563,7 @@ fn memory_layout_demo() {
 // 9. RWO 权限分析
 // ============================================================================
 
-fn rwo_permissions_demo() {
+pub(crate) fn rwo_permissions_demo() {
     println!("\n📍 9. RWO 权限分析");
     println!("{}", "-".repeat(40));
     
@@ -619,7 +619,7 @@ fn rwo_permissions_demo() {
 // 10. 安全实践
 // ============================================================================
 
-fn safety_practices_demo() {
+pub(crate) fn safety_practices_demo() {
     println!("\n📍 10. 安全实践");
     println!("{}", "-".repeat(40));
     
@@ -0,0 +1,892 @@
+// 章节注册表
+// 菜单、批量运行和章节帮助都从这里生成，新增章节只需在 CHAPTERS 中登记一次
+
+use std::io::{self, Write};
+
+use crate::examples;
+use crate::i18n;
+use crate::sections::{self, Section};
+use crate::tr;
+
+/// 章节分组
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    /// 基础教程
+    Basic,
+    /// 核心概念
+    Core,
+    /// 进阶特性
+    Advanced,
+    /// 专业主题
+    Pro,
+}
+
+impl Group {
+    /// 所有分组，按学习顺序排列
+    pub const ALL: [Group; 4] = [Group::Basic, Group::Core, Group::Advanced, Group::Pro];
+
+    /// 分组的英文标识，用于命令行参数
+    pub fn key(self) -> &'static str {
+        match self {
+            Group::Basic => "basic",
+            Group::Core => "core",
+            Group::Advanced => "advanced",
+            Group::Pro => "pro",
+        }
+    }
+
+    /// 分组的显示名称，随界面语言变化
+    pub fn title(self) -> &'static str {
+        i18n::text(match self {
+            Group::Basic => "group.basic",
+            Group::Core => "group.core",
+            Group::Advanced => "group.advanced",
+            Group::Pro => "group.pro",
+        })
+    }
+
+    /// 根据英文标识查找分组
+    pub fn from_key(key: &str) -> Option<Group> {
+        Group::ALL.into_iter().find(|group| group.key() == key)
+    }
+}
+
+/// 章节的示例入口
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    /// `examples` 模块中的函数名
+    pub name: &'static str,
+    /// 打印到标准输出的版本
+    pub run: fn(),
+    /// 写入任意输出的版本（`<name>_to`）
+    pub write: fn(&mut dyn Write) -> io::Result<()>,
+}
+
+/// 一个章节的完整描述
+///
+/// 文字字段是中文原文，其他语言的译文在消息目录的 `chapter.<id>.<字段>` 键下，
+/// 界面显示时使用 `localized_*` 方法。
+#[derive(Debug)]
+pub struct Chapter {
+    /// 菜单编号，发布后保持不变
+    pub id: u8,
+    pub title: &'static str,
+    /// 帮助页中的一句话简介
+    pub brief: &'static str,
+    pub group: Group,
+    /// 前置章节：学习本章之前应先学完的章节编号
+    pub prerequisites: &'static [u8],
+    pub goals: &'static [&'static str],
+    pub concepts: &'static [&'static str],
+    pub summary: &'static [&'static str],
+    pub example: Example,
+    /// 独立运行的二进制名称（`cargo run --bin <bin>`）
+    pub bin: &'static str,
+    /// 教程文档路径，相对于仓库根目录
+    pub tutorial: &'static str,
+}
+
+impl Chapter {
+    /// 带章节号的完整名称，例如 "第1章：变量与常量"
+    pub fn display_name(&self) -> String {
+        tr!("chapter.display_name", id = self.id, title = self.localized_title())
+    }
+
+    /// 当前语言的标题，未翻译时为中文原文
+    pub fn localized_title(&self) -> &'static str {
+        i18n::text_or(&i18n::chapter_key(self.id, "title"), self.title)
+    }
+
+    pub fn localized_brief(&self) -> &'static str {
+        i18n::text_or(&i18n::chapter_key(self.id, "brief"), self.brief)
+    }
+
+    pub fn localized_goals(&self) -> Vec<&'static str> {
+        self.localized_list("goals", self.goals)
+    }
+
+    pub fn localized_concepts(&self) -> Vec<&'static str> {
+        self.localized_list("concepts", self.concepts)
+    }
+
+    pub fn localized_summary(&self) -> Vec<&'static str> {
+        self.localized_list("summary", self.summary)
+    }
+
+    /// 列表字段的译文每行一项
+    fn localized_list(&self, field: &str, original: &'static [&'static str]) -> Vec<&'static str> {
+        match i18n::lookup(&i18n::chapter_key(self.id, field)) {
+            Some(text) => text.lines().map(str::trim).filter(|line| !line.is_empty()).collect(),
+            None => original.to_vec(),
+        }
+    }
+
+    /// 独立二进制的源码路径，相对于 `lesson/task`
+    pub fn source_path(&self) -> String {
+        format!("src/bin/{}.rs", self.bin)
+    }
+
+    /// 运行本章示例，输出到标准输出
+    pub fn run(&self) {
+        (self.example.run)()
+    }
+
+    /// 运行本章示例，输出写入 `out`
+    pub fn write_to(&self, out: &mut dyn Write) -> io::Result<()> {
+        (self.example.write)(out)
+    }
+
+    /// 可以单独运行的小节，示例没有拆分时为空
+    pub fn sections(&self) -> &'static [Section] {
+        sections::of(self)
+    }
+}
+
+macro_rules! example {
+    ($name:ident, $write:ident) => {
+        Example {
+            name: stringify!($name),
+            run: examples::$name,
+            write: examples::$write,
+        }
+    };
+}
+
+/// 所有章节，按推荐学习顺序排列
+pub static CHAPTERS: &[Chapter] = &[
+    Chapter {
+        id: 1,
+        title: "变量与常量",
+        brief: "学习变量声明、可变性和常量",
+        group: Group::Basic,
+        prerequisites: &[],
+        goals: &[
+            "理解变量的声明和赋值",
+            "掌握可变性 (mut) 的概念",
+            "学习常量和静态变量的区别",
+            "了解变量遮蔽 (shadowing)",
+        ],
+        concepts: &[
+            "let 关键字：声明变量",
+            "mut 关键字：使变量可变",
+            "const 关键字：编译时常量",
+            "static 关键字：全局变量",
+        ],
+        summary: &[
+            "使用 let 声明变量",
+            "使用 mut 创建可变变量",
+            "定义常量和静态变量",
+            "理解变量遮蔽的机制",
+        ],
+        example: example!(variables_and_constants, variables_and_constants_to),
+        bin: "variables",
+        tutorial: "tutorial/01_rust_basics.md",
+    },
+    Chapter {
+        id: 2,
+        title: "数据类型",
+        brief: "掌握基本和复合数据类型",
+        group: Group::Basic,
+        prerequisites: &[1],
+        goals: &[
+            "掌握 Rust 的基本数据类型",
+            "理解标量类型和复合类型",
+            "学习类型推断和显式标注",
+            "了解类型转换的安全性",
+        ],
+        concepts: &[
+            "整数类型：i8, i16, i32, i64, isize, u8, u16, u32, u64, usize",
+            "浮点类型：f32, f64",
+            "布尔类型：bool",
+            "字符类型：char (Unicode)",
+            "复合类型：元组 (tuple), 数组 (array)",
+        ],
+        summary: &[
+            "Rust 的基本数据类型",
+            "类型推断和显式标注",
+            "元组和数组的使用",
+            "类型安全的重要性",
+        ],
+        example: example!(data_types, data_types_to),
+        bin: "data_types",
+        tutorial: "tutorial/01_rust_basics.md",
+    },
+    Chapter {
+        id: 3,
+        title: "函数",
+        brief: "理解函数定义和调用",
+        group: Group::Basic,
+        prerequisites: &[1],
+        goals: &[
+            "掌握函数的定义和调用",
+            "理解参数传递机制",
+            "学习返回值的语法",
+            "了解表达式和语句的区别",
+        ],
+        concepts: &[
+            "fn 关键字：函数定义",
+            "参数类型标注",
+            "返回类型箭头 ->",
+            "表达式返回值（无分号）",
+        ],
+        summary: &[
+            "函数的定义和调用",
+            "参数和返回值的语法",
+            "表达式和语句的区别",
+            "函数作为程序的构建块",
+        ],
+        example: example!(functions_demo, functions_demo_to),
+        bin: "functions",
+        tutorial: "tutorial/01_rust_basics.md",
+    },
+    Chapter {
+        id: 4,
+        title: "控制流",
+        brief: "掌握条件语句和循环",
+        group: Group::Basic,
+        prerequisites: &[2, 3],
+        goals: &[
+            "掌握条件分支 if/else",
+            "学习循环结构 loop/while/for",
+            "理解 match 模式匹配",
+            "了解控制流的表达式特性",
+        ],
+        concepts: &[
+            "if 表达式：条件分支",
+            "loop：无限循环",
+            "while：条件循环",
+            "for：遍历循环",
+            "break/continue：循环控制",
+        ],
+        summary: &[
+            "条件分支和循环结构",
+            "match 模式匹配",
+            "控制流表达式的特性",
+            "循环控制和跳转",
+        ],
+        example: example!(control_flow, control_flow_to),
+        bin: "control_flow",
+        tutorial: "tutorial/01_rust_basics.md",
+    },
+    Chapter {
+        id: 5,
+        title: "内存管理",
+        brief: "理解栈和堆的区别",
+        group: Group::Core,
+        prerequisites: &[2],
+        goals: &[
+            "理解程序内存布局",
+            "掌握栈和堆的区别",
+            "学习指针和引用概念",
+            "了解内存安全的重要性",
+        ],
+        concepts: &[
+            "栈内存：快速，固定大小，自动管理",
+            "堆内存：灵活，动态大小，手动管理",
+            "指针：内存地址",
+            "引用：安全的指针",
+        ],
+        summary: &[
+            "程序内存的基本布局",
+            "栈和堆的区别和使用",
+            "指针和引用的概念",
+            "内存安全的重要性",
+        ],
+        example: example!(memory_management, memory_management_to),
+        bin: "memory_management",
+        tutorial: "tutorial/02_memory_management.md",
+    },
+    Chapter {
+        id: 6,
+        title: "所有权",
+        brief: "掌握 Rust 的核心特性",
+        group: Group::Core,
+        prerequisites: &[3, 5],
+        goals: &[
+            "理解 Rust 的所有权系统",
+            "掌握移动语义",
+            "学习 Copy 和 Clone trait",
+            "了解 Drop trait 的作用",
+        ],
+        concepts: &[
+            "所有权三原则",
+            "移动 (move)：转移所有权",
+            "复制 (copy)：浅拷贝",
+            "克隆 (clone)：深拷贝",
+        ],
+        summary: &[
+            "Rust 独特的所有权系统",
+            "移动语义和内存安全",
+            "Copy 和 Clone 的区别",
+            "RAII 和自动内存管理",
+        ],
+        example: example!(ownership, ownership_to),
+        bin: "ownership",
+        tutorial: "tutorial/03_ownership.md",
+    },
+    Chapter {
+        id: 7,
+        title: "借用机制",
+        brief: "学习引用和借用",
+        group: Group::Core,
+        prerequisites: &[6],
+        goals: &[
+            "掌握引用和借用",
+            "理解可变引用和不可变引用",
+            "学习借用检查器规则",
+            "了解切片的概念",
+        ],
+        concepts: &[
+            "& 引用：借用不可变",
+            "&mut 引用：借用可变",
+            "借用规则：要么多个不可变，要么一个可变",
+            "切片：引用集合的一部分",
+        ],
+        summary: &[
+            "引用和借用的概念",
+            "借用检查器的规则",
+            "可变和不可变引用",
+            "切片的定义和使用",
+        ],
+        example: example!(borrowing, borrowing_to),
+        bin: "borrowing",
+        tutorial: "tutorial/04_borrowing.md",
+    },
+    Chapter {
+        id: 8,
+        title: "结构体",
+        brief: "构建自定义数据类型",
+        group: Group::Core,
+        prerequisites: &[7],
+        goals: &[
+            "掌握结构体的定义和使用",
+            "学习方法和关联函数",
+            "理解结构体的内存布局",
+            "了解元组结构体和单元结构体",
+        ],
+        concepts: &[
+            "struct 关键字：定义结构体",
+            "impl 块：实现方法",
+            "&self：方法的接收者",
+            "Self::new()：关联函数",
+        ],
+        summary: &[
+            "结构体的定义和实例化",
+            "方法和关联函数",
+            "结构体的内存布局",
+            "面向对象编程的基础",
+        ],
+        example: example!(structs, structs_to),
+        bin: "structs",
+        tutorial: "tutorial/05_structs.md",
+    },
+    Chapter {
+        id: 9,
+        title: "常用类型",
+        brief: "Vector、String、HashMap",
+        group: Group::Advanced,
+        prerequisites: &[7],
+        goals: &[
+            "掌握 Vec 的创建、访问和修改",
+            "理解 String 与 &str 的区别",
+            "学习 HashMap 的插入和查找",
+            "了解集合的所有权规则",
+        ],
+        concepts: &[
+            "Vec<T>：可增长的数组",
+            "String：UTF-8 编码的可变字符串",
+            "HashMap<K, V>：键值对集合",
+            "vec! 宏：快速创建 Vector",
+        ],
+        summary: &[
+            "Vec、String、HashMap 的基本操作",
+            "字符串拼接与所有权转移",
+            "使用 get 安全地查找值",
+            "遍历集合的常见写法",
+        ],
+        example: example!(common_types, common_types_to),
+        bin: "common_types",
+        tutorial: "tutorial/06_common_types.md",
+    },
+    Chapter {
+        id: 10,
+        title: "枚举",
+        brief: "枚举定义和模式匹配",
+        group: Group::Advanced,
+        prerequisites: &[4, 8],
+        goals: &[
+            "掌握枚举的定义和携带数据的变体",
+            "理解 Option 如何取代空值",
+            "学习 match 的穷尽性检查",
+            "了解 if let 的简洁写法",
+        ],
+        concepts: &[
+            "enum 关键字：定义枚举",
+            "Option<T>：Some 与 None",
+            "match：穷尽的模式匹配",
+            "if let：只关心一种模式",
+        ],
+        summary: &[
+            "定义携带数据的枚举",
+            "使用 Option 表达可能缺失的值",
+            "match 和 if let 的使用场景",
+            "在模式中解构枚举数据",
+        ],
+        example: example!(enums, enums_to),
+        bin: "enums",
+        tutorial: "tutorial/07_enums.md",
+    },
+    Chapter {
+        id: 11,
+        title: "泛型与特征",
+        brief: "泛型编程和特征系统",
+        group: Group::Advanced,
+        prerequisites: &[8, 10],
+        goals: &[
+            "掌握泛型函数和泛型结构体",
+            "学习定义和实现 trait",
+            "理解 trait 约束 (trait bound)",
+            "了解单态化带来的零成本抽象",
+        ],
+        concepts: &[
+            "<T>：泛型参数",
+            "trait：共享行为的定义",
+            "impl Trait for Type：实现特征",
+            "T: PartialOrd + Copy：特征约束",
+        ],
+        summary: &[
+            "编写泛型函数和结构体",
+            "定义并实现自己的 trait",
+            "使用特征约束限制泛型",
+            "泛型的编译期单态化",
+        ],
+        example: example!(generics_traits, generics_traits_to),
+        bin: "generics_traits",
+        tutorial: "tutorial/08_generics_traits.md",
+    },
+    Chapter {
+        id: 12,
+        title: "生命周期",
+        brief: "引用有效性管理",
+        group: Group::Advanced,
+        prerequisites: &[7, 11],
+        goals: &[
+            "理解生命周期解决的问题",
+            "掌握函数签名中的生命周期标注",
+            "学习结构体中的生命周期",
+            "了解生命周期省略规则",
+        ],
+        concepts: &[
+            "'a：生命周期参数",
+            "悬垂引用：借用检查器阻止的错误",
+            "生命周期省略三规则",
+            "'static：整个程序期间有效",
+        ],
+        summary: &[
+            "为返回引用的函数标注生命周期",
+            "在结构体中持有引用",
+            "生命周期省略的适用场景",
+            "借用检查器如何验证引用",
+        ],
+        example: example!(lifetimes, lifetimes_to),
+        bin: "lifetimes",
+        tutorial: "tutorial/09_lifetimes.md",
+    },
+    Chapter {
+        id: 23,
+        title: "特征对象",
+        brief: "动态分发与 dyn Trait",
+        group: Group::Advanced,
+        prerequisites: &[11],
+        goals: &[
+            "理解静态分发与动态分发的区别",
+            "掌握 Box<dyn Trait> 的使用",
+            "学习在集合中存放不同类型",
+            "了解对象安全的要求",
+        ],
+        concepts: &[
+            "dyn Trait：特征对象类型",
+            "Box<dyn Trait>：堆上的特征对象",
+            "虚函数表 (vtable)",
+            "对象安全 (object safety)",
+        ],
+        summary: &[
+            "使用特征对象实现多态",
+            "在 Vec 中存放不同类型的值",
+            "动态分发的运行时开销",
+            "对象安全的基本规则",
+        ],
+        example: example!(trait_objects, trait_objects_to),
+        bin: "trait_objects",
+        tutorial: "tutorial/10_trait_objects.md",
+    },
+    Chapter {
+        id: 24,
+        title: "常用特征",
+        brief: "Display、Clone、PartialEq、Ord 等",
+        group: Group::Advanced,
+        prerequisites: &[11],
+        goals: &[
+            "掌握 Display 和 Debug 的区别",
+            "理解 Clone 与 Copy",
+            "学习相等性与排序特征",
+            "了解 derive 自动派生",
+        ],
+        concepts: &[
+            "Display / Debug：格式化输出",
+            "Clone / Copy：复制语义",
+            "PartialEq / Eq：相等性比较",
+            "PartialOrd / Ord：排序比较",
+        ],
+        summary: &[
+            "为自定义类型实现 Display",
+            "使用 derive 派生常用特征",
+            "比较和排序自定义类型",
+            "选择合适的复制语义",
+        ],
+        example: example!(common_traits, common_traits_to),
+        bin: "common_traits",
+        tutorial: "tutorial/11_common_traits.md",
+    },
+    Chapter {
+        id: 25,
+        title: "错误处理",
+        brief: "panic!、Result 与 ? 运算符",
+        group: Group::Advanced,
+        prerequisites: &[10, 24],
+        goals: &[
+            "区分可恢复错误和不可恢复错误",
+            "掌握 Result 的处理方式",
+            "学习使用 ? 传播错误",
+            "了解自定义错误类型",
+        ],
+        concepts: &[
+            "panic!：不可恢复错误",
+            "Result<T, E>：可恢复错误",
+            "? 运算符：错误传播",
+            "unwrap / expect：快速原型",
+        ],
+        summary: &[
+            "何时使用 panic! 与 Result",
+            "用 match 处理 Result",
+            "使用 ? 简化错误传播",
+            "设计清晰的错误类型",
+        ],
+        example: example!(error_handling, error_handling_to),
+        bin: "error_handling",
+        tutorial: "tutorial/12_error_handling.md",
+    },
+    Chapter {
+        id: 13,
+        title: "项目管理",
+        brief: "模块系统和包管理",
+        group: Group::Advanced,
+        prerequisites: &[3],
+        goals: &[
+            "理解包 (package) 与 crate",
+            "掌握模块的定义与可见性",
+            "学习 use 引入路径",
+            "了解工作空间和依赖管理",
+        ],
+        concepts: &[
+            "Cargo.toml：包的清单文件",
+            "mod：定义模块",
+            "pub：控制可见性",
+            "use：引入路径",
+        ],
+        summary: &[
+            "组织多模块的项目结构",
+            "控制条目的可见性",
+            "使用 use 简化路径",
+            "Cargo 的依赖与工作空间",
+        ],
+        example: example!(project_management, project_management_to),
+        bin: "project_management",
+        tutorial: "tutorial/13_project_management.md",
+    },
+    Chapter {
+        id: 14,
+        title: "文档与测试",
+        brief: "文档注释和测试编写",
+        group: Group::Advanced,
+        prerequisites: &[13],
+        goals: &[
+            "掌握文档注释的写法",
+            "学习编写单元测试",
+            "理解集成测试的组织方式",
+            "了解文档测试",
+        ],
+        concepts: &[
+            "///：条目文档注释",
+            "#[test]：测试函数",
+            "#[cfg(test)]：测试模块",
+            "assert! / assert_eq!：断言宏",
+        ],
+        summary: &[
+            "为公开 API 编写文档",
+            "编写和运行单元测试",
+            "组织集成测试",
+            "让文档示例成为测试",
+        ],
+        example: example!(docs_and_testing, docs_and_testing_to),
+        bin: "docs_and_testing",
+        tutorial: "tutorial/14_docs_and_testing.md",
+    },
+    Chapter {
+        id: 15,
+        title: "闭包",
+        brief: "函数式编程特性",
+        group: Group::Advanced,
+        prerequisites: &[7, 11],
+        goals: &[
+            "掌握闭包的语法",
+            "理解闭包捕获环境的三种方式",
+            "学习 Fn、FnMut、FnOnce",
+            "了解 move 闭包",
+        ],
+        concepts: &[
+            "|x| x + 1：闭包语法",
+            "捕获：借用、可变借用、移动",
+            "Fn / FnMut / FnOnce 特征",
+            "move 关键字：转移所有权",
+        ],
+        summary: &[
+            "定义和调用闭包",
+            "闭包如何捕获环境变量",
+            "三种闭包特征的区别",
+            "闭包与迭代器配合使用",
+        ],
+        example: example!(closures, closures_to),
+        bin: "closures",
+        tutorial: "tutorial/15_closures.md",
+    },
+    Chapter {
+        id: 16,
+        title: "迭代器",
+        brief: "高效的数据处理",
+        group: Group::Advanced,
+        prerequisites: &[9, 15],
+        goals: &[
+            "理解 Iterator trait",
+            "掌握适配器与消费者",
+            "学习链式数据处理",
+            "了解迭代器的惰性求值",
+        ],
+        concepts: &[
+            "Iterator trait 与 next 方法",
+            "map / filter：迭代器适配器",
+            "collect / sum：消费者",
+            "惰性求值：不消费就不执行",
+        ],
+        summary: &[
+            "iter、iter_mut、into_iter 的区别",
+            "使用适配器组合数据处理",
+            "实现自定义迭代器",
+            "迭代器的零成本抽象",
+        ],
+        example: example!(iterators, iterators_to),
+        bin: "iterators",
+        tutorial: "tutorial/16_iterators.md",
+    },
+    Chapter {
+        id: 17,
+        title: "智能指针",
+        brief: "高级内存管理",
+        group: Group::Advanced,
+        prerequisites: &[8, 24],
+        goals: &[
+            "理解智能指针的概念",
+            "掌握 Box<T> 的使用场景",
+            "学习 Deref 和 Drop trait",
+            "了解递归类型的定义",
+        ],
+        concepts: &[
+            "Box<T>：堆上分配",
+            "Deref：像引用一样使用",
+            "Drop：离开作用域时清理",
+            "递归类型：需要间接层",
+        ],
+        summary: &[
+            "使用 Box 在堆上存储数据",
+            "实现 Deref 和 Drop",
+            "定义递归数据结构",
+            "智能指针与普通引用的区别",
+        ],
+        example: example!(smart_pointers, smart_pointers_to),
+        bin: "smart_pointers",
+        tutorial: "tutorial/17_smart_pointers.md",
+    },
+    Chapter {
+        id: 18,
+        title: "常用智能指针",
+        brief: "Rc、Arc、RefCell 等",
+        group: Group::Pro,
+        prerequisites: &[17],
+        goals: &[
+            "掌握 Rc<T> 的引用计数",
+            "理解 RefCell<T> 的内部可变性",
+            "学习 Weak<T> 打破循环引用",
+            "了解 Arc<T> 的线程安全共享",
+        ],
+        concepts: &[
+            "Rc<T>：单线程共享所有权",
+            "RefCell<T>：运行时借用检查",
+            "Weak<T>：不增加强引用计数",
+            "Arc<T>：原子引用计数",
+        ],
+        summary: &[
+            "使用 Rc 共享数据",
+            "Rc<RefCell<T>> 组合模式",
+            "用 Weak 避免内存泄漏",
+            "单线程与多线程共享的选择",
+        ],
+        example: example!(common_smart_pointers, common_smart_pointers_to),
+        bin: "common_smart_pointers",
+        tutorial: "tutorial/18_common_smart_pointers.md",
+    },
+    Chapter {
+        id: 19,
+        title: "并发编程",
+        brief: "线程和异步编程",
+        group: Group::Pro,
+        prerequisites: &[15, 18],
+        goals: &[
+            "掌握线程的创建与 join",
+            "学习通道 (channel) 消息传递",
+            "理解 Mutex 与 Arc 共享状态",
+            "了解 Send 与 Sync",
+        ],
+        concepts: &[
+            "thread::spawn：创建线程",
+            "mpsc::channel：消息传递",
+            "Arc<Mutex<T>>：共享可变状态",
+            "Send / Sync：线程安全标记",
+        ],
+        summary: &[
+            "创建和等待线程",
+            "使用通道在线程间通信",
+            "安全地共享可变状态",
+            "无畏并发的编译期保证",
+        ],
+        example: example!(concurrency, concurrency_to),
+        bin: "concurrency",
+        tutorial: "tutorial/19_concurrency.md",
+    },
+    Chapter {
+        id: 20,
+        title: "Unsafe Rust",
+        brief: "底层系统编程",
+        group: Group::Pro,
+        prerequisites: &[12, 17],
+        goals: &[
+            "理解 unsafe 的五种超能力",
+            "学习裸指针的使用",
+            "掌握 unsafe 函数与安全封装",
+            "了解 FFI 与可变静态变量",
+        ],
+        concepts: &[
+            "unsafe 块：告诉编译器“我负责”",
+            "*const T / *mut T：裸指针",
+            "extern \"C\"：外部函数接口",
+            "static mut：可变静态变量",
+        ],
+        summary: &[
+            "unsafe 代码的适用场景",
+            "解引用裸指针",
+            "用安全 API 封装 unsafe 代码",
+            "最小化 unsafe 的范围",
+        ],
+        example: example!(unsafe_rust, unsafe_rust_to),
+        bin: "unsafe_rust",
+        tutorial: "tutorial/20_unsafe_rust.md",
+    },
+    Chapter {
+        id: 21,
+        title: "宏系统",
+        brief: "声明宏与元编程",
+        group: Group::Pro,
+        prerequisites: &[4, 11],
+        goals: &[
+            "理解宏的基本概念和语法",
+            "掌握声明宏的编写",
+            "学习宏参数类型和重复模式",
+            "了解宏的实际应用场景",
+        ],
+        concepts: &[
+            "macro_rules!：声明宏定义",
+            "模式匹配：=> 语法",
+            "参数类型：expr, ident, ty, item 等",
+            "重复模式：$(...),*, $(...)?",
+            "元编程：编译时代码生成",
+        ],
+        summary: &[
+            "宏的基本概念和语法",
+            "声明宏的编写技巧",
+            "宏参数类型的使用",
+            "重复模式和代码生成",
+        ],
+        example: example!(macros, macros_to),
+        bin: "macros",
+        tutorial: "tutorial/21_macros.md",
+    },
+    Chapter {
+        id: 22,
+        title: "过程宏",
+        brief: "派生宏、属性宏与函数式宏",
+        group: Group::Pro,
+        prerequisites: &[13, 21],
+        goals: &[
+            "理解过程宏的工作原理",
+            "掌握三种过程宏类型",
+            "学习 TokenStream 操作",
+            "了解过程宏的实际应用",
+        ],
+        concepts: &[
+            "TokenStream：词法标记流",
+            "syn：语法分析库",
+            "quote：代码生成库",
+            "proc-macro crate：独立的宏包",
+            "三种类型：派生宏、属性宏、函数式宏",
+        ],
+        summary: &[
+            "过程宏的工作原理",
+            "三种过程宏类型的应用",
+            "TokenStream 的基本操作",
+            "过程宏的实际用途",
+        ],
+        example: example!(procedural_macros, procedural_macros_to),
+        bin: "procedural_macros",
+        tutorial: "tutorial/22_procedural_macros.md",
+    },
+];
+
+/// 所有章节，按推荐学习顺序排列
+pub fn all() -> &'static [Chapter] {
+    CHAPTERS
+}
+
+/// 根据菜单编号查找章节
+pub fn get(id: u8) -> Option<&'static Chapter> {
+    CHAPTERS.iter().find(|chapter| chapter.id == id)
+}
+
+/// 根据独立二进制名称查找章节
+pub fn by_bin(bin: &str) -> Option<&'static Chapter> {
+    CHAPTERS.iter().find(|chapter| chapter.bin == bin)
+}
+
+/// 某个分组下的所有章节
+pub fn in_group(group: Group) -> impl Iterator<Item = &'static Chapter> {
+    CHAPTERS.iter().filter(move |chapter| chapter.group == group)
+}
+
+/// 推荐学习顺序中的下一章
+pub fn next_after(id: u8) -> Option<&'static Chapter> {
+    let index = CHAPTERS.iter().position(|chapter| chapter.id == id)?;
+    CHAPTERS.get(index + 1)
+}
+
+/// 最大的章节编号
+pub fn max_id() -> u8 {
+    CHAPTERS.iter().map(|chapter| chapter.id).max().unwrap_or(0)
+}
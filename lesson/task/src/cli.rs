@@ -0,0 +1,415 @@
+// 命令行参数解析
+// 只使用标准库，不带参数运行时进入交互式菜单
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::chapters::{self, Group};
+use crate::exercises;
+use crate::explain;
+use crate::grader;
+use crate::i18n::{self, Locale};
+use crate::search;
+use crate::sections;
+use crate::term::Theme;
+use crate::tr;
+
+/// 成功
+pub const EXIT_OK: i32 = 0;
+/// 命令执行失败，例如章节没有可显示的内容
+pub const EXIT_FAILURE: i32 = 1;
+/// 命令行用法错误
+pub const EXIT_USAGE: i32 = 2;
+
+/// 命令行用法说明，随界面语言变化
+pub fn usage() -> &'static str {
+    tr!("cli.usage")
+}
+
+/// 一次完整的命令行调用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub command: Command,
+    pub options: Options,
+}
+
+/// 可以出现在任意位置的全局选项
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub profile_dir: Option<PathBuf>,
+    /// `--lang` 指定的界面语言，未指定时由环境变量决定
+    pub lang: Option<Locale>,
+    /// `--theme` 指定的输出主题，未指定时由环境变量决定
+    pub theme: Option<Theme>,
+}
+
+/// 解析后的命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// 交互式菜单
+    Interactive,
+    /// 回放脚本文件中的菜单命令
+    Script(PathBuf),
+    List,
+    Run(RunTarget),
+    /// 列出章节示例的小节
+    Sections(u8),
+    /// 显示章节示例或其中一个小节的源码
+    Source { chapter: u8, section: Option<&'static str> },
+    Summary(u8),
+    Knowledge(u8),
+    Quiz(QuizArgs),
+    Search(SearchArgs),
+    /// 讲解编译错误
+    Explain(ExplainTarget),
+    /// 改错练习
+    Exercise(ExerciseCommand),
+    /// 用隐藏测试给练习题的答案评分
+    Grade(GradeTarget),
+    /// 监视目录中的练习文件，保存后自动重新检查；未指定目录时监视改错练习的目录
+    Watch(Option<PathBuf>),
+    /// 验证指定章节或全部章节的代码示例
+    Verify(Option<u8>),
+    Progress,
+    /// 根据学习进度推荐下一章
+    Next,
+    /// 学到指定章节的路线
+    Path(u8),
+    /// 输出章节前置关系图
+    Graph,
+    Help,
+}
+
+/// `run` 命令的运行范围
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunTarget {
+    Chapter(u8),
+    /// 只运行章节中的一个小节，小节标识已经过查找
+    Section { chapter: u8, section: &'static str },
+    /// 逐节运行，每节之后等待回车
+    Step(u8),
+    Group(Group),
+    All,
+}
+
+/// `quiz` 命令的参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizArgs {
+    pub chapter: u8,
+    /// 答案文件，未指定时从标准输入读取
+    pub answers: Option<PathBuf>,
+    /// 出题顺序的随机种子，未指定时使用当前时间
+    pub seed: Option<u64>,
+}
+
+/// `explain` 命令讲解的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExplainTarget {
+    /// 错误码，例如 `E0382`
+    Code(String),
+    /// `.rs` 文件用 rustc 编译检查，其他文件按诊断输出读取
+    File(PathBuf),
+    /// 从标准输入读取粘贴的诊断输出
+    Stdin,
+}
+
+/// `exercise` 命令的操作，练习编号已换成完整的文件名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExerciseCommand {
+    /// 列出所有练习
+    List,
+    /// 准备练习文件并显示要求
+    Start(String),
+    /// 重新编译练习文件
+    Check(String),
+    /// 恢复练习的原始代码
+    Reset(String),
+    /// 检查目录中的练习用例是否按说明编译失败，未指定目录时检查内置练习
+    Verify(Option<PathBuf>),
+}
+
+/// `grade` 命令的操作，题目编号已换成评分文件名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GradeTarget {
+    /// 列出可以评分的题目
+    List,
+    /// 给提交的文件评分
+    Submission { id: String, file: PathBuf },
+}
+
+/// `search` 命令的参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchArgs {
+    /// 所有非选项参数用空格连接
+    pub query: String,
+    pub limit: usize,
+    /// 同时显示排在最前的章节的知识点
+    pub open: bool,
+}
+
+/// 命令行解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    UnknownCommand(String),
+    /// 缺少的参数，附带参数名称的翻译键，例如 `cli.argument.chapter`
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    UnknownChapter(String),
+    UnknownGroup(String),
+    InvalidNumber(String),
+    UnknownLanguage(String),
+    UnknownExercise(String),
+    UnknownAssignment(String),
+    /// 练习存在但没有评分文件，附带原因的翻译键
+    NoGrader { id: String, reason: &'static str },
+    UnknownTheme(String),
+    UnknownSection { chapter: u8, section: String },
+    /// 小节关键词匹配到多个小节
+    AmbiguousSection { section: String, candidates: Vec<&'static str> },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(cmd) => write!(f, "{}", tr!("cli.error.unknown_command", command = cmd)),
+            CliError::MissingArgument(key) => {
+                write!(f, "{}", tr!("cli.error.missing_argument", what = i18n::text(key)))
+            }
+            CliError::UnexpectedArgument(arg) => {
+                write!(f, "{}", tr!("cli.error.unexpected_argument", argument = arg))
+            }
+            CliError::UnknownChapter(id) => {
+                write!(f, "{}", tr!("cli.error.unknown_chapter", id = id, max = chapters::max_id()))
+            }
+            CliError::UnknownGroup(group) => write!(f, "{}", tr!("cli.error.unknown_group", group = group)),
+            CliError::InvalidNumber(value) => write!(f, "{}", tr!("cli.error.invalid_number", value = value)),
+            CliError::UnknownLanguage(lang) => write!(f, "{}", tr!("cli.error.unknown_language", lang = lang)),
+            CliError::UnknownExercise(id) => write!(f, "{}", tr!("cli.error.unknown_exercise", id = id)),
+            CliError::UnknownAssignment(id) => write!(f, "{}", tr!("cli.error.unknown_assignment", id = id)),
+            CliError::NoGrader { id, reason } => write!(f, "{}", tr!("cli.error.no_grader", id = id, reason = i18n::text(reason))),
+            CliError::UnknownTheme(theme) => write!(f, "{}", tr!("cli.error.unknown_theme", theme = theme)),
+            CliError::UnknownSection { chapter, section } => {
+                write!(f, "{}", tr!("cli.error.unknown_section", id = chapter, section = section))
+            }
+            CliError::AmbiguousSection { section, candidates } => {
+                let candidates = candidates.join(", ");
+                write!(f, "{}", tr!("cli.error.ambiguous_section", section = section, candidates = candidates))
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// 解析命令行参数（不包含程序名）
+pub fn parse<I>(args: I) -> Result<Invocation, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let (options, rest) = extract_options(args)?;
+    let command = parse_command(rest)?;
+    Ok(Invocation { command, options })
+}
+
+/// 取出全局选项，返回剩余参数
+fn extract_options<I>(args: I) -> Result<(Options, Vec<String>), CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--profile-dir" => {
+                let dir = args.next().ok_or(CliError::MissingArgument("cli.argument.profile_dir"))?;
+                options.profile_dir = Some(PathBuf::from(dir));
+            }
+            "--lang" => {
+                let tag = args.next().ok_or(CliError::MissingArgument("cli.argument.lang"))?;
+                let locale = Locale::from_tag(&tag).ok_or(CliError::UnknownLanguage(tag))?;
+                options.lang = Some(locale);
+            }
+            "--theme" => {
+                let tag = args.next().ok_or(CliError::MissingArgument("cli.argument.theme"))?;
+                let theme = Theme::from_tag(&tag).ok_or(CliError::UnknownTheme(tag))?;
+                options.theme = Some(theme);
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+fn parse_command(args: Vec<String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    let Some(command) = args.next() else {
+        return Ok(Command::Interactive);
+    };
+
+    let parsed = match command.as_str() {
+        "list" => Command::List,
+        "progress" => Command::Progress,
+        "next" => Command::Next,
+        "graph" => Command::Graph,
+        "help" | "--help" | "-h" => Command::Help,
+        "--script" => {
+            let path = args.next().ok_or(CliError::MissingArgument("cli.argument.script"))?;
+            Command::Script(PathBuf::from(path))
+        }
+        "run" => Command::Run(parse_run_target(&mut args)?),
+        "summary" => Command::Summary(parse_chapter(args.next())?),
+        "knowledge" => Command::Knowledge(parse_chapter(args.next())?),
+        "sections" => Command::Sections(parse_chapter(args.next())?),
+        "source" => {
+            let chapter = parse_chapter(args.next())?;
+            let section = args.next().map(|query| parse_section(chapter, query)).transpose()?;
+            Command::Source { chapter, section }
+        }
+        "path" => Command::Path(parse_chapter(args.next())?),
+        "quiz" => Command::Quiz(parse_quiz(&mut args)?),
+        "search" => Command::Search(parse_search(&mut args)?),
+        "explain" => Command::Explain(match args.next() {
+            Some(arg) if explain::is_error_code(&arg) => ExplainTarget::Code(arg.to_uppercase()),
+            Some(arg) if arg == "-" => ExplainTarget::Stdin,
+            Some(arg) => ExplainTarget::File(PathBuf::from(arg)),
+            None => return Err(CliError::MissingArgument("cli.argument.explain")),
+        }),
+        "exercise" => Command::Exercise(match args.next().as_deref() {
+            None => ExerciseCommand::List,
+            Some("check") => ExerciseCommand::Check(parse_exercise(args.next())?),
+            Some("reset") => ExerciseCommand::Reset(parse_exercise(args.next())?),
+            Some("verify") => ExerciseCommand::Verify(args.next().map(PathBuf::from)),
+            Some(id) => ExerciseCommand::Start(parse_exercise(Some(id.to_string()))?),
+        }),
+        "grade" => Command::Grade(match args.next() {
+            None => return Err(CliError::MissingArgument("cli.argument.assignment")),
+            Some(arg) if arg == "list" => GradeTarget::List,
+            Some(arg) => GradeTarget::Submission {
+                id: parse_assignment(arg)?,
+                file: PathBuf::from(args.next().ok_or(CliError::MissingArgument("cli.argument.submission"))?),
+            },
+        }),
+        "watch" => Command::Watch(args.next().map(PathBuf::from)),
+        "verify" => match args.next() {
+            Some(id) => Command::Verify(Some(parse_chapter(Some(id))?)),
+            None => Command::Verify(None),
+        },
+        _ => return Err(CliError::UnknownCommand(command)),
+    };
+
+    match args.next() {
+        Some(extra) => Err(CliError::UnexpectedArgument(extra)),
+        None => Ok(parsed),
+    }
+}
+
+fn parse_run_target(args: &mut impl Iterator<Item = String>) -> Result<RunTarget, CliError> {
+    match args.next().as_deref() {
+        Some("--all") | Some("all") => Ok(RunTarget::All),
+        Some("--group") | Some("-g") => {
+            let key = args.next().ok_or(CliError::MissingArgument("cli.argument.group"))?;
+            Group::from_key(&key)
+                .map(RunTarget::Group)
+                .ok_or(CliError::UnknownGroup(key))
+        }
+        other => {
+            let chapter = parse_chapter(other.map(str::to_string))?;
+            match args.next().as_deref() {
+                None => Ok(RunTarget::Chapter(chapter)),
+                Some("--step") => Ok(RunTarget::Step(chapter)),
+                Some("--section") | Some("-s") => {
+                    let query = args.next().ok_or(CliError::MissingArgument("cli.argument.section"))?;
+                    Ok(RunTarget::Section { chapter, section: parse_section(chapter, query)? })
+                }
+                Some(arg) if arg.starts_with("--") => Err(CliError::UnexpectedArgument(arg.to_string())),
+                Some(query) => Ok(RunTarget::Section { chapter, section: parse_section(chapter, query.to_string())? }),
+            }
+        }
+    }
+}
+
+/// 查找小节，返回小节标识
+fn parse_section(chapter: u8, query: String) -> Result<&'static str, CliError> {
+    let found = sections::find(chapters::get(chapter).expect("parse_chapter 已校验章节编号"), &query);
+    match found {
+        Ok(section) => Ok(section.id),
+        Err(candidates) if candidates.is_empty() => Err(CliError::UnknownSection { chapter, section: query }),
+        Err(candidates) => Err(CliError::AmbiguousSection {
+            section: query,
+            candidates: candidates.iter().map(|section| section.id).collect(),
+        }),
+    }
+}
+
+fn parse_quiz(args: &mut impl Iterator<Item = String>) -> Result<QuizArgs, CliError> {
+    let mut quiz = QuizArgs {
+        chapter: parse_chapter(args.next())?,
+        answers: None,
+        seed: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let path = args.next().ok_or(CliError::MissingArgument("cli.argument.answers"))?;
+                quiz.answers = Some(PathBuf::from(path));
+            }
+            "--seed" => {
+                let seed = args.next().ok_or(CliError::MissingArgument("cli.argument.seed"))?;
+                quiz.seed = Some(seed.parse().map_err(|_| CliError::InvalidNumber(seed))?);
+            }
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(quiz)
+}
+
+fn parse_search(args: &mut impl Iterator<Item = String>) -> Result<SearchArgs, CliError> {
+    let mut words = Vec::new();
+    let mut search = SearchArgs {
+        query: String::new(),
+        limit: search::DEFAULT_LIMIT,
+        open: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--limit" => {
+                let limit = args.next().ok_or(CliError::MissingArgument("cli.argument.limit"))?;
+                search.limit = limit.parse().map_err(|_| CliError::InvalidNumber(limit))?;
+            }
+            "--open" => search.open = true,
+            _ => words.push(arg),
+        }
+    }
+    if words.is_empty() {
+        return Err(CliError::MissingArgument("cli.argument.query"));
+    }
+    search.query = words.join(" ");
+    Ok(search)
+}
+
+fn parse_chapter(arg: Option<String>) -> Result<u8, CliError> {
+    let arg = arg.ok_or(CliError::MissingArgument("cli.argument.chapter"))?;
+    arg.parse::<u8>()
+        .ok()
+        .and_then(chapters::get)
+        .map(|chapter| chapter.id)
+        .ok_or(CliError::UnknownChapter(arg))
+}
+
+fn parse_exercise(arg: Option<String>) -> Result<String, CliError> {
+    let arg = arg.ok_or(CliError::MissingArgument("cli.argument.exercise"))?;
+    exercises::get(&arg)
+        .map(|exercise| exercise.id.clone())
+        .ok_or(CliError::UnknownExercise(arg))
+}
+
+fn parse_assignment(arg: String) -> Result<String, CliError> {
+    if let Some(assignment) = grader::get(&arg) {
+        return Ok(assignment.id.clone());
+    }
+    match grader::ungraded(&arg) {
+        Some(reason) => Err(CliError::NoGrader { id: arg, reason }),
+        None => Err(CliError::UnknownAssignment(arg)),
+    }
+}
@@ -1,11 +1,19 @@
 // Rust 基础教程 - 示例代码集合
 // 包含所有章节的可运行示例
+// 每个示例都有两个版本：`xxx()` 打印到标准输出，`xxx_to(out)` 写入任意输出
 
 // 允许未使用的代码，因为这是教学示例
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
 use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// 把示例输出写到标准输出
+fn to_stdout(example: fn(&mut dyn Write) -> io::Result<()>) {
+    let stdout = io::stdout();
+    example(&mut stdout.lock()).expect("写入标准输出失败");
+}
 
 // ============================================================================
 // 第1-4章：基础教程示例
@@ -13,86 +21,107 @@ use std::collections::HashMap;
 
 /// 第1章：变量与常量示例
 pub fn variables_and_constants() {
-    println!("📝 变量与常量示例");
+    to_stdout(variables_and_constants_to)
+}
+
+/// 第1章：变量与常量示例，输出写入 `out`
+pub fn variables_and_constants_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "📝 变量与常量示例")?;
     
     // 不可变变量
     let x = 5;
-    println!("不可变变量 x = {}", x);
+    writeln!(out, "不可变变量 x = {}", x)?;
     
     // 可变变量
     let mut y = 10;
-    println!("可变变量 y = {}", y);
+    writeln!(out, "可变变量 y = {}", y)?;
     y = 15;
-    println!("修改后 y = {}", y);
+    writeln!(out, "修改后 y = {}", y)?;
     
     // 常量
     const MAX_POINTS: u32 = 100_000;
-    println!("常量 MAX_POINTS = {}", MAX_POINTS);
+    writeln!(out, "常量 MAX_POINTS = {}", MAX_POINTS)?;
     
     // 变量遮蔽
     let z = 5;
     let z = z + 1;
     let z = z * 2;
-    println!("遮蔽后 z = {}", z);
+    writeln!(out, "遮蔽后 z = {}", z)?;
     
     // 类型转换
     let guess: u32 = "42".parse().expect("不是数字！");
-    println!("解析的数字 = {}", guess);
+    writeln!(out, "解析的数字 = {}", guess)?;
+    
+    Ok(())
 }
 
 /// 第2章：数据类型示例
 pub fn data_types() {
-    println!("🔢 数据类型示例");
+    to_stdout(data_types_to)
+}
+
+/// 第2章：数据类型示例，输出写入 `out`
+pub fn data_types_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔢 数据类型示例")?;
     
     // 整数类型
     let a: i32 = 42;
     let b: u64 = 1_000_000;
-    println!("整数: i32 = {}, u64 = {}", a, b);
+    writeln!(out, "整数: i32 = {}, u64 = {}", a, b)?;
     
     // 浮点数
     let c: f64 = 3.14159;
     let d: f32 = 2.718;
-    println!("浮点数: f64 = {}, f32 = {}", c, d);
+    writeln!(out, "浮点数: f64 = {}, f32 = {}", c, d)?;
     
     // 布尔值
     let is_rust_awesome = true;
     let is_learning = false;
-    println!("布尔值: {} 和 {}", is_rust_awesome, is_learning);
+    writeln!(out, "布尔值: {} 和 {}", is_rust_awesome, is_learning)?;
     
     // 字符
     let heart_eyed_cat = '😻';
     let letter = 'A';
-    println!("字符: {} 和 {}", heart_eyed_cat, letter);
+    writeln!(out, "字符: {} 和 {}", heart_eyed_cat, letter)?;
     
     // 元组
     let tup: (i32, f64, u8) = (500, 6.4, 1);
     let (x, y, z) = tup;
-    println!("元组解构: x={}, y={}, z={}", x, y, z);
+    writeln!(out, "元组解构: x={}, y={}, z={}", x, y, z)?;
     
     // 数组
     let arr = [1, 2, 3, 4, 5];
-    println!("数组第一个元素: {}", arr[0]);
-    println!("数组长度: {}", arr.len());
+    writeln!(out, "数组第一个元素: {}", arr[0])?;
+    writeln!(out, "数组长度: {}", arr.len())?;
+    
+    Ok(())
 }
 
 /// 第3章：函数示例
 pub fn functions_demo() {
-    println!("⚙️ 函数示例");
+    to_stdout(functions_demo_to)
+}
+
+/// 第3章：函数示例，输出写入 `out`
+pub fn functions_demo_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "⚙️ 函数示例")?;
     
     // 基本函数调用
     let result = add_numbers(5, 3);
-    println!("5 + 3 = {}", result);
+    writeln!(out, "5 + 3 = {}", result)?;
     
     // 有返回值的函数
     let product = multiply(4, 7);
-    println!("4 × 7 = {}", product);
+    writeln!(out, "4 × 7 = {}", product)?;
     
     // 表达式与语句
     let y = {
         let x = 3;
         x + 1  // 表达式，没有分号
     };
-    println!("代码块的值: {}", y);
+    writeln!(out, "代码块的值: {}", y)?;
+    
+    Ok(())
 }
 
 fn add_numbers(a: i32, b: i32) -> i32 {
@@ -105,22 +134,27 @@ fn multiply(x: i32, y: i32) -> i32 {
 
 /// 第4章：控制流示例
 pub fn control_flow() {
-    println!("🔄 控制流示例");
+    to_stdout(control_flow_to)
+}
+
+/// 第4章：控制流示例，输出写入 `out`
+pub fn control_flow_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔄 控制流示例")?;
     
     // if 表达式
     let number = 6;
     if number % 4 == 0 {
-        println!("数字能被 4 整除");
+        writeln!(out, "数字能被 4 整除")?;
     } else if number % 3 == 0 {
-        println!("数字能被 3 整除");
+        writeln!(out, "数字能被 3 整除")?;
     } else {
-        println!("数字不能被 4 或 3 整除");
+        writeln!(out, "数字不能被 4 或 3 整除")?;
     }
     
     // if 作为表达式
     let condition = true;
     let number = if condition { 5 } else { 6 };
-    println!("条件表达式的值: {}", number);
+    writeln!(out, "条件表达式的值: {}", number)?;
     
     // loop 循环
     let mut counter = 0;
@@ -130,26 +164,28 @@ pub fn control_flow() {
             break counter * 2;
         }
     };
-    println!("loop 循环结果: {}", result);
+    writeln!(out, "loop 循环结果: {}", result)?;
     
     // while 循环
     let mut number = 3;
     while number != 0 {
-        println!("倒计时: {}!", number);
+        writeln!(out, "倒计时: {}!", number)?;
         number -= 1;
     }
-    println!("发射！🚀");
+    writeln!(out, "发射！🚀")?;
     
     // for 循环
     let a = [10, 20, 30, 40, 50];
     for element in a {
-        println!("数组元素: {}", element);
+        writeln!(out, "数组元素: {}", element)?;
     }
     
     // 范围循环
     for number in (1..4).rev() {
-        println!("倒序: {}!", number);
+        writeln!(out, "倒序: {}!", number)?;
     }
+    
+    Ok(())
 }
 
 // ============================================================================
@@ -158,121 +194,142 @@ pub fn control_flow() {
 
 /// 第5章：内存管理示例
 pub fn memory_management() {
-    println!("🧠 内存管理示例");
+    to_stdout(memory_management_to)
+}
+
+/// 第5章：内存管理示例，输出写入 `out`
+pub fn memory_management_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🧠 内存管理示例")?;
     
     // 栈分配
-    println!("\n📚 栈内存示例：");
+    writeln!(out, "\n📚 栈内存示例：")?;
     let stack_var = 42;
     let stack_array = [1, 2, 3, 4, 5];
-    println!("栈变量: {}", stack_var);
-    println!("栈数组: {:?}", stack_array);
+    writeln!(out, "栈变量: {}", stack_var)?;
+    writeln!(out, "栈数组: {:?}", stack_array)?;
     
     // 堆分配
-    println!("\n🏗️ 堆内存示例：");
+    writeln!(out, "\n🏗️ 堆内存示例：")?;
     let heap_string = String::from("Hello, Heap!");
     let heap_vector = vec![1, 2, 3, 4, 5];
-    println!("堆字符串: {}", heap_string);
-    println!("堆向量: {:?}", heap_vector);
+    writeln!(out, "堆字符串: {}", heap_string)?;
+    writeln!(out, "堆向量: {:?}", heap_vector)?;
     
     // 指针类型
-    println!("\n👉 指针类型示例：");
+    writeln!(out, "\n👉 指针类型示例：")?;
     let x = 5;
     let raw_ptr = &x as *const i32;
-    println!("原始指针地址: {:p}", raw_ptr);
+    writeln!(out, "原始指针地址: {:p}", raw_ptr)?;
     
     // 函数调用栈
-    println!("\n📞 函数调用栈：");
-    demonstrate_call_stack();
+    writeln!(out, "\n📞 函数调用栈：")?;
+    demonstrate_call_stack(out)?;
+    
+    Ok(())
 }
 
-fn demonstrate_call_stack() {
-    println!("  → 进入 demonstrate_call_stack");
-    call_level_1();
-    println!("  ← 离开 demonstrate_call_stack");
+fn demonstrate_call_stack(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "  → 进入 demonstrate_call_stack")?;
+    call_level_1(out)?;
+    writeln!(out, "  ← 离开 demonstrate_call_stack")
 }
 
-fn call_level_1() {
-    println!("    → 进入 call_level_1");
-    call_level_2();
-    println!("    ← 离开 call_level_1");
+fn call_level_1(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "    → 进入 call_level_1")?;
+    call_level_2(out)?;
+    writeln!(out, "    ← 离开 call_level_1")
 }
 
-fn call_level_2() {
-    println!("      → 进入 call_level_2");
-    println!("      ✨ 在最深层函数中");
-    println!("      ← 离开 call_level_2");
+fn call_level_2(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "      → 进入 call_level_2")?;
+    writeln!(out, "      ✨ 在最深层函数中")?;
+    writeln!(out, "      ← 离开 call_level_2")
 }
 
 /// 第6章：所有权示例
 pub fn ownership() {
-    println!("🏠 所有权示例");
+    to_stdout(ownership_to)
+}
+
+/// 第6章：所有权示例，输出写入 `out`
+pub fn ownership_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🏠 所有权示例")?;
     
     // 基本所有权
-    println!("\n📦 基本所有权：");
+    writeln!(out, "\n📦 基本所有权：")?;
     let s1 = String::from("hello");
     let s2 = s1; // s1 被移动到 s2
     // println!("{}", s1); // 这会报错
-    println!("s2: {}", s2);
+    writeln!(out, "s2: {}", s2)?;
     
     // Clone 复制
-    println!("\n🔄 Clone 复制：");
+    writeln!(out, "\n🔄 Clone 复制：")?;
     let s3 = String::from("world");
     let s4 = s3.clone(); // 深拷贝
-    println!("s3: {}, s4: {}", s3, s4);
+    writeln!(out, "s3: {}, s4: {}", s3, s4)?;
     
     // Copy trait
-    println!("\n📋 Copy trait：");
+    writeln!(out, "\n📋 Copy trait：")?;
     let x = 5;
     let y = x; // Copy，不是移动
-    println!("x: {}, y: {}", x, y);
+    writeln!(out, "x: {}, y: {}", x, y)?;
     
     // 函数所有权
-    println!("\n⚙️ 函数所有权：");
+    writeln!(out, "\n⚙️ 函数所有权：")?;
     let s = String::from("function");
-    takes_ownership(s);
+    takes_ownership(out, s)?;
     // println!("{}", s); // 这会报错，s 已被移动
     
     let x = 5;
-    makes_copy(x);
-    println!("x 仍然可用: {}", x);
+    makes_copy(out, x)?;
+    writeln!(out, "x 仍然可用: {}", x)?;
+    
+    Ok(())
 }
 
-fn takes_ownership(some_string: String) {
-    println!("函数获得所有权: {}", some_string);
+fn takes_ownership(out: &mut dyn Write, some_string: String) -> io::Result<()> {
+    writeln!(out, "函数获得所有权: {}", some_string)
 } // some_string 在这里被丢弃
 
-fn makes_copy(some_integer: i32) {
-    println!("函数获得副本: {}", some_integer);
+fn makes_copy(out: &mut dyn Write, some_integer: i32) -> io::Result<()> {
+    writeln!(out, "函数获得副本: {}", some_integer)
 } // some_integer 离开作用域，但没有特殊处理
 
 /// 第7章：借用机制示例
 pub fn borrowing() {
-    println!("🔗 借用机制示例");
+    to_stdout(borrowing_to)
+}
+
+/// 第7章：借用机制示例，输出写入 `out`
+pub fn borrowing_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔗 借用机制示例")?;
     
     // 不可变引用
-    println!("\n👀 不可变引用：");
+    writeln!(out, "\n👀 不可变引用：")?;
     let s1 = String::from("hello");
     let len = calculate_length(&s1);
-    println!("字符串 '{}' 的长度是 {}", s1, len);
+    writeln!(out, "字符串 '{}' 的长度是 {}", s1, len)?;
     
     // 可变引用
-    println!("\n✏️ 可变引用：");
+    writeln!(out, "\n✏️ 可变引用：")?;
     let mut s = String::from("hello");
     change(&mut s);
-    println!("修改后的字符串: {}", s);
+    writeln!(out, "修改后的字符串: {}", s)?;
     
     // 字符串切片
-    println!("\n🔪 字符串切片：");
+    writeln!(out, "\n🔪 字符串切片：")?;
     let s = String::from("hello world");
     let hello = &s[0..5];
     let world = &s[6..11];
-    println!("切片: '{}' 和 '{}'", hello, world);
+    writeln!(out, "切片: '{}' 和 '{}'", hello, world)?;
     
     // 数组切片
-    println!("\n📏 数组切片：");
+    writeln!(out, "\n📏 数组切片：")?;
     let a = [1, 2, 3, 4, 5];
     let slice = &a[1..4];
-    println!("数组切片: {:?}", slice);
+    writeln!(out, "数组切片: {:?}", slice)?;
+    
+    Ok(())
 }
 
 fn calculate_length(s: &String) -> usize {
@@ -285,51 +342,58 @@ fn change(some_string: &mut String) {
 
 /// 第8章：结构体示例
 pub fn structs() {
-    println!("🏗️ 结构体示例");
+    to_stdout(structs_to)
+}
+
+/// 第8章：结构体示例，输出写入 `out`
+pub fn structs_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🏗️ 结构体示例")?;
     
     // 基本结构体
-    println!("\n🏢 基本结构体：");
+    writeln!(out, "\n🏢 基本结构体：")?;
     let user1 = User {
         email: String::from("someone@example.com"),
         username: String::from("someusername123"),
         active: true,
         sign_in_count: 1,
     };
-    println!("用户: {} ({})", user1.username, user1.email);
+    writeln!(out, "用户: {} ({})", user1.username, user1.email)?;
     
     // 结构体更新语法
-    println!("\n🔄 结构体更新语法：");
+    writeln!(out, "\n🔄 结构体更新语法：")?;
     let user2 = User {
         email: String::from("another@example.com"),
         ..user1
     };
-    println!("新用户: {} ({})", user2.username, user2.email);
+    writeln!(out, "新用户: {} ({})", user2.username, user2.email)?;
     
     // 元组结构体
-    println!("\n📦 元组结构体：");
+    writeln!(out, "\n📦 元组结构体：")?;
     let black = Color(0, 0, 0);
     let origin = Point(0, 0, 0);
-    println!("颜色: ({}, {}, {})", black.0, black.1, black.2);
-    println!("点: ({}, {}, {})", origin.0, origin.1, origin.2);
+    writeln!(out, "颜色: ({}, {}, {})", black.0, black.1, black.2)?;
+    writeln!(out, "点: ({}, {}, {})", origin.0, origin.1, origin.2)?;
     
     // 方法
-    println!("\n⚙️ 方法示例：");
+    writeln!(out, "\n⚙️ 方法示例：")?;
     let rect1 = Rectangle {
         width: 30,
         height: 50,
     };
-    println!("矩形面积: {}", rect1.area());
+    writeln!(out, "矩形面积: {}", rect1.area())?;
     
     let rect2 = Rectangle {
         width: 10,
         height: 40,
     };
-    println!("rect1 能容纳 rect2 吗？{}", rect1.can_hold(&rect2));
+    writeln!(out, "rect1 能容纳 rect2 吗？{}", rect1.can_hold(&rect2))?;
     
     // 关联函数
-    println!("\n🔧 关联函数：");
+    writeln!(out, "\n🔧 关联函数：")?;
     let sq = Rectangle::square(3);
-    println!("正方形面积: {}", sq.area());
+    writeln!(out, "正方形面积: {}", sq.area())?;
+    
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -368,85 +432,99 @@ impl Rectangle {
 
 /// 第9章：常用类型示例
 pub fn common_types() {
-    println!("📦 常用类型示例");
+    to_stdout(common_types_to)
+}
+
+/// 第9章：常用类型示例，输出写入 `out`
+pub fn common_types_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "📦 常用类型示例")?;
     
     // Vector 示例
-    println!("\n📋 Vector 示例：");
+    writeln!(out, "\n📋 Vector 示例：")?;
     let mut v = Vec::new();
     v.push(5);
     v.push(6);
     v.push(7);
     v.push(8);
-    println!("Vector: {:?}", v);
+    writeln!(out, "Vector: {:?}", v)?;
     
     let v2 = vec![1, 2, 3];
-    println!("使用宏创建的 Vector: {:?}", v2);
+    writeln!(out, "使用宏创建的 Vector: {:?}", v2)?;
     
     // String 示例
-    println!("\n📝 String 示例：");
+    writeln!(out, "\n📝 String 示例：")?;
     let mut s = String::new();
     s.push_str("hello");
     s.push(' ');
     s.push_str("world");
-    println!("构建的字符串: {}", s);
+    writeln!(out, "构建的字符串: {}", s)?;
     
     let s1 = String::from("Hello, ");
     let s2 = String::from("world!");
     let s3 = s1 + &s2; // s1 被移动了，不能再使用
-    println!("连接的字符串: {}", s3);
+    writeln!(out, "连接的字符串: {}", s3)?;
     
     // HashMap 示例
-    println!("\n🗺️ HashMap 示例：");
+    writeln!(out, "\n🗺️ HashMap 示例：")?;
     let mut scores = HashMap::new();
     scores.insert(String::from("Blue"), 10);
     scores.insert(String::from("Yellow"), 50);
     
     for (key, value) in &scores {
-        println!("队伍 {} 得分 {}", key, value);
+        writeln!(out, "队伍 {} 得分 {}", key, value)?;
     }
     
     // 查找值
     let team_name = String::from("Blue");
     let score = scores.get(&team_name);
     match score {
-        Some(s) => println!("Blue 队得分: {}", s),
-        None => println!("Blue 队不存在"),
+        Some(s) => writeln!(out, "Blue 队得分: {}", s)?,
+        None => writeln!(out, "Blue 队不存在")?,
     }
+    
+    Ok(())
 }
 
 /// 第10章：枚举示例
 pub fn enums() {
-    println!("🎯 枚举示例");
+    to_stdout(enums_to)
+}
+
+/// 第10章：枚举示例，输出写入 `out`
+pub fn enums_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🎯 枚举示例")?;
     
     // 基本枚举
-    println!("\n🌐 IP 地址枚举：");
+    writeln!(out, "\n🌐 IP 地址枚举：")?;
     let home = IpAddr::V4(127, 0, 0, 1);
     let loopback = IpAddr::V6(String::from("::1"));
-    println!("IPv4: {:?}", home);
-    println!("IPv6: {:?}", loopback);
+    writeln!(out, "IPv4: {:?}", home)?;
+    writeln!(out, "IPv6: {:?}", loopback)?;
     
     // Option 枚举
-    println!("\n❓ Option 枚举：");
+    writeln!(out, "\n❓ Option 枚举：")?;
     let some_number = Some(5);
     let some_string = Some("a string");
     let absent_number: Option<i32> = None;
     
-    println!("Some number: {:?}", some_number);
-    println!("Some string: {:?}", some_string);
-    println!("Absent number: {:?}", absent_number);
+    writeln!(out, "Some number: {:?}", some_number)?;
+    writeln!(out, "Some string: {:?}", some_string)?;
+    writeln!(out, "Absent number: {:?}", absent_number)?;
     
     // match 模式匹配
-    println!("\n🎯 match 模式匹配：");
+    writeln!(out, "\n🎯 match 模式匹配：")?;
     let coin = Coin::Quarter(UsState::Alaska);
-    let value = value_in_cents(coin);
-    println!("硬币价值: {} 美分", value);
+    let value = value_in_cents(out, coin)?;
+    writeln!(out, "硬币价值: {} 美分", value)?;
     
     // if let 语法
-    println!("\n🔍 if let 语法：");
+    writeln!(out, "\n🔍 if let 语法：")?;
     let config_max = Some(3u8);
     if let Some(max) = config_max {
-        println!("最大值配置为 {}", max);
+        writeln!(out, "最大值配置为 {}", max)?;
     }
+    
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -469,51 +547,57 @@ enum Coin {
     Quarter(UsState),
 }
 
-fn value_in_cents(coin: Coin) -> u8 {
-    match coin {
+fn value_in_cents(out: &mut dyn Write, coin: Coin) -> io::Result<u8> {
+    let cents = match coin {
         Coin::Penny => {
-            println!("幸运便士！");
+            writeln!(out, "幸运便士！")?;
             1
         }
         Coin::Nickel => 5,
         Coin::Dime => 10,
         Coin::Quarter(state) => {
-            println!("来自 {:?} 的25美分硬币！", state);
+            writeln!(out, "来自 {:?} 的25美分硬币！", state)?;
             25
         }
-    }
+    };
+    Ok(cents)
 }
 
 /// 第11章：泛型与特征示例
 pub fn generics_traits() {
-    println!("🔧 泛型与特征示例");
+    to_stdout(generics_traits_to)
+}
+
+/// 第11章：泛型与特征示例，输出写入 `out`
+pub fn generics_traits_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔧 泛型与特征示例")?;
     
     // 泛型函数
-    println!("\n🔄 泛型函数：");
+    writeln!(out, "\n🔄 泛型函数：")?;
     let number_list = vec![34, 50, 25, 100, 65];
     let result = largest(&number_list);
-    println!("最大的数字是 {}", result);
+    writeln!(out, "最大的数字是 {}", result)?;
     
     let char_list = vec!['y', 'm', 'a', 'q'];
     let result = largest(&char_list);
-    println!("最大的字符是 {}", result);
+    writeln!(out, "最大的字符是 {}", result)?;
     
     // 泛型结构体
-    println!("\n📦 泛型结构体：");
+    writeln!(out, "\n📦 泛型结构体：")?;
     let integer = Point2D { x: 5, y: 10 };
     let float = Point2D { x: 1.0, y: 4.0 };
-    println!("整数点: ({}, {})", integer.x, integer.y);
-    println!("浮点数点: ({}, {})", float.x, float.y);
+    writeln!(out, "整数点: ({}, {})", integer.x, integer.y)?;
+    writeln!(out, "浮点数点: ({}, {})", float.x, float.y)?;
     
     // 特征
-    println!("\n🎭 特征示例：");
+    writeln!(out, "\n🎭 特征示例：")?;
     let tweet = Tweet {
         username: String::from("horse_ebooks"),
         content: String::from("当然，就像你可能知道的那样，人们"),
         reply: false,
         retweet: false,
     };
-    println!("1 条新推文：{}", tweet.summarize());
+    writeln!(out, "1 条新推文：{}", tweet.summarize())?;
     
     let article = NewsArticle {
         headline: String::from("企鹅队再次赢得冠军！"),
@@ -521,7 +605,9 @@ pub fn generics_traits() {
         author: String::from("Iceburgh"),
         content: String::from("企鹅队再次获得了冠军。"),
     };
-    println!("新文章可用！{}", article.summarize());
+    writeln!(out, "新文章可用！{}", article.summarize())?;
+    
+    Ok(())
 }
 
 fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
@@ -572,30 +658,37 @@ impl Summary for Tweet {
 
 /// 第12章：生命周期示例
 pub fn lifetimes() {
-    println!("⏰ 生命周期示例");
+    to_stdout(lifetimes_to)
+}
+
+/// 第12章：生命周期示例，输出写入 `out`
+pub fn lifetimes_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "⏰ 生命周期示例")?;
     
     // 基本生命周期
-    println!("\n🔗 基本生命周期：");
+    writeln!(out, "\n🔗 基本生命周期：")?;
     let string1 = String::from("abcd");
     let string2 = "xyz";
     
     let result = longest(string1.as_str(), string2);
-    println!("最长的字符串是 {}", result);
+    writeln!(out, "最长的字符串是 {}", result)?;
     
     // 结构体中的生命周期
-    println!("\n📚 结构体中的生命周期：");
+    writeln!(out, "\n📚 结构体中的生命周期：")?;
     let novel = String::from("Call me Ishmael. Some years ago...");
     let first_sentence = novel.split('.').next().expect("Could not find a '.'");
     let i = ImportantExcerpt {
         part: first_sentence,
     };
-    println!("重要摘录: {}", i.part);
+    writeln!(out, "重要摘录: {}", i.part)?;
     
     // 生命周期省略
-    println!("\n✂️ 生命周期省略：");
+    writeln!(out, "\n✂️ 生命周期省略：")?;
     let s = String::from("hello world");
     let word = first_word(&s);
-    println!("第一个单词: {}", word);
+    writeln!(out, "第一个单词: {}", word)?;
+    
+    Ok(())
 }
 
 fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
@@ -622,12 +715,17 @@ fn first_word(s: &str) -> &str {
     &s[..]
 }
 
-/// 第13章：特征对象示例
+/// 第23章：特征对象示例
 pub fn trait_objects() {
-    println!("🎭 特征对象示例");
+    to_stdout(trait_objects_to)
+}
+
+/// 第23章：特征对象示例，输出写入 `out`
+pub fn trait_objects_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🎭 特征对象示例")?;
     
     // 基本特征对象
-    println!("\n🎨 绘制示例：");
+    writeln!(out, "\n🎨 绘制示例：")?;
     let screen = Screen {
         components: vec![
             Box::new(SelectBox {
@@ -647,22 +745,24 @@ pub fn trait_objects() {
         ],
     };
     
-    screen.run();
+    screen.run(out)?;
     
     // 动态分发示例
-    println!("\n🔄 动态分发示例：");
+    writeln!(out, "\n🔄 动态分发示例：")?;
     let shapes: Vec<Box<dyn Shape>> = vec![
         Box::new(Circle { radius: 5.0 }),
         Box::new(Rectangle2D { width: 10.0, height: 5.0 }),
     ];
     
     for shape in shapes {
-        println!("面积: {:.2}", shape.area());
+        writeln!(out, "面积: {:.2}", shape.area())?;
     }
+    
+    Ok(())
 }
 
 trait Draw {
-    fn draw(&self);
+    fn draw(&self, out: &mut dyn Write) -> io::Result<()>;
 }
 
 struct Screen {
@@ -670,10 +770,11 @@ struct Screen {
 }
 
 impl Screen {
-    fn run(&self) {
+    fn run(&self, out: &mut dyn Write) -> io::Result<()> {
         for component in self.components.iter() {
-            component.draw();
+            component.draw(out)?;
         }
+        Ok(())
     }
 }
 
@@ -684,8 +785,8 @@ struct Button {
 }
 
 impl Draw for Button {
-    fn draw(&self) {
-        println!("绘制按钮: '{}' ({}x{})", self.label, self.width, self.height);
+    fn draw(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "绘制按钮: '{}' ({}x{})", self.label, self.width, self.height)
     }
 }
 
@@ -696,8 +797,8 @@ struct SelectBox {
 }
 
 impl Draw for SelectBox {
-    fn draw(&self) {
-        println!("绘制选择框 ({}x{}) 选项: {:?}", self.width, self.height, self.options);
+    fn draw(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "绘制选择框 ({}x{}) 选项: {:?}", self.width, self.height, self.options)
     }
 }
 
@@ -726,33 +827,38 @@ impl Shape for Rectangle2D {
     }
 }
 
-/// 第14章：常用特征示例
+/// 第24章：常用特征示例
 pub fn common_traits() {
-    println!("🛠️ 常用特征示例");
+    to_stdout(common_traits_to)
+}
+
+/// 第24章：常用特征示例，输出写入 `out`
+pub fn common_traits_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🛠️ 常用特征示例")?;
     
     // Display 和 Debug
-    println!("\n🖨️ Display 和 Debug：");
+    writeln!(out, "\n🖨️ Display 和 Debug：")?;
     let p = PersonDisplay { name: String::from("Alice"), age: 30 };
-    println!("Display: {}", p);
-    println!("Debug: {:?}", p);
+    writeln!(out, "Display: {}", p)?;
+    writeln!(out, "Debug: {:?}", p)?;
     
     // Clone 和 Copy
-    println!("\n📋 Clone 和 Copy：");
+    writeln!(out, "\n📋 Clone 和 Copy：")?;
     let original = CloneableStruct { data: String::from("original") };
     let cloned = original.clone();
-    println!("原始: {}, 克隆: {}", original.data, cloned.data);
+    writeln!(out, "原始: {}, 克隆: {}", original.data, cloned.data)?;
     
     // 相等性比较
-    println!("\n⚖️ 相等性比较：");
+    writeln!(out, "\n⚖️ 相等性比较：")?;
     let p1 = PersonEq { name: String::from("Bob"), age: 25 };
     let p2 = PersonEq { name: String::from("Bob"), age: 25 };
     let p3 = PersonEq { name: String::from("Charlie"), age: 30 };
     
-    println!("p1 == p2: {}", p1 == p2);
-    println!("p1 == p3: {}", p1 == p3);
+    writeln!(out, "p1 == p2: {}", p1 == p2)?;
+    writeln!(out, "p1 == p3: {}", p1 == p3)?;
     
     // 排序
-    println!("\n📊 排序示例：");
+    writeln!(out, "\n📊 排序示例：")?;
     let mut people = vec![
         PersonOrd { name: String::from("Alice"), age: 30 },
         PersonOrd { name: String::from("Bob"), age: 25 },
@@ -760,10 +866,12 @@ pub fn common_traits() {
     ];
     
     people.sort();
-    println!("按年龄排序:");
+    writeln!(out, "按年龄排序:")?;
     for person in people {
-        println!("  {} ({}岁)", person.name, person.age);
+        writeln!(out, "  {} ({}岁)", person.name, person.age)?;
     }
+    
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -795,43 +903,50 @@ struct PersonOrd {
     name: String,  // 然后按姓名排序
 }
 
-/// 第15章：错误处理示例
+/// 第25章：错误处理示例
 pub fn error_handling() {
-    println!("⚠️ 错误处理示例");
+    to_stdout(error_handling_to)
+}
+
+/// 第25章：错误处理示例，输出写入 `out`
+pub fn error_handling_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "⚠️ 错误处理示例")?;
     
     // panic! 示例
-    println!("\n💥 Panic 示例：");
-    println!("注意：这里我们不会真的 panic，只是演示概念");
+    writeln!(out, "\n💥 Panic 示例：")?;
+    writeln!(out, "注意：这里我们不会真的 panic，只是演示概念")?;
     // panic!("这是一个 panic!");  // 取消注释会导致程序崩溃
     
     // Result 类型
-    println!("\n✅ Result 类型示例：");
+    writeln!(out, "\n✅ Result 类型示例：")?;
     match divide(10.0, 2.0) {
-        Ok(result) => println!("10.0 / 2.0 = {}", result),
-        Err(e) => println!("错误: {}", e),
+        Ok(result) => writeln!(out, "10.0 / 2.0 = {}", result)?,
+        Err(e) => writeln!(out, "错误: {}", e)?,
     }
     
     match divide(10.0, 0.0) {
-        Ok(result) => println!("10.0 / 0.0 = {}", result),
-        Err(e) => println!("错误: {}", e),
+        Ok(result) => writeln!(out, "10.0 / 0.0 = {}", result)?,
+        Err(e) => writeln!(out, "错误: {}", e)?,
     }
     
     // 错误传播
-    println!("\n🔄 错误传播示例：");
+    writeln!(out, "\n🔄 错误传播示例：")?;
     match read_username_from_file() {
-        Ok(username) => println!("用户名: {}", username),
-        Err(e) => println!("读取失败: {}", e),
+        Ok(username) => writeln!(out, "用户名: {}", username)?,
+        Err(e) => writeln!(out, "读取失败: {}", e)?,
     }
     
     // unwrap 和 expect
-    println!("\n🎁 unwrap 和 expect：");
+    writeln!(out, "\n🎁 unwrap 和 expect：")?;
     let good_result: Result<i32, &str> = Ok(42);
     let value = good_result.unwrap();
-    println!("unwrap 的值: {}", value);
+    writeln!(out, "unwrap 的值: {}", value)?;
     
     let another_good_result: Result<i32, &str> = Ok(100);
     let value2 = another_good_result.expect("应该是一个好的结果");
-    println!("expect 的值: {}", value2);
+    writeln!(out, "expect 的值: {}", value2)?;
+    
+    Ok(())
 }
 
 fn divide(a: f64, b: f64) -> Result<f64, String> {
@@ -850,62 +965,78 @@ fn read_username_from_file() -> Result<String, String> {
 
 /// 第13章：项目管理示例
 pub fn project_management() {
-    println!("🏗️ 第13章：项目管理");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin project_management");
+    to_stdout(project_management_to)
+}
+
+/// 第13章：项目管理示例，输出写入 `out`
+pub fn project_management_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🏗️ 第13章：项目管理")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin project_management")?;
     
     // 简化的模块示例
-    println!("\n📦 模块系统演示：");
-    println!("  🔸 模块定义和可见性控制");
-    println!("  🔸 use 语句和路径");
-    println!("  🔸 包和 crate 的概念");
-    println!("  🔸 工作空间管理");
+    writeln!(out, "\n📦 模块系统演示：")?;
+    writeln!(out, "  🔸 模块定义和可见性控制")?;
+    writeln!(out, "  🔸 use 语句和路径")?;
+    writeln!(out, "  🔸 包和 crate 的概念")?;
+    writeln!(out, "  🔸 工作空间管理")?;
     
     // 简单的模块使用示例
     mod simple_module {
-        pub fn public_function() {
-            println!("    这是一个公开函数");
+        use std::io::{self, Write};
+
+        pub fn public_function(out: &mut dyn Write) -> io::Result<()> {
+            writeln!(out, "    这是一个公开函数")
         }
         
-        fn _private_function() {
-            println!("    这是一个私有函数");
+        fn _private_function(out: &mut dyn Write) -> io::Result<()> {
+            writeln!(out, "    这是一个私有函数")
         }
         
         pub mod nested {
-            pub fn nested_function() {
-                println!("    这是嵌套模块中的函数");
+            use std::io::{self, Write};
+
+            pub fn nested_function(out: &mut dyn Write) -> io::Result<()> {
+                writeln!(out, "    这是嵌套模块中的函数")
             }
         }
     }
     
-    println!("\n  🔸 调用模块函数：");
-    simple_module::public_function();
-    simple_module::nested::nested_function();
+    writeln!(out, "\n  🔸 调用模块函数：")?;
+    simple_module::public_function(out)?;
+    simple_module::nested::nested_function(out)?;
     
-    println!("\n📋 项目管理要点：");
-    println!("  • 模块系统帮助组织代码");
-    println!("  • pub 关键字控制可见性");
-    println!("  • use 语句简化路径");
-    println!("  • Cargo.toml 管理依赖");
+    writeln!(out, "\n📋 项目管理要点：")?;
+    writeln!(out, "  • 模块系统帮助组织代码")?;
+    writeln!(out, "  • pub 关键字控制可见性")?;
+    writeln!(out, "  • use 语句简化路径")?;
+    writeln!(out, "  • Cargo.toml 管理依赖")?;
+    
+    Ok(())
 }
 
 /// 第14章：文档与测试示例
 pub fn docs_and_testing() {
-    println!("📚 第14章：文档与测试");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin docs_and_testing");
-    
-    println!("\n📖 文档功能：");
-    println!("  🔸 文档注释 (///)");
-    println!("  🔸 文档测试");
-    println!("  🔸 模块级文档 (//!)");
-    println!("  🔸 cargo doc 生成文档");
-    
-    println!("\n🧪 测试功能：");
-    println!("  🔸 单元测试 (#[test])");
-    println!("  🔸 集成测试");
-    println!("  🔸 断言宏 (assert!, assert_eq!)");
-    println!("  🔸 cargo test 运行测试");
+    to_stdout(docs_and_testing_to)
+}
+
+/// 第14章：文档与测试示例，输出写入 `out`
+pub fn docs_and_testing_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "📚 第14章：文档与测试")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin docs_and_testing")?;
+    
+    writeln!(out, "\n📖 文档功能：")?;
+    writeln!(out, "  🔸 文档注释 (///)")?;
+    writeln!(out, "  🔸 文档测试")?;
+    writeln!(out, "  🔸 模块级文档 (//!)")?;
+    writeln!(out, "  🔸 cargo doc 生成文档")?;
+    
+    writeln!(out, "\n🧪 测试功能：")?;
+    writeln!(out, "  🔸 单元测试 (#[test])")?;
+    writeln!(out, "  🔸 集成测试")?;
+    writeln!(out, "  🔸 断言宏 (assert!, assert_eq!)")?;
+    writeln!(out, "  🔸 cargo test 运行测试")?;
     
     // 简单的测试示例（在实际项目中应该在 #[cfg(test)] 模块中）
     fn add(a: i32, b: i32) -> i32 {
@@ -914,77 +1045,98 @@ pub fn docs_and_testing() {
     
     // 模拟测试
     assert_eq!(add(2, 3), 5);
-    println!("\n✅ 简单测试通过：add(2, 3) = 5");
+    writeln!(out, "\n✅ 简单测试通过：add(2, 3) = 5")?;
     
-    println!("\n📋 文档与测试要点：");
-    println!("  • 文档注释自动生成API文档");
-    println!("  • 文档测试确保示例代码正确");
-    println!("  • 单元测试验证函数逻辑");
-    println!("  • 集成测试验证模块交互");
+    writeln!(out, "\n📋 文档与测试要点：")?;
+    writeln!(out, "  • 文档注释自动生成API文档")?;
+    writeln!(out, "  • 文档测试确保示例代码正确")?;
+    writeln!(out, "  • 单元测试验证函数逻辑")?;
+    writeln!(out, "  • 集成测试验证模块交互")?;
+    
+    Ok(())
 }
 
 /// 第15章：闭包示例
 pub fn closures() {
-    println!("🔒 闭包示例");
-    println!("提示：运行 'cargo run --bin closures' 查看完整示例");
+    to_stdout(closures_to)
+}
+
+/// 第15章：闭包示例，输出写入 `out`
+pub fn closures_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔒 闭包示例")?;
+    writeln!(out, "提示：运行 'cargo run --bin closures' 查看完整示例")?;
     
-    println!("\n🎯 闭包基础：");
+    writeln!(out, "\n🎯 闭包基础：")?;
     let add_one = |x| x + 1;
-    println!("闭包计算：{} + 1 = {}", 5, add_one(5));
+    writeln!(out, "闭包计算：{} + 1 = {}", 5, add_one(5))?;
     
-    println!("\n📊 迭代器中的闭包：");
+    writeln!(out, "\n📊 迭代器中的闭包：")?;
     let numbers = vec![1, 2, 3, 4, 5];
     let squares: Vec<i32> = numbers.iter().map(|x| x * x).collect();
-    println!("平方：{:?}", squares);
+    writeln!(out, "平方：{:?}", squares)?;
     
     let evens: Vec<&i32> = numbers.iter().filter(|&x| x % 2 == 0).collect();
-    println!("偶数：{:?}", evens);
+    writeln!(out, "偶数：{:?}", evens)?;
     
-    println!("\n🎭 闭包特征：");
-    println!("- Fn：不可变借用");
-    println!("- FnMut：可变借用");
-    println!("- FnOnce：获取所有权");
+    writeln!(out, "\n🎭 闭包特征：")?;
+    writeln!(out, "- Fn：不可变借用")?;
+    writeln!(out, "- FnMut：可变借用")?;
+    writeln!(out, "- FnOnce：获取所有权")?;
+    
+    Ok(())
 }
 
 /// 第16章：迭代器示例
 pub fn iterators() {
-    println!("🔄 迭代器示例");
-    println!("提示：运行 'cargo run --bin iterators' 查看完整示例");
+    to_stdout(iterators_to)
+}
+
+/// 第16章：迭代器示例，输出写入 `out`
+pub fn iterators_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔄 迭代器示例")?;
+    writeln!(out, "提示：运行 'cargo run --bin iterators' 查看完整示例")?;
     
-    println!("\n📋 迭代器类型：");
+    writeln!(out, "\n📋 迭代器类型：")?;
     let vec = vec![1, 2, 3, 4, 5];
     
     // iter() - 不可变引用
-    println!("iter() - 不可变引用：");
+    writeln!(out, "iter() - 不可变引用：")?;
     for item in vec.iter() {
-        println!("  {}", item);
+        writeln!(out, "  {}", item)?;
     }
     
     // into_iter() - 获取所有权
     let vec2 = vec![1, 2, 3];
-    println!("into_iter() - 获取所有权：");
+    writeln!(out, "into_iter() - 获取所有权：")?;
     for item in vec2.into_iter() {
-        println!("  {}", item);
+        writeln!(out, "  {}", item)?;
     }
     
-    println!("\n🔧 迭代器适配器：");
+    writeln!(out, "\n🔧 迭代器适配器：")?;
     let numbers = vec![1, 2, 3, 4, 5];
     let doubled: Vec<i32> = numbers.iter().map(|x| x * 2).collect();
-    println!("翻倍：{:?}", doubled);
+    writeln!(out, "翻倍：{:?}", doubled)?;
     
     let sum: i32 = numbers.iter().sum();
-    println!("求和：{}", sum);
+    writeln!(out, "求和：{}", sum)?;
+    
+    Ok(())
 }
 
 /// 第17章：智能指针示例
 pub fn smart_pointers() {
-    println!("📦 第17章：智能指针");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin smart_pointers");
+    to_stdout(smart_pointers_to)
+}
+
+/// 第17章：智能指针示例，输出写入 `out`
+pub fn smart_pointers_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "📦 第17章：智能指针")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin smart_pointers")?;
     
-    println!("\n📦 Box<T> 示例：");
+    writeln!(out, "\n📦 Box<T> 示例：")?;
     let b = Box::new(5);
-    println!("  Box 中的值：{}", b);
+    writeln!(out, "  Box 中的值：{}", b)?;
     
     // 递归类型示例
     #[derive(Debug)]
@@ -995,78 +1147,100 @@ pub fn smart_pointers() {
     
     use List::{Cons, Nil};
     let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
-    println!("  递归链表：{:?}", list);
+    writeln!(out, "  递归链表：{:?}", list)?;
+    
+    writeln!(out, "\n🎭 智能指针特性：")?;
+    writeln!(out, "  🔸 Deref trait 允许智能指针表现得像引用")?;
+    writeln!(out, "  🔸 Drop trait 允许自定义清理代码")?;
+    writeln!(out, "  🔸 自动解引用强制转换")?;
     
-    println!("\n🎭 智能指针特性：");
-    println!("  🔸 Deref trait 允许智能指针表现得像引用");
-    println!("  🔸 Drop trait 允许自定义清理代码");
-    println!("  🔸 自动解引用强制转换");
+    writeln!(out, "\n📋 智能指针类型：")?;
+    writeln!(out, "  • Box<T> - 堆分配")?;
+    writeln!(out, "  • Rc<T> - 引用计数")?;
+    writeln!(out, "  • RefCell<T> - 内部可变性")?;
+    writeln!(out, "  • Arc<T> - 原子引用计数")?;
+    writeln!(out, "  • Mutex<T> - 互斥锁")?;
     
-    println!("\n📋 智能指针类型：");
-    println!("  • Box<T> - 堆分配");
-    println!("  • Rc<T> - 引用计数");
-    println!("  • RefCell<T> - 内部可变性");
-    println!("  • Arc<T> - 原子引用计数");
-    println!("  • Mutex<T> - 互斥锁");
+    Ok(())
 }
 
 /// 第18章：常用智能指针示例
 pub fn common_smart_pointers() {
-    println!("🐄 第18章：常用智能指针");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin common_smart_pointers");
+    to_stdout(common_smart_pointers_to)
+}
+
+/// 第18章：常用智能指针示例，输出写入 `out`
+pub fn common_smart_pointers_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🐄 第18章：常用智能指针")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin common_smart_pointers")?;
     
-    println!("\n🐄 Cow (Clone on Write)：");
+    writeln!(out, "\n🐄 Cow (Clone on Write)：")?;
     use std::borrow::Cow;
     
     let borrowed: Cow<str> = "hello".into();
     let owned: Cow<str> = String::from("world").into();
     
-    println!("  借用的字符串: {:?}", borrowed);
-    println!("  拥有的字符串: {:?}", owned);
+    writeln!(out, "  借用的字符串: {:?}", borrowed)?;
+    writeln!(out, "  拥有的字符串: {:?}", owned)?;
+    
+    writeln!(out, "\n🔗 Weak 弱引用：")?;
+    writeln!(out, "  🔸 避免循环引用")?;
+    writeln!(out, "  🔸 缓存场景应用")?;
+    writeln!(out, "  🔸 观察者模式")?;
     
-    println!("\n🔗 Weak 弱引用：");
-    println!("  🔸 避免循环引用");
-    println!("  🔸 缓存场景应用");
-    println!("  🔸 观察者模式");
+    writeln!(out, "\n📌 Pin 固定指针：")?;
+    writeln!(out, "  🔸 防止值移动")?;
+    writeln!(out, "  🔸 自引用结构体")?;
+    writeln!(out, "  🔸 异步编程中的应用")?;
     
-    println!("\n📌 Pin 固定指针：");
-    println!("  🔸 防止值移动");
-    println!("  🔸 自引用结构体");
-    println!("  🔸 异步编程中的应用");
+    writeln!(out, "\n📋 应用场景：")?;
+    writeln!(out, "  • Cow - 延迟克隆优化")?;
+    writeln!(out, "  • Weak - 打破循环引用")?;
+    writeln!(out, "  • Pin - 异步和自引用")?;
     
-    println!("\n📋 应用场景：");
-    println!("  • Cow - 延迟克隆优化");
-    println!("  • Weak - 打破循环引用");
-    println!("  • Pin - 异步和自引用");
+    Ok(())
 }
 
 /// 第19章：并发编程示例
 pub fn concurrency() {
-    println!("🧵 第19章：并发编程");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin concurrency");
+    to_stdout(concurrency_to)
+}
+
+/// 第19章：并发编程示例，输出写入 `out`
+pub fn concurrency_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🧵 第19章：并发编程")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin concurrency")?;
     
-    println!("\n🧵 线程基础：");
+    writeln!(out, "\n🧵 线程基础：")?;
     use std::thread;
     use std::time::Duration;
+    use std::sync::mpsc;
     
-    let handle = thread::spawn(|| {
+    // 子线程不能借用 out，要打印的内容通过通道交给主线程写出
+    let (log_tx, log_rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
         for i in 1..=3 {
-            println!("  子线程: {}", i);
+            log_tx.send(format!("  子线程: {}", i)).unwrap();
             thread::sleep(Duration::from_millis(100));
         }
     });
     
     for i in 1..=2 {
-        println!("  主线程: {}", i);
+        for line in log_rx.try_iter() {
+            writeln!(out, "{}", line)?;
+        }
+        writeln!(out, "  主线程: {}", i)?;
         thread::sleep(Duration::from_millis(150));
     }
     
     handle.join().unwrap();
+    for line in log_rx.try_iter() {
+        writeln!(out, "{}", line)?;
+    }
     
-    println!("\n📨 消息传递：");
-    use std::sync::mpsc;
+    writeln!(out, "\n📨 消息传递：")?;
     
     let (tx, rx) = mpsc::channel();
     
@@ -1075,84 +1249,98 @@ pub fn concurrency() {
     });
     
     let received = rx.recv().unwrap();
-    println!("  收到消息: {}", received);
+    writeln!(out, "  收到消息: {}", received)?;
     
-    println!("\n📋 并发概念：");
-    println!("  • 线程创建和同步");
-    println!("  • 消息传递通信");
-    println!("  • 共享状态管理");
-    println!("  • 原子操作");
+    writeln!(out, "\n📋 并发概念：")?;
+    writeln!(out, "  • 线程创建和同步")?;
+    writeln!(out, "  • 消息传递通信")?;
+    writeln!(out, "  • 共享状态管理")?;
+    writeln!(out, "  • 原子操作")?;
+    
+    Ok(())
 }
 
 /// 第20章：Unsafe Rust示例
 pub fn unsafe_rust() {
-    println!("⚠️ 第20章：Unsafe Rust");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin unsafe_rust");
+    to_stdout(unsafe_rust_to)
+}
+
+/// 第20章：Unsafe Rust示例，输出写入 `out`
+pub fn unsafe_rust_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "⚠️ 第20章：Unsafe Rust")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin unsafe_rust")?;
     
-    println!("\n🎯 原始指针：");
+    writeln!(out, "\n🎯 原始指针：")?;
     let mut num = 5;
     let r1 = &num as *const i32;
     let r2 = &mut num as *mut i32;
     
     unsafe {
-        println!("  r1 指向的值: {}", *r1);
-        println!("  r2 指向的值: {}", *r2);
+        writeln!(out, "  r1 指向的值: {}", *r1)?;
+        writeln!(out, "  r2 指向的值: {}", *r2)?;
     }
     
-    println!("\n⚠️ 不安全函数：");
-    unsafe fn dangerous() {
-        println!("  这是一个不安全函数");
+    writeln!(out, "\n⚠️ 不安全函数：")?;
+    unsafe fn dangerous(out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "  这是一个不安全函数")
     }
     
     unsafe {
-        dangerous();
+        dangerous(out)?;
     }
     
-    println!("\n📋 Unsafe 能力：");
-    println!("  • 解引用原始指针");
-    println!("  • 调用不安全函数");
-    println!("  • 访问可变静态变量");
-    println!("  • 实现不安全 trait");
+    writeln!(out, "\n📋 Unsafe 能力：")?;
+    writeln!(out, "  • 解引用原始指针")?;
+    writeln!(out, "  • 调用不安全函数")?;
+    writeln!(out, "  • 访问可变静态变量")?;
+    writeln!(out, "  • 实现不安全 trait")?;
     
-    println!("\n⚠️ 安全原则：");
-    println!("  • 最小化 unsafe 代码");
-    println!("  • 在安全抽象中包装");
-    println!("  • 仔细验证内存安全");
+    writeln!(out, "\n⚠️ 安全原则：")?;
+    writeln!(out, "  • 最小化 unsafe 代码")?;
+    writeln!(out, "  • 在安全抽象中包装")?;
+    writeln!(out, "  • 仔细验证内存安全")?;
+    
+    Ok(())
 }
 
 /// 第21章：宏系统示例
 pub fn macros() {
-    println!("🎭 第21章：宏系统");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin macros");
+    to_stdout(macros_to)
+}
+
+/// 第21章：宏系统示例，输出写入 `out`
+pub fn macros_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🎭 第21章：宏系统")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin macros")?;
     
     // 简单演示一些宏的概念
     macro_rules! say_hello {
         () => {
-            println!("  Hello from a simple macro!");
+            writeln!(out, "  Hello from a simple macro!")?;
         };
         ($name:expr) => {
-            println!("  Hello, {}!", $name);
+            writeln!(out, "  Hello, {}!", $name)?;
         };
     }
     
-    println!("\n🎯 声明宏演示：");
+    writeln!(out, "\n🎯 声明宏演示：")?;
     say_hello!();
     say_hello!("Rust");
     
     // vec! 宏演示
     let v = vec![1, 2, 3, 4, 5];
-    println!("  vec! 宏创建的向量: {:?}", v);
+    writeln!(out, "  vec! 宏创建的向量: {:?}", v)?;
     
     // println! 宏演示
-    println!("  println! 宏支持格式化：{} + {} = {}", 2, 3, 2 + 3);
+    writeln!(out, "  println! 宏支持格式化：{} + {} = {}", 2, 3, 2 + 3)?;
     
     // 自定义重复模式宏
     macro_rules! create_function {
         ($func_name:ident) => {
-            fn $func_name() {
-                println!("  函数 {} 被调用了", stringify!($func_name));
+            fn $func_name(out: &mut dyn Write) -> io::Result<()> {
+                writeln!(out, "  函数 {} 被调用了", stringify!($func_name))
             }
         };
     }
@@ -1160,29 +1348,36 @@ pub fn macros() {
     create_function!(foo);
     create_function!(bar);
     
-    println!("\n🔧 宏生成的函数：");
-    foo();
-    bar();
+    writeln!(out, "\n🔧 宏生成的函数：")?;
+    foo(out)?;
+    bar(out)?;
+    
+    writeln!(out, "\n📚 宏系统的特点：")?;
+    writeln!(out, "  • 编译时代码生成")?;
+    writeln!(out, "  • 模式匹配语法")?;
+    writeln!(out, "  • 元编程能力")?;
+    writeln!(out, "  • 代码复用和简化")?;
+    writeln!(out, "  • 卫生宏系统")?;
     
-    println!("\n📚 宏系统的特点：");
-    println!("  • 编译时代码生成");
-    println!("  • 模式匹配语法");
-    println!("  • 元编程能力");
-    println!("  • 代码复用和简化");
-    println!("  • 卫生宏系统");
+    writeln!(out, "\n🎯 宏的类型：")?;
+    writeln!(out, "  • 声明宏 (macro_rules!)")?;
+    writeln!(out, "  • 过程宏 (proc_macro)")?;
+    writeln!(out, "  • 派生宏 (#[derive])")?;
+    writeln!(out, "  • 属性宏 (#[attribute])")?;
     
-    println!("\n🎯 宏的类型：");
-    println!("  • 声明宏 (macro_rules!)");
-    println!("  • 过程宏 (proc_macro)");
-    println!("  • 派生宏 (#[derive])");
-    println!("  • 属性宏 (#[attribute])");
+    Ok(())
 }
 
 /// 第22章：过程宏示例
 pub fn procedural_macros() {
-    println!("🔮 第22章：过程宏深入解析");
-    println!("=====================================");
-    println!("💡 运行完整示例：cargo run --bin procedural_macros");
+    to_stdout(procedural_macros_to)
+}
+
+/// 第22章：过程宏示例，输出写入 `out`
+pub fn procedural_macros_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔮 第22章：过程宏深入解析")?;
+    writeln!(out, "=====================================")?;
+    writeln!(out, "💡 运行完整示例：cargo run --bin procedural_macros")?;
     
     // 模拟过程宏的效果
     #[derive(Debug)]
@@ -1196,42 +1391,44 @@ pub fn procedural_macros() {
         value: 42,
     };
     
-    println!("\n🎯 派生宏演示：");
-    println!("  #[derive(Debug)] 宏自动实现了 Debug trait");
-    println!("  示例结构体: {:?}", example);
+    writeln!(out, "\n🎯 派生宏演示：")?;
+    writeln!(out, "  #[derive(Debug)] 宏自动实现了 Debug trait")?;
+    writeln!(out, "  示例结构体: {:?}", example)?;
     
     // 模拟 Builder 模式
-    println!("\n🏗️ Builder 模式概念：");
-    println!("  // 使用派生宏自动生成 Builder");
-    println!("  #[derive(Builder)]");
-    println!("  struct User {{");
-    println!("      name: String,");
-    println!("      email: String,");
-    println!("  }}");
-    println!("  // 生成：UserBuilder, name(), email(), build()");
-    
-    println!("\n🔧 属性宏概念：");
-    println!("  // 给函数添加计时功能");
-    println!("  #[timing]");
-    println!("  fn expensive_function() {{ ... }}");
-    println!("  // 自动添加性能监控代码");
-    
-    println!("\n📚 过程宏的类型：");
-    println!("  • 派生宏 (Derive Macros): #[derive(MyTrait)]");
-    println!("  • 属性宏 (Attribute Macros): #[my_attribute]");
-    println!("  • 函数式宏 (Function-like Macros): my_macro!()");
-    
-    println!("\n🔧 过程宏的特点：");
-    println!("  • 操作 TokenStream");
-    println!("  • 生成任意复杂代码");
-    println!("  • 需要独立的 proc-macro crate");
-    println!("  • 比声明宏更强大");
-    println!("  • 使用 syn、quote、proc-macro2 库");
-    
-    println!("\n🎯 应用场景：");
-    println!("  • ORM 框架 (如 Diesel)");
-    println!("  • 序列化库 (如 Serde)");
-    println!("  • Web 框架 (如 Actix)");
-    println!("  • 自定义 DSL");
-    println!("  • 代码生成工具");
-} 
\ No newline at end of file
+    writeln!(out, "\n🏗️ Builder 模式概念：")?;
+    writeln!(out, "  // 使用派生宏自动生成 Builder")?;
+    writeln!(out, "  #[derive(Builder)]")?;
+    writeln!(out, "  struct User {{")?;
+    writeln!(out, "      name: String,")?;
+    writeln!(out, "      email: String,")?;
+    writeln!(out, "  }}")?;
+    writeln!(out, "  // 生成：UserBuilder, name(), email(), build()")?;
+    
+    writeln!(out, "\n🔧 属性宏概念：")?;
+    writeln!(out, "  // 给函数添加计时功能")?;
+    writeln!(out, "  #[timing]")?;
+    writeln!(out, "  fn expensive_function() {{ ... }}")?;
+    writeln!(out, "  // 自动添加性能监控代码")?;
+    
+    writeln!(out, "\n📚 过程宏的类型：")?;
+    writeln!(out, "  • 派生宏 (Derive Macros): #[derive(MyTrait)]")?;
+    writeln!(out, "  • 属性宏 (Attribute Macros): #[my_attribute]")?;
+    writeln!(out, "  • 函数式宏 (Function-like Macros): my_macro!()")?;
+    
+    writeln!(out, "\n🔧 过程宏的特点：")?;
+    writeln!(out, "  • 操作 TokenStream")?;
+    writeln!(out, "  • 生成任意复杂代码")?;
+    writeln!(out, "  • 需要独立的 proc-macro crate")?;
+    writeln!(out, "  • 比声明宏更强大")?;
+    writeln!(out, "  • 使用 syn、quote、proc-macro2 库")?;
+    
+    writeln!(out, "\n🎯 应用场景：")?;
+    writeln!(out, "  • ORM 框架 (如 Diesel)")?;
+    writeln!(out, "  • 序列化库 (如 Serde)")?;
+    writeln!(out, "  • Web 框架 (如 Actix)")?;
+    writeln!(out, "  • 自定义 DSL")?;
+    writeln!(out, "  • 代码生成工具")?;
+    
+    Ok(())
+}
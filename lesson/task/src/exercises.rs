@@ -0,0 +1,387 @@
+// 改错练习
+// 每个练习是 tutorial/exercises/ 中一段无法通过编译的代码，文件开头的注释说明预期的编译错误：
+//
+//   // title: 移动之后再使用
+//   // chapter: 6
+//   // error: E0382
+//   // message: borrow of moved value: `s1`
+//   // hint: 可选的修改提示
+//
+// 检查用例时要求 rustc 报告的每个错误都是 error 指定的错误码，且至少一条错误信息包含 message；
+// 学习者把练习复制到工作目录中修改，重新编译没有错误即为完成。格式说明见该目录下的 README.md
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::chapters;
+use crate::explain::{self, Diagnostic};
+use crate::knowledge;
+use crate::progress::{ActivityResult, Progress, ProgressStore, ResultKind};
+use crate::tr;
+use crate::verify::{self, VerifyOptions};
+
+/// 内置的练习，位于仓库的 tutorial/exercises/ 目录
+const EMBEDDED: &[(&str, &str)] = &[
+    ("06_use_after_move.rs", include_str!("../../../tutorial/exercises/06_use_after_move.rs")),
+    ("06_drop_after_move.rs", include_str!("../../../tutorial/exercises/06_drop_after_move.rs")),
+    ("06_moved_into_function.rs", include_str!("../../../tutorial/exercises/06_moved_into_function.rs")),
+    ("06_partial_move.rs", include_str!("../../../tutorial/exercises/06_partial_move.rs")),
+    ("06_drop_while_borrowed.rs", include_str!("../../../tutorial/exercises/06_drop_while_borrowed.rs")),
+    ("07_mut_while_shared.rs", include_str!("../../../tutorial/exercises/07_mut_while_shared.rs")),
+    ("07_two_mutable_borrows.rs", include_str!("../../../tutorial/exercises/07_two_mutable_borrows.rs")),
+    ("07_dangling_reference.rs", include_str!("../../../tutorial/exercises/07_dangling_reference.rs")),
+    ("12_missing_lifetime.rs", include_str!("../../../tutorial/exercises/12_missing_lifetime.rs")),
+    ("12_not_static.rs", include_str!("../../../tutorial/exercises/12_not_static.rs")),
+];
+
+/// 一个改错练习
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    /// 文件名去掉 `.rs`，例如 `06_use_after_move`
+    pub id: String,
+    pub title: String,
+    pub chapter: u8,
+    /// 预期的错误码
+    pub error: String,
+    /// 预期错误信息中的片段
+    pub message: String,
+    pub hint: Option<String>,
+    /// 完整的源文件，包括开头的说明注释
+    pub source: String,
+}
+
+impl Exercise {
+    /// 去掉开头说明注释后的代码
+    pub fn code(&self) -> &str {
+        let mut rest = self.source.as_str();
+        while !rest.is_empty() {
+            let (line, tail) = rest.split_once('\n').unwrap_or((rest, ""));
+            if !line.starts_with("//") && !line.trim().is_empty() {
+                break;
+            }
+            rest = tail;
+        }
+        rest
+    }
+}
+
+/// 练习文件的格式错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExerciseError {
+    pub file: String,
+    /// 从 1 开始的行号，为 0 时表示与具体行无关
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ExerciseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "{}:{}: {}", self.file, self.line, self.message)
+        } else {
+            write!(f, "{}: {}", self.file, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ExerciseError {}
+
+/// 解析一个练习文件；开头连续的注释行中 `// 字段: 值` 形式的行是说明字段
+pub fn parse_exercise(file: &str, text: &str) -> Result<Exercise, ExerciseError> {
+    let error = |line: usize, message: String| ExerciseError {
+        file: file.to_string(),
+        line,
+        message,
+    };
+    let mut fields: Vec<(&str, &str)> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        let Some((key, value)) = comment.trim().split_once(':') else {
+            continue;
+        };
+        if key.is_empty() || !key.bytes().all(|b| b.is_ascii_lowercase()) {
+            continue;
+        }
+        if !["title", "chapter", "error", "message", "hint"].contains(&key) {
+            return Err(error(index + 1, tr!("grade.header.unknown_field", key = key)));
+        }
+        if fields.iter().any(|(known, _)| *known == key) {
+            return Err(error(index + 1, tr!("grade.header.duplicate_field", key = key)));
+        }
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(error(index + 1, tr!("grade.header.empty_field", key = key)));
+        }
+        if key == "chapter" && value.parse::<u8>().ok().and_then(chapters::get).is_none() {
+            return Err(error(index + 1, tr!("grade.header.unknown_chapter", chapter = value)));
+        }
+        if key == "error" && !explain::is_error_code(value) {
+            return Err(error(index + 1, tr!("grade.header.bad_error_code", value = value)));
+        }
+        fields.push((key, value));
+    }
+
+    let field = |key: &str| fields.iter().find(|(known, _)| *known == key).map(|(_, value)| value.to_string());
+    let required = |key: &str| field(key).ok_or_else(|| error(0, tr!("grade.header.missing_field", key = key)));
+    Ok(Exercise {
+        id: file.strip_suffix(".rs").unwrap_or(file).to_string(),
+        title: required("title")?,
+        chapter: required("chapter")?.parse().expect("已检查是章节编号"),
+        error: required("error")?.to_uppercase(),
+        message: required("message")?,
+        hint: field("hint"),
+        source: text.to_string(),
+    })
+}
+
+/// 所有内置练习，按文件名排序；格式错误的练习由测试报告，这里跳过
+pub fn all() -> &'static [Exercise] {
+    static EXERCISES: OnceLock<Vec<Exercise>> = OnceLock::new();
+    EXERCISES.get_or_init(|| {
+        let mut exercises: Vec<Exercise> = EMBEDDED
+            .iter()
+            .filter_map(|(file, text)| parse_exercise(file, text).ok())
+            .collect();
+        exercises.sort_by(|a, b| a.id.cmp(&b.id));
+        exercises
+    })
+}
+
+/// 内置练习的文件名和内容
+pub fn embedded() -> &'static [(&'static str, &'static str)] {
+    EMBEDDED
+}
+
+/// 按编号查找练习，可以省略开头的章节号，例如 `use_after_move`
+pub fn get(id: &str) -> Option<&'static Exercise> {
+    let id = id.strip_suffix(".rs").unwrap_or(id);
+    all().iter().find(|exercise| {
+        exercise.id == id || exercise.id.split_once('_').is_some_and(|(_, name)| name == id)
+    })
+}
+
+/// 某一章的练习
+pub fn for_chapter(chapter: u8) -> impl Iterator<Item = &'static Exercise> {
+    all().iter().filter(move |exercise| exercise.chapter == chapter)
+}
+
+/// 读取目录中的全部 `.rs` 练习文件，按文件名排序
+pub fn load_dir(dir: &Path) -> io::Result<Vec<Result<Exercise, ExerciseError>>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    let mut exercises = Vec::new();
+    for path in files {
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        exercises.push(parse_exercise(&name, &fs::read_to_string(&path)?));
+    }
+    Ok(exercises)
+}
+
+/// 检查一个练习用例的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaseOutcome {
+    /// 按说明的方式编译失败
+    FailsAsDeclared,
+    /// 没有编译错误
+    UnexpectedSuccess,
+    /// 错误码或错误信息与说明不符
+    WrongErrors(Vec<Diagnostic>),
+}
+
+impl CaseOutcome {
+    pub fn passed(&self) -> bool {
+        *self == CaseOutcome::FailsAsDeclared
+    }
+}
+
+impl fmt::Display for CaseOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaseOutcome::FailsAsDeclared => write!(f, "{}", tr!("exercise.outcome.fails_as_declared")),
+            CaseOutcome::UnexpectedSuccess => write!(f, "{}", tr!("exercise.outcome.unexpected_success")),
+            CaseOutcome::WrongErrors(diagnostics) => {
+                let errors: Vec<String> = diagnostics
+                    .iter()
+                    .map(|diagnostic| match &diagnostic.code {
+                        Some(code) => format!("error[{}]: {}", code, diagnostic.message),
+                        None => format!("error: {}", diagnostic.message),
+                    })
+                    .collect();
+                write!(f, "{}", tr!("exercise.outcome.wrong_errors", errors = errors.join("; ")))
+            }
+        }
+    }
+}
+
+/// 判断编译错误是否与练习的说明一致
+pub fn classify(exercise: &Exercise, diagnostics: Vec<Diagnostic>) -> CaseOutcome {
+    if diagnostics.is_empty() {
+        return CaseOutcome::UnexpectedSuccess;
+    }
+    let codes_match = diagnostics.iter().all(|diagnostic| diagnostic.code.as_deref() == Some(exercise.error.as_str()));
+    let message_found = diagnostics.iter().any(|diagnostic| diagnostic.message.contains(&exercise.message));
+    if codes_match && message_found {
+        CaseOutcome::FailsAsDeclared
+    } else {
+        CaseOutcome::WrongErrors(diagnostics)
+    }
+}
+
+/// 用 rustc 编译练习的原始代码，检查它是否按说明失败
+pub fn check_case(exercise: &Exercise, options: &VerifyOptions) -> io::Result<CaseOutcome> {
+    let dir = verify::scratch_dir();
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.rs", exercise.id));
+    let diagnostics = fs::write(&path, &exercise.source).and_then(|_| explain::compile(&path, options));
+    let _ = fs::remove_dir_all(&dir);
+    Ok(classify(exercise, diagnostics?))
+}
+
+/// 逐个检查练习用例并输出结果，全部按说明失败时返回 true
+pub fn run_cases(
+    out: &mut impl Write,
+    cases: &[Result<Exercise, ExerciseError>],
+    options: &VerifyOptions,
+) -> io::Result<bool> {
+    let mut failed = 0;
+    for case in cases {
+        match case {
+            Ok(exercise) => {
+                let outcome = check_case(exercise, options)?;
+                let mark = if outcome.passed() { "✅" } else { "❌" };
+                writeln!(out, "{} {} ({}) {}", mark, exercise.id, exercise.error, outcome)?;
+                if !outcome.passed() {
+                    failed += 1;
+                }
+            }
+            Err(err) => {
+                writeln!(out, "❌ {}", err)?;
+                failed += 1;
+            }
+        }
+    }
+    writeln!(out, "{}", tr!("exercise.cases_done", total = cases.len(), failed = failed))?;
+    Ok(failed == 0)
+}
+
+/// 学习者修改练习的目录，每个练习一个文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    dir: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Workspace { dir: dir.into() }
+    }
+
+    /// 放在进度目录的 exercises/ 下；没有进度目录时使用系统临时目录
+    pub fn for_store(store: Option<&ProgressStore>) -> Self {
+        match store {
+            Some(store) => Workspace::new(store.dir().join("exercises")),
+            None => Workspace::new(env::temp_dir().join("lets-rust-exercises")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, exercise: &Exercise) -> PathBuf {
+        self.dir.join(format!("{}.rs", exercise.id))
+    }
+
+    /// 准备练习文件，已经存在时保留学习者的修改
+    pub fn start(&self, exercise: &Exercise) -> io::Result<PathBuf> {
+        let path = self.path(exercise);
+        if !path.exists() {
+            fs::create_dir_all(&self.dir)?;
+            fs::write(&path, &exercise.source)?;
+        }
+        Ok(path)
+    }
+
+    /// 恢复为练习的原始代码
+    pub fn reset(&self, exercise: &Exercise) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(exercise);
+        fs::write(&path, &exercise.source)?;
+        Ok(path)
+    }
+
+    /// 重新编译学习者的文件，返回剩下的编译错误；没有错误即为完成
+    pub fn check(&self, exercise: &Exercise, options: &VerifyOptions) -> io::Result<Vec<Diagnostic>> {
+        explain::compile(&self.start(exercise)?, options)
+    }
+}
+
+/// 记录完成一个练习，同时把该章的完成数记为一次练习成绩
+pub fn record_solved(progress: &mut Progress, exercise: &Exercise, at: u64) {
+    progress.record_solved(&exercise.id, at);
+    let exercises: Vec<&Exercise> = for_chapter(exercise.chapter).collect();
+    let solved = exercises.iter().filter(|other| progress.is_solved(&other.id)).count();
+    progress.record_result(ActivityResult {
+        kind: ResultKind::Exercise,
+        chapter: exercise.chapter,
+        score: solved as u32,
+        total: exercises.len() as u32,
+        at,
+    });
+}
+
+/// 列出所有练习，标出已经完成的
+pub fn write_list(out: &mut impl Write, progress: &Progress) -> io::Result<()> {
+    writeln!(out, "{}", tr!("exercise.list_title", count = all().len()))?;
+    writeln!(out, "{}", "─".repeat(40))?;
+    let mut chapter = 0;
+    for exercise in all() {
+        if exercise.chapter != chapter {
+            chapter = exercise.chapter;
+            if let Some(info) = chapters::get(chapter) {
+                writeln!(out, "\n{}", info.display_name())?;
+            }
+        }
+        let mark = if progress.is_solved(&exercise.id) { "✓" } else { "○" };
+        writeln!(out, "  {} {} {}（{}）", mark, exercise.id, exercise.title, exercise.error)?;
+    }
+    writeln!(out, "\n{}", tr!("exercise.list_hint"))
+}
+
+/// 显示练习的要求、代码和学习者文件的位置
+pub fn write_exercise(out: &mut impl Write, exercise: &Exercise, path: &Path) -> io::Result<()> {
+    writeln!(out, "{}", "═".repeat(50))?;
+    writeln!(out, "🧩 {}", exercise.title)?;
+    writeln!(out, "{}", "═".repeat(50))?;
+    if let Some(chapter) = chapters::get(exercise.chapter) {
+        writeln!(out, "{}", tr!("explain.chapter", chapter = chapter.display_name(), id = chapter.id))?;
+    }
+    writeln!(out, "{}", tr!("exercise.expected", code = exercise.error, message = exercise.message))?;
+    knowledge::write_listing(out, exercise.code())?;
+    writeln!(out, "{}", tr!("exercise.file", path = path.display()))
+}
+
+/// 显示一次检查的结果，通过时返回 true
+pub fn write_check(out: &mut impl Write, exercise: &Exercise, diagnostics: &[Diagnostic]) -> io::Result<bool> {
+    if diagnostics.is_empty() {
+        writeln!(out, "{}", tr!("exercise.solved", title = exercise.title))?;
+        return Ok(true);
+    }
+    writeln!(out, "{}", tr!("exercise.still_failing", count = diagnostics.len()))?;
+    explain::write_errors(out, diagnostics)?;
+    if let Some(hint) = &exercise.hint {
+        writeln!(out, "\n💡 {}", hint)?;
+    }
+    Ok(false)
+}
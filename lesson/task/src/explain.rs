@@ -0,0 +1,414 @@
+// rustc 诊断讲解
+// 把编译错误码对应到讲解它的章节和知识点，并给出一对最小的错误写法和正确写法。
+// 诊断可以来自 rustc --error-format=json 的输出，也可以是直接粘贴的终端输出。
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+
+use crate::chapters;
+use crate::json;
+use crate::knowledge::{self, CodeExample, Expectation};
+use crate::tr;
+use crate::verify::{self, Finished, VerifyOptions};
+
+/// 一个错误码的讲解
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorInfo {
+    pub code: &'static str,
+    /// 错误含义，消息目录中的键
+    pub title: &'static str,
+    /// 修改方法，消息目录中的键
+    pub fix: &'static str,
+    /// 讲解这类错误的章节
+    pub chapter: u8,
+    /// 章节中对应的知识点序号（从 1 开始），没有知识点时为 `None`
+    pub point: Option<usize>,
+    /// 触发该错误的最小代码
+    pub broken: &'static str,
+    /// 改正后的代码
+    pub fixed: &'static str,
+    /// 改正后代码的输出
+    pub output: &'static str,
+}
+
+impl ErrorInfo {
+    /// 错误写法和正确写法，可以直接交给 `verify::verify_example` 检查
+    pub fn examples(&self) -> [CodeExample; 2] {
+        [
+            CodeExample {
+                title: tr!("explain.broken").to_string(),
+                code: self.broken.to_string(),
+                explanation: String::new(),
+                output: None,
+                expect: Expectation::CompileFail {
+                    code: Some(self.code.to_string()),
+                },
+            },
+            CodeExample {
+                title: tr!("explain.fixed").to_string(),
+                code: self.fixed.to_string(),
+                explanation: tr!(self.fix).to_string(),
+                output: Some(self.output.to_string()),
+                expect: Expectation::Runs,
+            },
+        ]
+    }
+}
+
+/// `E0382` 形式的错误码
+pub fn is_error_code(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() == 5 && bytes[0].eq_ignore_ascii_case(&b'e') && bytes[1..].iter().all(u8::is_ascii_digit)
+}
+
+/// 查找错误码的讲解，忽略大小写
+pub fn lookup(code: &str) -> Option<&'static ErrorInfo> {
+    ERRORS.iter().find(|info| info.code.eq_ignore_ascii_case(code))
+}
+
+/// 诊断的主要位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// 一条编译错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<String>,
+    pub location: Option<Location>,
+    /// 主要位置上的说明，例如 `value borrowed here after move`
+    pub label: Option<String>,
+}
+
+/// 解析诊断：以 `{` 开头的行按 rustc 的 JSON 格式读取，否则按终端输出读取
+pub fn parse_diagnostics(text: &str) -> Vec<Diagnostic> {
+    if text.lines().any(|line| line.trim_start().starts_with('{')) {
+        parse_json_diagnostics(text)
+    } else {
+        parse_text_diagnostics(text)
+    }
+}
+
+/// 读取 `rustc --error-format=json` 的输出，每行一个 JSON 对象；只保留错误，
+/// 跳过警告、`aborting due to` 之类没有位置的汇总和无法解析的行
+pub fn parse_json_diagnostics(text: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for line in text.lines().map(str::trim).filter(|line| line.starts_with('{')) {
+        let Ok(value) = json::parse(line) else {
+            continue;
+        };
+        if value.get("level").and_then(|level| level.as_str()) != Some("error") {
+            continue;
+        }
+        let spans = value.get("spans").map_or(&[][..], |spans| spans.items());
+        if spans.is_empty() {
+            continue;
+        }
+        let primary = spans
+            .iter()
+            .find(|span| span.get("is_primary").and_then(|primary| primary.as_bool()) == Some(true))
+            .unwrap_or(&spans[0]);
+        let field = |name: &str| primary.get(name).and_then(|value| value.as_u64()).unwrap_or(0) as usize;
+        let location = primary.get("file_name").and_then(|file| file.as_str()).map(|file| Location {
+            file: file.to_string(),
+            line: field("line_start"),
+            column: field("column_start"),
+        });
+        diagnostics.push(Diagnostic {
+            message: value.get("message").and_then(|message| message.as_str()).unwrap_or_default().to_string(),
+            code: value
+                .get("code")
+                .and_then(|code| code.get("code"))
+                .and_then(|code| code.as_str())
+                .map(str::to_string),
+            location,
+            label: primary.get("label").and_then(|label| label.as_str()).map(str::to_string),
+        });
+    }
+    diagnostics
+}
+
+/// 读取终端中显示的编译错误：
+///
+/// ```text
+/// error[E0382]: borrow of moved value: `s1`
+///  --> src/main.rs:4:23
+/// ```
+pub fn parse_text_diagnostics(text: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut located = true;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("error") {
+            let (code, message) = match rest.strip_prefix('[').and_then(|rest| rest.split_once("]:")) {
+                Some((code, message)) => (Some(code.to_string()), message),
+                None => match rest.strip_prefix(':') {
+                    Some(message) => (None, message),
+                    None => continue,
+                },
+            };
+            let message = message.trim();
+            if code.is_none() && (message.starts_with("aborting due to") || message.starts_with("could not compile")) {
+                continue;
+            }
+            diagnostics.push(Diagnostic {
+                message: message.to_string(),
+                code,
+                location: None,
+                label: None,
+            });
+            located = false;
+        } else if let Some(position) = trimmed.strip_prefix("--> ") {
+            if located {
+                continue;
+            }
+            located = true;
+            if let Some(location) = parse_location(position) {
+                diagnostics.last_mut().expect("定位行之前已有错误").location = Some(location);
+            }
+        }
+    }
+    diagnostics
+}
+
+/// `src/main.rs:4:23`，文件名中可能带有冒号（例如 Windows 盘符）
+fn parse_location(text: &str) -> Option<Location> {
+    let (rest, column) = text.rsplit_once(':')?;
+    let (file, line) = rest.rsplit_once(':')?;
+    Some(Location {
+        file: file.to_string(),
+        line: line.parse().ok()?,
+        column: column.parse().ok()?,
+    })
+}
+
+/// 用 rustc 检查源文件，返回其中的编译错误；只生成元数据，不产生可执行文件，超过 `options.timeout` 时以 `Err` 返回
+pub fn compile(path: &Path, options: &VerifyOptions) -> io::Result<Vec<Diagnostic>> {
+    let out_dir = verify::scratch_dir();
+    fs::create_dir_all(&out_dir)?;
+    let finished = verify::run(
+        Command::new(&options.rustc)
+            .args(["--error-format=json", "--edition", "2021", "--emit=metadata", "--out-dir"])
+            .arg(&out_dir)
+            .arg(path),
+        options.timeout,
+    )
+    .map_err(|err| io::Error::new(err.kind(), tr!("verify.rustc_failed", rustc = format!("{:?}", options.rustc), error = err)));
+    let _ = fs::remove_dir_all(&out_dir);
+    match finished? {
+        Finished::Exited { stderr, .. } => Ok(parse_json_diagnostics(&stderr)),
+        Finished::TimedOut => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            tr!("explain.compile_timed_out", seconds = options.timeout.as_secs_f64()),
+        )),
+    }
+}
+
+/// 列出编译错误，再逐个讲解其中出现的错误码（同一错误码只讲解一次）
+pub fn write_diagnostics(out: &mut impl Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    if diagnostics.is_empty() {
+        return writeln!(out, "{}", tr!("explain.no_errors"));
+    }
+    writeln!(out, "{}", tr!("explain.found", count = diagnostics.len()))?;
+    write_errors(out, diagnostics)?;
+    let mut codes: Vec<&str> = Vec::new();
+    for code in diagnostics.iter().filter_map(|diagnostic| diagnostic.code.as_deref()) {
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    for code in codes {
+        writeln!(out)?;
+        write_code(out, code)?;
+    }
+    Ok(())
+}
+
+/// 逐条列出编译错误和出错位置，不附带讲解
+pub fn write_errors(out: &mut impl Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    for diagnostic in diagnostics {
+        match &diagnostic.code {
+            Some(code) => writeln!(out, "\n{}", tr!("explain.error_with_code", code = code, message = diagnostic.message))?,
+            None => writeln!(out, "\n{}", tr!("explain.error", message = diagnostic.message))?,
+        }
+        match (&diagnostic.location, &diagnostic.label) {
+            (Some(location), Some(label)) => writeln!(out, "   {}", tr!("explain.location", location = location, label = label))?,
+            (Some(location), None) => writeln!(out, "   --> {}", location)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// 讲解一个错误码，不在讲解列表中时提示查看官方说明并返回 false
+pub fn write_code(out: &mut impl Write, code: &str) -> io::Result<bool> {
+    let Some(info) = lookup(code) else {
+        writeln!(out, "{}", tr!("explain.unknown", code = code.to_uppercase()))?;
+        return Ok(false);
+    };
+    writeln!(out, "{}", "═".repeat(50))?;
+    writeln!(out, "🩺 {}：{}", info.code, tr!(info.title))?;
+    writeln!(out, "{}", "═".repeat(50))?;
+    if let Some(chapter) = chapters::get(info.chapter) {
+        writeln!(out, "{}", tr!("explain.chapter", chapter = chapter.display_name(), id = chapter.id))?;
+    }
+    let point = info.point.and_then(|index| knowledge::base().chapter(info.chapter)?.get(index - 1));
+    if let Some(point) = point {
+        writeln!(out, "{}", tr!("explain.point", title = point.title))?;
+        for concept in &point.key_concepts {
+            writeln!(out, "   • {}", concept)?;
+        }
+    }
+    for (index, example) in info.examples().iter().enumerate() {
+        knowledge::write_code_example(out, index + 1, example)?;
+    }
+    writeln!(out, "\n{}", tr!("explain.more", code = info.code))?;
+    Ok(true)
+}
+
+/// 新手最常遇到的编译错误，按章节顺序排列
+pub static ERRORS: &[ErrorInfo] = &[
+    ErrorInfo {
+        code: "E0384",
+        title: "explain.E0384.title",
+        fix: "explain.E0384.fix",
+        chapter: 1,
+        point: Some(1),
+        broken: "let x = 5;\nx = 6;\nprintln!(\"{}\", x);",
+        fixed: "let mut x = 5;\nx = 6;\nprintln!(\"{}\", x);",
+        output: "6",
+    },
+    ErrorInfo {
+        code: "E0308",
+        title: "explain.E0308.title",
+        fix: "explain.E0308.fix",
+        chapter: 3,
+        point: Some(1),
+        broken: "fn plus_one(x: i32) -> i32 {\n    x + 1;\n}\n\nfn main() {\n    println!(\"{}\", plus_one(5));\n}",
+        fixed: "fn plus_one(x: i32) -> i32 {\n    x + 1\n}\n\nfn main() {\n    println!(\"{}\", plus_one(5));\n}",
+        output: "6",
+    },
+    ErrorInfo {
+        code: "E0382",
+        title: "explain.E0382.title",
+        fix: "explain.E0382.fix",
+        chapter: 6,
+        point: Some(1),
+        broken: "let s1 = String::from(\"hello\");\nlet s2 = s1;\nprintln!(\"{} {}\", s1, s2);",
+        fixed: "let s1 = String::from(\"hello\");\nlet s2 = s1.clone();\nprintln!(\"{} {}\", s1, s2);",
+        output: "hello hello",
+    },
+    ErrorInfo {
+        code: "E0499",
+        title: "explain.E0499.title",
+        fix: "explain.E0499.fix",
+        chapter: 7,
+        point: Some(1),
+        broken: "let mut s = String::from(\"hello\");\nlet r1 = &mut s;\nlet r2 = &mut s;\nr1.push('!');\nr2.push('?');\nprintln!(\"{}\", s);",
+        fixed: "let mut s = String::from(\"hello\");\n{\n    let r1 = &mut s;\n    r1.push('!');\n}\nlet r2 = &mut s;\nr2.push('?');\nprintln!(\"{}\", s);",
+        output: "hello!?",
+    },
+    ErrorInfo {
+        code: "E0502",
+        title: "explain.E0502.title",
+        fix: "explain.E0502.fix",
+        chapter: 7,
+        point: Some(1),
+        broken: "let mut v = vec![1, 2, 3];\nlet first = &v[0];\nv.push(4);\nprintln!(\"{}\", first);",
+        fixed: "let mut v = vec![1, 2, 3];\nlet first = v[0];\nv.push(4);\nprintln!(\"{}\", first);",
+        output: "1",
+    },
+    ErrorInfo {
+        code: "E0505",
+        title: "explain.E0505.title",
+        fix: "explain.E0505.fix",
+        chapter: 7,
+        point: Some(1),
+        broken: "let s = String::from(\"hello\");\nlet r = &s;\nlet t = s;\nprintln!(\"{} {}\", r, t);",
+        fixed: "let s = String::from(\"hello\");\nlet r = &s;\nprintln!(\"{}\", r);\nlet t = s;\nprintln!(\"{}\", t);",
+        output: "hello\nhello",
+    },
+    ErrorInfo {
+        code: "E0596",
+        title: "explain.E0596.title",
+        fix: "explain.E0596.fix",
+        chapter: 7,
+        point: Some(1),
+        broken: "let s = String::from(\"hello\");\nlet r = &mut s;\nr.push_str(\" world\");\nprintln!(\"{}\", r);",
+        fixed: "let mut s = String::from(\"hello\");\nlet r = &mut s;\nr.push_str(\" world\");\nprintln!(\"{}\", r);",
+        output: "hello world",
+    },
+    ErrorInfo {
+        code: "E0507",
+        title: "explain.E0507.title",
+        fix: "explain.E0507.fix",
+        chapter: 7,
+        point: Some(1),
+        broken: "let names = vec![String::from(\"Ferris\")];\nlet first = names[0];\nprintln!(\"{}\", first);",
+        fixed: "let names = vec![String::from(\"Ferris\")];\nlet first = &names[0];\nprintln!(\"{}\", first);",
+        output: "Ferris",
+    },
+    ErrorInfo {
+        code: "E0004",
+        title: "explain.E0004.title",
+        fix: "explain.E0004.fix",
+        chapter: 10,
+        point: None,
+        broken: "enum Direction {\n    Up,\n    Down,\n}\n\nfn main() {\n    let d = Direction::Down;\n    match d {\n        Direction::Up => println!(\"up\"),\n    }\n}",
+        fixed: "enum Direction {\n    Up,\n    Down,\n}\n\nfn main() {\n    let d = Direction::Down;\n    match d {\n        Direction::Up => println!(\"up\"),\n        Direction::Down => println!(\"down\"),\n    }\n}",
+        output: "down",
+    },
+    ErrorInfo {
+        code: "E0277",
+        title: "explain.E0277.title",
+        fix: "explain.E0277.fix",
+        chapter: 11,
+        point: None,
+        broken: "use std::fmt::Display;\n\nfn show<T: Display>(value: T) {\n    println!(\"{}\", value);\n}\n\nfn main() {\n    show(vec![1, 2]);\n}",
+        fixed: "use std::fmt::Debug;\n\nfn show<T: Debug>(value: T) {\n    println!(\"{:?}\", value);\n}\n\nfn main() {\n    show(vec![1, 2]);\n}",
+        output: "[1, 2]",
+    },
+    ErrorInfo {
+        code: "E0106",
+        title: "explain.E0106.title",
+        fix: "explain.E0106.fix",
+        chapter: 12,
+        point: None,
+        broken: "fn longest(x: &str, y: &str) -> &str {\n    if x.len() > y.len() { x } else { y }\n}\n\nfn main() {\n    println!(\"{}\", longest(\"Rust\", \"Go\"));\n}",
+        fixed: "fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {\n    if x.len() > y.len() { x } else { y }\n}\n\nfn main() {\n    println!(\"{}\", longest(\"Rust\", \"Go\"));\n}",
+        output: "Rust",
+    },
+    ErrorInfo {
+        code: "E0597",
+        title: "explain.E0597.title",
+        fix: "explain.E0597.fix",
+        chapter: 12,
+        point: None,
+        broken: "let r;\n{\n    let x = 5;\n    r = &x;\n}\nprintln!(\"{}\", r);",
+        fixed: "let x = 5;\nlet r;\n{\n    r = &x;\n}\nprintln!(\"{}\", r);",
+        output: "5",
+    },
+    ErrorInfo {
+        code: "E0373",
+        title: "explain.E0373.title",
+        fix: "explain.E0373.fix",
+        chapter: 19,
+        point: None,
+        broken: "use std::thread;\n\nfn main() {\n    let v = vec![1, 2, 3];\n    let handle = thread::spawn(|| println!(\"{:?}\", v));\n    handle.join().unwrap();\n}",
+        fixed: "use std::thread;\n\nfn main() {\n    let v = vec![1, 2, 3];\n    let handle = thread::spawn(move || println!(\"{:?}\", v));\n    handle.join().unwrap();\n}",
+        output: "[1, 2, 3]",
+    },
+];
@@ -0,0 +1,292 @@
+// 间隔重复抽认卡
+// 卡片由已学章节知识点的核心概念和常见错误生成，复习间隔按 SM-2 算法安排：
+// 每次复习按 0-5 自评记忆程度，3 分及以上间隔逐次拉长，低于 3 分从头开始。
+// 调度只以天为单位，时间由调用方传入，测试中可以使用固定的时钟。
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::chapters;
+use crate::knowledge;
+use crate::progress::Progress;
+use crate::tr;
+
+/// 一天的秒数
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// 每天最多引入的新卡片数
+pub const NEW_CARDS_PER_DAY: usize = 10;
+
+/// 新卡片的难度系数，以百分之一为单位（250 表示 2.5）
+pub const INITIAL_EASE: u32 = 250;
+
+/// 难度系数的下限
+pub const MIN_EASE: u32 = 130;
+
+/// 难度系数的上限
+pub const MAX_EASE: u32 = 1000;
+
+/// 复习间隔的上限（天），约一百年
+pub const MAX_INTERVAL: u32 = 36_500;
+
+/// Unix 时间所在的天数（UTC）
+pub fn day_of(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+/// 卡片内容的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CardKind {
+    /// 核心概念
+    Concept,
+    /// 常见错误
+    Mistake,
+}
+
+impl CardKind {
+    fn key(self) -> char {
+        match self {
+            CardKind::Concept => 'c',
+            CardKind::Mistake => 'm',
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CardKind::Concept => tr!("flashcards.kind.concept"),
+            CardKind::Mistake => tr!("flashcards.kind.mistake"),
+        }
+    }
+}
+
+/// 卡片编号：章节、知识点序号、来源和条目序号，序号从 1 开始。
+/// 写成 `6.1.c2` 的形式，不依赖界面语言，换语言后复习记录仍然有效。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardId {
+    pub chapter: u8,
+    pub point: usize,
+    pub kind: CardKind,
+    pub item: usize,
+}
+
+impl fmt::Display for CardId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}{}", self.chapter, self.point, self.kind.key(), self.item)
+    }
+}
+
+impl FromStr for CardId {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<CardId, ()> {
+        let mut parts = text.split('.');
+        let chapter = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        let point = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        let last = parts.next().ok_or(())?;
+        if parts.next().is_some() {
+            return Err(());
+        }
+        let kind = match last.chars().next() {
+            Some('c') => CardKind::Concept,
+            Some('m') => CardKind::Mistake,
+            _ => return Err(()),
+        };
+        let item = last[1..].parse().map_err(|_| ())?;
+        if point == 0 || item == 0 {
+            return Err(());
+        }
+        Ok(CardId { chapter, point, kind, item })
+    }
+}
+
+/// 一张卡片：正面是提问，背面是答案
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: CardId,
+    /// 所属知识点的标题
+    pub topic: String,
+    pub front: String,
+    pub back: String,
+}
+
+/// 某一章知识点生成的全部卡片，按知识点顺序排列
+pub fn cards_for_chapter(chapter: u8) -> Vec<Card> {
+    let points = knowledge::base().chapter(chapter).unwrap_or_default();
+    let mut cards = Vec::new();
+    for (index, point) in points.iter().enumerate() {
+        for (kind, items) in [
+            (CardKind::Concept, &point.key_concepts),
+            (CardKind::Mistake, &point.common_mistakes),
+        ] {
+            for (item, text) in items.iter().enumerate() {
+                let front = match kind {
+                    CardKind::Concept => tr!(
+                        "flashcards.front.concept",
+                        topic = point.title,
+                        number = item + 1,
+                        total = items.len()
+                    ),
+                    CardKind::Mistake => tr!(
+                        "flashcards.front.mistake",
+                        topic = point.title,
+                        number = item + 1,
+                        total = items.len()
+                    ),
+                };
+                cards.push(Card {
+                    id: CardId {
+                        chapter,
+                        point: index + 1,
+                        kind,
+                        item: item + 1,
+                    },
+                    topic: point.title.clone(),
+                    front,
+                    back: text.clone(),
+                });
+            }
+        }
+    }
+    cards
+}
+
+/// 自评的记忆程度，0 完全想不起来，5 毫不费力
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Quality(u8);
+
+impl Quality {
+    /// 超出 0-5 时返回 `None`
+    pub fn new(value: u8) -> Option<Quality> {
+        (value <= 5).then_some(Quality(value))
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+
+    /// 3 分及以上算作记住了
+    pub fn is_pass(self) -> bool {
+        self.0 >= 3
+    }
+}
+
+/// 一张卡片的复习状态，日期都是 [`day_of`] 得到的天数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardState {
+    /// 第一次复习的日期
+    pub added: u64,
+    /// 最近一次复习的日期
+    pub reviewed: u64,
+    /// 连续记住的次数，答错时清零
+    pub repetitions: u32,
+    /// 当前复习间隔（天）
+    pub interval: u32,
+    /// 难度系数，以百分之一为单位
+    pub ease: u32,
+    /// 下次到期的日期
+    pub due: u64,
+}
+
+impl CardState {
+    /// 在 `today` 第一次复习的卡片
+    pub fn new(today: u64) -> CardState {
+        CardState {
+            added: today,
+            reviewed: today,
+            repetitions: 0,
+            interval: 0,
+            ease: INITIAL_EASE,
+            due: today,
+        }
+    }
+
+    pub fn is_due(&self, today: u64) -> bool {
+        self.due <= today
+    }
+
+    /// 按 SM-2 更新复习状态：
+    /// 记住时间隔依次为 1 天、6 天，之后乘以难度系数；
+    /// 难度系数加上 0.1 - (5-q)(0.08 + (5-q)0.02)，保持在 1.3 到 10 之间，间隔不超过 [`MAX_INTERVAL`]；
+    /// 没记住时从 1 天重新开始，难度系数不变。
+    pub fn review(&mut self, quality: Quality, today: u64) {
+        if quality.is_pass() {
+            self.repetitions = self.repetitions.saturating_add(1);
+            self.interval = match self.repetitions {
+                1 => 1,
+                2 => 6,
+                // 四舍五入到整天
+                _ => {
+                    let days = (u64::from(self.interval).saturating_mul(u64::from(self.ease)) + 50) / 100;
+                    days.min(u64::from(MAX_INTERVAL)) as u32
+                }
+            };
+            let miss = 5 - u32::from(quality.value());
+            let delta = 10 - (miss * (8 + miss * 2)) as i64;
+            self.ease = (i64::from(self.ease) + delta).clamp(i64::from(MIN_EASE), i64::from(MAX_EASE)) as u32;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        self.reviewed = today;
+        self.due = today + u64::from(self.interval);
+    }
+}
+
+/// 今天要复习的卡片
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewQueue {
+    /// 到期的旧卡片，最早到期的在前
+    pub due: Vec<Card>,
+    /// 今天可以引入的新卡片
+    pub new: Vec<Card>,
+}
+
+impl ReviewQueue {
+    pub fn len(&self) -> usize {
+        self.due.len() + self.new.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 先复习到期卡片，再学新卡片
+    pub fn cards(&self) -> impl Iterator<Item = &Card> {
+        self.due.iter().chain(&self.new)
+    }
+}
+
+/// 根据学习进度安排今天的复习：只包含已学过的章节，
+/// 新卡片加上今天已经引入的不超过 [`NEW_CARDS_PER_DAY`]
+pub fn review_queue(progress: &Progress, today: u64) -> ReviewQueue {
+    let mut queue = ReviewQueue::default();
+    let mut due = Vec::new();
+    let introduced = progress.cards().filter(|(_, state)| state.added == today).count();
+    let mut new_left = NEW_CARDS_PER_DAY.saturating_sub(introduced);
+
+    for chapter in chapters::all().iter().filter(|chapter| progress.is_completed(chapter.id)) {
+        for card in cards_for_chapter(chapter.id) {
+            match progress.card(card.id) {
+                Some(state) if state.is_due(today) => due.push((state.due, card)),
+                Some(_) => {}
+                None if new_left > 0 => {
+                    new_left -= 1;
+                    queue.new.push(card);
+                }
+                None => {}
+            }
+        }
+    }
+    // 同一天到期的保持章节顺序
+    due.sort_by_key(|(day, _)| *day);
+    queue.due = due.into_iter().map(|(_, card)| card).collect();
+    queue
+}
+
+/// 记录一次复习，返回更新后的状态
+pub fn record_review(progress: &mut Progress, id: CardId, quality: Quality, today: u64) -> CardState {
+    let mut state = progress.card(id).copied().unwrap_or_else(|| CardState::new(today));
+    state.review(quality, today);
+    progress.set_card(id, state);
+    state
+}
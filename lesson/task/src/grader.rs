@@ -0,0 +1,512 @@
+// 练习评分
+// Smartcontractcode/rs-*.rs 是一组独立的练习题，每道可评分的题目在 grader/ 下有一个评分文件：
+//
+//   // title: 代币特征 Money
+//   // chapter: 11
+//   // api: pub trait Money
+//   // api: pub fn balance_of(money: &impl Money, account: &str) -> u32
+//   // timeout: 5
+//
+//   #[test]
+//   fn mint_adds_to_the_balance() { ... }
+//
+// api 可以有多行，是提交的文件中必须出现的公开接口（按记号比较，忽略空白和注释）；timeout 是每个测试的时间限制（秒）。
+// 无法评分的题目（只打印输出、依赖 tokio 等）列在 UNGRADED 中并注明原因。
+// 评分时把提交的文件作为 submission 模块放进临时 crate，与隐藏测试一起用 rustc --test 编译，再逐个运行测试。
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::chapters;
+use crate::exercises::ExerciseError;
+use crate::explain::{self, Diagnostic};
+use crate::i18n;
+use crate::knowledge;
+use crate::lexer;
+use crate::tr;
+use crate::verify::{self, Finished, VerifyOptions};
+
+/// 内置的评分文件，位于 lesson/task/grader/
+const EMBEDDED: &[(&str, &str)] = &[
+    ("rs-003.rs", include_str!("../grader/rs-003.rs")),
+    ("rs-004.rs", include_str!("../grader/rs-004.rs")),
+    ("rs-005.rs", include_str!("../grader/rs-005.rs")),
+    ("rs-006.rs", include_str!("../grader/rs-006.rs")),
+    ("rs-007.rs", include_str!("../grader/rs-007.rs")),
+    ("rs-008.rs", include_str!("../grader/rs-008.rs")),
+    ("rs-009.rs", include_str!("../grader/rs-009.rs")),
+    ("rs-010.rs", include_str!("../grader/rs-010.rs")),
+    ("rs-012.rs", include_str!("../grader/rs-012.rs")),
+    ("rs-013.rs", include_str!("../grader/rs-013.rs")),
+    ("rs-014.rs", include_str!("../grader/rs-014.rs")),
+    ("rs-015.rs", include_str!("../grader/rs-015.rs")),
+    ("rs-016.rs", include_str!("../grader/rs-016.rs")),
+    ("rs-018.rs", include_str!("../grader/rs-018.rs")),
+    ("rs-019.rs", include_str!("../grader/rs-019.rs")),
+    ("rs-020.rs", include_str!("../grader/rs-020.rs")),
+    ("rs-021.rs", include_str!("../grader/rs-021.rs")),
+    ("rs-024.rs", include_str!("../grader/rs-024.rs")),
+    ("rs-025.rs", include_str!("../grader/rs-025.rs")),
+    ("rs-026.rs", include_str!("../grader/rs-026.rs")),
+    ("rs-027.rs", include_str!("../grader/rs-027.rs")),
+    ("rs-028.rs", include_str!("../grader/rs-028.rs")),
+    ("rs-029.rs", include_str!("../grader/rs-029.rs")),
+    ("rs-030.rs", include_str!("../grader/rs-030.rs")),
+    ("rs-031.rs", include_str!("../grader/rs-031.rs")),
+    ("rs-032.rs", include_str!("../grader/rs-032.rs")),
+    ("rs-033.rs", include_str!("../grader/rs-033.rs")),
+    ("rs-035.rs", include_str!("../grader/rs-035.rs")),
+    ("rs-036.rs", include_str!("../grader/rs-036.rs")),
+    ("rs-037.rs", include_str!("../grader/rs-037.rs")),
+    ("rs-038.rs", include_str!("../grader/rs-038.rs")),
+    ("rs-039.rs", include_str!("../grader/rs-039.rs")),
+    ("rs-040.rs", include_str!("../grader/rs-040.rs")),
+    ("rs-041.rs", include_str!("../grader/rs-041.rs")),
+    ("rs-042.rs", include_str!("../grader/rs-042.rs")),
+    ("rs-043.rs", include_str!("../grader/rs-043.rs")),
+    ("rs-044.rs", include_str!("../grader/rs-044.rs")),
+    ("rs-045.rs", include_str!("../grader/rs-045.rs")),
+    ("rs-046.rs", include_str!("../grader/rs-046.rs")),
+    ("rs-047.rs", include_str!("../grader/rs-047.rs")),
+    ("rs-048.rs", include_str!("../grader/rs-048.rs")),
+    ("rs-049.rs", include_str!("../grader/rs-049.rs")),
+    ("rs-050.rs", include_str!("../grader/rs-050.rs")),
+    ("rs-051.rs", include_str!("../grader/rs-051.rs")),
+    ("rs-052.rs", include_str!("../grader/rs-052.rs")),
+    ("rs-053.rs", include_str!("../grader/rs-053.rs")),
+    ("rs-054.rs", include_str!("../grader/rs-054.rs")),
+    ("rs-055.rs", include_str!("../grader/rs-055.rs")),
+    ("rs-056.rs", include_str!("../grader/rs-056.rs")),
+    ("rs-057.rs", include_str!("../grader/rs-057.rs")),
+    ("rs-058.rs", include_str!("../grader/rs-058.rs")),
+    ("rs-059.rs", include_str!("../grader/rs-059.rs")),
+    ("rs-060.rs", include_str!("../grader/rs-060.rs")),
+    ("rs-061.rs", include_str!("../grader/rs-061.rs")),
+    ("rs-062.rs", include_str!("../grader/rs-062.rs")),
+    ("rs-063.rs", include_str!("../grader/rs-063.rs")),
+    ("rs-064.rs", include_str!("../grader/rs-064.rs")),
+    ("rs-065.rs", include_str!("../grader/rs-065.rs")),
+    ("rs-066.rs", include_str!("../grader/rs-066.rs")),
+];
+
+/// 没有评分文件的练习和原因的翻译键
+const UNGRADED: &[(&str, &str)] = &[
+    ("rs-001", "grade.ungraded.prints"),
+    ("rs-002", "grade.ungraded.prints"),
+    ("rs-011", "grade.ungraded.inside_main"),
+    ("rs-017", "grade.ungraded.module_file"),
+    ("rs-022", "grade.ungraded.compile_only"),
+    ("rs-023", "grade.ungraded.compile_only"),
+    ("rs-034", "grade.ungraded.prints"),
+    ("rs-067", "grade.ungraded.tokio"),
+    ("rs-068", "grade.ungraded.tokio"),
+    ("rs-069", "grade.ungraded.tokio"),
+];
+
+/// 未指定 timeout 时每个测试的时间限制
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 隐藏测试所在的模块，测试名以它开头
+const TEST_MODULE: &str = "grader_tests";
+
+/// 一道可评分的题目
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    /// 评分文件名去掉 `.rs`，例如 `rs-031`
+    pub id: String,
+    pub title: String,
+    /// 讲解相关内容的章节
+    pub chapter: u8,
+    /// 必须提供的公开接口
+    pub api: Vec<String>,
+    /// 每个测试的时间限制
+    pub timeout: Duration,
+    /// 完整的评分文件，隐藏测试写在说明注释之后
+    pub tests: String,
+}
+
+/// 解析一个评分文件；开头连续的注释行中 `// 字段: 值` 形式的行是说明字段
+pub fn parse_assignment(file: &str, text: &str) -> Result<Assignment, ExerciseError> {
+    let error = |line: usize, message: String| ExerciseError {
+        file: file.to_string(),
+        line,
+        message,
+    };
+    let (mut title, mut chapter, mut timeout) = (None, None, None);
+    let mut api = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        let Some((key, value)) = comment.trim().split_once(':') else {
+            continue;
+        };
+        if key.is_empty() || !key.bytes().all(|b| b.is_ascii_lowercase()) {
+            continue;
+        }
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(error(index + 1, tr!("grade.header.empty_field", key = key)));
+        }
+        let slot = match key {
+            "title" => &mut title,
+            "chapter" => &mut chapter,
+            "timeout" => &mut timeout,
+            "api" => {
+                api.push(value.to_string());
+                continue;
+            }
+            _ => return Err(error(index + 1, tr!("grade.header.unknown_field", key = key))),
+        };
+        if slot.is_some() {
+            return Err(error(index + 1, tr!("grade.header.duplicate_field", key = key)));
+        }
+        *slot = Some((index + 1, value));
+    }
+
+    let missing = |key: &str| error(0, tr!("grade.header.missing_field", key = key));
+    let (_, title) = title.ok_or_else(|| missing("title"))?;
+    let (line, chapter) = chapter.ok_or_else(|| missing("chapter"))?;
+    let chapter = chapter
+        .parse::<u8>()
+        .ok()
+        .and_then(chapters::get)
+        .ok_or_else(|| error(line, tr!("grade.header.unknown_chapter", chapter = chapter)))?;
+    if api.is_empty() {
+        return Err(error(0, tr!("grade.header.no_api").to_string()));
+    }
+    let timeout = match timeout {
+        None => DEFAULT_TIMEOUT,
+        Some((line, value)) => value
+            .parse::<f64>()
+            .ok()
+            .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+            .map(Duration::from_secs_f64)
+            .ok_or_else(|| error(line, tr!("grade.header.bad_timeout", value = value)))?,
+    };
+    Ok(Assignment {
+        id: file.strip_suffix(".rs").unwrap_or(file).to_string(),
+        title: title.to_string(),
+        chapter: chapter.id,
+        api,
+        timeout,
+        tests: text.to_string(),
+    })
+}
+
+/// 所有内置题目，按编号排序；格式错误的评分文件由测试报告，这里跳过
+pub fn all() -> &'static [Assignment] {
+    static ASSIGNMENTS: OnceLock<Vec<Assignment>> = OnceLock::new();
+    ASSIGNMENTS.get_or_init(|| {
+        let mut assignments: Vec<Assignment> = EMBEDDED
+            .iter()
+            .filter_map(|(file, text)| parse_assignment(file, text).ok())
+            .collect();
+        assignments.sort_by(|a, b| a.id.cmp(&b.id));
+        assignments
+    })
+}
+
+/// 内置评分文件的文件名和内容
+pub fn embedded() -> &'static [(&'static str, &'static str)] {
+    EMBEDDED
+}
+
+/// 按编号查找题目，例如 `rs-031`
+pub fn get(id: &str) -> Option<&'static Assignment> {
+    let id = id.strip_suffix(".rs").unwrap_or(id);
+    all().iter().find(|assignment| assignment.id.eq_ignore_ascii_case(id))
+}
+
+/// 没有评分文件的练习，返回无法评分的原因的翻译键
+pub fn ungraded(id: &str) -> Option<&'static str> {
+    let id = id.strip_suffix(".rs").unwrap_or(id);
+    UNGRADED
+        .iter()
+        .find(|(ungraded, _)| ungraded.eq_ignore_ascii_case(id))
+        .map(|(_, reason)| *reason)
+}
+
+/// 所有没有评分文件的练习编号
+pub fn ungraded_ids() -> impl Iterator<Item = &'static str> {
+    UNGRADED.iter().map(|(id, _)| *id)
+}
+
+/// 跳过空白和注释后的记号，去掉多行参数列表末尾的逗号，让排版不同的签名也能比较
+fn signature_tokens(code: &str) -> Vec<&str> {
+    let tokens: Vec<&str> = lexer::tokenize(code)
+        .iter()
+        .filter(|token| !token.is_trivia())
+        .map(|token| token.text(code))
+        .collect();
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|&(index, &text)| !(text == "," && matches!(tokens.get(index + 1), Some(&")" | &">"))))
+        .map(|(_, &text)| text)
+        .collect()
+}
+
+/// 提交的代码中找不到的公开接口；按记号比较，`pub fn foobar` 不算 `pub fn foo`，注释和字符串中的文字也不算
+pub fn missing_api<'a>(source: &str, api: &'a [String]) -> Vec<&'a str> {
+    let source = signature_tokens(source);
+    api.iter()
+        .filter(|item| {
+            let item = signature_tokens(item);
+            !item.is_empty() && !source.windows(item.len()).any(|window| window == item)
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+/// 单个隐藏测试的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestOutcome {
+    Passed,
+    /// 断言失败或 panic，附带失败信息
+    Failed(String),
+    /// 超过时间限制
+    TimedOut,
+}
+
+/// 一个隐藏测试的名称和结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    /// 去掉模块前缀的测试函数名
+    pub name: String,
+    pub outcome: TestOutcome,
+}
+
+/// 一次评分的结果：缺少接口时不编译，编译失败时不运行测试
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GradeReport {
+    pub missing_api: Vec<String>,
+    pub compile_errors: Vec<Diagnostic>,
+    pub tests: Vec<TestResult>,
+}
+
+impl GradeReport {
+    pub fn passed_count(&self) -> usize {
+        self.tests.iter().filter(|test| test.outcome == TestOutcome::Passed).count()
+    }
+
+    /// 接口齐全、编译通过且所有测试都通过
+    pub fn passed(&self) -> bool {
+        self.missing_api.is_empty()
+            && self.compile_errors.is_empty()
+            && !self.tests.is_empty()
+            && self.passed_count() == self.tests.len()
+    }
+}
+
+impl fmt::Display for TestOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestOutcome::Passed => write!(f, "{}", tr!("grade.outcome.passed")),
+            TestOutcome::Failed(message) => write!(f, "{}", tr!("grade.outcome.failed", message = message)),
+            TestOutcome::TimedOut => write!(f, "{}", tr!("grade.outcome.timed_out")),
+        }
+    }
+}
+
+/// 临时 crate 的根文件：提交的文件作为 submission 模块，隐藏测试放在 grader_tests 模块中
+fn harness(assignment: &Assignment) -> String {
+    format!(
+        "#![allow(dead_code, unused_imports)]\n#[path = \"submission.rs\"]\nmod submission;\nmod {} {{\nuse super::submission::*;\n{}\n}}\n",
+        TEST_MODULE, assignment.tests
+    )
+}
+
+/// 根文件中隐藏测试之前的行数，用于把出错位置换算回评分文件
+const HARNESS_LINES: usize = 5;
+
+/// 编译提交的文件并逐个运行隐藏测试；找不到 rustc、编译超时等环境问题以 `Err` 返回
+pub fn grade(assignment: &Assignment, submission: &Path, options: &VerifyOptions) -> io::Result<GradeReport> {
+    let source = fs::read_to_string(submission)?;
+    let mut report = GradeReport {
+        missing_api: missing_api(&source, &assignment.api).into_iter().map(str::to_string).collect(),
+        ..GradeReport::default()
+    };
+    if !report.missing_api.is_empty() {
+        return Ok(report);
+    }
+    let dir = verify::scratch_dir();
+    fs::create_dir_all(&dir)?;
+    let result = grade_in(&dir, assignment, &source, submission, options, &mut report);
+    let _ = fs::remove_dir_all(&dir);
+    result.map(|_| report)
+}
+
+fn grade_in(
+    dir: &Path,
+    assignment: &Assignment,
+    source: &str,
+    submission: &Path,
+    options: &VerifyOptions,
+    report: &mut GradeReport,
+) -> io::Result<()> {
+    let binary = dir.join(if cfg!(windows) { "grade.exe" } else { "grade" });
+    fs::write(dir.join("submission.rs"), source)?;
+    fs::write(dir.join("main.rs"), harness(assignment))?;
+
+    let compiled = verify::run(
+        Command::new(&options.rustc)
+            .args(["--test", "--edition", "2021", "--error-format=json", "-A", "warnings"])
+            .args(["--crate-name", "grade", "-o"])
+            .arg(&binary)
+            .arg("main.rs")
+            .current_dir(dir),
+        options.timeout,
+    )
+    .map_err(|err| io::Error::new(err.kind(), tr!("verify.rustc_failed", rustc = format!("{:?}", options.rustc), error = err)))?;
+    match compiled {
+        Finished::TimedOut => return Err(io::Error::new(io::ErrorKind::TimedOut, tr!("grade.compile_timed_out"))),
+        Finished::Exited { success: false, stderr, .. } => {
+            report.compile_errors = explain::parse_json_diagnostics(&stderr);
+            for diagnostic in &mut report.compile_errors {
+                relocate(diagnostic, assignment, submission);
+            }
+            if report.compile_errors.is_empty() {
+                return Err(io::Error::other(tr!("grade.compile_error", stderr = stderr.trim())));
+            }
+            return Ok(());
+        }
+        Finished::Exited { .. } => {}
+    }
+
+    for name in list_tests(&binary, options)? {
+        let ran = verify::run(
+            Command::new(&binary)
+                .args(["--exact", &name, "--test-threads", "1"])
+                .env("RUST_BACKTRACE", "0")
+                .current_dir(dir),
+            assignment.timeout,
+        )?;
+        let outcome = match ran {
+            Finished::TimedOut => TestOutcome::TimedOut,
+            Finished::Exited { success: true, .. } => TestOutcome::Passed,
+            Finished::Exited { stdout, stderr, .. } => TestOutcome::Failed(failure_message(&stdout, &stderr)),
+        };
+        let name = name.strip_prefix(TEST_MODULE).and_then(|rest| rest.strip_prefix("::")).unwrap_or(&name);
+        report.tests.push(TestResult {
+            name: name.to_string(),
+            outcome,
+        });
+    }
+    Ok(())
+}
+
+/// 把临时 crate 中的出错位置换成提交的文件或评分文件中的位置
+fn relocate(diagnostic: &mut Diagnostic, assignment: &Assignment, submission: &Path) {
+    let Some(location) = &mut diagnostic.location else {
+        return;
+    };
+    if location.file.ends_with("submission.rs") {
+        location.file = submission.display().to_string();
+    } else if location.file.ends_with("main.rs") && location.line > HARNESS_LINES {
+        location.file = format!("grader/{}.rs", assignment.id);
+        location.line -= HARNESS_LINES;
+    }
+}
+
+/// 测试程序中隐藏测试的完整名称
+fn list_tests(binary: &Path, options: &VerifyOptions) -> io::Result<Vec<String>> {
+    let listed = verify::run(Command::new(binary).arg("--list"), options.timeout)?;
+    let Finished::Exited { success: true, stdout, .. } = listed else {
+        return Err(io::Error::other(tr!("grade.list_failed")));
+    };
+    let prefix = format!("{}::", TEST_MODULE);
+    Ok(stdout
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .filter(|name| name.starts_with(&prefix))
+        .map(str::to_string)
+        .collect())
+}
+
+/// 从测试输出中取出 panic 信息，去掉线程名和回溯提示
+fn failure_message(stdout: &str, stderr: &str) -> String {
+    let section = stdout.split_once(" stdout ----\n").map_or("", |(_, rest)| rest);
+    let section = section.split("\nfailures:\n").next().unwrap_or(section);
+    let lines: Vec<&str> = section
+        .lines()
+        .filter(|line| !line.starts_with("thread '") && !line.starts_with("note: "))
+        .collect();
+    let message = lines.join("\n").trim().to_string();
+    if !message.is_empty() {
+        return message;
+    }
+    match stderr.trim() {
+        "" => tr!("grade.abnormal_exit").to_string(),
+        stderr => stderr.to_string(),
+    }
+}
+
+/// 列出可以评分的题目
+pub fn write_list(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", tr!("grade.list_title", count = all().len()))?;
+    writeln!(out, "{}", "─".repeat(40))?;
+    for assignment in all() {
+        let chapter = chapters::get(assignment.chapter).map_or_else(String::new, |chapter| chapter.display_name());
+        writeln!(out, "  {}  {}（{}）", assignment.id, assignment.title, chapter)?;
+    }
+    writeln!(out, "\n{}", tr!("grade.ungraded_title", count = UNGRADED.len()))?;
+    for (id, reason) in UNGRADED {
+        writeln!(out, "  {}  {}", id, i18n::text(reason))?;
+    }
+    writeln!(out, "\n{}", tr!("grade.list_hint"))
+}
+
+/// 显示评分报告
+pub fn write_report(out: &mut impl Write, assignment: &Assignment, report: &GradeReport) -> io::Result<()> {
+    writeln!(out, "{}", "═".repeat(50))?;
+    writeln!(out, "📝 {} {}", assignment.id, assignment.title)?;
+    writeln!(out, "{}", "═".repeat(50))?;
+    if let Some(chapter) = chapters::get(assignment.chapter) {
+        // 只有带知识点的章节才提示 knowledge 命令
+        if knowledge::base().chapter(chapter.id).is_some() {
+            writeln!(out, "{}", tr!("explain.chapter", chapter = chapter.display_name(), id = chapter.id))?;
+        } else {
+            writeln!(out, "{}", tr!("grade.chapter", chapter = chapter.display_name()))?;
+        }
+    }
+
+    if !report.missing_api.is_empty() {
+        writeln!(out, "\n{}", tr!("grade.missing_api"))?;
+        for item in &report.missing_api {
+            writeln!(out, "   {}", item)?;
+        }
+        return writeln!(out, "\n{}", tr!("grade.failed"));
+    }
+    if !report.compile_errors.is_empty() {
+        writeln!(out, "\n{}", tr!("grade.compile_failed", count = report.compile_errors.len()))?;
+        explain::write_errors(out, &report.compile_errors)?;
+        return writeln!(out, "\n{}", tr!("grade.failed"));
+    }
+
+    writeln!(out)?;
+    let seconds = assignment.timeout.as_secs_f64();
+    for test in &report.tests {
+        match &test.outcome {
+            TestOutcome::Passed => writeln!(out, "  ✅ {}", test.name)?,
+            TestOutcome::Failed(message) => {
+                writeln!(out, "  ❌ {}", test.name)?;
+                for line in message.lines() {
+                    writeln!(out, "     {}", line)?;
+                }
+            }
+            TestOutcome::TimedOut => writeln!(out, "  ⏱️  {}", tr!("grade.timed_out", name = test.name, seconds = seconds))?,
+        }
+    }
+    writeln!(out, "\n{}", tr!("grade.summary", passed = report.passed_count(), total = report.tests.len()))?;
+    if report.passed() {
+        writeln!(out, "{}", tr!("grade.passed"))
+    } else {
+        writeln!(out, "{}", tr!("grade.failed"))
+    }
+}
@@ -0,0 +1,265 @@
+// 章节前置关系图
+// 每章在注册表中声明前置章节，这里据此给出拓扑顺序、学习阶段、
+// 结合学习进度的下一步推荐，以及 Graphviz DOT 格式的导出。
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+use crate::chapters::{self, Chapter, Group};
+use crate::knowledge;
+use crate::progress::Progress;
+
+/// 以章节编号为节点、前置关系为边的有向图
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    /// 节点按加入顺序排列，排序时互不依赖的节点保持这个顺序
+    nodes: Vec<u8>,
+    prerequisites: HashMap<u8, Vec<u8>>,
+}
+
+impl Graph {
+    /// 由 `(节点, 前置节点)` 建图
+    pub fn new<I, P>(nodes: I) -> Graph
+    where
+        I: IntoIterator<Item = (u8, P)>,
+        P: IntoIterator<Item = u8>,
+    {
+        let mut graph = Graph::default();
+        for (id, prerequisites) in nodes {
+            graph.nodes.push(id);
+            graph.prerequisites.insert(id, prerequisites.into_iter().collect());
+        }
+        graph
+    }
+
+    pub fn from_chapters(chapters: &[Chapter]) -> Graph {
+        Graph::new(
+            chapters
+                .iter()
+                .map(|chapter| (chapter.id, chapter.prerequisites.iter().copied())),
+        )
+    }
+
+    pub fn nodes(&self) -> &[u8] {
+        &self.nodes
+    }
+
+    pub fn contains(&self, id: u8) -> bool {
+        self.prerequisites.contains_key(&id)
+    }
+
+    /// 直接前置，忽略图中不存在的节点
+    pub fn prerequisites(&self, id: u8) -> Vec<u8> {
+        self.prerequisites
+            .get(&id)
+            .map(|list| list.iter().copied().filter(|pre| self.contains(*pre)).collect())
+            .unwrap_or_default()
+    }
+
+    /// 直接以 `id` 为前置的节点
+    pub fn dependents(&self, id: u8) -> Vec<u8> {
+        self.nodes
+            .iter()
+            .copied()
+            .filter(|node| self.prerequisites(*node).contains(&id))
+            .collect()
+    }
+
+    /// 指向不存在节点的前置关系，返回 `(节点, 前置)`
+    pub fn unknown_prerequisites(&self) -> Vec<(u8, u8)> {
+        let mut unknown = Vec::new();
+        for &id in &self.nodes {
+            for &pre in &self.prerequisites[&id] {
+                if !self.contains(pre) {
+                    unknown.push((id, pre));
+                }
+            }
+        }
+        unknown
+    }
+
+    /// 沿前置关系找出一个环，首尾是同一个节点，例如 `[3, 5, 3]` 表示 3 依赖 5、5 又依赖 3
+    pub fn find_cycle(&self) -> Option<Vec<u8>> {
+        let mut done = HashSet::new();
+        let mut stack = Vec::new();
+        self.nodes
+            .iter()
+            .find_map(|&node| self.visit(node, &mut done, &mut stack))
+    }
+
+    fn visit(&self, node: u8, done: &mut HashSet<u8>, stack: &mut Vec<u8>) -> Option<Vec<u8>> {
+        if done.contains(&node) {
+            return None;
+        }
+        if let Some(start) = stack.iter().position(|active| *active == node) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(node);
+            return Some(cycle);
+        }
+        stack.push(node);
+        for pre in self.prerequisites(node) {
+            if let Some(cycle) = self.visit(pre, done, stack) {
+                return Some(cycle);
+            }
+        }
+        stack.pop();
+        done.insert(node);
+        None
+    }
+
+    /// 拓扑排序，每个节点都排在它的前置之后；有环时返回环
+    pub fn topological_order(&self) -> Result<Vec<u8>, Vec<u8>> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut placed = HashSet::new();
+        while order.len() < self.nodes.len() {
+            let next = self.nodes.iter().copied().find(|id| {
+                !placed.contains(id) && self.prerequisites(*id).iter().all(|pre| placed.contains(pre))
+            });
+            let Some(next) = next else {
+                return Err(self.find_cycle().unwrap_or_default());
+            };
+            placed.insert(next);
+            order.push(next);
+        }
+        Ok(order)
+    }
+
+    /// 学习阶段：每个节点所在的阶段比它所有前置都靠后，没有前置的节点在第一阶段
+    pub fn stages(&self) -> Result<Vec<Vec<u8>>, Vec<u8>> {
+        let order = self.topological_order()?;
+        let mut stage_of: HashMap<u8, usize> = HashMap::new();
+        for &id in &order {
+            let stage = self
+                .prerequisites(id)
+                .iter()
+                .map(|pre| stage_of[pre] + 1)
+                .max()
+                .unwrap_or(0);
+            stage_of.insert(id, stage);
+        }
+        let mut stages = vec![Vec::new(); stage_of.values().max().map_or(0, |max| max + 1)];
+        for &id in &self.nodes {
+            stages[stage_of[&id]].push(id);
+        }
+        Ok(stages)
+    }
+
+    /// 全部直接和间接前置，按拓扑顺序排列
+    pub fn ancestors(&self, id: u8) -> Vec<u8> {
+        let mut found = HashSet::new();
+        let mut pending = self.prerequisites(id);
+        while let Some(pre) = pending.pop() {
+            if pre != id && found.insert(pre) {
+                pending.extend(self.prerequisites(pre));
+            }
+        }
+        let order = self.topological_order().unwrap_or_else(|_| self.nodes.clone());
+        order.into_iter().filter(|node| found.contains(node)).collect()
+    }
+
+    /// 可以开始学习的节点：自己未完成、前置都已完成。
+    /// `recent` 刚解锁的节点排在前面，其余按拓扑顺序
+    pub fn ready(&self, completed: impl Fn(u8) -> bool, recent: Option<u8>) -> Vec<u8> {
+        let order = self.topological_order().unwrap_or_else(|_| self.nodes.clone());
+        let (mut unlocked, others): (Vec<u8>, Vec<u8>) = order
+            .into_iter()
+            .filter(|id| !completed(*id) && self.prerequisites(*id).iter().all(|pre| completed(*pre)))
+            .partition(|id| recent.is_some_and(|recent| self.prerequisites(*id).contains(&recent)));
+        unlocked.extend(others);
+        unlocked
+    }
+}
+
+/// 注册表中所有章节的前置关系图
+pub fn graph() -> &'static Graph {
+    static GRAPH: OnceLock<Graph> = OnceLock::new();
+    GRAPH.get_or_init(|| Graph::from_chapters(chapters::all()))
+}
+
+fn to_chapters(ids: Vec<u8>) -> Vec<&'static Chapter> {
+    ids.into_iter().filter_map(chapters::get).collect()
+}
+
+/// 根据学习记录可以开始的章节，最近学完的章节解锁的排在最前面
+pub fn ready_chapters(progress: &Progress) -> Vec<&'static Chapter> {
+    let recent = progress.last_chapter().map(|chapter| chapter.id);
+    to_chapters(graph().ready(|id| progress.is_completed(id), recent))
+}
+
+/// 建议下一步学习的章节，全部学完时为 `None`
+pub fn recommend(progress: &Progress) -> Option<&'static Chapter> {
+    ready_chapters(progress).first().copied()
+}
+
+/// 学到 `target` 为止的路线：全部前置章节按拓扑顺序排列，最后是 `target` 本身
+pub fn learning_path(target: &Chapter) -> Vec<&'static Chapter> {
+    let mut ids = graph().ancestors(target.id);
+    ids.push(target.id);
+    to_chapters(ids)
+}
+
+/// 知识点相关主题指向的其他章节，返回 `(章节, 相关章节)`，已有前置关系的不再重复
+pub fn related_edges() -> Vec<(u8, u8)> {
+    let graph = graph();
+    let base = knowledge::base();
+    let mut edges = Vec::new();
+    for id in base.chapters() {
+        for point in base.chapter(id).unwrap_or_default() {
+            for target in point.related_topics.iter().filter_map(|topic| knowledge::resolve_topic(topic)) {
+                let linked = graph.prerequisites(id).contains(&target) || graph.prerequisites(target).contains(&id);
+                if target != id && !linked && !edges.contains(&(id, target)) && !edges.contains(&(target, id)) {
+                    edges.push((id, target));
+                }
+            }
+        }
+    }
+    edges
+}
+
+/// DOT 字符串中的引号和反斜杠需要转义
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// 写出 Graphviz DOT 格式的章节图：实线是前置关系，虚线是知识点中的相关主题。
+/// 提供学习进度时，学过的章节填成绿色，推荐的下一章填成金色。
+pub fn write_dot(out: &mut impl Write, progress: Option<&Progress>) -> io::Result<()> {
+    let next = progress.and_then(recommend).map(|chapter| chapter.id);
+    writeln!(out, "digraph chapters {{")?;
+    writeln!(out, "    rankdir=LR;")?;
+    writeln!(out, "    node [shape=box, style=rounded];")?;
+    for group in Group::ALL {
+        writeln!(out, "    subgraph cluster_{} {{", group.key())?;
+        writeln!(out, "        label=\"{}\";", dot_escape(group.title()))?;
+        for chapter in chapters::in_group(group) {
+            let label = dot_escape(&format!("{}. {}", chapter.id, chapter.localized_title()));
+            let fill = if next == Some(chapter.id) {
+                Some("gold")
+            } else if progress.is_some_and(|progress| progress.is_completed(chapter.id)) {
+                Some("palegreen")
+            } else {
+                None
+            };
+            match fill {
+                Some(color) => writeln!(
+                    out,
+                    "        c{} [label=\"{}\", style=\"rounded,filled\", fillcolor={}];",
+                    chapter.id, label, color
+                )?,
+                None => writeln!(out, "        c{} [label=\"{}\"];", chapter.id, label)?,
+            }
+        }
+        writeln!(out, "    }}")?;
+    }
+    let graph = graph();
+    for &id in graph.nodes() {
+        for pre in graph.prerequisites(id) {
+            writeln!(out, "    c{} -> c{};", pre, id)?;
+        }
+    }
+    for (from, to) in related_edges() {
+        writeln!(out, "    c{} -> c{} [style=dashed, color=gray, dir=none, constraint=false];", from, to)?;
+    }
+    writeln!(out, "}}")
+}
@@ -0,0 +1,123 @@
+// Rust 代码的语法高亮
+// 在 lexer 切出的记号上按种类着色：终端中用 ANSI 颜色，导出网页时用带 class 的 <span>。
+// 两种输出去掉颜色或标签后都和原文一致。
+
+use crate::lexer::{self, Token, TokenKind};
+use crate::term::{self, Color};
+
+/// 高亮时区分的样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Keyword,
+    Comment,
+    /// 字符串和字符
+    Literal,
+    Number,
+    Lifetime,
+    /// 宏调用的名字，如 `println!` 中的 `println`
+    Macro,
+}
+
+impl Style {
+    fn color(self) -> Color {
+        match self {
+            Style::Keyword => Color::Magenta,
+            Style::Comment => Color::Gray,
+            Style::Literal => Color::Green,
+            Style::Number => Color::Yellow,
+            Style::Lifetime | Style::Macro => Color::Cyan,
+        }
+    }
+
+    /// HTML 输出中 `<span>` 的 class
+    pub fn class(self) -> &'static str {
+        match self {
+            Style::Keyword => "kw",
+            Style::Comment => "comment",
+            Style::Literal => "str",
+            Style::Number => "num",
+            Style::Lifetime => "lifetime",
+            Style::Macro => "macro",
+        }
+    }
+}
+
+/// 每个记号的样式，`None` 为不着色
+fn styles<'a>(source: &'a str, tokens: &'a [Token]) -> impl Iterator<Item = (&'a str, Option<Style>)> + 'a {
+    tokens.iter().enumerate().map(move |(index, token)| {
+        let style = match token.kind {
+            TokenKind::Keyword => Some(Style::Keyword),
+            TokenKind::LineComment | TokenKind::BlockComment { .. } => Some(Style::Comment),
+            TokenKind::Char { .. } | TokenKind::Str { .. } | TokenKind::RawStr { .. } => Some(Style::Literal),
+            TokenKind::Number { .. } => Some(Style::Number),
+            TokenKind::Lifetime => Some(Style::Lifetime),
+            // 紧跟 `!` 的标识符是宏名，`a != b` 中的 `!` 后面还有 `=`
+            TokenKind::Ident if is_macro_bang(source, &tokens[index + 1..]) => Some(Style::Macro),
+            TokenKind::Ident | TokenKind::Whitespace | TokenKind::Punct => None,
+        };
+        (token.text(source), style)
+    })
+}
+
+fn is_macro_bang(source: &str, next: &[Token]) -> bool {
+    match next {
+        [bang, rest @ ..] if bang.text(source) == "!" => rest.first().is_none_or(|token| token.text(source) != "="),
+        _ => false,
+    }
+}
+
+/// 用 ANSI 颜色高亮，转义序列不跨行，逐行截取后颜色仍然完整
+pub fn ansi(source: &str) -> String {
+    let tokens = lexer::tokenize(source);
+    let mut highlighted = String::with_capacity(source.len() * 2);
+    for (text, style) in styles(source, &tokens) {
+        for (line, part) in text.split('\n').enumerate() {
+            if line > 0 {
+                highlighted.push('\n');
+            }
+            match style {
+                Some(style) if !part.is_empty() => highlighted.push_str(&term::paint(part, style.color())),
+                _ => highlighted.push_str(part),
+            }
+        }
+    }
+    highlighted
+}
+
+/// 输出是支持颜色的终端时高亮，否则原样返回
+pub fn for_terminal(source: &str) -> String {
+    if term::terminal().color {
+        ansi(source)
+    } else {
+        source.to_string()
+    }
+}
+
+/// 高亮成 HTML 片段，放在 `<pre><code>` 中使用；`&`、`<`、`>` 和 `"` 会转义
+pub fn html(source: &str) -> String {
+    let tokens = lexer::tokenize(source);
+    let mut highlighted = String::with_capacity(source.len() * 2);
+    for (text, style) in styles(source, &tokens) {
+        match style {
+            Some(style) => {
+                highlighted.push_str(&format!("<span class=\"{}\">", style.class()));
+                escape_html(&mut highlighted, text);
+                highlighted.push_str("</span>");
+            }
+            None => escape_html(&mut highlighted, text),
+        }
+    }
+    highlighted
+}
+
+fn escape_html(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
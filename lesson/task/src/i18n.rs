@@ -0,0 +1,252 @@
+// 界面文字的本地化
+// 菜单、帮助、章节介绍和知识点的界面文字按键名存放在 locales/*.txt 中，
+// 通过 --lang 或 LANG 选择语言，缺少的键回退到中文。
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use crate::chapters;
+
+/// 支持的界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// 中文，所有文字的原文
+    Zh,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Zh, Locale::En];
+
+    /// 语言代码，也是 locales/ 下的文件名
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Zh => "zh",
+            Locale::En => "en",
+        }
+    }
+
+    /// 识别 `en`、`en_US.UTF-8`、`zh-CN` 这样的语言标签
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        Locale::ALL.into_iter().find(|locale| locale.code() == language)
+    }
+
+    /// 按 `LC_ALL`、`LC_MESSAGES`、`LANG` 的顺序取第一个非空的设置，无法识别时使用中文
+    pub fn from_env() -> Locale {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|tag| Locale::from_tag(&tag))
+            .unwrap_or(Locale::Zh)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// 内置的消息目录，与 Locale::ALL 顺序一致
+const CATALOGS: [&str; 2] = [include_str!("../locales/zh.txt"), include_str!("../locales/en.txt")];
+
+/// 进程当前使用的语言，默认中文
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_locale(locale: Locale) {
+    CURRENT.store(locale.index() as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    Locale::ALL
+        .get(CURRENT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(Locale::Zh)
+}
+
+/// 一种语言的消息目录
+#[derive(Debug, Default)]
+pub struct Catalog {
+    entries: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// 解析消息目录，格式错误的行跳过并返回警告
+    ///
+    /// 每行一条 `键 = 值`，`#` 开头为注释；多行的值写在 `键 = """` 与单独一行的 `"""` 之间，
+    /// 其中的行原样保留。
+    pub fn parse(text: &str) -> (Catalog, Vec<String>) {
+        let mut catalog = Catalog::default();
+        let mut warnings = Vec::new();
+        let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line));
+
+        while let Some((line_no, line)) = lines.next() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = trimmed.split_once('=') else {
+                warnings.push(format!("第{}行：应为 键 = 值", line_no));
+                continue;
+            };
+            let key = key.trim();
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                warnings.push(format!("第{}行：无效的键名 {:?}", line_no, key));
+                continue;
+            }
+            let mut value = value.trim().to_string();
+            if value == "\"\"\"" {
+                let block: Vec<&str> = lines
+                    .by_ref()
+                    .map(|(_, line)| line)
+                    .take_while(|line| line.trim_end() != "\"\"\"")
+                    .collect();
+                value = block.join("\n");
+            }
+            // 重复的键保留第一次的定义
+            if catalog.entries.contains_key(key) {
+                warnings.push(format!("第{}行：键 {} 重复定义", line_no, key));
+            } else {
+                catalog.entries.insert(key.to_string(), value);
+            }
+        }
+        (catalog, warnings)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// 所有键名，按字母顺序排列
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.entries.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+}
+
+/// 内置的消息目录及其解析警告
+pub fn catalog(locale: Locale) -> &'static (Catalog, Vec<String>) {
+    static LOADED: [OnceLock<(Catalog, Vec<String>)>; 2] = [OnceLock::new(), OnceLock::new()];
+    LOADED[locale.index()].get_or_init(|| Catalog::parse(CATALOGS[locale.index()]))
+}
+
+/// 在当前语言中查找，找不到时回退到中文目录
+pub fn lookup(key: &str) -> Option<&'static str> {
+    catalog(locale()).0.get(key).or_else(|| catalog(Locale::Zh).0.get(key))
+}
+
+/// 查找界面文字，两种语言都没有时返回键名本身，方便发现遗漏
+pub fn text(key: &'static str) -> &'static str {
+    lookup(key).unwrap_or(key)
+}
+
+/// 查找界面文字，找不到时使用 `fallback`（通常是注册表里的中文原文）
+pub fn text_or(key: &str, fallback: &'static str) -> &'static str {
+    lookup(key).unwrap_or(fallback)
+}
+
+/// 查找界面文字并替换其中的 `{名称}` 占位符
+pub fn format(key: &'static str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    fill(text(key), args)
+}
+
+/// 替换模板中的 `{名称}` 占位符，未知的占位符原样保留
+pub fn fill(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after
+            .find('}')
+            .and_then(|end| args.iter().find(|(name, _)| *name == &after[..end]).map(|arg| (end, arg.1)));
+        match value {
+            Some((end, value)) => {
+                result.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 模板中的占位符名称，按出现顺序排列
+pub fn placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let name = &rest[..end];
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            names.push(name);
+        }
+        rest = &rest[end + 1..];
+    }
+    names
+}
+
+/// 章节注册表中需要翻译的字段
+pub const CHAPTER_FIELDS: [&str; 5] = ["title", "brief", "goals", "concepts", "summary"];
+
+/// 章节字段的键名，例如 `chapter.6.title`
+pub fn chapter_key(id: u8, field: &str) -> String {
+    format!("chapter.{}.{}", id, field)
+}
+
+/// 某种语言尚未翻译的键
+///
+/// 中文目录中的键都需要翻译；章节字段的中文原文在注册表里，只有其他语言需要提供。
+pub fn missing_keys(locale: Locale) -> Vec<String> {
+    let own = &catalog(locale).0;
+    let mut missing: Vec<String> = catalog(Locale::Zh)
+        .0
+        .keys()
+        .into_iter()
+        .filter(|key| own.get(key).is_none())
+        .map(str::to_string)
+        .collect();
+    if locale != Locale::Zh {
+        for chapter in chapters::all() {
+            for field in CHAPTER_FIELDS {
+                let key = chapter_key(chapter.id, field);
+                if own.get(&key).is_none() {
+                    missing.push(key);
+                }
+            }
+        }
+    }
+    missing
+}
+
+/// 查找界面文字：`tr!("键")` 返回 `&str`，`tr!("键", 名称 = 值, ...)` 替换占位符后返回 `String`
+#[macro_export]
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::text($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::format($key, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+])
+    };
+}
@@ -1,5 +1,19 @@
 // 知识点详细解释模块
 // 为每个章节提供深入的概念讲解
+// 知识点写在 tutorial/knowledge/*.md 中，编译时嵌入，格式说明见该目录下的 README.md
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::chapters;
+use crate::highlight;
+use crate::i18n::{self, Locale};
+use crate::tr;
 
 /// 知识点结构
 #[derive(Debug, Clone)]
@@ -20,107 +34,726 @@ pub struct CodeExample {
     pub code: String,
     pub explanation: String,
     pub output: Option<String>,
+    pub expect: Expectation,
+}
+
+/// 代码示例的预期结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Expectation {
+    /// 能编译运行，有 `output` 时输出必须一致
+    #[default]
+    Runs,
+    /// 应当编译失败，可以指定错误码，例如 `E0382`
+    CompileFail { code: Option<String> },
 }
 
+/// 内置的知识点文件，位于仓库的 tutorial/knowledge/ 目录
+const EMBEDDED_PACKS: &[(&str, &str)] = &[
+    ("01_variables.md", include_str!("../../../tutorial/knowledge/01_variables.md")),
+    ("02_data_types.md", include_str!("../../../tutorial/knowledge/02_data_types.md")),
+    ("03_functions.md", include_str!("../../../tutorial/knowledge/03_functions.md")),
+    ("04_control_flow.md", include_str!("../../../tutorial/knowledge/04_control_flow.md")),
+    ("05_memory_management.md", include_str!("../../../tutorial/knowledge/05_memory_management.md")),
+    ("06_ownership.md", include_str!("../../../tutorial/knowledge/06_ownership.md")),
+    ("07_borrowing.md", include_str!("../../../tutorial/knowledge/07_borrowing.md")),
+    ("08_structs.md", include_str!("../../../tutorial/knowledge/08_structs.md")),
+];
+
+/// 英文译本，位于 tutorial/knowledge/en/，缺少的章节使用中文内容
+const EMBEDDED_PACKS_EN: &[(&str, &str)] = &[
+    ("en/01_variables.md", include_str!("../../../tutorial/knowledge/en/01_variables.md")),
+    ("en/02_data_types.md", include_str!("../../../tutorial/knowledge/en/02_data_types.md")),
+    ("en/03_functions.md", include_str!("../../../tutorial/knowledge/en/03_functions.md")),
+    ("en/04_control_flow.md", include_str!("../../../tutorial/knowledge/en/04_control_flow.md")),
+    ("en/05_memory_management.md", include_str!("../../../tutorial/knowledge/en/05_memory_management.md")),
+    ("en/06_ownership.md", include_str!("../../../tutorial/knowledge/en/06_ownership.md")),
+    ("en/07_borrowing.md", include_str!("../../../tutorial/knowledge/en/07_borrowing.md")),
+    ("en/08_structs.md", include_str!("../../../tutorial/knowledge/en/08_structs.md")),
+];
+
+/// 某种语言内置的知识点文件，文件名相对于 tutorial/knowledge/
+pub fn embedded_packs(locale: Locale) -> &'static [(&'static str, &'static str)] {
+    match locale {
+        Locale::Zh => EMBEDDED_PACKS,
+        Locale::En => EMBEDDED_PACKS_EN,
+    }
+}
+
+/// 指定知识点覆盖目录的环境变量，目录中的文件按章节替换内置内容
+pub const KNOWLEDGE_DIR_VAR: &str = "TASK_KNOWLEDGE_DIR";
+
 /// 获取章节的详细知识点
 pub fn get_chapter_knowledge(chapter_id: u8) -> Option<Vec<KnowledgePoint>> {
-    match chapter_id {
-        1 => Some(get_variables_knowledge()),
-        2 => Some(get_data_types_knowledge()),
-        3 => Some(get_functions_knowledge()),
-        4 => Some(get_control_flow_knowledge()),
-        5 => Some(get_memory_management_knowledge()),
-        6 => Some(get_ownership_knowledge()),
-        7 => Some(get_borrowing_knowledge()),
-        8 => Some(get_structs_knowledge()),
-        _ => None,
+    base().chapter(chapter_id).map(<[KnowledgePoint]>::to_vec)
+}
+
+/// 进程内共享的当前语言知识库：内置内容加上 `TASK_KNOWLEDGE_DIR` 中的覆盖文件
+pub fn base() -> &'static KnowledgeBase {
+    static BASES: [OnceLock<KnowledgeBase>; 2] = [OnceLock::new(), OnceLock::new()];
+    let locale = i18n::locale();
+    let index = Locale::ALL.iter().position(|known| *known == locale).unwrap_or(0);
+    BASES[index].get_or_init(|| KnowledgeBase::from_env(locale))
+}
+
+/// 知识点文件的解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnowledgeError {
+    pub file: String,
+    /// 从 1 开始的行号，为 0 时表示与具体行无关
+    pub line: usize,
+    /// 出错的字段，例如 `chapter`、`核心概念` 或 `示例：标题`
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for KnowledgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "{}:{}: [{}] {}", self.file, self.line, self.field, self.message)
+        } else {
+            write!(f, "{}: [{}] {}", self.file, self.field, self.message)
+        }
     }
 }
 
-/// 第1章：变量与常量的知识点
-fn get_variables_knowledge() -> Vec<KnowledgePoint> {
-    vec![
-        KnowledgePoint {
-            title: "变量声明与可变性".to_string(),
-            description: "Rust 中变量默认是不可变的，这是 Rust 安全性和并发性的基础。".to_string(),
-            key_concepts: vec![
-                "let 关键字用于声明变量".to_string(),
-                "变量默认不可变 (immutable)".to_string(),
-                "mut 关键字使变量可变".to_string(),
-                "不可变性有助于防止意外修改".to_string(),
-            ],
-            code_examples: vec![
-                CodeExample {
-                    title: "基本变量声明".to_string(),
-                    code: "let x = 5;\nlet mut y = 10;\ny = 15;".to_string(),
-                    explanation: "使用 let 声明变量，默认不可变。加上 mut 关键字使变量可变。".to_string(),
-                    output: Some("y 的值从 10 变为 15".to_string()),
+impl std::error::Error for KnowledgeError {}
+
+/// 一个知识点文件的内容
+#[derive(Debug, Clone)]
+pub struct KnowledgePack {
+    pub chapter: u8,
+    pub points: Vec<KnowledgePoint>,
+}
+
+/// 按章节索引的知识点
+#[derive(Debug, Clone, Default)]
+pub struct KnowledgeBase {
+    packs: BTreeMap<u8, KnowledgePack>,
+    errors: Vec<KnowledgeError>,
+}
+
+impl KnowledgeBase {
+    /// 只包含编译时嵌入的中文知识点
+    pub fn embedded() -> Self {
+        let mut base = KnowledgeBase::default();
+        for (file, text) in EMBEDDED_PACKS {
+            base.add(file, text);
+        }
+        base
+    }
+
+    /// 编译时嵌入的某种语言的知识点，没有译文的章节保留中文内容
+    pub fn embedded_for(locale: Locale) -> Self {
+        let mut base = KnowledgeBase::embedded();
+        if locale != Locale::Zh {
+            let mut translated = KnowledgeBase::default();
+            for (file, text) in embedded_packs(locale) {
+                translated.add(file, text);
+            }
+            base.packs.append(&mut translated.packs);
+            base.errors.append(&mut translated.errors);
+        }
+        base
+    }
+
+    /// 内置知识点，设置了 `TASK_KNOWLEDGE_DIR` 时再应用其中的覆盖文件；
+    /// 中文以外的语言读取该目录下以语言代码命名的子目录，例如 `en/`
+    pub fn from_env(locale: Locale) -> Self {
+        let base = KnowledgeBase::embedded_for(locale);
+        let Some(dir) = env::var_os(KNOWLEDGE_DIR_VAR) else {
+            return base;
+        };
+        let dir = Path::new(&dir);
+        match locale {
+            Locale::Zh => base.with_overrides(dir),
+            other if dir.join(other.code()).is_dir() => base.with_overrides(&dir.join(other.code())),
+            _ => base,
+        }
+    }
+
+    /// 读取目录中的 `*.md` 文件，按章节替换已有内容；出错的文件保留原内容并记录错误
+    pub fn with_overrides(mut self, dir: &Path) -> Self {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                self.errors.push(KnowledgeError {
+                    file: dir.display().to_string(),
+                    line: 0,
+                    field: "目录".to_string(),
+                    message: format!("无法读取：{}", err),
+                });
+                return self;
+            }
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .filter(|path| path.file_name().is_some_and(|name| name != "README.md"))
+            .collect();
+        paths.sort();
+
+        let mut replaced = Vec::new();
+        for path in paths {
+            let file = path.display().to_string();
+            match fs::read_to_string(&path) {
+                Ok(text) => match parse_pack(&file, &text) {
+                    Ok(pack) if replaced.contains(&pack.chapter) => self.errors.push(KnowledgeError {
+                        file,
+                        line: 0,
+                        field: "chapter".to_string(),
+                        message: format!("第{}章已由同目录的其他文件提供", pack.chapter),
+                    }),
+                    Ok(pack) => {
+                        replaced.push(pack.chapter);
+                        self.packs.insert(pack.chapter, pack);
+                    }
+                    Err(err) => self.errors.push(err),
                 },
-            ],
-            common_mistakes: vec![
-                "忘记使用 mut 关键字就尝试修改变量".to_string(),
-                "混淆变量遮蔽和变量修改的概念".to_string(),
-            ],
-            best_practices: vec![
-                "默认使用不可变变量，只在需要时使用 mut".to_string(),
-                "使用描述性的变量名".to_string(),
-            ],
-            related_topics: vec![
-                "内存安全".to_string(),
-                "并发编程".to_string(),
-            ],
-        },
-    ]
+                Err(err) => self.errors.push(KnowledgeError {
+                    file,
+                    line: 0,
+                    field: "文件".to_string(),
+                    message: format!("无法读取：{}", err),
+                }),
+            }
+        }
+        self
+    }
+
+    fn add(&mut self, file: &str, text: &str) {
+        match parse_pack(file, text) {
+            Ok(pack) if self.packs.contains_key(&pack.chapter) => self.errors.push(KnowledgeError {
+                file: file.to_string(),
+                line: 0,
+                field: "chapter".to_string(),
+                message: format!("第{}章的知识点重复定义", pack.chapter),
+            }),
+            Ok(pack) => {
+                self.packs.insert(pack.chapter, pack);
+            }
+            Err(err) => self.errors.push(err),
+        }
+    }
+
+    pub fn chapter(&self, id: u8) -> Option<&[KnowledgePoint]> {
+        self.packs.get(&id).map(|pack| pack.points.as_slice())
+    }
+
+    /// 有知识点的章节编号，从小到大
+    pub fn chapters(&self) -> impl Iterator<Item = u8> + '_ {
+        self.packs.keys().copied()
+    }
+
+    /// 加载过程中遇到的错误
+    pub fn errors(&self) -> &[KnowledgeError] {
+        &self.errors
+    }
+}
+
+/// 知识点中正在解析的部分
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Description,
+    KeyConcepts,
+    CommonMistakes,
+    BestPractices,
+    RelatedTopics,
+    Example,
 }
 
-fn get_data_types_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+impl Section {
+    /// 译本中也可以使用英文小节名
+    fn from_heading(heading: &str) -> Option<Section> {
+        match heading {
+            "核心概念" | "Key concepts" => Some(Section::KeyConcepts),
+            "常见错误" | "Common mistakes" => Some(Section::CommonMistakes),
+            "最佳实践" | "Best practices" => Some(Section::BestPractices),
+            "相关主题" | "Related topics" => Some(Section::RelatedTopics),
+            _ => None,
+        }
+    }
 }
 
-fn get_functions_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+/// 逐行解析知识点文件的状态
+struct PackParser<'a> {
+    file: &'a str,
+    points: Vec<KnowledgePoint>,
+    section: Section,
+    /// 当前知识点和示例标题所在的行，用于报告缺失内容
+    point_line: usize,
+    example_line: usize,
+    text: Vec<&'a str>,
 }
 
-fn get_control_flow_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+impl<'a> PackParser<'a> {
+    fn error(&self, line: usize, field: impl Into<String>, message: impl Into<String>) -> KnowledgeError {
+        KnowledgeError {
+            file: self.file.to_string(),
+            line,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    /// 当前字段名，用于错误信息
+    fn field(&self) -> String {
+        let Some(point) = self.points.last() else {
+            return "正文".to_string();
+        };
+        match self.section {
+            Section::Description => point.title.clone(),
+            Section::KeyConcepts => "核心概念".to_string(),
+            Section::CommonMistakes => "常见错误".to_string(),
+            Section::BestPractices => "最佳实践".to_string(),
+            Section::RelatedTopics => "相关主题".to_string(),
+            Section::Example => match point.code_examples.last() {
+                Some(example) => format!("示例：{}", example.title),
+                None => "示例".to_string(),
+            },
+        }
+    }
+
+    /// 把累积的文字写入当前字段，并检查上一个小节是否完整
+    fn finish_section(&mut self) -> Result<(), KnowledgeError> {
+        let text = join_paragraphs(&self.text);
+        self.text.clear();
+        let field = self.field();
+        let Some(point) = self.points.last_mut() else {
+            return Ok(());
+        };
+        match self.section {
+            Section::Description => {
+                if text.is_empty() {
+                    return Err(self.error(self.point_line, field, "缺少概述"));
+                }
+                point.description = text;
+            }
+            Section::Example => {
+                let example = point.code_examples.last_mut().expect("示例小节总是先创建示例");
+                example.explanation = text;
+                if example.code.is_empty() {
+                    return Err(self.error(self.example_line, field, "缺少 rust 代码块"));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn heading(&mut self, line_no: usize, level: usize, title: &str) -> Result<(), KnowledgeError> {
+        if title.is_empty() {
+            return Err(self.error(line_no, "标题", "标题不能为空"));
+        }
+        self.finish_section()?;
+        if level == 1 {
+            self.points.push(KnowledgePoint {
+                title: title.to_string(),
+                description: String::new(),
+                key_concepts: Vec::new(),
+                code_examples: Vec::new(),
+                common_mistakes: Vec::new(),
+                best_practices: Vec::new(),
+                related_topics: Vec::new(),
+            });
+            self.section = Section::Description;
+            self.point_line = line_no;
+            return Ok(());
+        }
+
+        let Some(point) = self.points.last_mut() else {
+            return Err(self.error(line_no, title, "小节必须位于 # 知识点标题之后"));
+        };
+        let example = ["示例：", "示例:", "Example:"]
+            .iter()
+            .find_map(|prefix| title.strip_prefix(prefix));
+        if let Some(example) = example {
+            point.code_examples.push(CodeExample {
+                title: example.trim().to_string(),
+                code: String::new(),
+                explanation: String::new(),
+                output: None,
+                expect: Expectation::Runs,
+            });
+            self.section = Section::Example;
+            self.example_line = line_no;
+            return Ok(());
+        }
+        match Section::from_heading(title) {
+            Some(section) => {
+                self.section = section;
+                Ok(())
+            }
+            None => Err(self.error(
+                line_no,
+                title,
+                "未知小节，可用：核心概念、常见错误、最佳实践、相关主题、示例：标题",
+            )),
+        }
+    }
+
+    /// `- ` 开头的行；在概述和示例说明中作为普通文字保留
+    fn list_item(&mut self, line_no: usize, line: &'a str) -> Result<(), KnowledgeError> {
+        let item = line.strip_prefix("- ").unwrap_or(line).trim();
+        let field = self.field();
+        let Some(point) = self.points.last_mut() else {
+            return Err(self.error(line_no, field, "内容必须位于 # 知识点标题之后"));
+        };
+        let list = match self.section {
+            Section::KeyConcepts => &mut point.key_concepts,
+            Section::CommonMistakes => &mut point.common_mistakes,
+            Section::BestPractices => &mut point.best_practices,
+            Section::RelatedTopics => &mut point.related_topics,
+            Section::Description | Section::Example => {
+                self.text.push(line);
+                return Ok(());
+            }
+        };
+        if item.is_empty() {
+            return Err(self.error(line_no, field, "列表项不能为空"));
+        }
+        list.push(item.to_string());
+        Ok(())
+    }
+
+    fn text_line(&mut self, line_no: usize, line: &'a str) -> Result<(), KnowledgeError> {
+        let field = self.field();
+        if self.points.is_empty() {
+            if line.is_empty() {
+                return Ok(());
+            }
+            return Err(self.error(line_no, field, "内容必须位于 # 知识点标题之后"));
+        }
+        match self.section {
+            Section::Description | Section::Example => {
+                self.text.push(line);
+                Ok(())
+            }
+            _ if line.is_empty() => Ok(()),
+            _ => Err(self.error(line_no, field, "列表项应以 \"- \" 开头")),
+        }
+    }
+
+    fn code_block(&mut self, line_no: usize, info: &str, code: String) -> Result<(), KnowledgeError> {
+        let field = self.field();
+        if self.section != Section::Example {
+            return Err(self.error(line_no, field, "代码块只能出现在 ## 示例：标题 小节中"));
+        }
+        let mut attributes = info.split(',').map(str::trim);
+        let language = attributes.next().unwrap_or("");
+        let attributes: Vec<&str> = attributes.collect();
+        let expect = match (language, attributes.as_slice()) {
+            ("rust", []) | ("text", []) => None,
+            ("rust", ["compile_fail"]) => Some(Expectation::CompileFail { code: None }),
+            ("rust", ["compile_fail", code]) if is_error_code(code) => Some(Expectation::CompileFail {
+                code: Some(code.to_string()),
+            }),
+            ("rust", _) | ("text", _) => {
+                return Err(self.error(
+                    line_no,
+                    field,
+                    format!("无法识别的代码块属性 {:?}，可用 rust,compile_fail 或 rust,compile_fail,E0382", info),
+                ))
+            }
+            (other, _) => {
+                return Err(self.error(
+                    line_no,
+                    field,
+                    format!("不支持的代码块类型 {:?}，可用 rust 或 text", other),
+                ))
+            }
+        };
+
+        let example = self
+            .points
+            .last()
+            .and_then(|point| point.code_examples.last())
+            .expect("示例小节总是先创建示例");
+        let problem = match language {
+            "rust" if !example.code.is_empty() => Some("每个示例只能有一个 rust 代码块"),
+            "text" if example.code.is_empty() => Some("预期输出必须写在 rust 代码块之后"),
+            "text" if example.output.is_some() => Some("每个示例只能有一个 text 输出块"),
+            "text" if example.expect != Expectation::Runs => Some("编译失败的示例不能有预期输出"),
+            _ => None,
+        };
+        if let Some(problem) = problem {
+            return Err(self.error(line_no, field, problem));
+        }
+
+        let example = self
+            .points
+            .last_mut()
+            .and_then(|point| point.code_examples.last_mut())
+            .expect("示例小节总是先创建示例");
+        if language == "rust" {
+            example.code = code;
+            example.expect = expect.unwrap_or_default();
+        } else {
+            example.output = Some(code);
+        }
+        Ok(())
+    }
 }
 
-fn get_memory_management_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+/// 形如 `E0382` 的编译错误码
+fn is_error_code(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit())
 }
 
-fn get_ownership_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+/// 连续的行用换行连接，多个空行合并为一个段落分隔
+fn join_paragraphs(lines: &[&str]) -> String {
+    let mut text = String::new();
+    let mut blank = false;
+    for line in lines {
+        if line.is_empty() {
+            blank = !text.is_empty();
+            continue;
+        }
+        if !text.is_empty() {
+            text.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        text.push_str(line);
+        blank = false;
+    }
+    text
 }
 
-fn get_borrowing_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+/// 解析一个知识点文件，`file` 只用于错误信息
+pub fn parse_pack(file: &str, text: &str) -> Result<KnowledgePack, KnowledgeError> {
+    let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line));
+    let chapter = parse_front_matter(file, &mut lines)?;
+
+    let mut parser = PackParser {
+        file,
+        points: Vec::new(),
+        section: Section::Description,
+        point_line: 0,
+        example_line: 0,
+        text: Vec::new(),
+    };
+
+    while let Some((line_no, raw)) = lines.next() {
+        let line = raw.trim_end();
+        if let Some(info) = line.strip_prefix("```") {
+            let mut code = Vec::new();
+            let closed = lines.by_ref().any(|(_, code_line)| {
+                let end = code_line.trim_end() == "```";
+                if !end {
+                    code.push(code_line.trim_end());
+                }
+                end
+            });
+            if !closed {
+                return Err(parser.error(line_no, parser.field(), "代码块没有结束的 ```"));
+            }
+            parser.code_block(line_no, info.trim(), code.join("\n"))?;
+        } else if let Some(title) = line.strip_prefix("## ") {
+            parser.heading(line_no, 2, title.trim())?;
+        } else if let Some(title) = line.strip_prefix("# ") {
+            parser.heading(line_no, 1, title.trim())?;
+        } else if line.starts_with("- ") {
+            parser.list_item(line_no, line)?;
+        } else {
+            parser.text_line(line_no, line.trim())?;
+        }
+    }
+    parser.finish_section()?;
+
+    if parser.points.is_empty() {
+        return Err(parser.error(0, "正文", "没有任何 # 知识点"));
+    }
+    Ok(KnowledgePack {
+        chapter,
+        points: parser.points,
+    })
 }
 
-fn get_structs_knowledge() -> Vec<KnowledgePoint> {
-    vec![]
+/// 解析 `---` 包围的头部，返回章节编号
+fn parse_front_matter<'a>(
+    file: &str,
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<u8, KnowledgeError> {
+    let error = |line, field: &str, message: String| KnowledgeError {
+        file: file.to_string(),
+        line,
+        field: field.to_string(),
+        message,
+    };
+
+    match lines.find(|(_, line)| !line.trim().is_empty()) {
+        Some((_, line)) if line.trim() == "---" => {}
+        Some((line_no, _)) => return Err(error(line_no, "头部", "文件必须以 --- 开头".to_string())),
+        None => return Err(error(0, "头部", "文件为空".to_string())),
+    }
+
+    let mut chapter = None;
+    for (line_no, line) in lines {
+        let line = line.trim();
+        if line == "---" {
+            return chapter.ok_or_else(|| error(line_no, "chapter", "头部缺少 chapter".to_string()));
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(error(line_no, "头部", format!("应为 键: 值，实际为 {:?}", line)));
+        };
+        let value = value.trim();
+        match key.trim() {
+            "chapter" => {
+                let id = value
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|id| chapters::get(*id).is_some())
+                    .ok_or_else(|| error(line_no, "chapter", format!("无效的章节编号 {:?}", value)))?;
+                chapter = Some(id);
+            }
+            other => return Err(error(line_no, other, "未知的头部字段".to_string())),
+        }
+    }
+    Err(error(0, "头部", "头部没有结束的 ---".to_string()))
 }
 
 /// 显示知识点详情
 pub fn display_knowledge_point(kp: &KnowledgePoint) {
-    println!("📚 {}", kp.title);
-    println!("{}", "═".repeat(50));
-    
-    println!("\n📖 概述：");
-    println!("   {}", kp.description);
-    
-    println!("\n🎯 核心概念：");
-    for concept in &kp.key_concepts {
-        println!("   • {}", concept);
+    let stdout = io::stdout();
+    write_knowledge_point(&mut stdout.lock(), kp).expect("写入标准输出失败");
+}
+
+/// 完整显示一个知识点：概述、核心概念、代码示例、常见错误、最佳实践和相关主题
+pub fn write_knowledge_point(out: &mut impl Write, kp: &KnowledgePoint) -> io::Result<()> {
+    writeln!(out, "📚 {}", kp.title)?;
+    writeln!(out, "{}", "═".repeat(50))?;
+
+    writeln!(out, "\n{}", tr!("knowledge.overview"))?;
+    for line in kp.description.lines() {
+        if line.is_empty() {
+            writeln!(out)?;
+        } else {
+            writeln!(out, "   {}", line)?;
+        }
+    }
+
+    write_list(out, tr!("knowledge.key_concepts"), "•", &kp.key_concepts)?;
+
+    if !kp.code_examples.is_empty() {
+        writeln!(out, "\n{}", tr!("knowledge.examples"))?;
+        for (index, example) in kp.code_examples.iter().enumerate() {
+            write_code_example(out, index + 1, example)?;
+        }
+    }
+
+    write_list(out, tr!("knowledge.mistakes"), "✗", &kp.common_mistakes)?;
+    write_list(out, tr!("knowledge.practices"), "✓", &kp.best_practices)?;
+
+    if !kp.related_topics.is_empty() {
+        writeln!(out, "\n{}", tr!("knowledge.related"))?;
+        for topic in &kp.related_topics {
+            match resolve_topic(topic).and_then(chapters::get) {
+                Some(chapter) => {
+                    let link = tr!(
+                        "knowledge.related.chapter",
+                        topic = topic,
+                        chapter = chapter.display_name(),
+                        id = chapter.id
+                    );
+                    writeln!(out, "   → {}", link)?
+                }
+                None => writeln!(out, "   → {}", topic)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_list(out: &mut impl Write, heading: &str, bullet: &str, items: &[String]) -> io::Result<()> {
+    if items.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "\n{}", heading)?;
+    for item in items {
+        writeln!(out, "   {} {}", bullet, item)?;
+    }
+    Ok(())
+}
+
+/// 带行号的代码清单，之后是预期输出和解释
+pub fn write_code_example(out: &mut impl Write, number: usize, example: &CodeExample) -> io::Result<()> {
+    writeln!(out, "\n  {}", tr!("knowledge.example", number = number, title = example.title))?;
+    write_listing(out, &example.code)?;
+
+    if let Expectation::CompileFail { code } = &example.expect {
+        match code {
+            Some(code) => writeln!(out, "  {}", tr!("knowledge.compile_fail.code", code = code))?,
+            None => writeln!(out, "  {}", tr!("knowledge.compile_fail"))?,
+        }
+    }
+    if let Some(output) = &example.output {
+        writeln!(out, "  {}", tr!("knowledge.expected_output"))?;
+        for line in output.lines() {
+            writeln!(out, "  ▏ {}", line)?;
+        }
+    }
+    for line in example.explanation.lines().filter(|line| !line.is_empty()) {
+        writeln!(out, "  💡 {}", line)?;
+    }
+    Ok(())
+}
+
+/// 带行号的代码清单，支持颜色时高亮
+pub fn write_listing(out: &mut impl Write, code: &str) -> io::Result<()> {
+    let code = highlight::for_terminal(code);
+    let lines: Vec<&str> = code.lines().collect();
+    let width = lines.len().to_string().len();
+    writeln!(out, "  ┌{}", "─".repeat(40))?;
+    for (index, line) in lines.iter().enumerate() {
+        let listing = format!("  │ {:>width$} │ {}", index + 1, line, width = width);
+        writeln!(out, "{}", listing.trim_end())?;
+    }
+    writeln!(out, "  └{}", "─".repeat(40))
+}
+
+/// 显示一章的全部知识点，没有知识点时返回 false
+pub fn write_chapter_knowledge(out: &mut impl Write, chapter_id: u8) -> io::Result<bool> {
+    let Some(points) = base().chapter(chapter_id) else {
+        return Ok(false);
+    };
+    if let Some(chapter) = chapters::get(chapter_id) {
+        let heading = tr!("knowledge.chapter", chapter = chapter.display_name(), count = points.len());
+        writeln!(out, "{}\n", heading)?;
+    }
+    for point in points {
+        write_knowledge_point(out, point)?;
+        writeln!(out)?;
+    }
+    Ok(true)
+}
+
+/// 把相关主题解析为章节编号：先匹配章节标题，再匹配各章的知识点标题，
+/// 最后接受唯一一个包含该主题的章节标题；中文标题和当前语言的标题都可以匹配，比较时忽略大小写
+pub fn resolve_topic(topic: &str) -> Option<u8> {
+    let topic = topic.trim().to_lowercase();
+    let all = chapters::all();
+    let titles = |chapter: &chapters::Chapter| [chapter.title.to_lowercase(), chapter.localized_title().to_lowercase()];
+    if let Some(chapter) = all.iter().find(|chapter| titles(chapter).contains(&topic)) {
+        return Some(chapter.id);
+    }
+    let base = base();
+    if let Some(id) = base.chapters().find(|id| {
+        base.chapter(*id)
+            .is_some_and(|points| points.iter().any(|point| point.title.to_lowercase() == topic))
+    }) {
+        return Some(id);
+    }
+    let mut partial = all
+        .iter()
+        .filter(|chapter| titles(chapter).iter().any(|title| title.contains(&topic)));
+    match (partial.next(), partial.next()) {
+        (Some(chapter), None) => Some(chapter.id),
+        _ => None,
     }
 }
 
 /// 获取通用的编程技巧
 pub fn get_programming_tips() -> Vec<String> {
-    vec![
-        "🎯 每次只学习一个概念，确保理解后再继续".to_string(),
-        "💻 多写代码，通过实践加深理解".to_string(),
-        "🔍 使用 Rust 编译器的错误信息学习".to_string(),
-        "📚 阅读标准库文档了解最佳实践".to_string(),
-    ]
+    tr!("knowledge.tips").lines().map(str::to_string).collect()
 } 
\ No newline at end of file
@@ -0,0 +1,454 @@
+// 按显示宽度排版
+// 汉字、全角标点和大多数表情符号在终端中占两列，用 `{:<width$}` 按字符数补空格会让方框右边框错位。
+// 这里按终端的显示列数计算宽度，用于补齐、截断（末尾加省略号）、按宽度折行，以及菜单方框和多列表格。
+// 默认按字符组合规则近似划分字素（表情符号序列、组合附加符号），启用 `grapheme` 特性时使用 unicode-segmentation 划分。
+
+use std::borrow::Cow;
+
+use crate::term::{self, Terminal, Theme};
+
+/// 菜单方框的默认宽度（含边框）
+pub const MENU_WIDTH: usize = 54;
+
+/// 对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// 不占列宽的字符：控制字符、组合附加符号、零宽字符、变体选择符和肤色修饰
+fn is_zero_width(c: char) -> bool {
+    matches!(c as u32,
+        0x00..=0x1F | 0x7F..=0x9F
+        | 0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F
+        | 0x200B..=0x200F | 0xFE00..=0xFE0F | 0x1F3FB..=0x1F3FF | 0xE0000..=0xE007F)
+}
+
+/// 东亚宽字符、全角字符和默认以表情样式显示的符号
+fn is_wide(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F | 0x2E80..=0x303E | 0x3041..=0x33FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE30..=0xFE4F | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 | 0x20000..=0x3FFFD
+        | 0x231A..=0x231B | 0x23E9..=0x23EC | 0x23F0 | 0x23F3 | 0x25FD..=0x25FE | 0x2614..=0x2615
+        | 0x2648..=0x2653 | 0x267F | 0x2693 | 0x26A1 | 0x26AA..=0x26AB | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5 | 0x26CE | 0x26D4 | 0x26EA | 0x26F2..=0x26F3 | 0x26F5 | 0x26FA | 0x26FD
+        | 0x2705 | 0x270A..=0x270B | 0x2728 | 0x274C | 0x274E | 0x2753..=0x2755 | 0x2757
+        | 0x2795..=0x2797 | 0x27B0 | 0x27BF | 0x2B1B..=0x2B1C | 0x2B50 | 0x2B55
+        | 0x1F004 | 0x1F0CF | 0x1F18E | 0x1F191..=0x1F19A | 0x1F1E6..=0x1F1FF | 0x1F200..=0x1F2FF
+        | 0x1F300..=0x1F64F | 0x1F680..=0x1F6FF | 0x1F900..=0x1F9FF | 0x1FA70..=0x1FAFF)
+}
+
+/// 一个字素占的列数：取其中最宽的字符，带 U+FE0F 的符号按表情样式占两列
+fn cluster_width(cluster: &str) -> usize {
+    if cluster.starts_with('\x1b') {
+        return 0;
+    }
+    let widest = cluster
+        .chars()
+        .map(|c| if is_zero_width(c) { 0 } else if is_wide(c) { 2 } else { 1 })
+        .max()
+        .unwrap_or(0);
+    if widest == 1 && cluster.contains('\u{FE0F}') {
+        2
+    } else {
+        widest
+    }
+}
+
+/// ANSI 转义序列（`ESC [ … 结束字母`）的字节长度
+fn escape_len(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix("\x1b[")?;
+    let end = rest.find(|c: char| ('@'..='~').contains(&c))?;
+    Some(2 + end + 1)
+}
+
+/// 把文字分成字素，转义序列单独成段
+fn clusters(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let plain_end = rest.find('\x1b').unwrap_or(rest.len());
+        if plain_end == 0 {
+            let len = escape_len(rest).unwrap_or(1);
+            clusters.push(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        split_graphemes(&rest[..plain_end], &mut clusters);
+        rest = &rest[plain_end..];
+    }
+    clusters
+}
+
+#[cfg(feature = "grapheme")]
+fn split_graphemes<'a>(text: &'a str, clusters: &mut Vec<&'a str>) {
+    use unicode_segmentation::UnicodeSegmentation;
+    clusters.extend(text.graphemes(true));
+}
+
+/// 近似的字素划分：零宽字符、零宽连接符之后的字符和成对的国旗字母并入前一个字素
+#[cfg(not(feature = "grapheme"))]
+fn split_graphemes<'a>(text: &'a str, clusters: &mut Vec<&'a str>) {
+    let is_flag_letter = |c: char| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c);
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+    let mut flag_letters = 0;
+    for (index, c) in text.char_indices() {
+        let joins = match previous {
+            None => false,
+            Some('\u{200D}') => true,
+            Some(_) if is_flag_letter(c) => flag_letters % 2 == 1,
+            Some(_) => is_zero_width(c) && !c.is_control(),
+        };
+        if !joins && index > 0 {
+            clusters.push(&text[start..index]);
+            start = index;
+            flag_letters = 0;
+        }
+        if is_flag_letter(c) {
+            flag_letters += 1;
+        }
+        previous = Some(c);
+    }
+    if start < text.len() {
+        clusters.push(&text[start..]);
+    }
+}
+
+/// 文字在终端中占的列数，忽略 ANSI 转义序列
+pub fn width(text: &str) -> usize {
+    clusters(text).into_iter().map(cluster_width).sum()
+}
+
+/// 超过 `max` 列时截断并以 `…` 结尾
+pub fn truncate(text: &str, max: usize) -> Cow<'_, str> {
+    truncate_with(text, max, "…")
+}
+
+fn truncate_with<'a>(text: &'a str, max: usize, ellipsis: &str) -> Cow<'a, str> {
+    if width(text) <= max {
+        return Cow::Borrowed(text);
+    }
+    let room = max.saturating_sub(width(ellipsis));
+    let mut used = 0;
+    let mut truncated = String::new();
+    for cluster in clusters(text) {
+        let cluster_width = cluster_width(cluster);
+        if used + cluster_width > room {
+            break;
+        }
+        used += cluster_width;
+        truncated.push_str(cluster);
+    }
+    if width(ellipsis) <= max {
+        truncated.push_str(ellipsis);
+    }
+    Cow::Owned(truncated)
+}
+
+/// 补空格到 `width` 列，已经更宽的文字不截断
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let gap = width.saturating_sub(self::width(text));
+    let (left, right) = match align {
+        Align::Left => (0, gap),
+        Align::Right => (gap, 0),
+        Align::Center => (gap / 2, gap - gap / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// 行首不能出现的全角标点，折行时跟着前一个字
+fn is_closing_punctuation(cluster: &str) -> bool {
+    matches!(cluster, "，" | "。" | "、" | "；" | "：" | "！" | "？" | "）" | "》" | "」" | "』" | "】")
+}
+
+/// 折行的单位：连续的空格、连续的窄字符（英文单词、数字），或者一个宽字符及其后的全角标点
+fn words(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut last_kind = None;
+    for cluster in clusters(text) {
+        let kind = match cluster {
+            " " => 0,
+            _ if cluster_width(cluster) == 2 && !is_closing_punctuation(cluster) => 2,
+            _ if is_closing_punctuation(cluster) => 3,
+            _ => 1,
+        };
+        let joins = match (last_kind, kind) {
+            (Some(last), 0 | 1) => last == kind,
+            (Some(last), 3) => last != 0,
+            (_, _) => false,
+        };
+        match words.last_mut() {
+            Some(word) if joins => word.push_str(cluster),
+            _ => words.push(cluster.to_string()),
+        }
+        last_kind = Some(kind);
+    }
+    words
+}
+
+/// 按 `max` 列折行：在空格处和汉字之间断开，单个过长的单词强行断开；保留原有的换行
+pub fn wrap(text: &str, max: usize) -> Vec<String> {
+    let max = max.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut used = 0;
+        for word in words(paragraph) {
+            let word_width = width(&word);
+            let space = word.starts_with(' ');
+            if used + word_width > max && used > 0 {
+                lines.push(line.trim_end().to_string());
+                line.clear();
+                used = 0;
+                // 断开处的空格不带到下一行
+                if space {
+                    continue;
+                }
+            }
+            if word_width <= max {
+                line.push_str(&word);
+                used += word_width;
+                continue;
+            }
+            if space {
+                continue;
+            }
+            for cluster in clusters(&word) {
+                let cluster_width = cluster_width(cluster);
+                if used + cluster_width > max && used > 0 {
+                    lines.push(std::mem::take(&mut line));
+                    used = 0;
+                }
+                line.push_str(cluster);
+                used += cluster_width;
+            }
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
+
+/// 把若干项依次排成行，每行不超过 `max` 列，项之间空 `gap` 列
+pub fn flow(items: &[String], max: usize, gap: usize) -> Vec<String> {
+    let mut rows: Vec<String> = Vec::new();
+    let mut used = 0;
+    for item in items {
+        let item_width = width(item);
+        match rows.last_mut() {
+            Some(row) if used + gap + item_width <= max => {
+                row.push_str(&" ".repeat(gap));
+                row.push_str(item);
+                used += gap + item_width;
+            }
+            _ => {
+                rows.push(item.to_string());
+                used = item_width;
+            }
+        }
+    }
+    rows
+}
+
+/// 按输出主题转换符号，排版前先转换才能算准宽度（ASCII 主题中 `🦀` 变成 `*`，`…` 变成 `...`）
+fn themed(text: &str, theme: Theme) -> Cow<'_, str> {
+    term::render(
+        text,
+        Terminal {
+            theme,
+            ..Terminal::default()
+        },
+    )
+}
+
+fn ellipsis(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Unicode => "…",
+        Theme::Ascii => "...",
+    }
+}
+
+/// 带边框的方框，例如交互式菜单；每个方法返回一行或多行完整的文字
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Panel {
+    width: usize,
+    theme: Theme,
+}
+
+impl Panel {
+    /// `width` 是包括边框在内的总宽度，按当前输出主题排版
+    pub fn new(width: usize) -> Self {
+        Panel::with_theme(width, term::terminal().theme)
+    }
+
+    pub fn with_theme(width: usize, theme: Theme) -> Self {
+        Panel { width: width.max(6), theme }
+    }
+
+    /// 边框和两侧各一个空格之内可以写字的列数
+    pub fn inner(&self) -> usize {
+        self.width - 4
+    }
+
+    fn rule(&self, left: char, right: char) -> String {
+        format!("{}{}{}", left, "─".repeat(self.width - 2), right)
+    }
+
+    pub fn top(&self) -> String {
+        self.rule('┌', '┐')
+    }
+
+    pub fn divider(&self) -> String {
+        self.rule('├', '┤')
+    }
+
+    pub fn bottom(&self) -> String {
+        self.rule('└', '┘')
+    }
+
+    /// 一行文字，过长时截断
+    pub fn line(&self, text: &str) -> String {
+        self.aligned(text, Align::Left)
+    }
+
+    /// 居中的一行文字，例如标题
+    pub fn centered(&self, text: &str) -> String {
+        self.aligned(text, Align::Center)
+    }
+
+    fn aligned(&self, text: &str, align: Align) -> String {
+        let text = themed(text, self.theme);
+        let text = truncate_with(&text, self.inner(), ellipsis(self.theme));
+        format!("│ {} │", pad(&text, self.inner(), align))
+    }
+
+    /// 折成多行的文字，每行缩进 `indent` 列
+    pub fn paragraph(&self, text: &str, indent: usize) -> Vec<String> {
+        let indent = indent.min(self.inner() - 1);
+        wrap(&themed(text, self.theme), self.inner() - indent)
+            .iter()
+            .map(|line| self.line(&format!("{}{}", " ".repeat(indent), line)))
+            .collect()
+    }
+
+    /// 依次排列的若干项，放不下时换行，每行缩进 `indent` 列
+    pub fn items(&self, items: &[String], indent: usize) -> Vec<String> {
+        let indent = indent.min(self.inner() - 1);
+        let items: Vec<String> = items.iter().map(|item| themed(item, self.theme).into_owned()).collect();
+        flow(&items, self.inner() - indent, 2)
+            .iter()
+            .map(|row| self.line(&format!("{}{}", " ".repeat(indent), row)))
+            .collect()
+    }
+}
+
+/// 表格中的一行
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Row {
+    Cells(Vec<String>),
+    /// 分组标题等独占一行的文字，不参与列宽计算
+    Heading(String),
+}
+
+/// 按显示宽度对齐的多列表格，没有边框。
+/// 各列宽度取所有行中最宽的单元格（分组标题行除外），最后一列不补空格；
+/// 指定了最大宽度时最后一列超出部分折行，续行与该列对齐
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    aligns: Vec<Align>,
+    rows: Vec<Row>,
+    indent: usize,
+    max_width: Option<usize>,
+    theme: Theme,
+}
+
+impl Table {
+    /// 每列一个对齐方式，按当前输出主题排版
+    pub fn new(aligns: &[Align]) -> Self {
+        Table {
+            aligns: aligns.to_vec(),
+            rows: Vec::new(),
+            indent: 0,
+            max_width: None,
+            theme: term::terminal().theme,
+        }
+    }
+
+    /// 每行开头的缩进
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// 添加一行，单元格少于列数时补空
+    pub fn row<S: AsRef<str>>(&mut self, cells: &[S]) {
+        let mut cells: Vec<String> = cells.iter().map(|cell| themed(cell.as_ref(), self.theme).into_owned()).collect();
+        cells.resize(self.aligns.len().max(cells.len()), String::new());
+        self.rows.push(Row::Cells(cells));
+    }
+
+    /// 添加独占一行、不缩进的标题
+    pub fn heading(&mut self, text: &str) {
+        self.rows.push(Row::Heading(themed(text, self.theme).into_owned()));
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = vec![0; self.aligns.len()];
+        for row in &self.rows {
+            if let Row::Cells(cells) = row {
+                widths.resize(widths.len().max(cells.len()), 0);
+                for (column, cell) in cells.iter().enumerate() {
+                    widths[column] = widths[column].max(width(cell));
+                }
+            }
+        }
+        widths
+    }
+
+    /// 排好的各行文字
+    pub fn lines(&self) -> Vec<String> {
+        let widths = self.column_widths();
+        let mut lines = Vec::new();
+        for row in &self.rows {
+            let cells = match row {
+                Row::Heading(text) => {
+                    lines.push(text.clone());
+                    continue;
+                }
+                Row::Cells(cells) => cells,
+            };
+            let Some((last, leading)) = cells.split_last() else {
+                continue;
+            };
+            let mut line = " ".repeat(self.indent);
+            for (column, cell) in leading.iter().enumerate() {
+                let align = self.aligns.get(column).copied().unwrap_or_default();
+                line.push_str(&pad(cell, widths[column], align));
+                line.push(' ');
+            }
+            let start = width(&line);
+            let room = self.max_width.map_or(usize::MAX, |max| max.saturating_sub(start).max(10));
+            let mut wrapped = if width(last) <= room { vec![last.clone()] } else { wrap(last, room) }.into_iter();
+            line.push_str(&wrapped.next().unwrap_or_default());
+            lines.push(line.trim_end().to_string());
+            for more in wrapped {
+                lines.push(format!("{}{}", " ".repeat(start), more));
+            }
+        }
+        lines
+    }
+}
@@ -0,0 +1,281 @@
+// Rust 源码词法分析
+// 只用标准库把 Rust 代码切成带字节范围的记号，供语法高亮和查找函数体使用。
+// 记号首尾相接、覆盖整个输入，拼起来就是原文；写了一半的代码（没闭合的字符串、注释）也不会出错，
+// 只是把对应记号标为未结束。不做语法分析，`a.0.1` 这样的写法按数字 `0.1` 切分。
+
+use std::ops::Range;
+
+/// 严格关键字和 2018 版本起保留的关键字
+pub const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in",
+    "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while",
+];
+
+/// 记号的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// 空格、制表符和换行
+    Whitespace,
+    /// `//` 到行尾，包括文档注释 `///` 和 `//!`
+    LineComment,
+    /// `/* */`，可以嵌套
+    BlockComment { terminated: bool },
+    Ident,
+    Keyword,
+    /// `'a`、`'static`
+    Lifetime,
+    /// 字符 `'a'`、`'\n'` 和字节 `b'a'`
+    Char { terminated: bool },
+    /// 字符串 `"…"`、字节串 `b"…"` 和 C 字符串 `c"…"`
+    Str { terminated: bool },
+    /// 原始字符串 `r"…"`、`r#"…"#`、`br"…"` 和 `cr"…"`
+    RawStr { terminated: bool },
+    /// 整数和浮点数，`suffix` 是末尾类型后缀（如 `u8`、`f64`）的字节数，没有后缀时为 0
+    Number { suffix: usize },
+    /// 单个标点符号，`::`、`->` 这样的组合按字符分开
+    Punct,
+}
+
+/// 一个记号和它在源码中的字节范围
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Range<usize>,
+}
+
+impl Token {
+    /// 记号在源码中的文字
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.span.clone()]
+    }
+
+    /// 空白和注释，查找代码结构时跳过
+    pub fn is_trivia(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment { .. })
+    }
+}
+
+/// 把源码切成记号
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let mut lexer = Lexer { source, position: 0 };
+    let mut tokens = Vec::new();
+    while lexer.position < source.len() {
+        let start = lexer.position;
+        let kind = lexer.next_kind();
+        tokens.push(Token { kind, span: start..lexer.position });
+    }
+    tokens
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    /// 下一个字符的字节位置，总在字符边界上
+    position: usize,
+}
+
+impl Lexer<'_> {
+    /// 向后第 `n` 个字符，不移动位置
+    fn peek(&self, n: usize) -> Option<char> {
+        self.source[self.position..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn bump_while(&mut self, accept: impl Fn(char) -> bool) {
+        while self.peek(0).is_some_and(&accept) {
+            self.bump();
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.position..]
+    }
+
+    fn next_kind(&mut self) -> TokenKind {
+        let c = self.peek(0).expect("调用前已确认还有字符");
+        match c {
+            c if c.is_whitespace() => {
+                self.bump_while(char::is_whitespace);
+                TokenKind::Whitespace
+            }
+            '/' if self.peek(1) == Some('/') => {
+                self.bump_while(|c| c != '\n');
+                TokenKind::LineComment
+            }
+            '/' if self.peek(1) == Some('*') => self.block_comment(),
+            '\'' => {
+                self.bump();
+                self.char_or_lifetime()
+            }
+            '"' => {
+                self.bump();
+                TokenKind::Str { terminated: self.quoted('"') }
+            }
+            c if c.is_ascii_digit() => self.number(),
+            c if is_ident_start(c) => self.word(),
+            _ => {
+                self.bump();
+                TokenKind::Punct
+            }
+        }
+    }
+
+    /// 块注释，按层数匹配嵌套的 `/*` 和 `*/`
+    fn block_comment(&mut self) -> TokenKind {
+        self.position += 2;
+        let mut depth = 1;
+        while depth > 0 {
+            if self.rest().starts_with("/*") {
+                self.position += 2;
+                depth += 1;
+            } else if self.rest().starts_with("*/") {
+                self.position += 2;
+                depth -= 1;
+            } else if self.bump().is_none() {
+                return TokenKind::BlockComment { terminated: false };
+            }
+        }
+        TokenKind::BlockComment { terminated: true }
+    }
+
+    /// 读到没有被反斜杠转义的 `quote` 为止，返回是否遇到了它
+    fn quoted(&mut self, quote: char) -> bool {
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                c if c == quote => return true,
+                // 字符字面量不跨行，换行说明这个单引号不是字符的开头
+                '\n' if quote == '\'' => {
+                    self.position -= 1;
+                    return false;
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// 已经读过开头的 `'`：`'a'` 是字符，`'a` 是生命周期
+    fn char_or_lifetime(&mut self) -> TokenKind {
+        match (self.peek(0), self.peek(1)) {
+            (Some('\\'), _) => TokenKind::Char { terminated: self.quoted('\'') },
+            (Some(c), Some('\'')) if c != '\n' => {
+                self.bump();
+                self.bump();
+                TokenKind::Char { terminated: true }
+            }
+            (Some(c), _) if is_ident_start(c) => {
+                // 原始生命周期 'r#ident
+                if self.rest().starts_with("r#") && self.peek(2).is_some_and(is_ident_start) {
+                    self.position += 2;
+                }
+                self.bump_while(is_ident_continue);
+                TokenKind::Lifetime
+            }
+            _ => TokenKind::Char { terminated: self.quoted('\'') },
+        }
+    }
+
+    /// 标识符、关键字，以及以字母开头的字面量前缀 `b'`、`b"`、`c"`、`r"`、`br"`、`cr"` 和原始标识符 `r#ident`
+    fn word(&mut self) -> TokenKind {
+        let rest = self.rest();
+        let prefix = ["br", "cr", "r", "b", "c"].into_iter().find(|prefix| rest.starts_with(prefix)).unwrap_or("");
+        let after = &rest[prefix.len()..];
+        if prefix.ends_with('r') {
+            let hashes = after.len() - after.trim_start_matches('#').len();
+            if after[hashes..].starts_with('"') {
+                self.position += prefix.len() + hashes + 1;
+                return self.raw_string(hashes);
+            }
+            if prefix == "r" && hashes == 1 && after[1..].starts_with(is_ident_start) {
+                self.position += 2;
+                self.bump_while(is_ident_continue);
+                return TokenKind::Ident;
+            }
+        }
+        match (prefix, after.chars().next()) {
+            ("b" | "c", Some('"')) => {
+                self.position += 2;
+                return TokenKind::Str { terminated: self.quoted('"') };
+            }
+            ("b", Some('\'')) => {
+                self.position += 2;
+                return TokenKind::Char { terminated: self.quoted('\'') };
+            }
+            _ => {}
+        }
+        let start = self.position;
+        self.bump_while(is_ident_continue);
+        if KEYWORDS.contains(&&self.source[start..self.position]) {
+            TokenKind::Keyword
+        } else {
+            TokenKind::Ident
+        }
+    }
+
+    /// 已经读过开头的 `r#…#"`，读到 `"` 加同样多的 `#`
+    fn raw_string(&mut self, hashes: usize) -> TokenKind {
+        let closing = format!("\"{}", "#".repeat(hashes));
+        match self.rest().find(&closing) {
+            Some(end) => {
+                self.position += end + closing.len();
+                TokenKind::RawStr { terminated: true }
+            }
+            None => {
+                self.position = self.source.len();
+                TokenKind::RawStr { terminated: false }
+            }
+        }
+    }
+
+    /// 数字：`0x`/`0o`/`0b` 前缀、`_` 分隔、小数和指数部分，以及类型后缀
+    fn number(&mut self) -> TokenKind {
+        let radix = match (self.peek(0), self.peek(1)) {
+            (Some('0'), Some('x')) => 16,
+            (Some('0'), Some('o')) => 8,
+            (Some('0'), Some('b')) => 2,
+            _ => 10,
+        };
+        if radix == 10 {
+            self.bump_while(|c| c.is_ascii_digit() || c == '_');
+            // `1.5` 和 `1.` 是浮点数，`1..2` 是范围，`1.max(2)` 是方法调用
+            if self.peek(0) == Some('.') && !self.peek(1).is_some_and(|c| c == '.' || is_ident_start(c)) {
+                self.bump();
+                self.bump_while(|c| c.is_ascii_digit() || c == '_');
+            }
+            let exponent = match (self.peek(1), self.peek(2)) {
+                (Some('+' | '-'), Some(c)) => c.is_ascii_digit(),
+                (Some(c), _) => c.is_ascii_digit(),
+                _ => false,
+            };
+            if matches!(self.peek(0), Some('e' | 'E')) && exponent {
+                self.bump();
+                if matches!(self.peek(0), Some('+' | '-')) {
+                    self.bump();
+                }
+                self.bump_while(|c| c.is_ascii_digit() || c == '_');
+            }
+        } else {
+            self.position += 2;
+            self.bump_while(|c| c.is_digit(radix) || c == '_');
+        }
+        let suffix_start = self.position;
+        self.bump_while(is_ident_continue);
+        TokenKind::Number { suffix: self.position - suffix_start }
+    }
+}
@@ -3,6 +3,29 @@ pub fn sum(a: i32, b: i32) -> i32 {
     a + b
 }
 
+pub mod chapters;
+pub mod cli;
 // 公开 examples 模块，供 bin 文件使用
 pub mod examples;
+pub mod exercises;
+pub mod explain;
+pub mod flashcards;
+pub mod grader;
+pub mod graph;
+pub mod highlight;
+pub mod i18n;
+pub mod json;
 pub mod knowledge;
+pub mod layout;
+pub mod lexer;
+pub mod pager;
+pub mod playground;
+pub mod progress;
+pub mod quiz;
+pub mod search;
+pub mod sections;
+pub mod session;
+pub mod source;
+pub mod term;
+pub mod verify;
+pub mod watch;
@@ -0,0 +1,312 @@
+// 分页查看
+// 章节运行的输出有上百行，一次打印完会滚出屏幕。交互式菜单在终端中运行时先把输出收集起来，
+// 超过一屏再按页显示：回车翻到下一页，`/关键词` 搜索，`s` 按小节跳转。
+// 只用标准库，终端保持行输入模式，每条命令以回车结束；输出不是终端时不分页，直接写出全部内容。
+
+use std::io::{self, BufRead, Write};
+
+use crate::layout;
+use crate::term;
+use crate::tr;
+
+/// 小节标题：示例程序打印的 `📍 N.` 标记，或者以表情符号开头、冒号结尾的标题行（例如 `🧵 线程基础：`）
+pub fn is_section_header(line: &str) -> bool {
+    let trimmed = line.trim();
+    if trimmed.starts_with('📍') {
+        return true;
+    }
+    let starts_with_emoji = trimmed.chars().next().is_some_and(|c| !c.is_ascii() && layout::width(&c.to_string()) == 2 && !c.is_alphabetic());
+    !line.starts_with(' ') && starts_with_emoji && (trimmed.ends_with('：') || trimmed.ends_with(':'))
+}
+
+/// 分页时的一条命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PagerCommand {
+    NextPage,
+    PreviousPage,
+    /// 向下滚动若干行
+    Down(usize),
+    Up(usize),
+    Top,
+    Bottom,
+    Search(String),
+    NextMatch,
+    PreviousMatch,
+    NextSection,
+    PreviousSection,
+    /// 跳到第 N 个小节，从 1 开始
+    Section(usize),
+    /// 列出所有小节
+    Sections,
+    Help,
+    Quit,
+    Unknown(String),
+}
+
+/// 解析一行输入，大小写有区别（`n` 下一个匹配，`N` 上一个匹配）
+pub fn parse_command(line: &str) -> PagerCommand {
+    let line = line.trim();
+    if let Some(query) = line.strip_prefix('/') {
+        return PagerCommand::Search(query.trim().to_string());
+    }
+    let count = |digits: &str| if digits.is_empty() { Some(1) } else { digits.parse().ok() };
+    match line {
+        "" | "f" => PagerCommand::NextPage,
+        "b" => PagerCommand::PreviousPage,
+        "j" => PagerCommand::Down(1),
+        "k" => PagerCommand::Up(1),
+        "g" => PagerCommand::Top,
+        "G" => PagerCommand::Bottom,
+        "n" => PagerCommand::NextMatch,
+        "N" | "p" => PagerCommand::PreviousMatch,
+        "]" => PagerCommand::NextSection,
+        "[" => PagerCommand::PreviousSection,
+        "s" => PagerCommand::Sections,
+        "h" | "?" => PagerCommand::Help,
+        "q" | "Q" => PagerCommand::Quit,
+        _ => {
+            let parsed = if let Some(digits) = line.strip_prefix('+') {
+                count(digits).map(PagerCommand::Down)
+            } else if let Some(digits) = line.strip_prefix('-') {
+                count(digits).map(PagerCommand::Up)
+            } else if let Some(number) = line.strip_prefix('s') {
+                number.trim().parse().ok().map(PagerCommand::Section)
+            } else {
+                None
+            };
+            parsed.unwrap_or_else(|| PagerCommand::Unknown(line.to_string()))
+        }
+    }
+}
+
+/// 执行一条命令之后要做的事
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// 重新显示当前页
+    Page,
+    /// 在当前页下面显示一段文字（帮助、小节列表），不翻页
+    Text(String),
+    Quit,
+}
+
+/// 按页查看的一段文字
+#[derive(Debug, Clone)]
+pub struct Pager {
+    lines: Vec<String>,
+    sections: Vec<usize>,
+    height: usize,
+    top: usize,
+    query: Option<String>,
+    /// 上次搜索命中的行
+    found: Option<usize>,
+    /// 显示在状态行中的提示，例如没有找到
+    message: Option<String>,
+}
+
+impl Pager {
+    /// `height` 是每页显示的行数
+    pub fn new(text: &str, height: usize) -> Self {
+        let lines: Vec<String> = text.lines().map(str::to_string).collect();
+        Pager {
+            sections: (0..lines.len()).filter(|&index| is_section_header(&lines[index])).collect(),
+            lines,
+            height: height.max(1),
+            top: 0,
+            query: None,
+            found: None,
+            message: None,
+        }
+    }
+
+    /// 按终端宽度折行，一行输出在屏幕上占一行
+    pub fn wrap_to(self, width: usize) -> Self {
+        let text: Vec<String> = self.lines.iter().flat_map(|line| layout::wrap(line, width)).collect();
+        Pager::new(&text.join("\n"), self.height)
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// 小节标题所在的行
+    pub fn sections(&self) -> &[usize] {
+        &self.sections
+    }
+
+    /// 当前页第一行
+    pub fn top(&self) -> usize {
+        self.top
+    }
+
+    /// 内容不超过一屏时不需要分页
+    pub fn fits(&self) -> bool {
+        self.lines.len() <= self.height
+    }
+
+    pub fn visible(&self) -> &[String] {
+        let end = (self.top + self.height).min(self.lines.len());
+        &self.lines[self.top..end]
+    }
+
+    fn max_top(&self) -> usize {
+        self.lines.len().saturating_sub(self.height)
+    }
+
+    fn scroll_to(&mut self, top: usize) {
+        self.top = top.min(self.max_top());
+    }
+
+    /// 从 `start` 开始按方向查找包含关键词的行，不区分大小写
+    fn find(&self, query: &str, start: usize, forward: bool) -> Option<usize> {
+        let query = query.to_lowercase();
+        let matches = |index: &usize| self.lines[*index].to_lowercase().contains(&query);
+        if forward {
+            (start..self.lines.len()).find(matches)
+        } else {
+            (0..=start.min(self.lines.len().saturating_sub(1))).rev().find(matches)
+        }
+    }
+
+    fn jump_to_match(&mut self, forward: bool) {
+        let Some(query) = self.query.clone() else {
+            self.message = Some(tr!("pager.no_query").to_string());
+            return;
+        };
+        let start = match (self.found, forward) {
+            (Some(found), true) => found + 1,
+            (None, true) => self.top,
+            (Some(found), false) if found > 0 => found - 1,
+            (Some(_), false) => {
+                self.message = Some(tr!("pager.not_found", query = query));
+                return;
+            }
+            (None, false) => self.top,
+        };
+        match self.find(&query, start, forward) {
+            Some(line) => {
+                self.found = Some(line);
+                self.scroll_to(line);
+            }
+            None => self.message = Some(tr!("pager.not_found", query = query)),
+        }
+    }
+
+    fn jump_to_section(&mut self, forward: bool) {
+        let next = if forward {
+            self.sections.iter().find(|&&line| line > self.top)
+        } else {
+            self.sections.iter().rev().find(|&&line| line < self.top)
+        };
+        match next {
+            Some(&line) => self.scroll_to(line),
+            None if self.sections.is_empty() => self.message = Some(tr!("pager.no_sections").to_string()),
+            None => self.message = Some(tr!("pager.no_more_sections").to_string()),
+        }
+    }
+
+    /// 执行一条命令；在最后一页继续向下翻时结束
+    pub fn apply(&mut self, command: PagerCommand) -> Step {
+        self.message = None;
+        match command {
+            PagerCommand::NextPage if self.top >= self.max_top() => return Step::Quit,
+            PagerCommand::NextPage => self.scroll_to(self.top + self.height),
+            PagerCommand::PreviousPage => self.scroll_to(self.top.saturating_sub(self.height)),
+            PagerCommand::Down(count) => self.scroll_to(self.top + count),
+            PagerCommand::Up(count) => self.scroll_to(self.top.saturating_sub(count)),
+            PagerCommand::Top => self.scroll_to(0),
+            PagerCommand::Bottom => self.scroll_to(self.max_top()),
+            PagerCommand::Search(query) if query.is_empty() => self.jump_to_match(true),
+            PagerCommand::Search(query) => {
+                self.query = Some(query);
+                self.found = None;
+                self.jump_to_match(true);
+            }
+            PagerCommand::NextMatch => self.jump_to_match(true),
+            PagerCommand::PreviousMatch => self.jump_to_match(false),
+            PagerCommand::NextSection => self.jump_to_section(true),
+            PagerCommand::PreviousSection => self.jump_to_section(false),
+            PagerCommand::Section(number) => match number.checked_sub(1).and_then(|index| self.sections.get(index)) {
+                Some(&line) => self.scroll_to(line),
+                None if self.sections.is_empty() => self.message = Some(tr!("pager.no_sections").to_string()),
+                None => self.message = Some(tr!("pager.section_usage", count = self.sections.len())),
+            },
+            PagerCommand::Sections if self.sections.is_empty() => self.message = Some(tr!("pager.no_sections").to_string()),
+            PagerCommand::Sections => {
+                let mut text = tr!("pager.sections").to_string();
+                for (number, &line) in self.sections.iter().enumerate() {
+                    text.push_str(&format!("\n  s{:<3} {}", number + 1, self.lines[line].trim()));
+                }
+                return Step::Text(text);
+            }
+            PagerCommand::Help => return Step::Text(tr!("pager.help").to_string()),
+            PagerCommand::Quit => return Step::Quit,
+            PagerCommand::Unknown(command) => self.message = Some(tr!("pager.unknown_command", command = command)),
+        }
+        Step::Page
+    }
+
+    /// 写出当前页和状态行；搜索命中的关键词在支持颜色的终端中反色显示
+    pub fn write_page(&self, out: &mut impl Write) -> io::Result<()> {
+        let highlight = term::terminal().color;
+        for line in self.visible() {
+            match &self.query {
+                Some(query) if highlight && !query.is_empty() => writeln!(out, "{}", highlight_matches(line, query))?,
+                _ => writeln!(out, "{}", line)?,
+            }
+        }
+        let last = (self.top + self.height).min(self.lines.len());
+        let percent = if self.lines.is_empty() { 100 } else { last * 100 / self.lines.len() };
+        let status = tr!("pager.status", first = self.top + 1, last = last, total = self.lines.len(), percent = percent);
+        match &self.message {
+            Some(message) => write!(out, "{}  {} ", status, message),
+            None => write!(out, "{} ", status),
+        }
+    }
+}
+
+/// 用反色标出一行中所有的关键词，不区分大小写
+fn highlight_matches(line: &str, query: &str) -> String {
+    let lower = line.to_lowercase();
+    let query = query.to_lowercase();
+    // 大小写转换改变了字节长度时不标记，避免切到字符中间
+    if lower.len() != line.len() {
+        return line.to_string();
+    }
+    let mut highlighted = String::with_capacity(line.len());
+    let mut last = 0;
+    for (start, found) in lower.match_indices(&query) {
+        highlighted.push_str(&line[last..start]);
+        highlighted.push_str(&format!("\x1b[7m{}\x1b[27m", &line[start..start + found.len()]));
+        last = start + found.len();
+    }
+    highlighted.push_str(&line[last..]);
+    highlighted
+}
+
+/// 逐页显示，直到读到 `q`、翻过最后一页或输入结束
+pub fn run(input: &mut impl BufRead, out: &mut impl Write, pager: &mut Pager) -> io::Result<()> {
+    let mut step = Step::Page;
+    loop {
+        match &step {
+            Step::Page => {
+                if term::terminal().ansi {
+                    write!(out, "{}", term::CLEAR)?;
+                }
+                pager.write_page(out)?;
+            }
+            Step::Text(text) => write!(out, "\n{}\n{} ", text, tr!("pager.prompt"))?,
+            Step::Quit => return Ok(()),
+        }
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+        // 看完帮助或小节列表后回车回到当前页，不翻页
+        step = match step {
+            Step::Text(_) if line.trim().is_empty() => Step::Page,
+            _ => pager.apply(parse_command(&line)),
+        };
+    }
+}
@@ -0,0 +1,497 @@
+// 代码试验场
+// 在菜单中逐行输入代码，:run 时用本机的 rustc 编译运行，不需要新建 Cargo 项目：
+// 输入中的结构体、函数等定义编译通过后保留下来，之后的输入可以继续使用；
+// 其余的语句包进 fn main，只运行一次。每行输入都有编号，编译错误和 panic 的位置换算回输入的行号。
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::chapters;
+use crate::explain::{self, Diagnostic};
+use crate::knowledge::{self, CodeExample};
+use crate::lexer::{self, TokenKind};
+use crate::tr;
+use crate::verify::{self, Finished, VerifyOptions};
+
+/// 程序运行的默认时间限制，编译使用 `VerifyOptions::timeout`
+pub const RUN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 编译错误和 panic 信息中代替临时文件名的名称
+const SOURCE_NAME: &str = "playground";
+
+/// 一行输入和它的编号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+/// 以 `:` 开头的试验场命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaygroundCommand {
+    /// 编译运行已输入的代码
+    Run,
+    /// 清空输入和保留的定义
+    Clear,
+    /// 载入章节知识点中的代码示例，参数为空时列出有示例的章节
+    Load(String),
+    /// 显示保留的定义和当前输入
+    Show,
+    Help,
+    Quit,
+    Unknown(String),
+}
+
+/// 解析试验场命令，不以 `:` 开头的行是代码
+pub fn parse_command(line: &str) -> Option<PlaygroundCommand> {
+    let line = line.trim();
+    let rest = line.strip_prefix(':')?;
+    // `::std::mem::swap(...)` 之类的路径是代码
+    if rest.starts_with(':') {
+        return None;
+    }
+    let (name, argument) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    Some(match name.to_lowercase().as_str() {
+        "run" | "r" => PlaygroundCommand::Run,
+        "clear" | "c" => PlaygroundCommand::Clear,
+        "load" | "l" => PlaygroundCommand::Load(argument.trim().to_string()),
+        "show" | "s" => PlaygroundCommand::Show,
+        "help" | "h" => PlaygroundCommand::Help,
+        "quit" | "q" => PlaygroundCommand::Quit,
+        _ => PlaygroundCommand::Unknown(line.to_string()),
+    })
+}
+
+/// 已输入的代码，用 lexer 切分后跟踪括号深度，字符串、字符和注释中的括号不算
+#[derive(Debug, Default)]
+struct Scanner {
+    text: String,
+}
+
+impl Scanner {
+    fn feed(&mut self, line: &str) {
+        self.text.push_str(line);
+        self.text.push('\n');
+    }
+
+    /// 是否回到了顶层：括号都已闭合，也不在字符串或块注释中
+    fn at_top(&self) -> bool {
+        let mut depth = 0usize;
+        for token in lexer::tokenize(&self.text) {
+            match token.kind {
+                TokenKind::Str { terminated: false } | TokenKind::RawStr { terminated: false } | TokenKind::BlockComment { terminated: false } => {
+                    return false;
+                }
+                TokenKind::Punct => match token.text(&self.text) {
+                    "{" | "(" | "[" => depth += 1,
+                    "}" | ")" | "]" => depth = depth.saturating_sub(1),
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        depth == 0
+    }
+
+    /// 最后一个不是空白和注释的记号，用于判断定义是否结束
+    fn last_token(&self) -> Option<&str> {
+        lexer::tokenize(&self.text).into_iter().rev().find(|token| !token.is_trivia()).map(|token| &self.text[token.span])
+    }
+}
+
+/// 这一行是否开始一个定义（结构体、函数、impl 等），而不是语句
+fn starts_item(line: &str) -> bool {
+    let mut words = line.split_whitespace().peekable();
+    while let Some(word) = words.peek() {
+        if word.starts_with("pub") || ["unsafe", "async", "extern", "\"C\"", "default"].contains(word) {
+            words.next();
+        } else {
+            break;
+        }
+    }
+    let Some(word) = words.next() else {
+        return false;
+    };
+    let word = word.split(['<', '(', '{', ':']).next().unwrap_or(word);
+    ["fn", "struct", "enum", "union", "impl", "trait", "use", "const", "static", "mod", "type", "crate"].contains(&word)
+        || word.starts_with("macro_rules!")
+}
+
+/// 把一次输入分成定义和语句；`fn main` 的函数体作为语句
+pub fn split_entry(lines: &[Line]) -> (Vec<Vec<Line>>, Vec<Line>) {
+    let mut items = Vec::new();
+    let mut statements = Vec::new();
+    // 属性、文档注释和空行先放着，跟随后面的定义或语句
+    let mut pending: Vec<Line> = Vec::new();
+    let mut current: Option<(bool, Vec<Line>)> = None;
+    let mut scanner = Scanner::default();
+
+    for line in lines {
+        let trimmed = line.text.trim();
+        if current.is_none() {
+            if trimmed.starts_with("#![") {
+                items.push(vec![line.clone()]);
+                continue;
+            }
+            if trimmed.is_empty() || trimmed.starts_with("#[") || trimmed.starts_with("//") {
+                pending.push(line.clone());
+                scanner.feed(&line.text);
+                continue;
+            }
+            current = Some((starts_item(trimmed), std::mem::take(&mut pending)));
+        }
+        let (is_item, chunk) = current.as_mut().expect("上面已经开始");
+        chunk.push(line.clone());
+        scanner.feed(&line.text);
+        if !scanner.at_top() {
+            continue;
+        }
+        if !*is_item {
+            statements.append(chunk);
+            current = None;
+        } else if matches!(scanner.last_token(), Some("}" | ";")) {
+            let chunk = std::mem::take(chunk);
+            match main_body(&chunk) {
+                Some(body) => statements.extend(body),
+                None => items.push(chunk),
+            }
+            current = None;
+        }
+    }
+    // 没有结束的定义或语句交给编译器报错
+    match current {
+        Some((true, chunk)) => items.push(chunk),
+        Some((false, chunk)) => statements.extend(chunk),
+        None => {}
+    }
+    statements.extend(pending.into_iter().filter(|line| !line.text.trim().is_empty()));
+    (items, statements)
+}
+
+/// `fn main` 的函数体；第一个 `{` 之前和最后一个 `}` 之后的内容换成空格，保持列号不变
+fn main_body(chunk: &[Line]) -> Option<Vec<Line>> {
+    let start = chunk.iter().position(|line| {
+        let text = line.text.trim_start();
+        !text.is_empty() && !text.starts_with("#[") && !text.starts_with("//")
+    })?;
+    if !chunk[start].text.trim_start().starts_with("fn main(") {
+        return None;
+    }
+    let mut body: Vec<Line> = chunk[start..].to_vec();
+    let open_line = body.iter().position(|line| line.text.contains('{'))?;
+    body.drain(..open_line);
+    let first = &mut body[0].text;
+    let open = first.find('{')?;
+    *first = format!("{}{}", " ".repeat(first[..=open].chars().count()), &first[open + 1..]);
+    let last = body.last_mut().expect("至少有一行");
+    let close = last.text.rfind('}')?;
+    last.text.truncate(close);
+    body.retain(|line| !line.text.trim().is_empty());
+    Some(body)
+}
+
+/// 组装好的源文件，记录每行对应的输入行号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub source: String,
+    /// 源文件第 i+1 行对应的输入行号和增加的缩进，生成的行为 `None`
+    lines: Vec<Option<(usize, usize)>>,
+}
+
+impl Program {
+    fn build(items: &[Vec<Line>], statements: &[Line]) -> Program {
+        let mut program = Program {
+            source: String::new(),
+            lines: Vec::new(),
+        };
+        program.push("#![allow(unused)]", None);
+        for line in items.iter().flatten() {
+            program.push(&line.text, Some((line.number, 0)));
+        }
+        program.push("fn main() {", None);
+        for line in statements {
+            program.push(&format!("    {}", line.text), Some((line.number, 4)));
+        }
+        program.push("}", None);
+        program
+    }
+
+    fn push(&mut self, text: &str, origin: Option<(usize, usize)>) {
+        self.source.push_str(text);
+        self.source.push('\n');
+        self.lines.push(origin);
+    }
+
+    /// 源文件中的行列号换算成输入的行列号
+    pub fn origin(&self, line: usize, column: usize) -> Option<(usize, usize)> {
+        let (number, indent) = (*self.lines.get(line.checked_sub(1)?)?)?;
+        Some((number, column.saturating_sub(indent).max(1)))
+    }
+
+    fn relocate(&self, diagnostic: &mut Diagnostic) {
+        let Some(location) = &mut diagnostic.location else {
+            return;
+        };
+        match self.origin(location.line, location.column) {
+            Some((line, column)) => {
+                location.file = SOURCE_NAME.to_string();
+                location.line = line;
+                location.column = column;
+            }
+            None => diagnostic.location = None,
+        }
+    }
+
+    /// 把 panic 信息中的 `main.rs:行:列` 换成输入的位置
+    fn relocate_panic(&self, stderr: &str) -> String {
+        let mut lines = Vec::new();
+        for line in stderr.lines().filter(|line| !line.starts_with("note: ")) {
+            let relocated = line.split_once(" panicked at main.rs:").and_then(|(thread, rest)| {
+                let mut parts = rest.trim_end_matches(':').splitn(2, ':');
+                let row = parts.next()?.parse().ok()?;
+                let column = parts.next()?.parse().ok()?;
+                let (row, column) = self.origin(row, column)?;
+                Some(format!("{} panicked at {}:{}:{}:", thread, SOURCE_NAME, row, column))
+            });
+            lines.push(relocated.unwrap_or_else(|| line.to_string()));
+        }
+        lines.join("\n")
+    }
+}
+
+/// 运行的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// 没有需要运行的输入
+    Empty,
+    CompileErrors(Vec<Diagnostic>),
+    Finished { success: bool, stdout: String, stderr: String },
+    TimedOut,
+}
+
+/// 一次 `:run` 的结果和新保留的定义数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunReport {
+    pub outcome: RunOutcome,
+    pub kept: usize,
+}
+
+/// 试验场的状态：保留的定义和正在输入的代码
+#[derive(Debug, Clone)]
+pub struct Playground {
+    items: Vec<Vec<Line>>,
+    buffer: Vec<Line>,
+    next_line: usize,
+    options: VerifyOptions,
+    run_timeout: Duration,
+}
+
+impl Playground {
+    pub fn new(options: VerifyOptions) -> Self {
+        Playground {
+            items: Vec::new(),
+            buffer: Vec::new(),
+            next_line: 1,
+            options,
+            run_timeout: RUN_TIMEOUT,
+        }
+    }
+
+    /// 替换程序运行的时间限制
+    pub fn with_run_timeout(mut self, timeout: Duration) -> Self {
+        self.run_timeout = timeout;
+        self
+    }
+
+    pub fn run_timeout(&self) -> Duration {
+        self.run_timeout
+    }
+
+    /// 下一行输入的编号
+    pub fn next_line(&self) -> usize {
+        self.next_line
+    }
+
+    pub fn buffer(&self) -> &[Line] {
+        &self.buffer
+    }
+
+    /// 保留下来的定义
+    pub fn items(&self) -> &[Vec<Line>] {
+        &self.items
+    }
+
+    /// 加入一行输入；还没有输入时忽略空行
+    pub fn push_line(&mut self, text: &str) {
+        if self.buffer.is_empty() && text.trim().is_empty() {
+            return;
+        }
+        self.buffer.push(Line {
+            number: self.next_line,
+            text: text.to_string(),
+        });
+        self.next_line += 1;
+    }
+
+    /// 清空输入和保留的定义，行号重新从 1 开始
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.buffer.clear();
+        self.next_line = 1;
+    }
+
+    /// 用代码示例替换当前输入
+    pub fn load(&mut self, example: &CodeExample) {
+        self.buffer.clear();
+        for line in example.code.lines() {
+            self.push_line(line);
+        }
+    }
+
+    /// 当前输入加上保留的定义组成的程序
+    pub fn program(&self) -> Program {
+        let (new_items, statements) = split_entry(&self.buffer);
+        let items: Vec<Vec<Line>> = self.items.iter().chain(&new_items).cloned().collect();
+        Program::build(&items, &statements)
+    }
+
+    /// 编译运行当前输入；编译通过时保留其中的定义。无论结果如何都清空当前输入。
+    /// 找不到 rustc、编译超时等环境问题以 `Err` 返回
+    pub fn run(&mut self) -> io::Result<RunReport> {
+        if self.buffer.is_empty() {
+            return Ok(RunReport {
+                outcome: RunOutcome::Empty,
+                kept: 0,
+            });
+        }
+        let (new_items, statements) = split_entry(&std::mem::take(&mut self.buffer));
+        let items: Vec<Vec<Line>> = self.items.iter().chain(&new_items).cloned().collect();
+        let program = Program::build(&items, &statements);
+
+        let dir = verify::scratch_dir();
+        fs::create_dir_all(&dir)?;
+        let outcome = self.compile_and_run(&dir, &program);
+        let _ = fs::remove_dir_all(&dir);
+        let outcome = outcome?;
+        let kept = if matches!(outcome, RunOutcome::CompileErrors(_)) { 0 } else { new_items.len() };
+        if kept > 0 {
+            self.items.extend(new_items);
+        }
+        Ok(RunReport { outcome, kept })
+    }
+
+    fn compile_and_run(&self, dir: &Path, program: &Program) -> io::Result<RunOutcome> {
+        let binary = dir.join(if cfg!(windows) { "main.exe" } else { "main" });
+        fs::write(dir.join("main.rs"), &program.source)?;
+        let compiled = verify::run(
+            Command::new(&self.options.rustc)
+                .args(["--edition", "2021", "--error-format=json", "-A", "warnings", "-o"])
+                .arg(&binary)
+                .arg("main.rs")
+                .current_dir(dir),
+            self.options.timeout,
+        )
+        .map_err(|err| io::Error::new(err.kind(), tr!("verify.rustc_failed", rustc = format!("{:?}", self.options.rustc), error = err)))?;
+        match compiled {
+            Finished::TimedOut => {
+                let seconds = self.options.timeout.as_secs_f64();
+                return Err(io::Error::new(io::ErrorKind::TimedOut, tr!("playground.compile_timed_out", seconds = seconds)));
+            }
+            Finished::Exited { success: false, stderr, .. } => {
+                let mut diagnostics = explain::parse_json_diagnostics(&stderr);
+                if diagnostics.is_empty() {
+                    return Err(io::Error::other(tr!("playground.compile_error", stderr = stderr.trim())));
+                }
+                for diagnostic in &mut diagnostics {
+                    program.relocate(diagnostic);
+                }
+                return Ok(RunOutcome::CompileErrors(diagnostics));
+            }
+            Finished::Exited { .. } => {}
+        }
+
+        let ran = verify::run(Command::new(&binary).env("RUST_BACKTRACE", "0").current_dir(dir), self.run_timeout)?;
+        Ok(match ran {
+            Finished::TimedOut => RunOutcome::TimedOut,
+            Finished::Exited { success, stdout, stderr } => RunOutcome::Finished {
+                success,
+                stdout,
+                stderr: program.relocate_panic(&stderr),
+            },
+        })
+    }
+}
+
+/// 一章知识点中的全部代码示例，按出现顺序
+pub fn chapter_examples(chapter: u8) -> Vec<&'static CodeExample> {
+    knowledge::base()
+        .chapter(chapter)
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|point| &point.code_examples)
+        .collect()
+}
+
+/// 按 `6.2` 或 `6 2` 找到第 6 章的第 2 个示例
+pub fn find_example(argument: &str) -> Option<&'static CodeExample> {
+    let (chapter, index) = argument.split_once(['.', ' '])?;
+    let index: usize = index.trim().parse().ok()?;
+    chapter_examples(chapter.trim().parse().ok()?).get(index.checked_sub(1)?).copied()
+}
+
+/// 列出一章的代码示例，没有示例时返回 false
+pub fn write_examples(out: &mut impl Write, chapter: u8) -> io::Result<bool> {
+    let examples = chapter_examples(chapter);
+    let Some(info) = chapters::get(chapter).filter(|_| !examples.is_empty()) else {
+        return Ok(false);
+    };
+    writeln!(out, "📚 {}", info.display_name())?;
+    for (index, example) in examples.iter().enumerate() {
+        writeln!(out, "  {}.{} {}", chapter, index + 1, example.title)?;
+    }
+    Ok(true)
+}
+
+/// 带行号显示代码
+pub fn write_lines<'a>(out: &mut impl Write, lines: impl IntoIterator<Item = &'a Line>) -> io::Result<()> {
+    for line in lines {
+        writeln!(out, "{}", format!("{:>3}│ {}", line.number, line.text).trim_end())?;
+    }
+    Ok(())
+}
+
+/// 显示一次运行的结果
+pub fn write_report(out: &mut impl Write, report: &RunReport, run_timeout: Duration) -> io::Result<()> {
+    match &report.outcome {
+        RunOutcome::Empty => writeln!(out, "{}", tr!("playground.empty"))?,
+        RunOutcome::CompileErrors(diagnostics) => {
+            writeln!(out, "{}", tr!("playground.compile_failed", count = diagnostics.len()))?;
+            explain::write_errors(out, diagnostics)?;
+            writeln!(out, "\n{}", tr!("playground.discarded"))?;
+        }
+        RunOutcome::Finished { success, stdout, stderr } => {
+            write!(out, "{}", stdout)?;
+            if !stdout.is_empty() && !stdout.ends_with('\n') {
+                writeln!(out)?;
+            }
+            if !stderr.trim().is_empty() {
+                writeln!(out, "{}", stderr.trim_end())?;
+            }
+            if !success {
+                writeln!(out, "{}", tr!("playground.runtime_error"))?;
+            } else if stdout.is_empty() && stderr.trim().is_empty() {
+                writeln!(out, "{}", tr!("playground.no_output"))?;
+            }
+        }
+        RunOutcome::TimedOut => {
+            writeln!(out, "{}", tr!("playground.timed_out", seconds = run_timeout.as_secs_f64()))?
+        }
+    }
+    if report.kept > 0 {
+        writeln!(out, "{}", tr!("playground.kept", count = report.kept))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,447 @@
+// 学习进度记录
+// 纯文本格式，每行一条记录，损坏的行会被跳过而不是让整个文件失效：
+//
+//   version 1
+//   run <章节> <次数> <首次运行时间> <最近运行时间>
+//   result <类型> <章节> <得分> <满分> <时间>
+//   card <卡片> <首次复习> <最近复习> <连续记住次数> <间隔> <难度系数> <到期日>
+//   solved <练习> <完成时间>
+//
+// 时间为 Unix 秒，卡片的日期为 Unix 天数。新增记录类型时旧版本读不懂这些行，但会原样保存下来再写回，
+// 所以不需要提升 SCHEMA_VERSION；已有记录的格式不兼容时才提升，旧版本的程序不会覆盖更新版本写入的文件。
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::chapters::{self, Chapter};
+use crate::flashcards::{CardId, CardState, MAX_EASE, MAX_INTERVAL, MIN_EASE};
+use crate::tr;
+
+/// 当前进度文件格式版本
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 进度文件名
+pub const FILE_NAME: &str = "progress.txt";
+
+/// 指定进度目录的环境变量，优先级低于 `--profile-dir`
+pub const PROFILE_DIR_VAR: &str = "TASK_PROFILE_DIR";
+
+/// 当前 Unix 时间（秒）
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// 时间来源，测试中可以换成固定的时间
+pub trait Clock {
+    /// 当前 Unix 时间（秒）
+    fn now(&self) -> u64;
+}
+
+/// 系统时钟
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        now()
+    }
+}
+
+/// 始终返回同一时间的时钟
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// 单个章节的运行记录
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChapterProgress {
+    pub runs: u32,
+    pub first_run: u64,
+    pub last_run: u64,
+}
+
+/// 测验或练习的结果类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultKind {
+    Quiz,
+    Exercise,
+}
+
+impl ResultKind {
+    pub fn key(self) -> &'static str {
+        match self {
+            ResultKind::Quiz => "quiz",
+            ResultKind::Exercise => "exercise",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ResultKind> {
+        match key {
+            "quiz" => Some(ResultKind::Quiz),
+            "exercise" => Some(ResultKind::Exercise),
+            _ => None,
+        }
+    }
+}
+
+/// 一次测验或练习的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivityResult {
+    pub kind: ResultKind,
+    pub chapter: u8,
+    pub score: u32,
+    pub total: u32,
+    pub at: u64,
+}
+
+/// 一位学习者的全部进度
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    chapters: BTreeMap<u8, ChapterProgress>,
+    results: Vec<ActivityResult>,
+    cards: BTreeMap<CardId, CardState>,
+    solved: BTreeMap<String, u64>,
+    /// 不认识的记录类型（通常由更新的版本写入），保存时原样写回
+    unknown: Vec<String>,
+}
+
+impl Progress {
+    /// 记录一次章节运行
+    pub fn record_run(&mut self, chapter: u8, at: u64) {
+        let entry = self.chapters.entry(chapter).or_insert(ChapterProgress {
+            runs: 0,
+            first_run: at,
+            last_run: at,
+        });
+        entry.runs += 1;
+        entry.first_run = entry.first_run.min(at);
+        entry.last_run = entry.last_run.max(at);
+    }
+
+    /// 记录一次测验或练习结果
+    pub fn record_result(&mut self, result: ActivityResult) {
+        self.results.push(result);
+    }
+
+    pub fn chapter(&self, id: u8) -> Option<&ChapterProgress> {
+        self.chapters.get(&id)
+    }
+
+    /// 章节至少运行过一次即视为完成
+    pub fn is_completed(&self, id: u8) -> bool {
+        self.chapters.contains_key(&id)
+    }
+
+    /// 已完成的已登记章节数
+    pub fn completed_count(&self) -> usize {
+        chapters::all()
+            .iter()
+            .filter(|chapter| self.is_completed(chapter.id))
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chapters.is_empty() && self.results.is_empty() && self.cards.is_empty() && self.solved.is_empty()
+    }
+
+    /// 所有测验和练习结果，按记录顺序
+    pub fn results(&self) -> &[ActivityResult] {
+        &self.results
+    }
+
+    /// 某一章最近一次指定类型的结果
+    pub fn latest_result(&self, kind: ResultKind, chapter: u8) -> Option<&ActivityResult> {
+        self.results
+            .iter()
+            .filter(|result| result.kind == kind && result.chapter == chapter)
+            .max_by_key(|result| result.at)
+    }
+
+    /// 抽认卡的复习状态
+    pub fn card(&self, id: CardId) -> Option<&CardState> {
+        self.cards.get(&id)
+    }
+
+    /// 所有复习过的卡片，按编号排序
+    pub fn cards(&self) -> impl Iterator<Item = (CardId, &CardState)> {
+        self.cards.iter().map(|(id, state)| (*id, state))
+    }
+
+    pub fn set_card(&mut self, id: CardId, state: CardState) {
+        self.cards.insert(id, state);
+    }
+
+    /// 记录完成一个改错练习，保留最早的完成时间
+    pub fn record_solved(&mut self, exercise: &str, at: u64) {
+        let entry = self.solved.entry(exercise.to_string()).or_insert(at);
+        *entry = (*entry).min(at);
+    }
+
+    pub fn is_solved(&self, exercise: &str) -> bool {
+        self.solved.contains_key(exercise)
+    }
+
+    /// 已完成的练习和完成时间，按练习编号排序
+    pub fn solved(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.solved.iter().map(|(id, at)| (id.as_str(), *at))
+    }
+
+    /// 最近运行过的章节
+    pub fn last_chapter(&self) -> Option<&'static Chapter> {
+        self.chapters
+            .iter()
+            .max_by_key(|(_, progress)| progress.last_run)
+            .and_then(|(id, _)| chapters::get(*id))
+    }
+
+    /// 建议继续学习的章节：最近运行章节之后第一个未完成的章节，
+    /// 没有的话从头找第一个未完成的章节
+    pub fn continue_with(&self) -> Option<&'static Chapter> {
+        let all = chapters::all();
+        let start = self
+            .last_chapter()
+            .and_then(|last| all.iter().position(|chapter| chapter.id == last.id))
+            .map_or(0, |index| index + 1);
+        all[start..]
+            .iter()
+            .chain(all[..start].iter())
+            .find(|chapter| !self.is_completed(chapter.id))
+    }
+
+    /// 解析进度文件，返回能读出的进度和被跳过内容的说明
+    pub fn parse(text: &str) -> (Progress, Vec<String>) {
+        let mut progress = Progress::default();
+        let mut warnings = Vec::new();
+        let mut version = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_no = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let parsed = match fields[0] {
+                "version" => parse_fields::<u32>(&fields[1..], 1).map(|v| {
+                    version = Some(v[0]);
+                }),
+                "run" => parse_run(&fields[1..]).map(|(id, run)| {
+                    progress.merge_run(id, run);
+                }),
+                "result" => parse_result(&fields[1..]).map(|result| {
+                    progress.results.push(result);
+                }),
+                "card" => parse_card(&fields[1..]).map(|(id, state)| {
+                    progress.cards.insert(id, state);
+                }),
+                "solved" => parse_solved(&fields[1..]).map(|(id, at)| {
+                    progress.record_solved(id, at);
+                }),
+                other => {
+                    warnings.push(tr!("progress.warning.unknown_record", line = line_no, kind = other));
+                    progress.unknown.push(line.to_string());
+                    continue;
+                }
+            };
+            if parsed.is_none() {
+                warnings.push(tr!("progress.warning.bad_record", line = line_no, record = line));
+            }
+        }
+
+        match version {
+            None if !text.trim().is_empty() => {
+                warnings.push(tr!("progress.warning.no_version", version = SCHEMA_VERSION));
+            }
+            Some(v) if v > SCHEMA_VERSION => {
+                warnings.push(tr!("progress.warning.newer_version", found = v, supported = SCHEMA_VERSION))
+            }
+            _ => {}
+        }
+
+        (progress, warnings)
+    }
+
+    /// 序列化为进度文件内容
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# Rust 教程学习进度，由 task 自动维护\n");
+        text.push_str(&format!("version {}\n", SCHEMA_VERSION));
+        for (id, run) in &self.chapters {
+            text.push_str(&format!(
+                "run {} {} {} {}\n",
+                id, run.runs, run.first_run, run.last_run
+            ));
+        }
+        for result in &self.results {
+            text.push_str(&format!(
+                "result {} {} {} {} {}\n",
+                result.kind.key(),
+                result.chapter,
+                result.score,
+                result.total,
+                result.at
+            ));
+        }
+        for (id, card) in &self.cards {
+            text.push_str(&format!(
+                "card {} {} {} {} {} {} {}\n",
+                id, card.added, card.reviewed, card.repetitions, card.interval, card.ease, card.due
+            ));
+        }
+        for (id, at) in &self.solved {
+            text.push_str(&format!("solved {} {}\n", id, at));
+        }
+        for line in &self.unknown {
+            text.push_str(line);
+            text.push('\n');
+        }
+        text
+    }
+
+    fn merge_run(&mut self, id: u8, run: ChapterProgress) {
+        let entry = self.chapters.entry(id).or_insert(ChapterProgress {
+            runs: 0,
+            first_run: run.first_run,
+            last_run: run.last_run,
+        });
+        entry.runs += run.runs;
+        entry.first_run = entry.first_run.min(run.first_run);
+        entry.last_run = entry.last_run.max(run.last_run);
+    }
+}
+
+fn parse_fields<T: std::str::FromStr>(fields: &[&str], count: usize) -> Option<Vec<T>> {
+    if fields.len() != count {
+        return None;
+    }
+    fields.iter().map(|field| field.parse().ok()).collect()
+}
+
+fn parse_run(fields: &[&str]) -> Option<(u8, ChapterProgress)> {
+    let id = fields.first()?.parse::<u8>().ok()?;
+    let numbers = parse_fields::<u64>(&fields[1..], 3)?;
+    let runs = u32::try_from(numbers[0]).ok().filter(|runs| *runs > 0)?;
+    Some((
+        id,
+        ChapterProgress {
+            runs,
+            first_run: numbers[1],
+            last_run: numbers[2],
+        },
+    ))
+}
+
+fn parse_result(fields: &[&str]) -> Option<ActivityResult> {
+    let kind = ResultKind::from_key(fields.first()?)?;
+    let chapter = fields.get(1)?.parse::<u8>().ok()?;
+    let numbers = parse_fields::<u64>(&fields[2..], 3)?;
+    Some(ActivityResult {
+        kind,
+        chapter,
+        score: u32::try_from(numbers[0]).ok()?,
+        total: u32::try_from(numbers[1]).ok()?,
+        at: numbers[2],
+    })
+}
+
+fn parse_card(fields: &[&str]) -> Option<(CardId, CardState)> {
+    let id = fields.first()?.parse::<CardId>().ok()?;
+    let numbers = parse_fields::<u64>(&fields[1..], 6)?;
+    Some((
+        id,
+        CardState {
+            added: numbers[0],
+            reviewed: numbers[1],
+            repetitions: u32::try_from(numbers[2]).ok()?,
+            interval: u32::try_from(numbers[3]).ok().filter(|interval| *interval <= MAX_INTERVAL)?,
+            ease: u32::try_from(numbers[4]).ok().filter(|ease| (MIN_EASE..=MAX_EASE).contains(ease))?,
+            due: numbers[5],
+        },
+    ))
+}
+
+fn parse_solved<'a>(fields: &[&'a str]) -> Option<(&'a str, u64)> {
+    let id = *fields.first()?;
+    let at = parse_fields::<u64>(&fields[1..], 1)?;
+    Some((id, at[0]))
+}
+
+/// 进度文件所在的目录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressStore {
+    dir: PathBuf,
+}
+
+impl ProgressStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        ProgressStore { dir: dir.into() }
+    }
+
+    /// 默认进度目录：`TASK_PROFILE_DIR`，其次是系统的用户数据目录
+    pub fn default_dir() -> Option<PathBuf> {
+        if let Some(dir) = env::var_os(PROFILE_DIR_VAR) {
+            return Some(PathBuf::from(dir));
+        }
+        let data_dir = if cfg!(target_os = "windows") {
+            env::var_os("APPDATA").map(PathBuf::from)
+        } else {
+            env::var_os("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+        };
+        data_dir.map(|dir| dir.join("lets-rust"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(FILE_NAME)
+    }
+
+    /// 读取进度；文件不存在时返回空进度
+    pub fn load(&self) -> io::Result<(Progress, Vec<String>)> {
+        match fs::read(self.path()) {
+            Ok(bytes) => Ok(Progress::parse(&String::from_utf8_lossy(&bytes))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok((Progress::default(), Vec::new())),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 先写临时文件再改名，写到一半中断也不会留下残缺的进度文件；
+    /// 已有的文件版本比 SCHEMA_VERSION 新时拒绝覆盖
+    pub fn save(&self, progress: &Progress) -> io::Result<()> {
+        if let Some(found) = self.file_version()?.filter(|found| *found > SCHEMA_VERSION) {
+            return Err(io::Error::other(tr!("progress.newer_schema", found = found, supported = SCHEMA_VERSION)));
+        }
+        fs::create_dir_all(&self.dir)?;
+        let tmp = self.dir.join(format!("{}.tmp", FILE_NAME));
+        fs::write(&tmp, progress.to_text())?;
+        fs::rename(&tmp, self.path())
+    }
+
+    /// 已有进度文件的版本号，文件不存在或没有版本行时为 `None`
+    fn file_version(&self) -> io::Result<Option<u32>> {
+        let text = match fs::read(self.path()) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        Ok(text.lines().find_map(|line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["version", version] => version.parse().ok(),
+            _ => None,
+        }))
+    }
+}
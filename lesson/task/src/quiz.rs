@@ -0,0 +1,587 @@
+// 章节测验
+// 题目按章节登记在 QUESTIONS 中，题干、选项和解释按键名存放在消息目录里（如 `quiz.1.2.explanation`）。
+// 出题顺序由可指定种子的随机数生成器打乱，
+// 同一个种子总是得到同样的顺序，方便用答案文件做非交互测试。
+
+use std::io::{self, BufRead, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::chapters;
+use crate::i18n;
+use crate::knowledge;
+use crate::tr;
+
+/// 题型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestionKind {
+    /// 单选题，选项在消息目录的 `<key>.options` 中每行一个，`answer` 为正确选项的下标
+    MultipleChoice { answer: usize },
+    /// 判断题
+    TrueFalse { answer: bool },
+    /// 预测代码输出，多行输出作答时用空格分隔
+    PredictOutput {
+        code: &'static str,
+        output: &'static str,
+    },
+    /// 找出无法通过编译的那一行（从 1 开始）
+    FailingLine { code: &'static str, line: usize },
+}
+
+/// 一道测验题
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Question {
+    pub chapter: u8,
+    /// 题目文字在消息目录中的键名前缀，如 `quiz.1.2`
+    pub key: &'static str,
+    pub kind: QuestionKind,
+    /// 对应的知识点在本章知识点中的序号（从 0 开始），答错时提示复习其中的核心概念
+    pub point: Option<usize>,
+}
+
+/// 对一个答案的判定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    /// 答案格式无法识别，按答错计分
+    Invalid,
+}
+
+impl Question {
+    /// 消息目录中 `<key>.<field>` 的文字
+    fn text(&self, field: &str) -> Option<&'static str> {
+        i18n::lookup(&format!("{}.{}", self.key, field))
+    }
+
+    /// 题干；预测输出和找出编译错误的题目使用统一的题干
+    pub fn prompt(&self) -> &'static str {
+        match self.kind {
+            QuestionKind::PredictOutput { .. } => tr!("quiz.prompt.predict_output"),
+            QuestionKind::FailingLine { .. } => tr!("quiz.prompt.failing_line"),
+            _ => self.text("prompt").unwrap_or(self.key),
+        }
+    }
+
+    /// 答题后显示的解释
+    pub fn explanation(&self) -> &'static str {
+        self.text("explanation").unwrap_or(self.key)
+    }
+
+    /// 单选题的选项，其他题型为空
+    pub fn options(&self) -> Vec<&'static str> {
+        match self.kind {
+            QuestionKind::MultipleChoice { .. } => self.text("options").map(|text| text.lines().collect()).unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// 题型名称
+    pub fn kind_label(&self) -> &'static str {
+        match self.kind {
+            QuestionKind::MultipleChoice { .. } => tr!("quiz.kind.choice"),
+            QuestionKind::TrueFalse { .. } => tr!("quiz.kind.true_false"),
+            QuestionKind::PredictOutput { .. } => tr!("quiz.kind.predict_output"),
+            QuestionKind::FailingLine { .. } => tr!("quiz.kind.failing_line"),
+        }
+    }
+
+    /// 作答方式提示
+    pub fn answer_hint(&self) -> String {
+        match self.kind {
+            QuestionKind::MultipleChoice { .. } => {
+                tr!("quiz.hint.choice", last = option_letter(self.options().len().saturating_sub(1)))
+            }
+            QuestionKind::TrueFalse { .. } => tr!("quiz.hint.true_false").to_string(),
+            QuestionKind::PredictOutput { .. } => tr!("quiz.hint.predict_output").to_string(),
+            QuestionKind::FailingLine { code, .. } => tr!("quiz.hint.failing_line", max = code.lines().count()),
+        }
+    }
+
+    /// 判定答案，忽略大小写和多余空白
+    pub fn check(&self, answer: &str) -> Verdict {
+        let answer = answer.trim().to_lowercase();
+        let matches = match self.kind {
+            QuestionKind::MultipleChoice { answer: expected } => {
+                parse_option(&answer, self.options().len()).map(|choice| choice == expected)
+            }
+            QuestionKind::TrueFalse { answer: expected } => {
+                parse_bool(&answer).map(|choice| choice == expected)
+            }
+            QuestionKind::PredictOutput { output, .. } => {
+                if answer.is_empty() {
+                    None
+                } else {
+                    Some(normalize_output(&answer) == normalize_output(&output.to_lowercase()))
+                }
+            }
+            QuestionKind::FailingLine { code, line } => answer
+                .parse::<usize>()
+                .ok()
+                .filter(|choice| (1..=code.lines().count()).contains(choice))
+                .map(|choice| choice == line),
+        };
+        match matches {
+            Some(true) => Verdict::Correct,
+            Some(false) => Verdict::Wrong,
+            None => Verdict::Invalid,
+        }
+    }
+
+    /// 正确答案的显示文本
+    pub fn answer_text(&self) -> String {
+        match self.kind {
+            QuestionKind::MultipleChoice { answer } => {
+                format!("{}) {}", option_letter(answer), self.options().get(answer).copied().unwrap_or(""))
+            }
+            QuestionKind::TrueFalse { answer } => if answer { tr!("quiz.true") } else { tr!("quiz.false") }.to_string(),
+            QuestionKind::PredictOutput { output, .. } => normalize_output(output),
+            QuestionKind::FailingLine { code, line } => {
+                tr!("quiz.answer.line", line = line, code = code.lines().nth(line - 1).unwrap_or("").trim())
+            }
+        }
+    }
+}
+
+fn option_letter(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+/// 接受字母（a、b…）或序号（1、2…）
+fn parse_option(answer: &str, count: usize) -> Option<usize> {
+    let index = match answer.as_bytes() {
+        [letter @ b'a'..=b'z'] => usize::from(letter - b'a'),
+        _ => answer.parse::<usize>().ok()?.checked_sub(1)?,
+    };
+    (index < count).then_some(index)
+}
+
+fn parse_bool(answer: &str) -> Option<bool> {
+    match answer {
+        "t" | "true" | "y" | "yes" | "对" | "是" | "正确" => Some(true),
+        "f" | "false" | "n" | "no" | "错" | "否" | "错误" => Some(false),
+        _ => None,
+    }
+}
+
+fn normalize_output(output: &str) -> String {
+    output.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 可指定种子的伪随机数生成器（SplitMix64），只用于打乱题目顺序
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// 以当前时间为种子
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0);
+        Rng::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `0..bound` 范围内的随机数，`bound` 必须大于 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Fisher-Yates 洗牌
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// 一次测验的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizReport {
+    pub chapter: u8,
+    pub score: u32,
+    pub total: u32,
+    /// 答错或未作答的题目
+    pub missed: Vec<&'static Question>,
+    /// 输入在答完所有题目前结束时为 false
+    pub complete: bool,
+}
+
+/// 某一章的全部题目，按登记顺序
+pub fn questions_for(chapter: u8) -> Vec<&'static Question> {
+    QUESTIONS
+        .iter()
+        .filter(|question| question.chapter == chapter)
+        .collect()
+}
+
+/// 有测验题的章节编号
+pub fn chapters_with_quiz() -> Vec<u8> {
+    chapters::all()
+        .iter()
+        .map(|chapter| chapter.id)
+        .filter(|id| QUESTIONS.iter().any(|question| question.chapter == *id))
+        .collect()
+}
+
+/// 进行一次测验：从 `input` 逐行读取答案，题目、判定和解释写入 `out`。
+/// `echo` 为 true 时回显读到的答案，用于答案文件和脚本回放。
+pub fn run_quiz(
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+    chapter: u8,
+    rng: &mut Rng,
+    echo: bool,
+) -> io::Result<QuizReport> {
+    let mut questions = questions_for(chapter);
+    rng.shuffle(&mut questions);
+
+    let mut report = QuizReport {
+        chapter,
+        score: 0,
+        total: questions.len() as u32,
+        missed: Vec::new(),
+        complete: true,
+    };
+
+    if let Some(chapter) = chapters::get(chapter) {
+        writeln!(out, "{}", tr!("quiz.header", chapter = chapter.display_name(), count = questions.len()))?;
+        writeln!(out, "{}", "═".repeat(40))?;
+    }
+
+    for (index, question) in questions.iter().enumerate() {
+        show_question(out, question, index + 1, questions.len())?;
+        write!(out, "{} ", tr!("quiz.answer_prompt"))?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out, "\n{}", tr!("quiz.input_ended"))?;
+            report.complete = false;
+            report.missed.extend(questions[index..].iter().copied());
+            break;
+        }
+        if echo {
+            writeln!(out, "{}", line.trim())?;
+        }
+
+        match question.check(&line) {
+            Verdict::Correct => {
+                report.score += 1;
+                writeln!(out, "{}", tr!("quiz.correct"))?;
+            }
+            verdict => {
+                report.missed.push(question);
+                if verdict == Verdict::Invalid {
+                    writeln!(out, "{}", tr!("quiz.invalid", hint = question.answer_hint()))?;
+                }
+                writeln!(out, "{}", tr!("quiz.wrong", answer = question.answer_text()))?;
+            }
+        }
+        writeln!(out, "💡 {}", question.explanation())?;
+    }
+
+    show_report(out, &report)?;
+    Ok(report)
+}
+
+fn show_question(out: &mut impl Write, question: &Question, number: usize, total: usize) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("quiz.question", number = number, total = total, kind = question.kind_label()))?;
+    writeln!(out, "{}", question.prompt())?;
+    match question.kind {
+        QuestionKind::MultipleChoice { .. } => {
+            for (index, option) in question.options().iter().enumerate() {
+                writeln!(out, "  {}) {}", option_letter(index), option)?;
+            }
+        }
+        QuestionKind::TrueFalse { .. } => {}
+        QuestionKind::PredictOutput { code, .. } | QuestionKind::FailingLine { code, .. } => {
+            for (index, line) in code.lines().enumerate() {
+                writeln!(out, "  {:>2} | {}", index + 1, line)?;
+            }
+        }
+    }
+    writeln!(out, "{}", tr!("quiz.hint", hint = question.answer_hint()))
+}
+
+fn show_report(out: &mut impl Write, report: &QuizReport) -> io::Result<()> {
+    let percent = (report.score * 100).checked_div(report.total).unwrap_or(0);
+    writeln!(out, "\n{}", "═".repeat(40))?;
+    writeln!(out, "{}", tr!("quiz.score", score = report.score, total = report.total, percent = percent))?;
+    if report.missed.is_empty() {
+        return writeln!(out, "{}", tr!("quiz.perfect"));
+    }
+
+    // 答错的题目对应的知识点，提示复习核心概念
+    let points = knowledge::get_chapter_knowledge(report.chapter).unwrap_or_default();
+    let mut indexes: Vec<usize> = Vec::new();
+    for index in report.missed.iter().filter_map(|question| question.point) {
+        if !indexes.contains(&index) {
+            indexes.push(index);
+        }
+    }
+    for point in indexes.into_iter().filter_map(|index| points.get(index)) {
+        writeln!(out, "{}", tr!("quiz.review", title = point.title))?;
+        for concept in &point.key_concepts {
+            writeln!(out, "  • {}", concept)?;
+        }
+    }
+    writeln!(out, "{}", tr!("quiz.retry"))
+}
+
+/// 所有章节的测验题
+pub static QUESTIONS: &[Question] = &[
+    // 第1章：变量与常量
+    Question {
+        chapter: 1,
+        key: "quiz.1.1",
+        kind: QuestionKind::TrueFalse { answer: false },
+        point: Some(0),
+    },
+    Question {
+        chapter: 1,
+        key: "quiz.1.2",
+        kind: QuestionKind::FailingLine {
+            code: "let x = 5;\nprintln!(\"{}\", x);\nx = 6;\nprintln!(\"{}\", x);",
+            line: 3,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 1,
+        key: "quiz.1.3",
+        kind: QuestionKind::PredictOutput {
+            code: "let x = 5;\nlet x = x + 1;\n{\n    let x = x * 2;\n    println!(\"{}\", x);\n}\nprintln!(\"{}\", x);",
+            output: "12\n6",
+        },
+        point: Some(1),
+    },
+    Question {
+        chapter: 1,
+        key: "quiz.1.4",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(1),
+    },
+    // 第2章：数据类型
+    Question {
+        chapter: 2,
+        key: "quiz.2.1",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    Question {
+        chapter: 2,
+        key: "quiz.2.2",
+        kind: QuestionKind::PredictOutput {
+            code: "let t = (1, 2.5, 'a');\nlet (a, _, c) = t;\nprintln!(\"{} {}\", a, c);",
+            output: "1 a",
+        },
+        point: Some(1),
+    },
+    Question {
+        chapter: 2,
+        key: "quiz.2.3",
+        kind: QuestionKind::TrueFalse { answer: true },
+        point: Some(1),
+    },
+    Question {
+        chapter: 2,
+        key: "quiz.2.4",
+        kind: QuestionKind::FailingLine {
+            code: "let a: u8 = 255;\nlet b: i32 = 10;\nlet c = a + b;",
+            line: 3,
+        },
+        point: Some(0),
+    },
+    // 第3章：函数
+    Question {
+        chapter: 3,
+        key: "quiz.3.1",
+        kind: QuestionKind::PredictOutput {
+            code: "fn plus_one(x: i32) -> i32 {\n    x + 1\n}\n\nfn main() {\n    println!(\"{}\", plus_one(5));\n}",
+            output: "6",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 3,
+        key: "quiz.3.2",
+        kind: QuestionKind::TrueFalse { answer: false },
+        point: Some(0),
+    },
+    Question {
+        chapter: 3,
+        key: "quiz.3.3",
+        kind: QuestionKind::FailingLine {
+            code: "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\nfn main() {\n    let sum = add(1, \"2\");\n}",
+            line: 6,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 3,
+        key: "quiz.3.4",
+        kind: QuestionKind::MultipleChoice { answer: 0 },
+        point: Some(0),
+    },
+    // 第4章：控制流
+    Question {
+        chapter: 4,
+        key: "quiz.4.1",
+        kind: QuestionKind::PredictOutput {
+            code: "let n = 7;\nlet kind = if n % 2 == 0 { \"even\" } else { \"odd\" };\nprintln!(\"{}\", kind);",
+            output: "odd",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 4,
+        key: "quiz.4.2",
+        kind: QuestionKind::PredictOutput {
+            code: "let mut count = 0;\nlet result = loop {\n    count += 1;\n    if count == 3 {\n        break count * 10;\n    }\n};\nprintln!(\"{}\", result);",
+            output: "30",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 4,
+        key: "quiz.4.3",
+        kind: QuestionKind::FailingLine {
+            code: "let n = 3;\nlet v = if n > 0 { 1 } else { \"negative\" };",
+            line: 2,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 4,
+        key: "quiz.4.4",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    // 第5章：内存管理
+    Question {
+        chapter: 5,
+        key: "quiz.5.1",
+        kind: QuestionKind::TrueFalse { answer: true },
+        point: Some(0),
+    },
+    Question {
+        chapter: 5,
+        key: "quiz.5.2",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    Question {
+        chapter: 5,
+        key: "quiz.5.3",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    // 第6章：所有权
+    Question {
+        chapter: 6,
+        key: "quiz.6.1",
+        kind: QuestionKind::FailingLine {
+            code: "let s1 = String::from(\"hello\");\nlet s2 = s1;\nprintln!(\"{}\", s1);",
+            line: 3,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 6,
+        key: "quiz.6.2",
+        kind: QuestionKind::TrueFalse { answer: true },
+        point: Some(0),
+    },
+    Question {
+        chapter: 6,
+        key: "quiz.6.3",
+        kind: QuestionKind::PredictOutput {
+            code: "let s1 = String::from(\"hi\");\nlet s2 = s1.clone();\nprintln!(\"{} {}\", s1, s2);",
+            output: "hi hi",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 6,
+        key: "quiz.6.4",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    // 第7章：借用机制
+    Question {
+        chapter: 7,
+        key: "quiz.7.1",
+        kind: QuestionKind::FailingLine {
+            code: "let mut s = String::from(\"hello\");\nlet r1 = &mut s;\nlet r2 = &mut s;\nprintln!(\"{} {}\", r1, r2);",
+            line: 3,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 7,
+        key: "quiz.7.2",
+        kind: QuestionKind::TrueFalse { answer: true },
+        point: Some(0),
+    },
+    Question {
+        chapter: 7,
+        key: "quiz.7.3",
+        kind: QuestionKind::PredictOutput {
+            code: "fn len(s: &String) -> usize {\n    s.len()\n}\n\nfn main() {\n    let s = String::from(\"rust\");\n    println!(\"{} {}\", len(&s), s);\n}",
+            output: "4 rust",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 7,
+        key: "quiz.7.4",
+        kind: QuestionKind::MultipleChoice { answer: 0 },
+        point: Some(0),
+    },
+    // 第8章：结构体
+    Question {
+        chapter: 8,
+        key: "quiz.8.1",
+        kind: QuestionKind::PredictOutput {
+            code: "struct Rect {\n    w: u32,\n    h: u32,\n}\n\nimpl Rect {\n    fn area(&self) -> u32 {\n        self.w * self.h\n    }\n}\n\nfn main() {\n    let r = Rect { w: 3, h: 4 };\n    println!(\"{}\", r.area());\n}",
+            output: "12",
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 8,
+        key: "quiz.8.2",
+        kind: QuestionKind::FailingLine {
+            code: "struct Point {\n    x: i32,\n    y: i32,\n}\n\nfn main() {\n    let p = Point { x: 1, y: 2 };\n    p.x = 5;\n}",
+            line: 8,
+        },
+        point: Some(0),
+    },
+    Question {
+        chapter: 8,
+        key: "quiz.8.3",
+        kind: QuestionKind::MultipleChoice { answer: 1 },
+        point: Some(0),
+    },
+    Question {
+        chapter: 8,
+        key: "quiz.8.4",
+        kind: QuestionKind::TrueFalse { answer: true },
+        point: Some(0),
+    },
+];
@@ -0,0 +1,695 @@
+// 全文搜索
+// 为章节介绍、知识点、tutorial/*.md、mdBook 页面（src/**/*.md）和各章示例源码建立倒排索引。
+// 分词同时处理中文（单字和相邻两字）和 Rust 标识符（包括 `Rc::downgrade` 这样的路径），
+// 结果按 BM25 排序，并截取命中最多的一行作为摘要。
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::chapters;
+use crate::i18n::{self, Locale};
+use crate::knowledge;
+use crate::tr;
+
+/// 指定仓库根目录的环境变量，用于查找教程文档和示例源码
+pub const REPO_DIR_VAR: &str = "TASK_REPO_DIR";
+
+/// 默认显示的结果数
+pub const DEFAULT_LIMIT: usize = 10;
+
+/// 摘要最多显示的字符数
+const SNIPPET_CHARS: usize = 60;
+/// 摘要中第一个命中词之前保留的字符数
+const SNIPPET_CONTEXT: usize = 16;
+
+/// 文档来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// 章节注册表中的介绍、目标、概念和总结
+    Chapter,
+    Knowledge,
+    /// tutorial/*.md
+    Tutorial,
+    /// mdBook 的页面，src/**/*.md
+    Book,
+    /// lesson/task/src/bin/*.rs
+    Example,
+}
+
+impl Source {
+    pub fn label(self) -> &'static str {
+        i18n::text(match self {
+            Source::Chapter => "search.source.chapter",
+            Source::Knowledge => "search.source.knowledge",
+            Source::Tutorial => "search.source.tutorial",
+            Source::Book => "search.source.book",
+            Source::Example => "search.source.example",
+        })
+    }
+
+    /// 排序权重：章节介绍和知识点是整理过的内容，排在原始文档前面
+    fn weight(self) -> f64 {
+        match self {
+            Source::Chapter => 1.5,
+            Source::Knowledge => 1.3,
+            Source::Tutorial => 1.0,
+            Source::Example => 0.9,
+            Source::Book => 0.8,
+        }
+    }
+}
+
+/// 一个可搜索的文档
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub source: Source,
+    /// 对应的章节，mdBook 的页面没有章节
+    pub chapter: Option<u8>,
+    pub title: String,
+    /// 来自文件时为相对仓库根目录的路径
+    pub path: Option<String>,
+    /// 正文第一行在文件中的行号，从 1 开始；不来自文件时为 0
+    pub line: usize,
+    pub text: String,
+}
+
+/// 词的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// 英文单词或 Rust 标识符，统一小写
+    Word,
+    /// 蛇形命名中的一段，例如 `read_to_string` 中的 `read`
+    Part,
+    /// `::` 连接的两个标识符，例如 `rc::downgrade`
+    Path,
+    /// 单个汉字
+    Unigram,
+    /// 相邻的两个汉字
+    Bigram,
+}
+
+/// 分词结果，`span` 是在原文中的字节范围
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub term: String,
+    pub kind: TokenKind,
+    pub span: Range<usize>,
+}
+
+/// 中日韩文字，没有空格分词，按字切分
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF | 0x20000..=0x2FA1F
+    )
+}
+
+fn is_word_char(c: char) -> bool {
+    (c.is_alphanumeric() || c == '_') && !is_cjk(c)
+}
+
+/// 分词：连续的字母、数字和下划线是一个词，中文按单字和相邻两字切分
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut previous_word: Option<(String, Range<usize>)> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if is_word_char(c) {
+            let mut end = start;
+            while let Some((index, c)) = chars.next_if(|&(_, c)| is_word_char(c)) {
+                end = index + c.len_utf8();
+            }
+            let word = text[start..end].to_lowercase();
+            if let Some((previous, span)) = &previous_word {
+                if &text[span.end..start] == "::" {
+                    tokens.push(Token {
+                        term: format!("{}::{}", previous, word),
+                        kind: TokenKind::Path,
+                        span: span.start..end,
+                    });
+                }
+            }
+            if word.contains('_') {
+                let mut offset = start;
+                for part in text[start..end].split('_') {
+                    if !part.is_empty() {
+                        tokens.push(Token {
+                            term: part.to_lowercase(),
+                            kind: TokenKind::Part,
+                            span: offset..offset + part.len(),
+                        });
+                    }
+                    offset += part.len() + 1;
+                }
+            }
+            tokens.push(Token {
+                term: word.clone(),
+                kind: TokenKind::Word,
+                span: start..end,
+            });
+            previous_word = Some((word, start..end));
+        } else if is_cjk(c) {
+            let mut run = Vec::new();
+            while let Some(entry) = chars.next_if(|&(_, c)| is_cjk(c)) {
+                run.push(entry);
+            }
+            for (n, &(index, c)) in run.iter().enumerate() {
+                tokens.push(Token {
+                    term: c.to_string(),
+                    kind: TokenKind::Unigram,
+                    span: index..index + c.len_utf8(),
+                });
+                if let Some(&(next_index, next)) = run.get(n + 1) {
+                    tokens.push(Token {
+                        term: format!("{}{}", c, next),
+                        kind: TokenKind::Bigram,
+                        span: index..next_index + next.len_utf8(),
+                    });
+                }
+            }
+        } else {
+            chars.next();
+        }
+    }
+    tokens
+}
+
+/// 查询中的词：`required` 必须全部出现，`optional` 只用于加分
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    pub required: Vec<String>,
+    pub optional: Vec<String>,
+}
+
+impl Query {
+    /// 单词和两字词必须出现；只有一个字的中文词按单字查找；路径和蛇形命名的分段用于加分
+    pub fn parse(query: &str) -> Query {
+        let tokens = tokenize(query);
+        let mut parsed = Query::default();
+        for token in &tokens {
+            let required = match token.kind {
+                TokenKind::Word | TokenKind::Bigram => true,
+                TokenKind::Unigram => {
+                    let in_bigram = tokens.iter().any(|other| {
+                        other.kind == TokenKind::Bigram
+                            && other.span.start <= token.span.start
+                            && token.span.end <= other.span.end
+                    });
+                    if in_bigram {
+                        continue;
+                    }
+                    true
+                }
+                TokenKind::Part | TokenKind::Path => false,
+            };
+            let list = if required { &mut parsed.required } else { &mut parsed.optional };
+            if !list.contains(&token.term) {
+                list.push(token.term.clone());
+            }
+        }
+        parsed
+    }
+
+    fn terms(&self) -> impl Iterator<Item = &str> {
+        self.required.iter().chain(&self.optional).map(String::as_str)
+    }
+}
+
+/// 搜索结果的摘要
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub text: String,
+    /// 命中词在 `text` 中的字节范围，已合并重叠部分
+    pub highlights: Vec<Range<usize>>,
+    /// 摘要所在行在正文中的序号，从 0 开始
+    pub line: usize,
+}
+
+impl Snippet {
+    /// 用 `mark` 包住每个命中词，`mark` 中的 `{text}` 替换为命中的文字
+    pub fn highlighted(&self, mark: &str) -> String {
+        let mut result = String::new();
+        let mut last = 0;
+        for range in &self.highlights {
+            result.push_str(&self.text[last..range.start]);
+            result.push_str(&i18n::fill(mark, &[("text", &&self.text[range.clone()])]));
+            last = range.end;
+        }
+        result.push_str(&self.text[last..]);
+        result
+    }
+}
+
+/// 一条搜索结果
+#[derive(Debug, Clone)]
+pub struct Hit<'a> {
+    pub document: &'a Document,
+    pub score: f64,
+    pub snippet: Snippet,
+}
+
+impl Hit<'_> {
+    /// 摘要所在的文件位置，例如 `tutorial/03_ownership.md:42`
+    pub fn location(&self) -> Option<String> {
+        let path = self.document.path.as_ref()?;
+        Some(format!("{}:{}", path, self.document.line + self.snippet.line))
+    }
+}
+
+/// 倒排索引中一个词在一个文档里的出现次数
+#[derive(Debug, Clone, Copy)]
+struct Posting {
+    document: usize,
+    count: u32,
+}
+
+/// 倒排索引
+#[derive(Debug, Default)]
+pub struct Index {
+    documents: Vec<Document>,
+    postings: HashMap<String, Vec<Posting>>,
+    /// 每个文档的长度（单词和单字的个数）
+    lengths: Vec<usize>,
+    average_length: f64,
+}
+
+/// BM25 参数
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+impl Index {
+    pub fn build(documents: Vec<Document>) -> Index {
+        let mut index = Index::default();
+        for (id, document) in documents.iter().enumerate() {
+            let tokens = tokenize(&format!("{}\n{}", document.title, document.text));
+            let mut counts: HashMap<String, u32> = HashMap::new();
+            let mut length = 0;
+            for token in tokens {
+                if matches!(token.kind, TokenKind::Word | TokenKind::Unigram) {
+                    length += 1;
+                }
+                *counts.entry(token.term).or_default() += 1;
+            }
+            for (term, count) in counts {
+                index.postings.entry(term).or_default().push(Posting { document: id, count });
+            }
+            index.lengths.push(length);
+        }
+        let total: usize = index.lengths.iter().sum();
+        index.average_length = total as f64 / documents.len().max(1) as f64;
+        index.documents = documents;
+        index
+    }
+
+    pub fn documents(&self) -> &[Document] {
+        &self.documents
+    }
+
+    /// 逆文档频率，出现在越少文档中的词越重要
+    fn idf(&self, postings: &[Posting]) -> f64 {
+        let n = self.documents.len() as f64;
+        let df = postings.len() as f64;
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    /// 按相关度从高到低返回包含全部必需词的文档
+    pub fn search(&self, query: &str) -> Vec<Hit<'_>> {
+        let query = Query::parse(query);
+        if query.required.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        for (n, term) in query.required.iter().enumerate() {
+            let Some(postings) = self.postings.get(term) else {
+                return Vec::new();
+            };
+            let idf = self.idf(postings);
+            let mut next = HashMap::new();
+            for posting in postings {
+                if n > 0 && !scores.contains_key(&posting.document) {
+                    continue;
+                }
+                let previous = scores.get(&posting.document).copied().unwrap_or(0.0);
+                next.insert(posting.document, previous + idf * self.term_frequency(posting));
+            }
+            scores = next;
+        }
+        for term in &query.optional {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let idf = self.idf(postings);
+            for posting in postings {
+                if let Some(score) = scores.get_mut(&posting.document) {
+                    *score += idf * self.term_frequency(posting);
+                }
+            }
+        }
+
+        let terms: HashSet<&str> = query.terms().collect();
+        let mut hits: Vec<(usize, Hit)> = scores
+            .into_iter()
+            .map(|(id, score)| {
+                let document = &self.documents[id];
+                // 标题中出现查询词的文档更可能是专门讲这个主题的
+                let in_title = tokenize(&document.title)
+                    .iter()
+                    .filter(|token| query.required.contains(&token.term))
+                    .count();
+                let score = score * document.source.weight() * (1.0 + 0.5 * in_title.min(1) as f64);
+                let snippet = make_snippet(&document.text, &terms);
+                (id, Hit { document, score, snippet })
+            })
+            .collect();
+        hits.sort_by(|(a_id, a), (b_id, b)| b.score.total_cmp(&a.score).then(a_id.cmp(b_id)));
+        hits.into_iter().map(|(_, hit)| hit).collect()
+    }
+
+    fn term_frequency(&self, posting: &Posting) -> f64 {
+        let tf = posting.count as f64;
+        let length = self.lengths[posting.document] as f64;
+        let norm = 1.0 - B + B * length / self.average_length.max(1.0);
+        tf * (K1 + 1.0) / (tf + K1 * norm)
+    }
+}
+
+/// 选出命中词种类最多的一行作为摘要，过长时截取第一个命中词附近的部分
+fn make_snippet(text: &str, terms: &HashSet<&str>) -> Snippet {
+    let mut best: Option<(usize, usize, &str, Vec<Range<usize>>)> = None;
+    for (number, line) in text.lines().enumerate() {
+        let matched: Vec<Token> = tokenize(line)
+            .into_iter()
+            .filter(|token| terms.contains(token.term.as_str()))
+            .collect();
+        let distinct = matched.iter().map(|token| token.term.as_str()).collect::<HashSet<_>>().len();
+        if distinct > best.as_ref().map_or(0, |(count, ..)| *count) {
+            let spans = merge_spans(matched.into_iter().map(|token| token.span).collect());
+            best = Some((distinct, number, line, spans));
+        }
+    }
+    let (number, line, spans) = match best {
+        Some((_, number, line, spans)) => (number, line, spans),
+        None => {
+            let (number, line) = text
+                .lines()
+                .enumerate()
+                .find(|(_, line)| !line.trim().is_empty())
+                .unwrap_or((0, ""));
+            (number, line, Vec::new())
+        }
+    };
+
+    // 去掉缩进，命中范围随之平移
+    let indent = line.len() - line.trim_start().len();
+    let line = line.trim();
+    let spans = spans
+        .into_iter()
+        .map(|span| span.start - indent..span.end - indent)
+        .filter(|span| span.end <= line.len())
+        .collect();
+    let (text, highlights) = clip(line, spans);
+    Snippet {
+        text,
+        highlights,
+        line: number,
+    }
+}
+
+/// 排序并合并重叠或相邻的范围
+fn merge_spans(mut spans: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    spans.sort_by_key(|span| (span.start, span.end));
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for span in spans {
+        match merged.last_mut() {
+            Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+            _ => merged.push(span),
+        }
+    }
+    merged
+}
+
+/// 把一行截到 SNIPPET_CHARS 个字符以内，两端被截掉时加省略号
+fn clip(line: &str, spans: Vec<Range<usize>>) -> (String, Vec<Range<usize>>) {
+    let chars: Vec<usize> = line.char_indices().map(|(index, _)| index).collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return (line.to_string(), spans);
+    }
+    let first = spans.first().map_or(0, |span| span.start);
+    let first_char = chars.iter().position(|index| *index >= first).unwrap_or(0);
+    let begin = first_char.saturating_sub(SNIPPET_CONTEXT).min(chars.len() - SNIPPET_CHARS);
+    let from = chars[begin];
+    let to = chars.get(begin + SNIPPET_CHARS).copied().unwrap_or(line.len());
+
+    let prefix = if from > 0 { "…" } else { "" };
+    let suffix = if to < line.len() { "…" } else { "" };
+    let shift = prefix.len();
+    let highlights = spans
+        .into_iter()
+        .filter(|span| span.start >= from && span.end <= to)
+        .map(|span| span.start - from + shift..span.end - from + shift)
+        .collect();
+    (format!("{}{}{}", prefix, &line[from..to], suffix), highlights)
+}
+
+/// 仓库根目录：优先取 `TASK_REPO_DIR`，否则使用编译时的源码位置；目录不存在时只搜索内置内容
+pub fn repo_dir() -> Option<PathBuf> {
+    let dir = env::var_os(REPO_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."));
+    dir.is_dir().then_some(dir)
+}
+
+/// 收集当前语言的章节介绍和知识点，以及 `root` 下的教程文档、mdBook 页面和示例源码
+pub fn collect_documents(root: Option<&Path>) -> Vec<Document> {
+    let mut documents = Vec::new();
+    for chapter in chapters::all() {
+        let mut text = vec![chapter.localized_brief()];
+        text.extend(chapter.localized_goals());
+        text.extend(chapter.localized_concepts());
+        text.extend(chapter.localized_summary());
+        documents.push(Document {
+            source: Source::Chapter,
+            chapter: Some(chapter.id),
+            title: chapter.display_name(),
+            path: None,
+            line: 0,
+            text: text.join("\n"),
+        });
+    }
+
+    let base = knowledge::base();
+    for id in base.chapters() {
+        for point in base.chapter(id).unwrap_or_default() {
+            documents.push(Document {
+                source: Source::Knowledge,
+                chapter: Some(id),
+                title: point.title.clone(),
+                path: None,
+                line: 0,
+                text: knowledge_text(point),
+            });
+        }
+    }
+
+    if let Some(root) = root {
+        collect_tutorial(root, &mut documents);
+        collect_book(root, &mut documents);
+        collect_examples(root, &mut documents);
+    }
+    documents
+}
+
+fn knowledge_text(point: &knowledge::KnowledgePoint) -> String {
+    let mut lines = vec![point.description.clone()];
+    lines.extend(point.key_concepts.iter().cloned());
+    lines.extend(point.common_mistakes.iter().cloned());
+    lines.extend(point.best_practices.iter().cloned());
+    lines.extend(point.related_topics.iter().cloned());
+    for example in &point.code_examples {
+        lines.push(example.title.clone());
+        lines.push(example.explanation.clone());
+        lines.push(example.code.clone());
+    }
+    lines.retain(|line| !line.is_empty());
+    lines.join("\n")
+}
+
+/// tutorial/*.md 按标题分节；多个章节共用一个文件时，按小节标题中的章节名归属
+fn collect_tutorial(root: &Path, documents: &mut Vec<Document>) {
+    for path in markdown_files(&root.join("tutorial"), false) {
+        let relative = relative_path(root, &path);
+        let owners: Vec<&chapters::Chapter> =
+            chapters::all().iter().filter(|chapter| chapter.tutorial == relative).collect();
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for section in markdown_sections(&text, &file_stem(&path)) {
+            let chapter = owners
+                .iter()
+                .find(|chapter| section.title.contains(chapter.title))
+                .or(owners.first())
+                .map(|chapter| chapter.id);
+            documents.push(section.into_document(Source::Tutorial, chapter, &relative));
+        }
+    }
+}
+
+/// mdBook 的源文件，位置见 book.toml 的 `src`
+fn collect_book(root: &Path, documents: &mut Vec<Document>) {
+    for path in markdown_files(&root.join("src"), true) {
+        let relative = relative_path(root, &path);
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for section in markdown_sections(&text, &file_stem(&path)) {
+            documents.push(section.into_document(Source::Book, None, &relative));
+        }
+    }
+}
+
+/// 每章的独立示例程序
+fn collect_examples(root: &Path, documents: &mut Vec<Document>) {
+    for chapter in chapters::all() {
+        let relative = format!("lesson/task/{}", chapter.source_path());
+        let Ok(text) = fs::read_to_string(root.join(&relative)) else {
+            continue;
+        };
+        documents.push(Document {
+            source: Source::Example,
+            chapter: Some(chapter.id),
+            title: relative.clone(),
+            path: Some(relative),
+            line: 1,
+            text,
+        });
+    }
+}
+
+/// 目录中的 Markdown 文件（不含 SUMMARY.md），按路径排序
+fn markdown_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if path.is_dir() {
+            if recursive {
+                files.extend(markdown_files(&path, true));
+            }
+        } else if path.extension().is_some_and(|ext| ext == "md")
+            && path.file_name().is_some_and(|name| name != "SUMMARY.md")
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+/// Markdown 中一个标题下的内容
+struct Section {
+    title: String,
+    /// 正文第一行的行号
+    line: usize,
+    text: String,
+}
+
+impl Section {
+    fn into_document(self, source: Source, chapter: Option<u8>, path: &str) -> Document {
+        Document {
+            source,
+            chapter,
+            title: self.title,
+            path: Some(path.to_string()),
+            line: self.line,
+            text: self.text,
+        }
+    }
+}
+
+/// 按 `#` 标题把 Markdown 切成小节，代码块中的 `#` 不算标题；第一个标题之前的内容以 `untitled` 为标题
+fn markdown_sections(text: &str, untitled: &str) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut current = Section {
+        title: untitled.to_string(),
+        line: 1,
+        text: String::new(),
+    };
+    let mut in_code = false;
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+        }
+        let heading = trimmed.trim_start_matches('#');
+        let level = trimmed.len() - heading.len();
+        if !in_code && (1..=6).contains(&level) && (heading.is_empty() || heading.starts_with(' ')) {
+            sections.push(current);
+            current = Section {
+                title: heading.trim().to_string(),
+                line: index + 2,
+                text: String::new(),
+            };
+            continue;
+        }
+        current.text.push_str(line);
+        current.text.push('\n');
+    }
+    sections.push(current);
+    sections.retain(|section| !section.text.trim().is_empty());
+    sections
+}
+
+/// 进程内共享的当前语言索引，第一次搜索时建立
+pub fn index() -> &'static Index {
+    static INDEXES: [OnceLock<Index>; 2] = [OnceLock::new(), OnceLock::new()];
+    let locale = i18n::locale();
+    let slot = Locale::ALL.iter().position(|known| *known == locale).unwrap_or(0);
+    INDEXES[slot].get_or_init(|| Index::build(collect_documents(repo_dir().as_deref())))
+}
+
+/// 写出前 `limit` 条结果，结果编号从 1 开始
+pub fn write_results(out: &mut impl Write, query: &str, hits: &[Hit], limit: usize) -> io::Result<()> {
+    if hits.is_empty() {
+        return writeln!(out, "{}", tr!("search.no_results", query = query));
+    }
+    writeln!(out, "{}\n", tr!("search.header", query = query, count = hits.len()))?;
+    for (number, hit) in hits.iter().take(limit).enumerate() {
+        let document = hit.document;
+        writeln!(out, "{:>2}. [{}] {}", number + 1, document.source.label(), document.title)?;
+        writeln!(out, "    {}", hit.snippet.highlighted(tr!("search.highlight")))?;
+        let mut place = Vec::new();
+        if let Some(chapter) = document.chapter.and_then(chapters::get) {
+            place.push(tr!("search.chapter", chapter = chapter.display_name(), id = chapter.id));
+        }
+        if let Some(location) = hit.location() {
+            place.push(tr!("search.file", location = location));
+        }
+        if !place.is_empty() {
+            writeln!(out, "    {}", place.join("  "))?;
+        }
+    }
+    if hits.len() > limit {
+        writeln!(out, "\n{}", tr!("search.more", count = hits.len() - limit))?;
+    }
+    Ok(())
+}
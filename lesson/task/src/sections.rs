@@ -0,0 +1,370 @@
+// 章节小节
+// 独立示例程序的 main 依次调用一组演示函数，这里把这些函数登记为章节的小节，
+// 可以只运行其中一节（`run 19 thread_pool`），也可以逐节运行、每节之后暂停。
+// 示例程序通过 #[path] 编进库中，演示函数声明为 pub(crate)，仍然可以用 `cargo run --bin` 独立运行。
+// 编进库中时 `print!` 和 `println!` 换成下面的同名宏，演示函数（以及它们启动的线程）的输出
+// 交给调用方的 `out`，和其他命令一样经过主题转换和分页；独立运行时仍是标准库的宏。
+// 小节标题和示例输出一样只有中文。
+// main 中没有拆成函数的章节（例如第10章枚举）、依赖 crate 根路径的第13章、带文档测试的第14章不分小节，整章运行。
+
+use std::fmt;
+use std::io::{self, Write};
+use std::panic;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+
+use crate::chapters::Chapter;
+
+/// 章节示例中的一节
+#[derive(Debug, Clone, Copy)]
+pub struct Section {
+    /// 演示函数名，也是命令行中使用的标识
+    pub id: &'static str,
+    pub title: &'static str,
+    /// 运行演示函数，输出写入 `out`
+    pub run: fn(&mut dyn Write) -> io::Result<()>,
+}
+
+impl Section {
+    pub fn run(&self, out: &mut dyn Write) -> io::Result<()> {
+        (self.run)(out)
+    }
+}
+
+/// 正在运行的小节的输出去向，没有小节在运行时为 None
+static SINK: Mutex<Option<Sender<String>>> = Mutex::new(None);
+
+/// 编进库中的示例程序里 `print!` 和 `println!` 的实现
+fn print(args: fmt::Arguments) {
+    match &*SINK.lock().unwrap_or_else(PoisonError::into_inner) {
+        // 读取的一方出错提前返回后，剩下的输出直接丢弃
+        Some(sink) => drop(sink.send(args.to_string())),
+        None => std::print!("{}", args),
+    }
+}
+
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::sections::print(format_args!($($arg)*))
+    };
+}
+
+macro_rules! println {
+    () => {
+        $crate::sections::print(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::sections::print(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// 在另一个线程中运行演示函数，同时把它的输出逐段写入 `out`，有停顿的演示也能边运行边显示。
+/// 输出去向是全局的，同一时间只运行一个小节
+fn capture(out: &mut dyn Write, demo: fn()) -> io::Result<()> {
+    static RUNNING: Mutex<()> = Mutex::new(());
+    let _running = RUNNING.lock().unwrap_or_else(PoisonError::into_inner);
+    let (sender, receiver) = mpsc::channel::<String>();
+    *SINK.lock().unwrap_or_else(PoisonError::into_inner) = Some(sender);
+
+    /// 演示函数结束（包括 panic）时关闭输出去向，读取的一方随之结束
+    struct Close;
+    impl Drop for Close {
+        fn drop(&mut self) {
+            SINK.lock().unwrap_or_else(PoisonError::into_inner).take();
+        }
+    }
+
+    thread::scope(|scope| {
+        let demo = scope.spawn(move || {
+            let _close = Close;
+            demo()
+        });
+        for text in receiver {
+            out.write_all(text.as_bytes())?;
+        }
+        match demo.join() {
+            Ok(()) => out.flush(),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
+
+// 示例程序的警告在编译二进制时已经报告过，编进库中时不再重复
+#[allow(warnings, clippy::all)]
+#[path = "bin/borrowing.rs"]
+mod borrowing;
+#[allow(warnings, clippy::all)]
+#[path = "bin/closures.rs"]
+mod closures;
+#[allow(warnings, clippy::all)]
+#[path = "bin/common_smart_pointers.rs"]
+mod common_smart_pointers;
+#[allow(warnings, clippy::all)]
+#[path = "bin/common_traits.rs"]
+mod common_traits;
+#[allow(warnings, clippy::all)]
+#[path = "bin/common_types.rs"]
+mod common_types;
+#[allow(warnings, clippy::all)]
+#[path = "bin/concurrency.rs"]
+mod concurrency;
+#[allow(warnings, clippy::all)]
+#[path = "bin/iterators.rs"]
+mod iterators;
+#[allow(warnings, clippy::all)]
+#[path = "bin/lifetimes.rs"]
+mod lifetimes;
+#[allow(warnings, clippy::all)]
+#[path = "bin/macros.rs"]
+mod macros;
+#[allow(warnings, clippy::all)]
+#[path = "bin/memory_management.rs"]
+mod memory_management;
+#[allow(warnings, clippy::all)]
+#[path = "bin/ownership.rs"]
+mod ownership;
+#[allow(warnings, clippy::all)]
+#[path = "bin/procedural_macros.rs"]
+mod procedural_macros;
+#[allow(warnings, clippy::all)]
+#[path = "bin/smart_pointers.rs"]
+mod smart_pointers;
+#[allow(warnings, clippy::all)]
+#[path = "bin/structs.rs"]
+mod structs;
+#[allow(warnings, clippy::all)]
+#[path = "bin/unsafe_rust.rs"]
+mod unsafe_rust;
+
+macro_rules! section {
+    ($module:ident :: $name:ident, $title:expr) => {
+        Section {
+            id: stringify!($name),
+            title: $title,
+            run: |out| capture(out, $module::$name),
+        }
+    };
+}
+
+/// 按独立二进制名称登记的小节，顺序与 main 中的调用顺序一致；
+/// “实际应用示例”拆成其中的几个例子
+static SECTIONS: &[(&str, &[Section])] = &[
+    (
+        "memory_management",
+        &[
+            section!(memory_management::program_execution_flow, "程序的基本执行流程"),
+            section!(memory_management::stack_and_heap, "栈与堆"),
+            section!(memory_management::pointer_types, "指针类型"),
+            section!(memory_management::function_calls, "函数调用"),
+        ],
+    ),
+    (
+        "ownership",
+        &[
+            section!(ownership::core_concepts, "目的与核心思想"),
+            section!(ownership::ownership_rules, "所有权规则"),
+            section!(ownership::verify_rules, "验证规则"),
+        ],
+    ),
+    (
+        "borrowing",
+        &[
+            section!(borrowing::reference_and_borrowing, "引用与借用规则"),
+            section!(borrowing::verify_borrowing_rules, "验证借用规则"),
+            section!(borrowing::slices_demo, "切片"),
+            section!(borrowing::dangling_references, "悬垂引用"),
+        ],
+    ),
+    (
+        "structs",
+        &[
+            section!(structs::struct_definition_and_usage, "定义与使用"),
+            section!(structs::associated_methods, "关联方法与函数"),
+            section!(structs::memory_layout, "内存布局"),
+        ],
+    ),
+    (
+        "common_types",
+        &[
+            section!(common_types::vector_demo, "Vector"),
+            section!(common_types::string_demo, "String"),
+            section!(common_types::type_comparison, "类型比较"),
+        ],
+    ),
+    (
+        "lifetimes",
+        &[
+            section!(lifetimes::basic_lifetimes, "基本生命周期概念"),
+            section!(lifetimes::function_lifetimes, "函数中的生命周期"),
+            section!(lifetimes::struct_lifetimes, "结构体中的生命周期"),
+            section!(lifetimes::lifetime_elision, "生命周期省略规则"),
+            section!(lifetimes::static_lifetimes, "静态生命周期"),
+            section!(lifetimes::advanced_lifetimes, "高级生命周期"),
+        ],
+    ),
+    (
+        "common_traits",
+        &[
+            section!(common_traits::display_debug_traits, "Display 和 Debug 特征"),
+            section!(common_traits::clone_copy_traits, "Clone 和 Copy 特征"),
+            section!(common_traits::equality_traits, "PartialEq 和 Eq 特征"),
+            section!(common_traits::ordering_traits, "PartialOrd 和 Ord 特征"),
+            section!(common_traits::hash_trait, "Hash 特征"),
+            section!(common_traits::iterator_trait, "Iterator 特征"),
+            section!(common_traits::conversion_traits, "From 和 Into 特征"),
+            section!(common_traits::default_trait, "Default 特征"),
+            section!(common_traits::drop_trait, "Drop 特征"),
+        ],
+    ),
+    (
+        "closures",
+        &[
+            section!(closures::closure_basics, "闭包基础"),
+            section!(closures::closure_syntax, "闭包语法和类型推断"),
+            section!(closures::capture_environment, "捕获环境"),
+            section!(closures::closure_traits, "闭包特征：Fn、FnMut、FnOnce"),
+            section!(closures::closures_as_parameters, "闭包作为参数"),
+            section!(closures::returning_closures, "返回闭包"),
+            section!(closures::cache_example, "实际应用：缓存/记忆化"),
+            section!(closures::event_handling_example, "实际应用：事件处理"),
+            section!(closures::functional_programming_example, "实际应用：函数式编程"),
+            section!(closures::strategy_pattern_example, "实际应用：配置和策略模式"),
+        ],
+    ),
+    (
+        "iterators",
+        &[
+            section!(iterators::iterator_basics, "迭代器基础"),
+            section!(iterators::iterator_adapters, "迭代器适配器"),
+            section!(iterators::consuming_adapters, "消费适配器"),
+            section!(iterators::custom_iterators, "自定义迭代器"),
+            section!(iterators::data_processing_pipeline, "实际应用：数据处理管道"),
+            section!(iterators::text_analysis, "实际应用：文本分析"),
+            section!(iterators::grouping_and_aggregation, "实际应用：分组和聚合"),
+        ],
+    ),
+    (
+        "smart_pointers",
+        &[
+            section!(smart_pointers::box_pointer_demo, "Box<T> - 堆分配"),
+            section!(smart_pointers::rc_pointer_demo, "Rc<T> - 引用计数"),
+            section!(smart_pointers::refcell_demo, "RefCell<T> - 内部可变性"),
+            section!(smart_pointers::rc_refcell_combo, "Rc<RefCell<T>> 组合"),
+            section!(smart_pointers::arc_demo, "Arc<T> - 原子引用计数"),
+            section!(smart_pointers::mutex_demo, "Mutex<T> - 互斥锁"),
+            section!(smart_pointers::cache_system_example, "实际应用：缓存系统"),
+            section!(smart_pointers::observer_pattern_example, "实际应用：观察者模式"),
+            section!(smart_pointers::thread_pool_example, "实际应用：线程池"),
+        ],
+    ),
+    (
+        "common_smart_pointers",
+        &[
+            section!(common_smart_pointers::cow_demo, "Cow (Clone on Write)"),
+            section!(common_smart_pointers::weak_reference_demo, "Weak 弱引用"),
+            section!(common_smart_pointers::pin_demo, "Pin 固定指针"),
+            section!(common_smart_pointers::config_system_example, "实际应用：配置系统"),
+            section!(common_smart_pointers::improved_observer_pattern, "实际应用：观察者模式改进"),
+            section!(common_smart_pointers::async_task_management, "实际应用：异步任务管理"),
+        ],
+    ),
+    (
+        "concurrency",
+        &[
+            section!(concurrency::thread_basics, "线程基础"),
+            section!(concurrency::message_passing, "消息传递"),
+            section!(concurrency::shared_state, "共享状态"),
+            section!(concurrency::atomic_operations, "原子操作"),
+            section!(concurrency::condition_variables, "条件变量"),
+            section!(concurrency::parallel_computation, "实际应用：并行计算"),
+            section!(concurrency::thread_pool_example, "实际应用：线程池"),
+            section!(concurrency::producer_consumer_queue, "实际应用：生产者消费者队列"),
+        ],
+    ),
+    (
+        "unsafe_rust",
+        &[
+            section!(unsafe_rust::unsafe_basics_demo, "Unsafe 的基本概念"),
+            section!(unsafe_rust::raw_pointers_demo, "原始指针操作"),
+            section!(unsafe_rust::unsafe_functions_demo, "不安全函数"),
+            section!(unsafe_rust::static_variables_demo, "静态变量访问"),
+            section!(unsafe_rust::safe_abstractions_demo, "创建安全抽象"),
+            section!(unsafe_rust::custom_smart_pointer_demo, "自定义智能指针"),
+            section!(unsafe_rust::high_performance_structures_demo, "高性能数据结构"),
+            section!(unsafe_rust::memory_layout_demo, "内存对齐和布局"),
+            section!(unsafe_rust::rwo_permissions_demo, "RWO 权限分析"),
+            section!(unsafe_rust::safety_practices_demo, "安全实践"),
+        ],
+    ),
+    (
+        "macros",
+        &[
+            section!(macros::macro_vs_function_demo, "宏与函数的区别"),
+            section!(macros::basic_macro_syntax_demo, "基本宏语法"),
+            section!(macros::macro_parameter_types_demo, "宏参数类型"),
+            section!(macros::repetition_patterns_demo, "重复模式宏"),
+            section!(macros::hashmap_creation_demo, "HashMap 创建宏"),
+            section!(macros::conditional_compilation_demo, "条件编译宏"),
+            section!(macros::assertion_macros_demo, "断言宏"),
+            section!(macros::logging_macros_demo, "日志宏"),
+            section!(macros::calculation_macros_demo, "计算宏"),
+            section!(macros::struct_generation_demo, "结构体生成宏"),
+            section!(macros::test_macros_demo, "测试宏"),
+            section!(macros::config_macros_demo, "配置宏"),
+        ],
+    ),
+    (
+        "procedural_macros",
+        &[
+            section!(procedural_macros::proc_macro_concepts_demo, "过程宏基本概念"),
+            section!(procedural_macros::derive_macro_demo, "派生宏演示"),
+            section!(procedural_macros::attribute_macro_demo, "属性宏演示"),
+            section!(procedural_macros::function_like_macro_demo, "函数式宏演示"),
+            section!(procedural_macros::custom_debug_demo, "Debug trait 自动实现演示"),
+            section!(procedural_macros::builder_pattern_demo, "Builder 模式演示"),
+            section!(procedural_macros::serialization_demo, "序列化宏演示"),
+            section!(procedural_macros::orm_mapping_demo, "ORM 映射演示"),
+            section!(procedural_macros::api_route_demo, "API 路由演示"),
+            section!(procedural_macros::state_machine_demo, "状态机演示"),
+            section!(procedural_macros::ownership_analysis_demo, "所有权分析演示"),
+            section!(procedural_macros::memory_safety_demo, "内存安全检查演示"),
+        ],
+    ),
+];
+
+/// 章节的小节，没有拆分时为空
+pub fn of(chapter: &Chapter) -> &'static [Section] {
+    SECTIONS
+        .iter()
+        .find(|(bin, _)| *bin == chapter.bin)
+        .map_or(&[], |(_, sections)| sections)
+}
+
+/// 按编号（从 1 开始）、标识或标题查找小节，不区分大小写，`-` 和 `_` 等同：
+/// 依次尝试编号和完整标识、标识前缀、标识或标题中包含关键词，取第一个有结果的规则。
+/// 没有找到或者有多个候选时返回 Err，其中是候选的小节
+pub fn find(chapter: &Chapter, query: &str) -> Result<&'static Section, Vec<&'static Section>> {
+    let sections = of(chapter);
+    let query = query.trim().to_lowercase().replace('-', "_");
+    if let Some(section) = query.parse::<usize>().ok().and_then(|number| number.checked_sub(1)).and_then(|index| sections.get(index)) {
+        return Ok(section);
+    }
+    if query.is_empty() {
+        return Err(Vec::new());
+    }
+    let rules: [&dyn Fn(&Section) -> bool; 3] = [
+        &|section| section.id == query,
+        &|section| section.id.starts_with(&query),
+        &|section| section.id.contains(&query) || section.title.to_lowercase().contains(&query),
+    ];
+    for rule in rules {
+        let mut candidates: Vec<&Section> = sections.iter().filter(|section| rule(section)).collect();
+        match candidates.len() {
+            0 => continue,
+            1 => return Ok(candidates.remove(0)),
+            _ => return Err(candidates),
+        }
+    }
+    Err(Vec::new())
+}
@@ -0,0 +1,1305 @@
+// 交互式菜单会话
+// 输入输出都可以替换，方便脚本回放和集成测试
+
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::chapters::{self, Chapter, Group};
+use crate::exercises::{self, Workspace};
+use crate::explain;
+use crate::flashcards::{self, Quality};
+use crate::graph;
+use crate::knowledge;
+use crate::pager::{self, Pager};
+use crate::layout::{self, Align, Panel, Table};
+use crate::playground::{self, Playground, PlaygroundCommand};
+use crate::progress::{ActivityResult, Clock, Progress, ProgressStore, ResultKind, SystemClock};
+use crate::quiz::{self, Rng};
+use crate::search;
+use crate::sections::{self, Section};
+use crate::source;
+use crate::term;
+use crate::tr;
+use crate::verify::VerifyOptions;
+
+/// 菜单方框的宽度，终端较窄时随之缩小
+fn menu_width() -> usize {
+    term::columns().map_or(layout::MENU_WIDTH, |columns| columns.min(layout::MENU_WIDTH))
+}
+
+/// 会话行为选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionOptions {
+    /// 每个页面结束后等待回车，批量运行时在章节之间停顿
+    pub pause: bool,
+    /// 切换页面时清屏
+    pub clear_screen: bool,
+}
+
+impl SessionOptions {
+    /// 终端交互：停顿并清屏
+    pub fn interactive() -> Self {
+        SessionOptions {
+            pause: true,
+            clear_screen: true,
+        }
+    }
+
+    /// 脚本回放：不停顿、不清屏
+    pub fn scripted() -> Self {
+        SessionOptions {
+            pause: false,
+            clear_screen: false,
+        }
+    }
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        SessionOptions::interactive()
+    }
+}
+
+/// 处理完一条命令后的去向
+enum Flow {
+    Continue,
+    Quit,
+}
+
+/// 一次菜单会话
+pub struct Session<R, W> {
+    input: R,
+    output: W,
+    options: SessionOptions,
+    eof: bool,
+    progress: Progress,
+    /// 未设置时不记录进度
+    store: Option<ProgressStore>,
+    /// 欢迎页上显示的进度读取警告
+    notices: Vec<String>,
+    save_failed: bool,
+    /// 记录进度和安排复习用的时间
+    clock: Box<dyn Clock>,
+    /// 章节输出超过这么多行时分页显示，未设置时直接写出
+    page_height: Option<usize>,
+}
+
+impl<R: BufRead, W: Write> Session<R, W> {
+    /// 创建终端交互会话
+    pub fn new(input: R, output: W) -> Self {
+        Session::with_options(input, output, SessionOptions::interactive())
+    }
+
+    /// 创建脚本回放会话，输入中每行是一条菜单命令
+    pub fn scripted(input: R, output: W) -> Self {
+        Session::with_options(input, output, SessionOptions::scripted())
+    }
+
+    pub fn with_options(input: R, output: W, options: SessionOptions) -> Self {
+        Session {
+            input,
+            output,
+            options,
+            eof: false,
+            progress: Progress::default(),
+            store: None,
+            notices: Vec::new(),
+            save_failed: false,
+            clock: Box::new(SystemClock),
+            page_height: None,
+        }
+    }
+
+    /// 替换时间来源，测试中用固定的时间检查复习安排
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// 章节输出超过一屏时用内置的分页器显示，`height` 是每页的行数；
+    /// 只在输出是终端时启用，重定向到文件或管道时直接写出
+    pub fn with_pager(mut self, height: usize) -> Self {
+        self.page_height = Some(height);
+        self
+    }
+
+    /// 从 `store` 读取学习进度，并在之后的每次章节运行后保存
+    pub fn with_progress(mut self, store: ProgressStore) -> Self {
+        match store.load() {
+            Ok((progress, warnings)) => {
+                self.progress = progress;
+                self.notices = warnings;
+            }
+            Err(err) => self.notices.push(tr!(
+                "session.progress_unreadable",
+                path = store.path().display(),
+                error = err
+            )),
+        }
+        self.store = Some(store);
+        self
+    }
+
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// 取回输出，测试中用于检查记录
+    pub fn into_output(self) -> W {
+        self.output
+    }
+
+    /// 运行菜单循环，直到用户退出或输入结束
+    pub fn run(&mut self) -> io::Result<()> {
+        self.show_welcome()?;
+
+        loop {
+            self.show_menu()?;
+
+            let Some(choice) = self.read_command()? else {
+                self.show_farewell()?;
+                break;
+            };
+
+            // 清屏
+            self.clear_screen()?;
+
+            match self.dispatch(&choice)? {
+                Flow::Continue => {}
+                Flow::Quit => break,
+            }
+        }
+        self.output.flush()
+    }
+
+    fn dispatch(&mut self, choice: &str) -> io::Result<Flow> {
+        // 带参数的命令，例如 `quiz 6`
+        let (command, argument) = match choice.split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, argument.trim()),
+            None => (choice, ""),
+        };
+        match command {
+            "quiz" => self.start_quiz(argument)?,
+            "knowledge" | "k" => self.show_knowledge(argument)?,
+            "run" => self.run_command(argument)?,
+            "sections" => self.show_sections(argument)?,
+            "source" => self.show_source(argument)?,
+            "goto" => self.goto(argument)?,
+            "search" => return self.search(argument),
+            "next" => {
+                show_recommendation(&mut self.output, &self.progress)?;
+                self.wait_for_enter()?
+            }
+            "path" => self.show_learning_path(argument)?,
+            "review" => self.review()?,
+            "explain" => self.explain(argument)?,
+            "exercise" => self.exercise(argument)?,
+            "playground" | "play" => self.playground()?,
+            "0" => self.run_all_examples()?,
+            "basic" => self.run_basic_examples()?,
+            "advanced" => self.run_advanced_examples()?,
+            "demo" => self.run_interactive_demo()?,
+            "help" | "h" => self.show_command_info()?,
+            "c" | "continue" if self.store.is_some() => self.continue_learning()?,
+            "exit" | "quit" | "q" => {
+                self.show_farewell()?;
+                return Ok(Flow::Quit);
+            }
+            "" => {}
+            _ => {
+                let other = choice;
+                let chapter = other.parse::<u8>().ok().and_then(chapters::get);
+                if let Some(chapter) = chapter {
+                    self.run_chapter_with_explanation(chapter)?;
+                    return Ok(Flow::Continue);
+                }
+                let max = chapters::max_id();
+                if let Ok(num) = other.parse::<usize>() {
+                    if num > max as usize {
+                        writeln!(self.output, "{}", tr!("session.out_of_range", max = max))?;
+                    } else {
+                        writeln!(self.output, "{}", tr!("session.invalid_chapter", number = num))?;
+                    }
+                } else {
+                    writeln!(self.output, "{}", tr!("session.invalid_choice", choice = other))?;
+                }
+                writeln!(self.output, "{}", tr!("session.choice_hint", max = max))?;
+                self.wait_for_enter()?;
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// 读取一行，输入结束时返回 `None`
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        if self.eof {
+            return Ok(None);
+        }
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            self.eof = true;
+            return Ok(None);
+        }
+        Ok(Some(line))
+    }
+
+    /// 读取下一条菜单命令，脚本中的空行和注释行会被跳过
+    fn read_command(&mut self) -> io::Result<Option<String>> {
+        loop {
+            let Some(line) = self.read_line()? else {
+                return Ok(None);
+            };
+            let command = line.trim();
+            if !self.options.pause && (command.is_empty() || command.starts_with('#')) {
+                continue;
+            }
+            if !self.options.pause {
+                // 回显脚本命令，让记录读起来像一次真实的会话
+                writeln!(self.output, "{}", command)?;
+            }
+            // 命令名不区分大小写，参数（如搜索词、练习名）保持原样
+            let command = match command.split_once(char::is_whitespace) {
+                Some((name, argument)) => format!("{} {}", name.to_lowercase(), argument),
+                None => command.to_lowercase(),
+            };
+            return Ok(Some(command));
+        }
+    }
+
+    fn wait_for_enter(&mut self) -> io::Result<()> {
+        if !self.options.pause {
+            return Ok(());
+        }
+        write!(self.output, "\n{}", tr!("session.press_enter"))?;
+        self.output.flush()?;
+        self.read_line()?;
+        self.clear_screen()
+    }
+
+    fn clear_screen(&mut self) -> io::Result<()> {
+        if !self.options.clear_screen {
+            return Ok(());
+        }
+        // 终端支持转义序列时直接清屏，否则（例如输出被重定向）用换行符分隔
+        if term::terminal().ansi {
+            write!(self.output, "{}", term::CLEAR)?;
+        } else {
+            write!(self.output, "\n{}", "\n".repeat(2))?;
+        }
+        Ok(())
+    }
+
+    fn show_welcome(&mut self) -> io::Result<()> {
+        let out = &mut self.output;
+        writeln!(out, "{}", tr!("welcome.title"))?;
+        writeln!(out, "=====================================")?;
+        writeln!(out, "{}", tr!("welcome.chapters", count = chapters::all().len()))?;
+        writeln!(out, "{}", tr!("welcome.examples"))?;
+        writeln!(out, "{}", tr!("welcome.start"))?;
+        writeln!(out, "{}", tr!("welcome.demo"))?;
+        if self.store.is_some() {
+            show_progress_overview(out, &self.progress)?;
+        }
+        for notice in &self.notices {
+            writeln!(out, "{}", tr!("welcome.progress_notice", notice = notice))?;
+        }
+        writeln!(out)
+    }
+
+    fn show_farewell(&mut self) -> io::Result<()> {
+        let out = &mut self.output;
+        writeln!(out, "\n{}", tr!("farewell.thanks"))?;
+        writeln!(out, "{}", tr!("farewell.hope"))?;
+        writeln!(out, "{}", tr!("farewell.more"))
+    }
+
+    fn show_menu(&mut self) -> io::Result<()> {
+        let out = &mut self.output;
+        let panel = Panel::new(menu_width());
+        writeln!(out, "{}", panel.top())?;
+        writeln!(out, "{}", panel.centered(tr!("menu.title")))?;
+        for group in Group::ALL {
+            writeln!(out, "{}", panel.divider())?;
+            writeln!(out, "{}", panel.line(group.title()))?;
+            let entries: Vec<String> = chapters::in_group(group)
+                .map(|chapter| {
+                    let mark = if self.progress.is_completed(chapter.id) { "✓" } else { "" };
+                    format!("{}. {}{}", chapter.id, chapter.localized_title(), mark)
+                })
+                .collect();
+            for row in panel.items(&entries, 1) {
+                writeln!(out, "{}", row)?;
+            }
+        }
+        writeln!(out, "{}", panel.divider())?;
+        writeln!(out, "{}", panel.line(tr!("menu.quick")))?;
+        let mut quick = vec!["menu.quick.run", "menu.quick.demo", "menu.quick.quiz", "menu.quick.search"];
+        quick.extend(["menu.quick.review", "menu.quick.exercise", "menu.quick.playground"]);
+        if self.store.is_some() {
+            quick.push("menu.quick.continue");
+        }
+        for key in quick {
+            for line in panel.paragraph(tr!(key), 1) {
+                writeln!(out, "{}", line)?;
+            }
+        }
+        writeln!(out, "{}", panel.bottom())?;
+        write!(out, "{} ", tr!("menu.prompt", max = chapters::max_id()))?;
+        out.flush()
+    }
+
+    fn run_chapter_with_explanation(&mut self, chapter: &Chapter) -> io::Result<()> {
+        let Some(height) = self.page_height else {
+            show_chapter_run(&mut self.output, chapter)?;
+            self.record_runs(&[chapter])?;
+            return self.wait_for_enter();
+        };
+        let mut captured = Vec::new();
+        show_chapter_run(&mut captured, chapter)?;
+        self.record_runs(&[chapter])?;
+        self.show_paged(&captured, height)
+    }
+
+    /// 超过一屏的输出分页显示，否则直接写出并等待回车
+    fn show_paged(&mut self, captured: &[u8], height: usize) -> io::Result<()> {
+        let mut pager = Pager::new(&String::from_utf8_lossy(captured), height);
+        if let Some(columns) = term::columns() {
+            pager = pager.wrap_to(columns);
+        }
+        if pager.fits() {
+            self.output.write_all(captured)?;
+            return self.wait_for_enter();
+        }
+        pager::run(&mut self.input, &mut self.output, &mut pager)?;
+        self.clear_screen()
+    }
+
+    /// `run N` 运行整章，`run N 小节` 只运行一节，`run N --step` 逐节运行
+    fn run_command(&mut self, argument: &str) -> io::Result<()> {
+        let mut words = argument.split_whitespace();
+        let Some(chapter) = words.next().and_then(|id| id.parse::<u8>().ok()).and_then(chapters::get) else {
+            writeln!(self.output, "{}", tr!("session.run_usage", max = chapters::max_id()))?;
+            return self.wait_for_enter();
+        };
+        let rest: Vec<&str> = words.collect();
+        match rest.as_slice() {
+            [] => self.run_chapter_with_explanation(chapter),
+            ["--step"] | ["step"] => self.step_through(chapter),
+            ["--section" | "-s", query @ ..] | query => self.run_section(chapter, &query.join(" ")),
+        }
+    }
+
+    fn run_section(&mut self, chapter: &Chapter, query: &str) -> io::Result<()> {
+        if let Some(section) = self.find_section(chapter, query)? {
+            run_section(&mut self.output, chapter, section)?;
+        }
+        self.wait_for_enter()
+    }
+
+    /// 按关键词查找小节，找不到或不唯一时写出错误
+    fn find_section(&mut self, chapter: &Chapter, query: &str) -> io::Result<Option<&'static Section>> {
+        match sections::find(chapter, query) {
+            Ok(section) => return Ok(Some(section)),
+            Err(candidates) if candidates.is_empty() => {
+                writeln!(self.output, "❌ {}", tr!("cli.error.unknown_section", id = chapter.id, section = query))?
+            }
+            Err(candidates) => {
+                let ids: Vec<&str> = candidates.iter().map(|section| section.id).collect();
+                let message = tr!("cli.error.ambiguous_section", section = query, candidates = ids.join(", "));
+                writeln!(self.output, "❌ {}", message)?
+            }
+        }
+        Ok(None)
+    }
+
+    /// 逐节运行；回放脚本时不暂停，也不读取输入
+    fn step_through(&mut self, chapter: &Chapter) -> io::Result<()> {
+        let input = if self.options.pause { Some(&mut self.input) } else { None };
+        if step_through_sections(&mut self.output, input, chapter)? {
+            self.record_runs(&[chapter])?;
+        }
+        self.wait_for_enter()
+    }
+
+    fn show_sections(&mut self, argument: &str) -> io::Result<()> {
+        match argument.parse::<u8>().ok().and_then(chapters::get) {
+            Some(chapter) => show_sections(&mut self.output, chapter)?,
+            None => writeln!(self.output, "{}", tr!("session.sections_usage", max = chapters::max_id()))?,
+        }
+        self.wait_for_enter()
+    }
+
+    /// `source N` 显示章节示例的源码，`source N 小节` 显示一个小节的源码
+    fn show_source(&mut self, argument: &str) -> io::Result<()> {
+        let (id, query) = argument.split_once(char::is_whitespace).unwrap_or((argument, ""));
+        let Some(chapter) = id.parse::<u8>().ok().and_then(chapters::get) else {
+            writeln!(self.output, "{}", tr!("session.source_usage", max = chapters::max_id()))?;
+            return self.wait_for_enter();
+        };
+        let query = query.trim();
+        let section = if query.is_empty() {
+            None
+        } else {
+            match self.find_section(chapter, query)? {
+                Some(section) => Some(section),
+                None => return self.wait_for_enter(),
+            }
+        };
+        let mut captured = Vec::new();
+        show_source(&mut captured, chapter, section)?;
+        match self.page_height {
+            Some(height) => self.show_paged(&captured, height),
+            None => {
+                self.output.write_all(&captured)?;
+                self.wait_for_enter()
+            }
+        }
+    }
+
+    fn continue_learning(&mut self) -> io::Result<()> {
+        match self.progress.continue_with() {
+            Some(chapter) => {
+                writeln!(self.output, "{}", tr!("session.continue", chapter = chapter.display_name()))?;
+                self.run_chapter_with_explanation(chapter)
+            }
+            None => {
+                writeln!(self.output, "{}", tr!("session.all_done"))?;
+                self.wait_for_enter()
+            }
+        }
+    }
+
+    fn show_knowledge(&mut self, argument: &str) -> io::Result<()> {
+        let Some(chapter) = argument.parse::<u8>().ok().and_then(chapters::get) else {
+            writeln!(self.output, "{}", tr!("session.knowledge_usage", max = chapters::max_id()))?;
+            return self.wait_for_enter();
+        };
+        if !knowledge::write_chapter_knowledge(&mut self.output, chapter.id)? {
+            writeln!(self.output, "{}", tr!("session.no_knowledge", chapter = chapter.display_name()))?;
+            show_chapter_introduction(&mut self.output, chapter)?;
+        }
+        writeln!(self.output, "{}", tr!("session.goto_hint"))?;
+        self.wait_for_enter()
+    }
+
+    /// 跟随相关主题的交叉引用
+    fn goto(&mut self, argument: &str) -> io::Result<()> {
+        let target = argument
+            .parse::<u8>()
+            .ok()
+            .filter(|id| chapters::get(*id).is_some())
+            .or_else(|| knowledge::resolve_topic(argument));
+        match target {
+            Some(id) => self.show_knowledge(&id.to_string()),
+            None => {
+                writeln!(self.output, "{}", tr!("session.goto_not_found", topic = argument))?;
+                self.wait_for_enter()
+            }
+        }
+    }
+
+    /// 全文搜索；结果有对应章节时，下一条输入可以是结果编号，用于跳转到该章的知识点
+    fn search(&mut self, query: &str) -> io::Result<Flow> {
+        if query.is_empty() {
+            writeln!(self.output, "{}", tr!("search.usage"))?;
+            self.wait_for_enter()?;
+            return Ok(Flow::Continue);
+        }
+        let hits = search::index().search(query);
+        search::write_results(&mut self.output, query, &hits, search::DEFAULT_LIMIT)?;
+        let targets: Vec<Option<u8>> = hits
+            .iter()
+            .take(search::DEFAULT_LIMIT)
+            .map(|hit| hit.document.chapter)
+            .collect();
+        if !targets.iter().any(Option::is_some) {
+            self.wait_for_enter()?;
+            return Ok(Flow::Continue);
+        }
+
+        write!(self.output, "\n{} ", tr!("search.jump_prompt"))?;
+        self.output.flush()?;
+        let Some(choice) = self.read_command()? else {
+            self.show_farewell()?;
+            return Ok(Flow::Quit);
+        };
+        if choice.is_empty() {
+            return Ok(Flow::Continue);
+        }
+        self.clear_screen()?;
+        let Ok(number) = choice.parse::<usize>() else {
+            // 不是结果编号，当作普通的菜单命令
+            return self.dispatch(&choice);
+        };
+        match number.checked_sub(1).and_then(|index| targets.get(index)).copied().flatten() {
+            Some(id) => self.show_knowledge(&id.to_string())?,
+            None => {
+                writeln!(self.output, "{}", tr!("search.jump_invalid", number = number))?;
+                self.wait_for_enter()?;
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    fn show_learning_path(&mut self, argument: &str) -> io::Result<()> {
+        match argument.parse::<u8>().ok().and_then(chapters::get) {
+            Some(chapter) => show_learning_path(&mut self.output, chapter, &self.progress)?,
+            None => writeln!(self.output, "{}", tr!("path.usage", max = chapters::max_id()))?,
+        }
+        self.wait_for_enter()
+    }
+
+    fn start_quiz(&mut self, argument: &str) -> io::Result<()> {
+        let available = quiz::chapters_with_quiz();
+        let chapter = argument.parse::<u8>().ok().filter(|id| available.contains(id));
+        let Some(chapter) = chapter else {
+            let ids: Vec<String> = available.iter().map(u8::to_string).collect();
+            writeln!(self.output, "{}", tr!("session.quiz_usage", chapters = ids.join(" ")))?;
+            return self.wait_for_enter();
+        };
+
+        let echo = !self.options.pause;
+        let report = quiz::run_quiz(&mut self.input, &mut self.output, chapter, &mut Rng::from_time(), echo)?;
+        if !report.complete {
+            self.eof = true;
+            return Ok(());
+        }
+        if self.store.is_some() {
+            self.progress.record_result(ActivityResult {
+                kind: ResultKind::Quiz,
+                chapter,
+                score: report.score,
+                total: report.total,
+                at: self.clock.now(),
+            });
+            self.save_progress()?;
+        }
+        self.wait_for_enter()
+    }
+
+    fn explain(&mut self, argument: &str) -> io::Result<()> {
+        if explain::is_error_code(argument) {
+            explain::write_code(&mut self.output, argument)?;
+        } else {
+            let codes: Vec<&str> = explain::ERRORS.iter().map(|info| info.code).collect();
+            writeln!(self.output, "{}", tr!("explain.usage", codes = codes.join(" ")))?;
+        }
+        self.wait_for_enter()
+    }
+
+    /// 改错练习：显示代码后反复重新编译练习文件，直到通过或输入 q
+    fn exercise(&mut self, argument: &str) -> io::Result<()> {
+        if argument.is_empty() {
+            exercises::write_list(&mut self.output, &self.progress)?;
+            return self.wait_for_enter();
+        }
+        let Some(exercise) = exercises::get(argument) else {
+            writeln!(self.output, "{}", tr!("exercise.unknown", id = argument))?;
+            return self.wait_for_enter();
+        };
+        let workspace = Workspace::for_store(self.store.as_ref());
+        let path = workspace.start(exercise)?;
+        exercises::write_exercise(&mut self.output, exercise, &path)?;
+
+        let options = VerifyOptions::default();
+        loop {
+            let diagnostics = match workspace.check(exercise, &options) {
+                Ok(diagnostics) => diagnostics,
+                Err(err) => {
+                    writeln!(self.output, "❌ {}", err)?;
+                    break;
+                }
+            };
+            if exercises::write_check(&mut self.output, exercise, &diagnostics)? {
+                if self.store.is_some() {
+                    exercises::record_solved(&mut self.progress, exercise, self.clock.now());
+                    self.save_progress()?;
+                }
+                break;
+            }
+            write!(self.output, "\n{} ", tr!("exercise.edit_prompt"))?;
+            self.output.flush()?;
+            match self.read_command()?.as_deref() {
+                None => return Ok(()),
+                Some("q") => break,
+                Some(_) => {}
+            }
+        }
+        self.wait_for_enter()
+    }
+
+    /// 代码试验场：逐行读取代码，以 `:` 开头的行是命令，输入 :q 返回菜单
+    fn playground(&mut self) -> io::Result<()> {
+        writeln!(self.output, "{}", tr!("playground.title"))?;
+        writeln!(self.output, "{}", "═".repeat(50))?;
+        writeln!(self.output, "{}", tr!("playground.intro"))?;
+        let mut playground = Playground::new(VerifyOptions::default());
+        loop {
+            write!(self.output, "{:>3}│ ", playground.next_line())?;
+            self.output.flush()?;
+            let Some(line) = self.read_line()? else {
+                return Ok(());
+            };
+            let line = line.trim_end_matches(['\n', '\r']);
+            if !self.options.pause {
+                writeln!(self.output, "{}", line)?;
+            }
+            let Some(command) = playground::parse_command(line) else {
+                playground.push_line(line);
+                continue;
+            };
+            let out = &mut self.output;
+            match command {
+                PlaygroundCommand::Run => match playground.run() {
+                    Ok(report) => playground::write_report(out, &report, playground.run_timeout())?,
+                    Err(err) => writeln!(out, "❌ {}", err)?,
+                },
+                PlaygroundCommand::Clear => {
+                    playground.clear();
+                    writeln!(out, "{}", tr!("playground.cleared"))?;
+                }
+                PlaygroundCommand::Load(argument) => {
+                    if let Some(example) = playground::find_example(&argument) {
+                        playground.load(example);
+                        playground::write_lines(out, playground.buffer())?;
+                        writeln!(out, "{}", tr!("playground.loaded", title = example.title))?;
+                    } else if let Ok(chapter) = argument.parse::<u8>() {
+                        if !playground::write_examples(out, chapter)? {
+                            writeln!(out, "{}", tr!("playground.no_examples", chapter = chapter))?;
+                        }
+                    } else {
+                        let ids: Vec<String> = knowledge::base().chapters().map(|id| id.to_string()).collect();
+                        writeln!(out, "{}", tr!("playground.load_usage", chapters = ids.join(" ")))?;
+                    }
+                }
+                PlaygroundCommand::Show => {
+                    if playground.items().is_empty() && playground.buffer().is_empty() {
+                        writeln!(out, "{}", tr!("playground.nothing_to_show"))?;
+                    }
+                    if !playground.items().is_empty() {
+                        writeln!(out, "{}", tr!("playground.items"))?;
+                        playground::write_lines(out, playground.items().iter().flatten())?;
+                    }
+                    if !playground.buffer().is_empty() {
+                        writeln!(out, "{}", tr!("playground.buffer"))?;
+                        playground::write_lines(out, playground.buffer())?;
+                    }
+                }
+                PlaygroundCommand::Help => writeln!(out, "{}", tr!("playground.intro"))?,
+                PlaygroundCommand::Quit => return Ok(()),
+                PlaygroundCommand::Unknown(command) => {
+                    writeln!(out, "{}", tr!("playground.unknown_command", command = command))?
+                }
+            }
+        }
+    }
+
+    /// 复习今天到期的抽认卡：先看正面回忆，再对照答案按 0-5 自评
+    fn review(&mut self) -> io::Result<()> {
+        let today = flashcards::day_of(self.clock.now());
+        let queue = flashcards::review_queue(&self.progress, today);
+        writeln!(self.output, "{}", tr!("review.title", due = queue.due.len(), new = queue.new.len()))?;
+        writeln!(self.output, "{}", "═".repeat(40))?;
+        if queue.is_empty() {
+            if self.progress.completed_count() == 0 {
+                writeln!(self.output, "{}", tr!("review.no_chapters"))?;
+            } else {
+                writeln!(self.output, "{}", tr!("review.nothing_due"))?;
+            }
+            return self.wait_for_enter();
+        }
+
+        let mut reviewed = 0;
+        for (index, card) in queue.cards().enumerate() {
+            let chapter = chapters::get(card.id.chapter).map_or_else(String::new, Chapter::display_name);
+            writeln!(
+                self.output,
+                "\n{}",
+                tr!(
+                    "review.card",
+                    number = index + 1,
+                    total = queue.len(),
+                    chapter = chapter,
+                    kind = card.id.kind.label()
+                )
+            )?;
+            writeln!(self.output, "❓ {}", card.front)?;
+            if self.options.pause {
+                write!(self.output, "{}", tr!("review.reveal_prompt"))?;
+                self.output.flush()?;
+                if self.read_line()?.is_none() {
+                    break;
+                }
+            }
+            writeln!(self.output, "💡 {}", card.back)?;
+
+            let Some(quality) = self.read_quality()? else {
+                break;
+            };
+            let state = flashcards::record_review(&mut self.progress, card.id, quality, today);
+            reviewed += 1;
+            writeln!(self.output, "{}", tr!("review.scheduled", days = state.interval))?;
+        }
+
+        if reviewed > 0 {
+            self.save_progress()?;
+        }
+        writeln!(self.output, "\n{}", "═".repeat(40))?;
+        writeln!(self.output, "{}", tr!("review.done", count = reviewed, left = queue.len() - reviewed))?;
+        self.wait_for_enter()
+    }
+
+    /// 读取 0-5 的自评，输入 q 或输入结束时返回 `None`
+    fn read_quality(&mut self) -> io::Result<Option<Quality>> {
+        loop {
+            write!(self.output, "{} ", tr!("review.rate_prompt"))?;
+            self.output.flush()?;
+            let Some(answer) = self.read_command()? else {
+                return Ok(None);
+            };
+            if answer == "q" {
+                return Ok(None);
+            }
+            match answer.parse::<u8>().ok().and_then(Quality::new) {
+                Some(quality) => return Ok(Some(quality)),
+                None => writeln!(self.output, "{}", tr!("review.invalid_rating"))?,
+            }
+        }
+    }
+
+    /// 记录章节运行并保存
+    fn record_runs(&mut self, batch: &[&Chapter]) -> io::Result<()> {
+        if self.store.is_none() {
+            return Ok(());
+        }
+        let now = self.clock.now();
+        for chapter in batch {
+            self.progress.record_run(chapter.id, now);
+        }
+        self.save_progress()
+    }
+
+    /// 保存进度；保存失败只提示一次，不打断学习
+    fn save_progress(&mut self) -> io::Result<()> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        if let Err(err) = store.save(&self.progress) {
+            if !self.save_failed {
+                self.save_failed = true;
+                let message = tr!("progress.save_failed", path = store.path().display(), error = err);
+                writeln!(self.output, "{}", message)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn run_interactive_demo(&mut self) -> io::Result<()> {
+        writeln!(self.output, "{}", tr!("demo.title"))?;
+        writeln!(self.output, "{}", "═".repeat(40))?;
+        writeln!(self.output, "{}", tr!("demo.intro"))?;
+        writeln!(self.output)?;
+
+        // 演示1：变量和可变性
+        demo_variables(&mut self.output)?;
+        self.wait_for_enter()?;
+
+        // 演示2：所有权
+        demo_ownership(&mut self.output)?;
+        self.wait_for_enter()?;
+
+        // 演示3：借用
+        demo_borrowing(&mut self.output)?;
+        self.wait_for_enter()?;
+
+        writeln!(self.output, "{}", tr!("demo.done"))?;
+        writeln!(self.output, "{}", tr!("demo.suggestion"))
+    }
+
+    fn show_command_info(&mut self) -> io::Result<()> {
+        let out = &mut self.output;
+        writeln!(out, "{}", tr!("help.title"))?;
+        writeln!(out, "═══════════════════════════════════════════")?;
+
+        let mut table = chapter_table();
+        for group in Group::ALL {
+            table.heading(&format!("\n{}:", group.title()));
+            for chapter in chapters::in_group(group) {
+                table.row(&[format!("{}.", chapter.id), chapter.localized_title().to_string(), format!("- {}", chapter.localized_brief())]);
+            }
+        }
+        for line in table.lines() {
+            writeln!(out, "{}", line)?;
+        }
+
+        writeln!(out, "\n{}", tr!("help.usage"))?;
+        writeln!(out, "  {}", tr!("help.interactive"))?;
+        writeln!(out, "    {}", tr!("help.interactive.number", max = chapters::max_id()))?;
+        for line in tr!("help.interactive.commands").lines() {
+            writeln!(out, "    {}", line)?;
+        }
+
+        writeln!(out, "\n  {}", tr!("help.standalone"))?;
+        for id in [1, 6, 19] {
+            if let Some(chapter) = chapters::get(id) {
+                let comment = tr!("help.standalone.chapter", id = chapter.id);
+                writeln!(out, "    cargo run --bin {:<18} # {}", chapter.bin, comment)?;
+            }
+        }
+
+        writeln!(out, "\n  {}", tr!("help.script"))?;
+        writeln!(out, "    cargo run -- --script commands.txt  # {}", tr!("help.script.comment"))?;
+
+        writeln!(out, "\n  {}", tr!("help.testing"))?;
+        writeln!(out, "    cargo test                    # {}", tr!("help.testing.test"))?;
+        writeln!(out, "    cargo check                   # {}", tr!("help.testing.check"))?;
+        writeln!(out, "    cargo build --release         # {}", tr!("help.testing.build"))?;
+
+        writeln!(out, "\n{}", tr!("help.path"))?;
+        // 注册表的前置关系有环时由测试报告，这里不显示阶段
+        for (index, stage) in graph::graph().stages().unwrap_or_default().iter().enumerate() {
+            let entries: Vec<String> = to_chapter_names(stage);
+            writeln!(out, "  {}", tr!("help.path.stage", stage = index + 1, chapters = entries.join("  ")))?;
+        }
+        writeln!(out, "  {}", tr!("help.path.hint"))?;
+
+        writeln!(out, "\n{}", tr!("help.extras"))?;
+        for line in tr!("help.extras.list").lines() {
+            writeln!(out, "  {}", line)?;
+        }
+
+        self.wait_for_enter()
+    }
+
+    fn run_all_examples(&mut self) -> io::Result<()> {
+        let total = chapters::all().len();
+        writeln!(self.output, "{}", tr!("batch.all.title", count = total))?;
+        writeln!(self.output, "{}", "═".repeat(50))?;
+        writeln!(self.output, "{}", tr!("batch.all.duration"))?;
+        writeln!(self.output, "{}", tr!("batch.all.intro"))?;
+        writeln!(self.output, "{}", "═".repeat(50))?;
+
+        let all: Vec<&Chapter> = chapters::all().iter().collect();
+        self.run_chapter_batch(&all, 500)?;
+
+        writeln!(self.output, "\n{}", tr!("batch.all.done", count = total))?;
+        writeln!(self.output, "{}", tr!("batch.all.mastered"))?;
+        writeln!(self.output, "{}", tr!("batch.all.docs"))?;
+        self.wait_for_enter()
+    }
+
+    fn run_basic_examples(&mut self) -> io::Result<()> {
+        writeln!(self.output, "{}", tr!("batch.basic.title"))?;
+        writeln!(self.output, "{}", "═".repeat(40))?;
+        writeln!(self.output, "{}", tr!("batch.basic.intro"))?;
+        writeln!(self.output, "{}", "─".repeat(40))?;
+
+        let basic: Vec<&Chapter> = chapters::in_group(Group::Basic).collect();
+        self.run_chapter_batch(&basic, 300)?;
+
+        writeln!(self.output, "\n{}", tr!("batch.basic.done"))?;
+        writeln!(self.output, "{}", tr!("batch.basic.next"))?;
+        writeln!(self.output, "{}", tr!("batch.basic.advanced_hint"))?;
+        self.wait_for_enter()
+    }
+
+    fn run_advanced_examples(&mut self) -> io::Result<()> {
+        writeln!(self.output, "{}", tr!("batch.advanced.title"))?;
+        writeln!(self.output, "{}", "═".repeat(40))?;
+        writeln!(self.output, "{}", tr!("batch.advanced.intro"))?;
+        writeln!(self.output, "{}", "─".repeat(40))?;
+
+        let advanced: Vec<&Chapter> = chapters::all()
+            .iter()
+            .filter(|chapter| chapter.group != Group::Basic)
+            .collect();
+        self.run_chapter_batch(&advanced, 300)?;
+
+        writeln!(self.output, "\n{}", tr!("batch.advanced.done"))?;
+        writeln!(self.output, "{}", tr!("batch.advanced.mastered"))?;
+        writeln!(self.output, "{}", tr!("batch.advanced.next"))?;
+        self.wait_for_enter()
+    }
+
+    fn run_chapter_batch(&mut self, batch: &[&Chapter], pause_ms: u64) -> io::Result<()> {
+        let pause_ms = if self.options.pause { pause_ms } else { 0 };
+        run_chapter_batch(&mut self.output, batch, pause_ms)?;
+        self.record_runs(batch)
+    }
+}
+
+/// 运行一个章节：介绍、示例、总结和相关命令
+pub fn show_chapter_run(out: &mut impl Write, chapter: &Chapter) -> io::Result<()> {
+    let name = chapter.display_name();
+
+    // 显示章节介绍
+    show_chapter_introduction(out, chapter)?;
+
+    // 运行代码示例
+    writeln!(out, "\n{}", tr!("chapter.running", chapter = name))?;
+    writeln!(out, "{}", "═".repeat(60))?;
+    chapter.write_to(out)?;
+    writeln!(out, "{}", "═".repeat(60))?;
+    writeln!(out, "{}", tr!("chapter.example_done", chapter = name))?;
+
+    // 显示知识点总结
+    show_chapter_summary(out, chapter)?;
+
+    // 显示相关命令
+    show_related_commands(out, chapter)
+}
+
+/// 列出章节示例的小节和运行方法
+pub fn show_sections(out: &mut impl Write, chapter: &Chapter) -> io::Result<()> {
+    let sections = chapter.sections();
+    if sections.is_empty() {
+        return writeln!(out, "{}", tr!("section.none", chapter = chapter.display_name(), id = chapter.id));
+    }
+    writeln!(out, "{}", tr!("section.list", chapter = chapter.display_name()))?;
+    let mut table = chapter_table();
+    for (index, section) in sections.iter().enumerate() {
+        table.row(&[format!("{}.", index + 1), section.id.to_string(), section.title.to_string()]);
+    }
+    for line in table.lines() {
+        writeln!(out, "{}", line)?;
+    }
+    writeln!(out, "\n{}", tr!("section.hint", id = chapter.id))
+}
+
+/// 运行一个小节：标题、源码，然后是演示函数的输出
+pub fn run_section(out: &mut impl Write, chapter: &Chapter, section: &Section) -> io::Result<()> {
+    let sections = chapter.sections();
+    let number = sections.iter().position(|other| other.id == section.id).map_or(1, |index| index + 1);
+    let header = tr!(
+        "section.running",
+        chapter = chapter.display_name(),
+        number = number,
+        total = sections.len(),
+        title = section.title,
+        id = section.id
+    );
+    writeln!(out, "\n{}", header)?;
+    if let Some(snippet) = source::section_snippet(chapter, section) {
+        source::write_snippet(out, &snippet)?;
+        writeln!(out, "{}", tr!("source.output"))?;
+    }
+    section.run(out)
+}
+
+/// 显示一个小节的源码；不指定小节时显示菜单中运行的章节示例。源码没有找到时返回 false
+pub fn show_source(out: &mut impl Write, chapter: &Chapter, section: Option<&Section>) -> io::Result<bool> {
+    let snippet = match section {
+        Some(section) => source::section_snippet(chapter, section),
+        None => source::chapter_snippet(chapter),
+    };
+    let Some(snippet) = snippet else {
+        writeln!(out, "{}", tr!("source.not_found", chapter = chapter.display_name()))?;
+        return Ok(false);
+    };
+    source::write_snippet(out, &snippet)?;
+    if section.is_none() {
+        writeln!(out, "\n{}", tr!("source.chapter_hint", path = chapter.source_path(), id = chapter.id))?;
+    }
+    Ok(true)
+}
+
+/// 逐节运行章节示例，每节之后等待回车，输入 q 提前结束；`input` 为 None 或输入结束时不再暂停。
+/// 没有拆分小节的章节整章运行。返回是否运行完了所有小节
+pub fn step_through_sections(out: &mut impl Write, mut input: Option<&mut impl BufRead>, chapter: &Chapter) -> io::Result<bool> {
+    let sections = chapter.sections();
+    if sections.is_empty() {
+        writeln!(out, "{}", tr!("section.whole_chapter", chapter = chapter.display_name()))?;
+        chapter.write_to(out)?;
+        return Ok(true);
+    }
+    for (index, section) in sections.iter().enumerate() {
+        run_section(out, chapter, section)?;
+        let Some(next) = sections.get(index + 1) else {
+            break;
+        };
+        let Some(reader) = input.as_mut() else {
+            continue;
+        };
+        write!(out, "\n{} ", tr!("section.step_prompt", title = next.title))?;
+        out.flush()?;
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            input = None;
+        } else if line.trim().eq_ignore_ascii_case("q") {
+            writeln!(out, "{}", tr!("section.step_stopped", count = index + 1, total = sections.len()))?;
+            return Ok(false);
+        }
+    }
+    writeln!(out, "\n{}", tr!("section.step_done", chapter = chapter.display_name(), total = sections.len()))?;
+    Ok(true)
+}
+
+/// 依次运行多个章节，`pause_ms` 为 0 时不停顿
+pub fn run_chapter_batch(out: &mut impl Write, batch: &[&Chapter], pause_ms: u64) -> io::Result<()> {
+    for (i, chapter) in batch.iter().enumerate() {
+        let name = chapter.display_name();
+        writeln!(out, "\n🔹 [{}/{}] {}", i + 1, batch.len(), name)?;
+        writeln!(out, "{}", "─".repeat(40))?;
+        chapter.write_to(out)?;
+        writeln!(out, "{}", tr!("batch.chapter_done", chapter = name))?;
+
+        if pause_ms > 0 && i < batch.len() - 1 {
+            out.flush()?;
+            thread::sleep(Duration::from_millis(pause_ms));
+        }
+    }
+    Ok(())
+}
+
+pub fn show_chapter_introduction(out: &mut impl Write, chapter: &Chapter) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("chapter.introduction"))?;
+    writeln!(out, "{}", "─".repeat(30))?;
+
+    writeln!(out, "{}", tr!("chapter.goals"))?;
+    for goal in chapter.localized_goals() {
+        writeln!(out, "  • {}", goal)?;
+    }
+    writeln!(out, "\n{}", tr!("chapter.concepts"))?;
+    for concept in chapter.localized_concepts() {
+        writeln!(out, "  • {}", concept)?;
+    }
+    Ok(())
+}
+
+pub fn show_chapter_summary(out: &mut impl Write, chapter: &Chapter) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("chapter.summary"))?;
+    writeln!(out, "{}", "─".repeat(30))?;
+
+    writeln!(out, "{}", tr!("chapter.learned"))?;
+    for point in chapter.localized_summary() {
+        writeln!(out, "  🔸 {}", point)?;
+    }
+    match chapters::next_after(chapter.id) {
+        Some(next) => {
+            writeln!(out, "\n{}", tr!("chapter.next"))?;
+            let suggestion = tr!(
+                "chapter.next.chapter",
+                id = next.id,
+                title = next.localized_title(),
+                brief = next.localized_brief()
+            );
+            writeln!(out, "  {}", suggestion)
+        }
+        None => {
+            writeln!(out, "\n{}", tr!("chapter.finished"))?;
+            writeln!(out, "  {}", tr!("chapter.finished.mastered"))?;
+            writeln!(out, "  {}", tr!("chapter.finished.explore"))
+        }
+    }
+}
+
+pub fn show_related_commands(out: &mut impl Write, chapter: &Chapter) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("chapter.commands"))?;
+    writeln!(out, "{}", "─".repeat(20))?;
+
+    writeln!(out, "{}", tr!("chapter.commands.run"))?;
+    writeln!(out, "   cargo run --bin {}", chapter.bin)?;
+    writeln!(out, "{}", tr!("chapter.commands.source"))?;
+    writeln!(out, "   cargo run -- source {}", chapter.id)?;
+    writeln!(out, "{}", tr!("chapter.commands.tutorial"))?;
+    writeln!(out, "   cat ../../{}", chapter.tutorial)
+}
+
+/// 已完成章节数和建议继续学习的章节
+pub fn show_progress_overview(out: &mut impl Write, progress: &Progress) -> io::Result<()> {
+    if progress.is_empty() {
+        return writeln!(out, "{}", tr!("progress.empty"));
+    }
+    let overview = tr!(
+        "progress.overview",
+        completed = progress.completed_count(),
+        total = chapters::all().len()
+    );
+    writeln!(out, "{}", overview)?;
+    match progress.continue_with() {
+        Some(next) => writeln!(out, "{}", tr!("progress.continue", chapter = next.display_name())),
+        None => writeln!(out, "{}", tr!("progress.all_done")),
+    }
+}
+
+/// `1. 变量与常量` 形式的章节列表
+fn to_chapter_names(ids: &[u8]) -> Vec<String> {
+    ids.iter()
+        .filter_map(|id| chapters::get(*id))
+        .map(|chapter| format!("{}. {}", chapter.id, chapter.localized_title()))
+        .collect()
+}
+
+/// 根据前置关系和学习记录推荐下一章
+pub fn show_recommendation(out: &mut impl Write, progress: &Progress) -> io::Result<()> {
+    writeln!(out, "{}", tr!("path.title"))?;
+    writeln!(out, "{}", "─".repeat(30))?;
+    let ready = graph::ready_chapters(progress);
+    let Some((next, others)) = ready.split_first() else {
+        return writeln!(out, "{}", tr!("progress.all_done"));
+    };
+    writeln!(out, "{}", tr!("path.next", chapter = next.display_name()))?;
+    writeln!(out, "   {}", next.localized_brief())?;
+    if next.prerequisites.is_empty() {
+        writeln!(out, "{}", tr!("path.no_prerequisites"))?;
+    } else {
+        let names = to_chapter_names(next.prerequisites);
+        writeln!(out, "{}", tr!("path.unlocked_by", chapters = names.join("  ")))?;
+    }
+    if !others.is_empty() {
+        let ids: Vec<u8> = others.iter().map(|chapter| chapter.id).collect();
+        writeln!(out, "{}", tr!("path.also_ready", chapters = to_chapter_names(&ids).join("  ")))?;
+    }
+    writeln!(out, "{}", tr!("path.run_hint", id = next.id))
+}
+
+/// 学到某一章的路线，标出已经学过的章节
+pub fn show_learning_path(out: &mut impl Write, target: &Chapter, progress: &Progress) -> io::Result<()> {
+    writeln!(out, "{}", tr!("path.route", chapter = target.display_name()))?;
+    writeln!(out, "{}", "─".repeat(30))?;
+    let route = graph::learning_path(target);
+    let mut remaining = 0;
+    for (index, chapter) in route.iter().enumerate() {
+        let mark = if progress.is_completed(chapter.id) {
+            "✓"
+        } else {
+            remaining += 1;
+            "○"
+        };
+        writeln!(out, "  {:>2}. {} {}", index + 1, mark, chapter.display_name())?;
+    }
+    if remaining == 0 {
+        writeln!(out, "{}", tr!("path.route_done", chapter = target.display_name()))
+    } else {
+        writeln!(out, "{}", tr!("path.route_remaining", count = remaining))
+    }
+}
+
+/// 逐章显示学习进度和测验、练习成绩
+pub fn show_progress(out: &mut impl Write, progress: &Progress) -> io::Result<()> {
+    show_progress_overview(out, progress)?;
+    let mut table = chapter_table();
+    for group in Group::ALL {
+        table.heading(&format!("\n{}:", group.title()));
+        for chapter in chapters::in_group(group) {
+            let status = match progress.chapter(chapter.id) {
+                Some(run) => tr!("progress.runs", runs = run.runs),
+                None => tr!("progress.not_started").to_string(),
+            };
+            table.row(&[format!("{}.", chapter.id), chapter.localized_title().to_string(), status]);
+        }
+    }
+    for line in table.lines() {
+        writeln!(out, "{}", line)?;
+    }
+    if !progress.results().is_empty() {
+        writeln!(out, "\n{}", tr!("progress.results"))?;
+        for result in progress.results() {
+            let line = tr!(
+                "progress.result",
+                chapter = result.chapter,
+                kind = result.kind.key(),
+                score = result.score,
+                total = result.total
+            );
+            writeln!(out, "  {}", line)?;
+        }
+    }
+    Ok(())
+}
+
+/// 按分组列出所有章节
+pub fn list_chapters(out: &mut impl Write) -> io::Result<()> {
+    let mut table = chapter_table();
+    for group in Group::ALL {
+        table.heading(&format!("{} ({})", group.title(), group.key()));
+        for chapter in chapters::in_group(group) {
+            table.row(&[format!("{}.", chapter.id), chapter.localized_title().to_string(), format!("cargo run --bin {}", chapter.bin)]);
+        }
+    }
+    for line in table.lines() {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// 章节列表：编号右对齐，标题按显示宽度对齐，最后一列按终端宽度折行
+fn chapter_table() -> Table {
+    Table::new(&[Align::Right, Align::Left, Align::Left]).indent(2).max_width(term::columns())
+}
+
+fn demo_variables(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", tr!("demo.variables.title"))?;
+    writeln!(out, "{}", "-".repeat(30))?;
+
+    writeln!(out, "{}", tr!("demo.variables.intro"))?;
+    for line in tr!("demo.variables.code").lines() {
+        writeln!(out, "   {}", line)?;
+    }
+
+    let x = 5;
+    let mut y = 10;
+
+    writeln!(out, "\n{}", tr!("demo.variables.values"))?;
+    writeln!(out, "   x = {}", x)?;
+    writeln!(out, "   y = {}", y)?;
+
+    writeln!(out, "\n{}", tr!("demo.variables.modify"))?;
+    y = 20;
+    writeln!(out, "   {}", tr!("demo.variables.modified", y = y))?;
+
+    writeln!(out, "\n{}", tr!("demo.variables.warning"))
+    // x = 10;  // 这行代码会导致编译错误
+}
+
+fn demo_ownership(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("demo.ownership.title"))?;
+    writeln!(out, "{}", "-".repeat(30))?;
+
+    writeln!(out, "{}", tr!("demo.ownership.intro"))?;
+
+    let s1 = String::from("hello");
+    writeln!(out, "   {}", tr!("demo.ownership.created", value = s1))?;
+
+    let s2 = s1; // s1 的所有权移动到 s2
+    writeln!(out, "   {}", tr!("demo.ownership.moved", value = s2))?;
+    writeln!(out, "   {}", tr!("demo.ownership.invalid"))?;
+
+    // println!("{}", s1);  // 这会导致编译错误
+
+    writeln!(out, "\n{}", tr!("demo.ownership.clone"))?;
+    let s3 = s2.clone();
+    writeln!(out, "   {}", tr!("demo.ownership.cloned", value = s3))?;
+    writeln!(out, "   {}", tr!("demo.ownership.both", s2 = s2, s3 = s3))
+}
+
+fn demo_borrowing(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "\n{}", tr!("demo.borrowing.title"))?;
+    writeln!(out, "{}", "-".repeat(30))?;
+
+    writeln!(out, "{}", tr!("demo.borrowing.intro"))?;
+
+    let s = String::from("hello world");
+    writeln!(out, "   {}", tr!("demo.borrowing.original", value = s))?;
+
+    let len = calculate_length(&s); // 借用 s
+    writeln!(out, "   {}", tr!("demo.borrowing.length", len = len))?;
+    writeln!(out, "   {}", tr!("demo.borrowing.still_valid", value = s))?;
+
+    writeln!(out, "\n{}", tr!("demo.borrowing.mutable"))?;
+    let mut s2 = String::from("hello");
+    writeln!(out, "   {}", tr!("demo.borrowing.mutable_value", value = s2))?;
+
+    change_string(&mut s2); // 可变借用
+    writeln!(out, "   {}", tr!("demo.borrowing.changed", value = s2))
+}
+
+fn calculate_length(s: &str) -> usize {
+    s.len()
+} // s 离开作用域，但因为它是借用，所以不会释放内存
+
+fn change_string(s: &mut String) {
+    s.push_str(", world");
+}
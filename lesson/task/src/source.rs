@@ -0,0 +1,157 @@
+// 源码查看
+// 章节示例的源码用 include_str! 编进二进制，按函数名取出刚刚运行的那一段，带行号显示在输出前面，
+// 不再让学习者自己去 cat 整个文件。支持颜色的终端中用 highlight 上色。
+// 函数体结束的大括号用 lexer 的记号查找，字符串、字符和注释中的括号不算。
+
+use std::io::{self, Write};
+
+use crate::chapters::Chapter;
+use crate::highlight;
+use crate::lexer::{self, Token, TokenKind};
+use crate::sections::Section;
+use crate::tr;
+
+/// 菜单和批量运行使用的精简示例
+pub const EXAMPLES_PATH: &str = "src/examples.rs";
+
+/// 编进二进制的源码，路径相对于 `lesson/task`
+const SOURCES: &[(&str, &str)] = &[
+    (EXAMPLES_PATH, include_str!("examples.rs")),
+    ("src/bin/borrowing.rs", include_str!("bin/borrowing.rs")),
+    ("src/bin/closures.rs", include_str!("bin/closures.rs")),
+    ("src/bin/common_smart_pointers.rs", include_str!("bin/common_smart_pointers.rs")),
+    ("src/bin/common_traits.rs", include_str!("bin/common_traits.rs")),
+    ("src/bin/common_types.rs", include_str!("bin/common_types.rs")),
+    ("src/bin/concurrency.rs", include_str!("bin/concurrency.rs")),
+    ("src/bin/control_flow.rs", include_str!("bin/control_flow.rs")),
+    ("src/bin/data_types.rs", include_str!("bin/data_types.rs")),
+    ("src/bin/docs_and_testing.rs", include_str!("bin/docs_and_testing.rs")),
+    ("src/bin/enums.rs", include_str!("bin/enums.rs")),
+    ("src/bin/error_handling.rs", include_str!("bin/error_handling.rs")),
+    ("src/bin/functions.rs", include_str!("bin/functions.rs")),
+    ("src/bin/generics_traits.rs", include_str!("bin/generics_traits.rs")),
+    ("src/bin/iterators.rs", include_str!("bin/iterators.rs")),
+    ("src/bin/lifetimes.rs", include_str!("bin/lifetimes.rs")),
+    ("src/bin/macros.rs", include_str!("bin/macros.rs")),
+    ("src/bin/memory_management.rs", include_str!("bin/memory_management.rs")),
+    ("src/bin/ownership.rs", include_str!("bin/ownership.rs")),
+    ("src/bin/procedural_macros.rs", include_str!("bin/procedural_macros.rs")),
+    ("src/bin/project_management.rs", include_str!("bin/project_management.rs")),
+    ("src/bin/smart_pointers.rs", include_str!("bin/smart_pointers.rs")),
+    ("src/bin/structs.rs", include_str!("bin/structs.rs")),
+    ("src/bin/trait_objects.rs", include_str!("bin/trait_objects.rs")),
+    ("src/bin/unsafe_rust.rs", include_str!("bin/unsafe_rust.rs")),
+    ("src/bin/variables.rs", include_str!("bin/variables.rs")),
+];
+
+/// 按路径取出编进二进制的源码
+pub fn embedded(path: &str) -> Option<(&'static str, &'static str)> {
+    SOURCES.iter().find(|(name, _)| *name == path).copied()
+}
+
+/// 源码中的一个函数，连同前面紧挨着的注释和属性
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub path: &'static str,
+    pub name: String,
+    /// 第一行的行号，从 1 开始
+    pub first_line: usize,
+    pub lines: Vec<&'static str>,
+}
+
+impl Snippet {
+    pub fn last_line(&self) -> usize {
+        self.first_line + self.lines.len() - 1
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// 章节在菜单中运行的示例函数（`examples.rs` 中的 `<name>_to`）
+pub fn chapter_snippet(chapter: &Chapter) -> Option<Snippet> {
+    let (path, source) = embedded(EXAMPLES_PATH)?;
+    find_function(path, source, &format!("{}_to", chapter.example.name))
+}
+
+/// 小节对应的演示函数
+pub fn section_snippet(chapter: &Chapter, section: &Section) -> Option<Snippet> {
+    let (path, source) = embedded(&chapter.source_path())?;
+    find_function(path, source, section.id)
+}
+
+/// 找出名为 `name` 的函数，优先取没有缩进的定义
+pub fn find_function(path: &'static str, source: &'static str, name: &str) -> Option<Snippet> {
+    let lines: Vec<&'static str> = source.lines().collect();
+    let defines = |line: &str| {
+        let mut rest = line.trim_start();
+        for prefix in ["pub(crate) ", "pub(super) ", "pub ", "const ", "unsafe "] {
+            rest = rest.strip_prefix(prefix).unwrap_or(rest);
+        }
+        rest.strip_prefix("fn ")
+            .and_then(|rest| rest.strip_prefix(name))
+            .is_some_and(|rest| rest.starts_with('(') || rest.starts_with('<'))
+    };
+    let start = (0..lines.len())
+        .find(|&index| !lines[index].starts_with(char::is_whitespace) && defines(lines[index]))
+        .or_else(|| (0..lines.len()).find(|&index| defines(lines[index])))?;
+    let mut first = start;
+    while first > 0 && matches!(lines[first - 1].trim_start(), line if line.starts_with("//") || line.starts_with("#[")) {
+        first -= 1;
+    }
+    let end = start + body_length(&lines[start..].join("\n")).unwrap_or(1) - 1;
+    Some(Snippet {
+        path,
+        name: name.to_string(),
+        first_line: first + 1,
+        lines: lines[first..=end.min(lines.len() - 1)].to_vec(),
+    })
+}
+
+/// 从第一行开始到函数体结束的行数；没有函数体（以分号结束）时为声明所在的行数。
+/// 函数体之前只看括号外的 `{` 和 `;`，参数类型 `[u8; 4]` 中的分号不算声明结束
+fn body_length(code: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut brackets = 0usize;
+    let mut opened = false;
+    let mut previous: Option<&Token> = None;
+    let lines = |end: usize| code[..end].matches('\n').count() + 1;
+    let tokens = lexer::tokenize(code);
+    for token in tokens.iter().filter(|token| token.kind == TokenKind::Punct) {
+        // `->` 中的 `>` 不是泛型参数的结尾
+        let arrow = previous.is_some_and(|previous| previous.text(code) == "-" && previous.span.end == token.span.start);
+        previous = Some(token);
+        match token.text(code) {
+            "(" | "[" | "<" if !opened => brackets += 1,
+            ")" | "]" if !opened => brackets = brackets.saturating_sub(1),
+            ">" if !opened && !arrow => brackets = brackets.saturating_sub(1),
+            "{" if opened || brackets == 0 => {
+                depth += 1;
+                opened = true;
+                continue;
+            }
+            "}" if opened => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(lines(token.span.end));
+                }
+            }
+            ";" if !opened && brackets == 0 => return Some(lines(token.span.end)),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 写出带路径、行号的源码；支持颜色时高亮
+pub fn write_snippet(out: &mut impl Write, snippet: &Snippet) -> io::Result<()> {
+    let header = tr!("source.header", path = snippet.path, first = snippet.first_line, last = snippet.last_line(), name = snippet.name);
+    writeln!(out, "{}", header)?;
+    let code = highlight::for_terminal(&snippet.text());
+    let width = snippet.last_line().to_string().len();
+    for (number, line) in (snippet.first_line..).zip(code.lines()) {
+        writeln!(out, "{:>width$} │ {}", number, line, width = width)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,271 @@
+// 终端输出
+// 根据终端的能力决定输出方式：清屏和光标移动使用 ANSI 转义序列，不再启动 clear/cls 子进程；
+// 状态符号（✅ ❌ ⚠️ 等）按主题上色。输出不是终端、TERM=dumb 时不写转义序列，设置了 NO_COLOR 时不上色。
+// ASCII 主题把表情符号和制表符换成纯 ASCII，适合只支持 ASCII 的终端、日志文件和读屏软件，
+// 通过 --theme ascii 或环境变量 TASK_THEME=ascii 选择，TERM=dumb 时默认使用。
+
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 清除整个屏幕和回滚区，并把光标移到左上角
+pub const CLEAR: &str = "\x1b[2J\x1b[3J\x1b[H";
+
+/// 输出主题
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// 表情符号和制表符，原样输出
+    #[default]
+    Unicode,
+    /// 只输出 ASCII 的装饰符号
+    Ascii,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Unicode, Theme::Ascii];
+
+    /// 主题名称，也是 --theme 的取值
+    pub fn code(self) -> &'static str {
+        match self {
+            Theme::Unicode => "unicode",
+            Theme::Ascii => "ascii",
+        }
+    }
+
+    /// 识别主题名称，`plain` 是 `ascii` 的别名
+    pub fn from_tag(tag: &str) -> Option<Theme> {
+        match tag.trim().to_ascii_lowercase().as_str() {
+            "plain" => Some(Theme::Ascii),
+            tag => Theme::ALL.into_iter().find(|theme| theme.code() == tag),
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// 终端的输出能力
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Terminal {
+    pub theme: Theme,
+    /// 给状态符号上色
+    pub color: bool,
+    /// 可以使用清屏、光标移动等转义序列
+    pub ansi: bool,
+}
+
+impl Terminal {
+    /// 按输出是否为终端和环境变量判断：
+    /// `TERM=dumb` 或不是终端时不用转义序列，`NO_COLOR` 非空时不上色，`TASK_THEME` 选择主题
+    pub fn detect(is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> Terminal {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+        let dumb = set("TERM").is_some_and(|term| term == "dumb");
+        let ansi = is_terminal && !dumb;
+        let theme = set("TASK_THEME")
+            .and_then(|tag| Theme::from_tag(&tag))
+            .unwrap_or(if dumb { Theme::Ascii } else { Theme::Unicode });
+        Terminal {
+            theme,
+            color: ansi && set("NO_COLOR").is_none(),
+            ansi,
+        }
+    }
+
+    /// 检测标准输出
+    pub fn from_env() -> Terminal {
+        Terminal::detect(io::stdout().is_terminal(), |name| env::var(name).ok())
+    }
+
+    /// 既不换符号也不上色时输出原样写出
+    fn is_plain(self) -> bool {
+        self.theme == Theme::Unicode && !self.color
+    }
+
+    fn bits(self) -> u8 {
+        (self.theme == Theme::Ascii) as u8 | (self.color as u8) << 1 | (self.ansi as u8) << 2
+    }
+
+    fn from_bits(bits: u8) -> Terminal {
+        Terminal {
+            theme: if bits & 1 != 0 { Theme::Ascii } else { Theme::Unicode },
+            color: bits & 2 != 0,
+            ansi: bits & 4 != 0,
+        }
+    }
+}
+
+/// 进程当前使用的输出方式，默认原样输出、不用转义序列
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_terminal(terminal: Terminal) {
+    CURRENT.store(terminal.bits(), Ordering::Relaxed);
+}
+
+pub fn terminal() -> Terminal {
+    Terminal::from_bits(CURRENT.load(Ordering::Relaxed))
+}
+
+/// 折行用的终端列数：输出是终端时读取环境变量 COLUMNS，没有时按 80 列；
+/// 输出被重定向时返回 None，不折行
+pub fn columns() -> Option<usize> {
+    terminal().ansi.then(|| size_from_env("COLUMNS", 80))
+}
+
+/// 分页用的终端行数：输出是终端时读取环境变量 LINES，没有时按 24 行；输出被重定向时返回 None
+pub fn rows() -> Option<usize> {
+    terminal().ansi.then(|| size_from_env("LINES", 24))
+}
+
+fn size_from_env(name: &str, default: usize) -> usize {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|&value| value > 0)
+        .unwrap_or(default)
+}
+
+/// 状态符号和源码高亮的颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Magenta,
+    Cyan,
+    /// 暗灰色，用于注释
+    Gray,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+            Color::Gray => "90",
+        }
+    }
+}
+
+/// 有特定含义的符号在 ASCII 主题中的写法和颜色
+const SYMBOLS: &[(char, &str, Option<Color>)] = &[
+    ('✅', "[OK]", Some(Color::Green)),
+    ('✓', "*", Some(Color::Green)),
+    ('❌', "[X]", Some(Color::Red)),
+    ('✗', "x", Some(Color::Red)),
+    ('🚫', "[X]", Some(Color::Red)),
+    ('⚠', "[!]", Some(Color::Yellow)),
+    ('💥', "[!]", Some(Color::Red)),
+    ('💡', "*", Some(Color::Cyan)),
+    ('○', "o", None),
+    ('❓', "?", None),
+    ('❔', "?", None),
+    ('•', "-", None),
+    ('→', "->", None),
+    ('←', "<-", None),
+    ('…', "...", None),
+    ('·', "-", None),
+    ('“', "\"", None),
+    ('”', "\"", None),
+    ('▶', ">", None),
+    ('─', "-", None),
+    ('═', "=", None),
+    ('│', "|", None),
+    ('▏', "|", None),
+];
+
+/// 其他表情符号在 ASCII 主题中统一写成 `*`
+fn is_pictograph(c: char) -> bool {
+    matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0x2190..=0x21FF | 0x2300..=0x23FF)
+}
+
+/// 按主题转换一段文字，需要时给状态符号上色
+pub fn render(text: &str, terminal: Terminal) -> Cow<'_, str> {
+    if terminal.is_plain() {
+        return Cow::Borrowed(text);
+    }
+    let ascii = terminal.theme == Theme::Ascii;
+    let mut rendered = String::with_capacity(text.len());
+    for c in text.chars() {
+        let symbol = SYMBOLS.iter().find(|(symbol, _, _)| *symbol == c);
+        let replacement: Cow<'_, str> = match symbol {
+            Some((_, ascii_text, _)) if ascii => Cow::Borrowed(ascii_text),
+            // 变体选择符、零宽连接符和肤色修饰只在表情符号中出现
+            None if ascii && matches!(c as u32, 0xFE0E | 0xFE0F | 0x200D | 0x1F3FB..=0x1F3FF) => continue,
+            // 其余的制表符都是拐角和交叉
+            None if ascii && ('\u{2500}'..='\u{257F}').contains(&c) => Cow::Borrowed("+"),
+            None if ascii && is_pictograph(c) => Cow::Borrowed("*"),
+            _ => Cow::Owned(c.to_string()),
+        };
+        match symbol.and_then(|(_, _, color)| *color).filter(|_| terminal.color) {
+            Some(color) => rendered.push_str(&paint(&replacement, color)),
+            None => rendered.push_str(&replacement),
+        }
+    }
+    Cow::Owned(rendered)
+}
+
+/// 用 ANSI 颜色包住一段文字
+pub fn paint(text: &str, color: Color) -> String {
+    format!("\x1b[{}m{}\x1b[0m", color.code(), text)
+}
+
+/// 写出前按主题转换文字的输出流
+pub struct Renderer<W> {
+    inner: W,
+    terminal: Terminal,
+    /// 上次写入末尾不完整的 UTF-8 字节
+    pending: Vec<u8>,
+}
+
+impl<W: Write> Renderer<W> {
+    pub fn new(inner: W, terminal: Terminal) -> Self {
+        Renderer {
+            inner,
+            terminal,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn terminal(&self) -> Terminal {
+        self.terminal
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for Renderer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.terminal.is_plain() {
+            return self.inner.write(buf);
+        }
+        self.pending.extend_from_slice(buf);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            // 末尾的多字节字符还没写完时留到下次；真正无效的字节原样写出
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(_) => {
+                self.inner.write_all(&self.pending)?;
+                self.pending.clear();
+                return Ok(buf.len());
+            }
+        };
+        let text = std::str::from_utf8(&self.pending[..valid]).expect("已检查是有效的 UTF-8");
+        self.inner.write_all(render(text, self.terminal).as_bytes())?;
+        self.pending.drain(..valid);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,329 @@
+// 知识点代码示例验证
+// 用本机的 rustc 在临时目录中编译并运行每个示例，检查预期输出和预期的编译错误。
+
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::chapters;
+use crate::knowledge::{self, CodeExample, Expectation};
+use crate::lexer;
+use crate::tr;
+
+/// 验证选项
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    /// rustc 可执行文件，默认取环境变量 `RUSTC`，否则为 `rustc`
+    pub rustc: OsString,
+    /// 编译和运行各自的超时时间
+    pub timeout: Duration,
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        VerifyOptions {
+            rustc: env::var_os("RUSTC").unwrap_or_else(|| OsString::from("rustc")),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 单个示例的验证结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// 编译失败，附带编译器输出
+    CompileError(String),
+    /// 程序运行失败（非零退出码或 panic），附带标准错误
+    RuntimeError(String),
+    OutputMismatch { expected: String, actual: String },
+    /// 预期编译失败却编译成功了
+    UnexpectedSuccess,
+    /// 编译失败了，但错误码与预期不同
+    WrongErrorCode { expected: String, actual: Vec<String> },
+    /// 编译或运行超时
+    Timeout,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        *self == Outcome::Passed
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Passed => write!(f, "{}", tr!("verify.passed")),
+            Outcome::CompileError(stderr) => write!(f, "{}\n{}", tr!("verify.compile_error"), stderr.trim_end()),
+            Outcome::RuntimeError(stderr) => write!(f, "{}\n{}", tr!("verify.runtime_error"), stderr.trim_end()),
+            Outcome::OutputMismatch { expected, actual } => {
+                writeln!(f, "{}", tr!("verify.output_mismatch"))?;
+                writeln!(f, "{}", tr!("verify.expected"))?;
+                for line in expected.lines() {
+                    writeln!(f, "  - {}", line)?;
+                }
+                writeln!(f, "{}", tr!("verify.actual"))?;
+                for line in actual.lines() {
+                    writeln!(f, "  + {}", line)?;
+                }
+                Ok(())
+            }
+            Outcome::UnexpectedSuccess => write!(f, "{}", tr!("verify.unexpected_success")),
+            Outcome::WrongErrorCode { expected, actual } => {
+                write!(f, "{}", tr!("verify.wrong_error_code", expected = expected, actual = format_codes(actual)))
+            }
+            Outcome::Timeout => write!(f, "{}", tr!("verify.timeout")),
+        }
+    }
+}
+
+fn format_codes(codes: &[String]) -> String {
+    if codes.is_empty() {
+        tr!("verify.no_error_code").to_string()
+    } else {
+        codes.join(", ")
+    }
+}
+
+/// 一个示例的验证报告
+#[derive(Debug, Clone)]
+pub struct ExampleReport {
+    pub chapter: u8,
+    pub point: String,
+    pub example: String,
+    pub outcome: Outcome,
+}
+
+/// 没有顶层 `fn main(` 的片段包进 `fn main`
+pub fn wrap_snippet(code: &str) -> String {
+    if has_main(code) {
+        return format!("{}\n", code);
+    }
+    let mut wrapped = String::from("fn main() {\n");
+    for line in code.lines() {
+        if line.is_empty() {
+            wrapped.push('\n');
+        } else {
+            wrapped.push_str("    ");
+            wrapped.push_str(line);
+            wrapped.push('\n');
+        }
+    }
+    wrapped.push_str("}\n");
+    wrapped
+}
+
+/// 花括号外是否定义了 `fn main(`；注释、字符串里的和嵌套在函数体内的都不算
+fn has_main(code: &str) -> bool {
+    let tokens: Vec<_> = lexer::tokenize(code).into_iter().filter(|token| !token.is_trivia()).collect();
+    let mut depth = 0usize;
+    for (index, token) in tokens.iter().enumerate() {
+        match token.text(code) {
+            "{" => depth += 1,
+            "}" => depth = depth.saturating_sub(1),
+            "fn" if depth == 0 => {
+                let next: Vec<&str> = tokens[index + 1..].iter().take(2).map(|token| token.text(code)).collect();
+                if next == ["main", "("] {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// 从编译器输出中提取 `error[E0382]` 形式的错误码
+pub fn error_codes(stderr: &str) -> Vec<String> {
+    let mut codes = Vec::new();
+    for part in stderr.split("error[").skip(1) {
+        if let Some(code) = part.split(']').next() {
+            if !codes.iter().any(|known| known == code) {
+                codes.push(code.to_string());
+            }
+        }
+    }
+    codes
+}
+
+/// 比较输出时忽略行尾空白和末尾空行
+fn normalize_output(output: &str) -> String {
+    let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_end().to_string()
+}
+
+/// 每次验证使用独立的临时目录
+pub(crate) fn scratch_dir() -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    env::temp_dir().join(format!("task-verify-{}-{}", std::process::id(), n))
+}
+
+/// 子进程的结束状态
+pub(crate) enum Finished {
+    Exited { success: bool, stdout: String, stderr: String },
+    TimedOut,
+}
+
+/// 等待子进程结束，超时后杀掉它
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> io::Result<Finished> {
+    // 在后台读取管道，防止输出过多时子进程阻塞在写入上
+    let stdout = child.stdout.take().map(spawn_reader);
+    let stderr = child.stderr.take().map(spawn_reader);
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let collect = |reader: Option<thread::JoinHandle<String>>| {
+        reader.map(|handle| handle.join().unwrap_or_default()).unwrap_or_default()
+    };
+    let (stdout, stderr) = (collect(stdout), collect(stderr));
+    Ok(match status {
+        Some(status) => Finished::Exited {
+            success: status.success(),
+            stdout,
+            stderr,
+        },
+        None => Finished::TimedOut,
+    })
+}
+
+fn spawn_reader(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// 运行子进程并收集输出，超时后杀掉它
+pub(crate) fn run(command: &mut Command, timeout: Duration) -> io::Result<Finished> {
+    let child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    wait_with_timeout(child, timeout)
+}
+
+/// 验证一个示例；找不到 rustc 等环境问题以 `Err` 返回
+pub fn verify_example(example: &CodeExample, options: &VerifyOptions) -> io::Result<Outcome> {
+    let dir = scratch_dir();
+    fs::create_dir_all(&dir)?;
+    let result = verify_in(&dir, example, options);
+    let _ = fs::remove_dir_all(&dir);
+    result
+}
+
+fn verify_in(dir: &Path, example: &CodeExample, options: &VerifyOptions) -> io::Result<Outcome> {
+    let source = dir.join("main.rs");
+    let binary = dir.join(if cfg!(windows) { "main.exe" } else { "main" });
+    fs::write(&source, wrap_snippet(&example.code))?;
+
+    let compiled = run(
+        Command::new(&options.rustc)
+            .args(["--edition", "2021", "-A", "warnings", "--color", "never", "-o"])
+            .arg(&binary)
+            .arg(&source)
+            .current_dir(dir),
+        options.timeout,
+    )
+    .map_err(|err| io::Error::new(err.kind(), tr!("verify.rustc_failed", rustc = format!("{:?}", options.rustc), error = err)))?;
+
+    let (compile_ok, compile_stderr) = match compiled {
+        Finished::TimedOut => return Ok(Outcome::Timeout),
+        Finished::Exited { success, stderr, .. } => (success, stderr),
+    };
+
+    match (&example.expect, compile_ok) {
+        (Expectation::CompileFail { .. }, true) => return Ok(Outcome::UnexpectedSuccess),
+        (Expectation::CompileFail { code }, false) => {
+            let actual = error_codes(&compile_stderr);
+            return Ok(match code {
+                Some(code) if !actual.contains(code) => Outcome::WrongErrorCode {
+                    expected: code.clone(),
+                    actual,
+                },
+                _ => Outcome::Passed,
+            });
+        }
+        (Expectation::Runs, false) => return Ok(Outcome::CompileError(compile_stderr)),
+        (Expectation::Runs, true) => {}
+    }
+
+    let ran = run(Command::new(&binary).current_dir(dir), options.timeout)?;
+    Ok(match ran {
+        Finished::TimedOut => Outcome::Timeout,
+        Finished::Exited { success: false, stderr, .. } => Outcome::RuntimeError(stderr),
+        Finished::Exited { stdout, .. } => match &example.output {
+            Some(expected) if normalize_output(expected) != normalize_output(&stdout) => {
+                Outcome::OutputMismatch {
+                    expected: expected.clone(),
+                    actual: stdout,
+                }
+            }
+            _ => Outcome::Passed,
+        },
+    })
+}
+
+/// 验证一章的全部示例
+pub fn verify_chapter(chapter: u8, options: &VerifyOptions) -> io::Result<Vec<ExampleReport>> {
+    let mut reports = Vec::new();
+    for point in knowledge::base().chapter(chapter).unwrap_or_default() {
+        for example in &point.code_examples {
+            reports.push(ExampleReport {
+                chapter,
+                point: point.title.clone(),
+                example: example.title.clone(),
+                outcome: verify_example(example, options)?,
+            });
+        }
+    }
+    Ok(reports)
+}
+
+/// 验证指定章节（默认全部有知识点的章节）并写出报告，返回是否全部通过
+pub fn run_verify(out: &mut impl Write, chapter: Option<u8>, options: &VerifyOptions) -> io::Result<bool> {
+    let ids: Vec<u8> = match chapter {
+        Some(id) => vec![id],
+        None => knowledge::base().chapters().collect(),
+    };
+
+    let (mut passed, mut total) = (0, 0);
+    for id in ids {
+        let name = chapters::get(id).map_or_else(|| tr!("verify.unknown_chapter", id = id), |chapter| chapter.display_name());
+        let reports = verify_chapter(id, options)?;
+        writeln!(out, "{}", tr!("verify.chapter", chapter = name, count = reports.len()))?;
+        for report in &reports {
+            total += 1;
+            if report.outcome.passed() {
+                passed += 1;
+                writeln!(out, "  ✅ {} / {}", report.point, report.example)?;
+            } else {
+                writeln!(out, "  {}", tr!("verify.example_failed", point = report.point, example = report.example, outcome = report.outcome))?;
+            }
+        }
+    }
+
+    writeln!(out, "\n{}", tr!("verify.summary", passed = passed, total = total))?;
+    Ok(passed == total)
+}
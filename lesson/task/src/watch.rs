@@ -0,0 +1,309 @@
+// 监视模式
+// 定时查看目录中练习文件的修改时间（只用标准库，不依赖文件系统通知），文件保存后重新检查：
+// 文件名是改错练习编号的（例如 06_use_after_move.rs）重新编译，是评分题编号的（例如 rs-062.rs）用隐藏测试评分。
+// 编辑器保存时常常连续写几次文件，检测到修改后要等文件一段时间不再变化才检查。
+// 当前练习通过后自动切换到下一个未完成的练习，目录中的练习全部完成时结束。
+// 找不到 rustc、编译超时、目录暂时读不到等错误显示出来后继续监视，不结束会话。
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::exercises::{self, Exercise};
+use crate::explain::{self, Diagnostic};
+use crate::grader::{self, Assignment, GradeReport, TestOutcome};
+use crate::tr;
+use crate::verify::VerifyOptions;
+
+/// 监视的间隔和编译选项
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// 查看修改时间的间隔
+    pub interval: Duration,
+    /// 文件在这段时间内不再变化才开始检查
+    pub debounce: Duration,
+    pub verify: VerifyOptions,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        WatchOptions {
+            interval: Duration::from_millis(500),
+            debounce: Duration::from_millis(300),
+            verify: VerifyOptions::default(),
+        }
+    }
+}
+
+/// 目录中可以检查的练习文件
+#[derive(Debug, Clone, Copy)]
+pub enum Target {
+    /// 改错练习，编译通过即为完成
+    Exercise(&'static Exercise),
+    /// 评分题，隐藏测试全部通过即为完成
+    Assignment(&'static Assignment),
+}
+
+impl Target {
+    /// 按文件名找到对应的练习，例如 `06_use_after_move.rs` 或 `rs-062.rs`
+    pub fn from_path(path: &Path) -> Option<Target> {
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            return None;
+        }
+        let stem = path.file_stem()?.to_str()?;
+        match exercises::all().iter().find(|exercise| exercise.id == stem) {
+            Some(exercise) => Some(Target::Exercise(exercise)),
+            None => grader::get(stem).map(Target::Assignment),
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Target::Exercise(exercise) => &exercise.id,
+            Target::Assignment(assignment) => &assignment.id,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Target::Exercise(exercise) => &exercise.title,
+            Target::Assignment(assignment) => &assignment.title,
+        }
+    }
+}
+
+/// 一次检查的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// 改错练习剩下的编译错误，为空时完成
+    Compiled(Vec<Diagnostic>),
+    Graded(GradeReport),
+    /// 无法完成检查（找不到 rustc、编译超时等），附带错误信息
+    Failed(String),
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        match self {
+            Check::Compiled(diagnostics) => diagnostics.is_empty(),
+            Check::Graded(report) => report.passed(),
+            Check::Failed(_) => false,
+        }
+    }
+}
+
+/// 重新编译或评分一个练习文件
+pub fn check(target: Target, path: &Path, options: &VerifyOptions) -> io::Result<Check> {
+    match target {
+        Target::Exercise(_) => explain::compile(path, options).map(Check::Compiled),
+        Target::Assignment(assignment) => grader::grade(assignment, path, options).map(Check::Graded),
+    }
+}
+
+/// 记录目录中 `.rs` 文件的修改时间和大小，找出上次查看之后变化的文件
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    dir: PathBuf,
+    seen: BTreeMap<PathBuf, (SystemTime, u64)>,
+}
+
+impl Watcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Watcher {
+            dir: dir.into(),
+            seen: BTreeMap::new(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 新出现、修改过或删除了的文件，按文件名排序；第一次调用返回全部文件
+    pub fn scan(&mut self) -> io::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+        let mut present = BTreeSet::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "rs") {
+                continue;
+            }
+            present.insert(path.clone());
+            // 文件可能正在被编辑器替换，读不到时留到下次
+            let Ok(metadata) = fs::metadata(&path) else {
+                continue;
+            };
+            let stamp = (metadata.modified()?, metadata.len());
+            if self.seen.get(&path) != Some(&stamp) {
+                self.seen.insert(path.clone(), stamp);
+                changed.push(path);
+            }
+        }
+        let removed: Vec<PathBuf> = self.seen.keys().filter(|path| !present.contains(*path)).cloned().collect();
+        for path in removed {
+            self.seen.remove(&path);
+            changed.push(path);
+        }
+        changed.sort();
+        Ok(changed)
+    }
+
+    /// 等到有文件变化，并且在 `debounce` 内不再变化，返回这期间变化过的文件
+    pub fn wait(&mut self, options: &WatchOptions) -> io::Result<Vec<PathBuf>> {
+        let mut changed = loop {
+            thread::sleep(options.interval);
+            let changed = self.scan()?;
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        loop {
+            thread::sleep(options.debounce);
+            let more = self.scan()?;
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+}
+
+/// 监视目录直到其中的练习全部完成；目录中没有练习时返回 false。
+/// 每完成一个练习调用一次 `solved`，由调用方记录学习进度
+pub fn run(
+    out: &mut impl Write,
+    watcher: &mut Watcher,
+    options: &WatchOptions,
+    mut solved: impl FnMut(Target),
+) -> io::Result<bool> {
+    let mut files: BTreeMap<PathBuf, (Target, Check)> = BTreeMap::new();
+    let mut changed = watcher.scan()?;
+    changed.retain(|path| Target::from_path(path).is_some());
+    if changed.is_empty() {
+        writeln!(out, "{}", tr!("watch.empty", dir = watcher.dir().display()))?;
+        return Ok(false);
+    }
+    writeln!(out, "{}", tr!("watch.started", dir = watcher.dir().display(), count = changed.len()))?;
+    let mut current: Option<PathBuf> = None;
+
+    loop {
+        let mut current_changed = false;
+        for path in changed {
+            let Some(target) = Target::from_path(&path) else {
+                continue;
+            };
+            // 检查前文件可能已经被删除
+            if !path.exists() {
+                files.remove(&path);
+                continue;
+            }
+            let result = check(target, &path, &options.verify).unwrap_or_else(|err| Check::Failed(err.to_string()));
+            write_status(out, target, &result)?;
+            let was_solved = files.get(&path).is_some_and(|(_, previous)| previous.passed());
+            if result.passed() && !was_solved {
+                solved(target);
+            }
+            current_changed |= current.as_ref() == Some(&path);
+            files.insert(path, (target, result));
+        }
+
+        // 练习文件都被删除了
+        if files.is_empty() {
+            writeln!(out, "{}", tr!("watch.empty", dir = watcher.dir().display()))?;
+            return Ok(false);
+        }
+        let Some((next, (target, result))) = files.iter().find(|(_, (_, result))| !result.passed()) else {
+            writeln!(out, "\n{}", tr!("watch.all_solved", count = files.len()))?;
+            return Ok(true);
+        };
+        if current.as_ref() != Some(next) {
+            writeln!(out, "\n{}", tr!("watch.current", id = target.id(), title = target.title(), path = next.display()))?;
+            write_details(out, *target, result)?;
+            current = Some(next.clone());
+        } else if current_changed {
+            write_details(out, *target, result)?;
+        }
+        writeln!(out, "\n{}", tr!("watch.waiting"))?;
+        out.flush()?;
+        changed = wait(out, watcher, options)?;
+    }
+}
+
+/// 等待文件变化；查看目录出错时显示错误并继续等待，同样的错误只显示一次
+fn wait(out: &mut impl Write, watcher: &mut Watcher, options: &WatchOptions) -> io::Result<Vec<PathBuf>> {
+    let mut reported = None;
+    loop {
+        match watcher.wait(options) {
+            Ok(changed) => return Ok(changed),
+            Err(err) => {
+                let message = err.to_string();
+                if reported.as_ref() != Some(&message) {
+                    writeln!(out, "{}", tr!("watch.scan_failed", dir = watcher.dir().display(), error = message))?;
+                    out.flush()?;
+                    reported = Some(message);
+                }
+            }
+        }
+    }
+}
+
+/// 每个练习一行的检查结果
+fn write_status(out: &mut impl Write, target: Target, check: &Check) -> io::Result<()> {
+    let status = match check {
+        Check::Compiled(diagnostics) if diagnostics.is_empty() => format!("✅ {}", tr!("watch.compiled")),
+        Check::Compiled(diagnostics) => format!("🔧 {}", tr!("watch.compile_errors", count = diagnostics.len())),
+        Check::Graded(report) if !report.missing_api.is_empty() => {
+            format!("🔍 {}", tr!("watch.missing_api", count = report.missing_api.len()))
+        }
+        Check::Graded(report) if !report.compile_errors.is_empty() => {
+            format!("🚫 {}", tr!("watch.compile_errors", count = report.compile_errors.len()))
+        }
+        Check::Graded(report) => {
+            let mark = if report.passed() { "✅" } else { "❌" };
+            format!("{} {}", mark, tr!("watch.tests", passed = report.passed_count(), total = report.tests.len()))
+        }
+        Check::Failed(error) => format!("⚠️  {}", tr!("watch.check_failed", error = error)),
+    };
+    writeln!(out, "  {:<20} {}", target.id(), status)
+}
+
+/// 当前练习没有通过时的错误或失败的测试
+fn write_details(out: &mut impl Write, target: Target, check: &Check) -> io::Result<()> {
+    match check {
+        Check::Compiled(diagnostics) => {
+            explain::write_errors(out, diagnostics)?;
+            if let Target::Exercise(Exercise { hint: Some(hint), .. }) = target {
+                writeln!(out, "\n💡 {}", hint)?;
+            }
+        }
+        Check::Graded(report) if !report.missing_api.is_empty() => {
+            for item in &report.missing_api {
+                writeln!(out, "     {}", item)?;
+            }
+        }
+        Check::Graded(report) if !report.compile_errors.is_empty() => explain::write_errors(out, &report.compile_errors)?,
+        // 错误已经显示在状态行中
+        Check::Failed(_) => {}
+        Check::Graded(report) => {
+            for test in &report.tests {
+                match &test.outcome {
+                    TestOutcome::Passed => {}
+                    TestOutcome::Failed(message) => {
+                        writeln!(out, "     ❌ {}", test.name)?;
+                        for line in message.lines() {
+                            writeln!(out, "        {}", line)?;
+                        }
+                    }
+                    TestOutcome::TimedOut => writeln!(out, "     ⏱️  {} {}", test.name, TestOutcome::TimedOut)?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use task::chapters::{self, Group};
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn every_bin_is_registered() {
+    let bin_dir = manifest_dir().join("src/bin");
+    for entry in fs::read_dir(&bin_dir).expect("src/bin 目录应存在") {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let bin = path.file_stem().unwrap().to_str().unwrap();
+        assert!(
+            chapters::by_bin(bin).is_some(),
+            "src/bin/{}.rs 没有在 chapters::CHAPTERS 中登记",
+            bin
+        );
+    }
+}
+
+#[test]
+fn every_example_is_registered() {
+    let source = fs::read_to_string(manifest_dir().join("src/examples.rs")).unwrap();
+    let registered: HashSet<&str> = chapters::all()
+        .iter()
+        .map(|chapter| chapter.example.name)
+        .collect();
+    for line in source.lines() {
+        let Some(rest) = line.strip_prefix("pub fn ") else {
+            continue;
+        };
+        let name = rest.split('(').next().unwrap();
+        // `xxx_to` 是写入任意输出的版本，和 `xxx` 登记为同一个示例
+        let name = name.strip_suffix("_to").unwrap_or(name);
+        assert!(
+            registered.contains(name),
+            "examples::{} 没有在 chapters::CHAPTERS 中登记",
+            name
+        );
+    }
+}
+
+#[test]
+fn registered_paths_exist() {
+    let repo_root = manifest_dir().join("../..");
+    for chapter in chapters::all() {
+        assert!(
+            manifest_dir().join(chapter.source_path()).is_file(),
+            "第{}章的二进制 {} 不存在",
+            chapter.id,
+            chapter.bin
+        );
+        assert!(
+            repo_root.join(chapter.tutorial).is_file(),
+            "第{}章的教程 {} 不存在",
+            chapter.id,
+            chapter.tutorial
+        );
+    }
+}
+
+#[test]
+fn ids_and_bins_are_unique() {
+    let mut ids = HashSet::new();
+    let mut bins = HashSet::new();
+    for chapter in chapters::all() {
+        assert!(ids.insert(chapter.id), "章节编号 {} 重复", chapter.id);
+        assert!(bins.insert(chapter.bin), "二进制 {} 重复", chapter.bin);
+        assert!(!chapter.goals.is_empty(), "第{}章缺少学习目标", chapter.id);
+        assert!(!chapter.summary.is_empty(), "第{}章缺少知识点总结", chapter.id);
+    }
+    assert_eq!(ids.len() as u8, chapters::max_id());
+}
+
+#[test]
+fn every_group_has_chapters() {
+    for group in Group::ALL {
+        assert!(chapters::in_group(group).next().is_some());
+        assert_eq!(Group::from_key(group.key()), Some(group));
+    }
+}
@@ -0,0 +1,178 @@
+use std::process::Command as Process;
+
+use task::chapters::Group;
+use task::cli::{self, CliError, Command, ExerciseCommand, ExplainTarget, GradeTarget, QuizArgs, RunTarget, SearchArgs};
+use task::i18n::Locale;
+use task::progress::PROFILE_DIR_VAR;
+use task::term::Theme;
+
+fn parse(args: &[&str]) -> Result<Command, CliError> {
+    cli::parse(args.iter().map(|arg| arg.to_string())).map(|invocation| invocation.command)
+}
+
+#[test]
+fn no_arguments_is_interactive() {
+    assert_eq!(parse(&[]), Ok(Command::Interactive));
+}
+
+#[test]
+fn parses_subcommands() {
+    assert_eq!(parse(&["list"]), Ok(Command::List));
+    assert_eq!(parse(&["progress"]), Ok(Command::Progress));
+    assert_eq!(parse(&["verify"]), Ok(Command::Verify(None)));
+    assert_eq!(parse(&["verify", "6"]), Ok(Command::Verify(Some(6))));
+    assert_eq!(parse(&["next"]), Ok(Command::Next));
+    assert_eq!(parse(&["path", "19"]), Ok(Command::Path(19)));
+    assert_eq!(parse(&["path", "99"]), Err(CliError::UnknownChapter("99".to_string())));
+    assert_eq!(parse(&["graph"]), Ok(Command::Graph));
+    assert_eq!(parse(&["explain", "e0382"]), Ok(Command::Explain(ExplainTarget::Code("E0382".to_string()))));
+    assert_eq!(parse(&["explain", "-"]), Ok(Command::Explain(ExplainTarget::Stdin)));
+    assert_eq!(
+        parse(&["explain", "src/main.rs"]),
+        Ok(Command::Explain(ExplainTarget::File("src/main.rs".into())))
+    );
+    assert_eq!(parse(&["explain"]), Err(CliError::MissingArgument("cli.argument.explain")));
+    assert_eq!(parse(&["exercise"]), Ok(Command::Exercise(ExerciseCommand::List)));
+    assert_eq!(
+        parse(&["exercise", "use_after_move"]),
+        Ok(Command::Exercise(ExerciseCommand::Start("06_use_after_move".to_string())))
+    );
+    assert_eq!(
+        parse(&["exercise", "check", "07_mut_while_shared.rs"]),
+        Ok(Command::Exercise(ExerciseCommand::Check("07_mut_while_shared".to_string())))
+    );
+    assert_eq!(
+        parse(&["exercise", "verify", "cases"]),
+        Ok(Command::Exercise(ExerciseCommand::Verify(Some("cases".into()))))
+    );
+    assert_eq!(parse(&["exercise", "reset"]), Err(CliError::MissingArgument("cli.argument.exercise")));
+    assert_eq!(parse(&["exercise", "nope"]), Err(CliError::UnknownExercise("nope".to_string())));
+    assert_eq!(parse(&["grade", "list"]), Ok(Command::Grade(GradeTarget::List)));
+    assert_eq!(
+        parse(&["grade", "RS-031", "answer.rs"]),
+        Ok(Command::Grade(GradeTarget::Submission {
+            id: "rs-031".to_string(),
+            file: "answer.rs".into()
+        }))
+    );
+    assert_eq!(parse(&["grade"]), Err(CliError::MissingArgument("cli.argument.assignment")));
+    assert_eq!(parse(&["grade", "rs-031"]), Err(CliError::MissingArgument("cli.argument.submission")));
+    assert_eq!(parse(&["grade", "rs-999", "a.rs"]), Err(CliError::UnknownAssignment("rs-999".to_string())));
+    assert!(matches!(parse(&["grade", "rs-017", "a.rs"]), Err(CliError::NoGrader { id, .. }) if id == "rs-017"));
+    assert_eq!(parse(&["watch"]), Ok(Command::Watch(None)));
+    assert_eq!(parse(&["watch", "answers"]), Ok(Command::Watch(Some("answers".into()))));
+    assert_eq!(parse(&["help"]), Ok(Command::Help));
+    assert_eq!(parse(&["--help"]), Ok(Command::Help));
+    assert_eq!(parse(&["run", "19"]), Ok(Command::Run(RunTarget::Chapter(19))));
+    assert_eq!(
+        parse(&["run", "--group", "basic"]),
+        Ok(Command::Run(RunTarget::Group(Group::Basic)))
+    );
+    assert_eq!(parse(&["run", "--all"]), Ok(Command::Run(RunTarget::All)));
+    assert_eq!(parse(&["summary", "6"]), Ok(Command::Summary(6)));
+    assert_eq!(parse(&["knowledge", "1"]), Ok(Command::Knowledge(1)));
+    assert_eq!(
+        parse(&["quiz", "3", "--seed", "7", "--answers", "a.txt"]),
+        Ok(Command::Quiz(QuizArgs {
+            chapter: 3,
+            answers: Some("a.txt".into()),
+            seed: Some(7),
+        }))
+    );
+    assert_eq!(
+        parse(&["search", "Rc::downgrade", "--open", "弱引用", "--limit", "3"]),
+        Ok(Command::Search(SearchArgs {
+            query: "Rc::downgrade 弱引用".into(),
+            limit: 3,
+            open: true,
+        }))
+    );
+    assert_eq!(
+        parse(&["--script", "menu.txt"]),
+        Ok(Command::Script("menu.txt".into()))
+    );
+}
+
+#[test]
+fn profile_dir_can_appear_anywhere() {
+    let invocation = cli::parse(["run", "--profile-dir", "/tmp/learner", "6"].map(String::from)).unwrap();
+    assert_eq!(invocation.command, Command::Run(RunTarget::Chapter(6)));
+    assert_eq!(invocation.options.profile_dir, Some("/tmp/learner".into()));
+
+    assert_eq!(parse(&["--profile-dir"]), Err(CliError::MissingArgument("cli.argument.profile_dir")));
+}
+
+#[test]
+fn lang_option_selects_locale() {
+    let invocation = cli::parse(["list", "--lang", "en_US.UTF-8"].map(String::from)).unwrap();
+    assert_eq!(invocation.command, Command::List);
+    assert_eq!(invocation.options.lang, Some(Locale::En));
+
+    assert_eq!(parse(&["--lang"]), Err(CliError::MissingArgument("cli.argument.lang")));
+    assert_eq!(parse(&["--lang", "fr", "list"]), Err(CliError::UnknownLanguage("fr".into())));
+}
+
+#[test]
+fn theme_option_selects_theme() {
+    let invocation = cli::parse(["--theme", "ASCII", "list"].map(String::from)).unwrap();
+    assert_eq!((invocation.command, invocation.options.theme), (Command::List, Some(Theme::Ascii)));
+    let invocation = cli::parse(["list", "--theme", "plain"].map(String::from)).unwrap();
+    assert_eq!(invocation.options.theme, Some(Theme::Ascii));
+    assert_eq!(cli::parse(["list".to_string()]).unwrap().options.theme, None);
+
+    assert_eq!(parse(&["list", "--theme"]), Err(CliError::MissingArgument("cli.argument.theme")));
+    assert_eq!(parse(&["--theme", "neon"]), Err(CliError::UnknownTheme("neon".into())));
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    assert_eq!(parse(&["bogus"]), Err(CliError::UnknownCommand("bogus".into())));
+    assert_eq!(parse(&["run"]), Err(CliError::MissingArgument("cli.argument.chapter")));
+    assert_eq!(parse(&["run", "99"]), Err(CliError::UnknownChapter("99".into())));
+    assert_eq!(parse(&["run", "--group"]), Err(CliError::MissingArgument("cli.argument.group")));
+    assert_eq!(
+        parse(&["run", "--group", "expert"]),
+        Err(CliError::UnknownGroup("expert".into()))
+    );
+    assert_eq!(parse(&["list", "extra"]), Err(CliError::UnexpectedArgument("extra".into())));
+    assert_eq!(parse(&["quiz", "1", "--seed", "x"]), Err(CliError::InvalidNumber("x".into())));
+    assert_eq!(parse(&["search"]), Err(CliError::MissingArgument("cli.argument.query")));
+    assert_eq!(parse(&["search", "rc", "--limit"]), Err(CliError::MissingArgument("cli.argument.limit")));
+    assert_eq!(CliError::MissingArgument("cli.argument.chapter").to_string(), "缺少参数：章节编号");
+}
+
+fn run_binary(args: &[&str]) -> (i32, String) {
+    let output = Process::new(env!("CARGO_BIN_EXE_task"))
+        // 固定界面语言，不受运行测试的终端设置影响
+        .env("LANG", "zh_CN.UTF-8")
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .args(args)
+        .env(
+            PROFILE_DIR_VAR,
+            std::env::temp_dir().join(format!("task-cli-profile-{}", std::process::id())),
+        )
+        .output()
+        .expect("无法启动 task 二进制");
+    (
+        output.status.code().unwrap_or(-1),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+#[test]
+fn binary_exit_codes() {
+    let (code, stdout) = run_binary(&["list"]);
+    assert_eq!(code, cli::EXIT_OK);
+    assert!(stdout.contains("变量与常量"));
+
+    let (code, stdout) = run_binary(&["run", "1"]);
+    assert_eq!(code, cli::EXIT_OK);
+    assert!(stdout.contains("第1章：变量与常量 示例运行完成"));
+
+    let (code, _) = run_binary(&["run", "0"]);
+    assert_eq!(code, cli::EXIT_USAGE);
+
+    let (code, _) = run_binary(&["knowledge", "20"]);
+    assert_eq!(code, cli::EXIT_FAILURE);
+}
@@ -0,0 +1,42 @@
+use task::chapters;
+use task::examples;
+
+fn capture(example: fn(&mut dyn std::io::Write) -> std::io::Result<()>) -> String {
+    let mut buf = Vec::new();
+    example(&mut buf).expect("写入内存缓冲区不应失败");
+    String::from_utf8(buf).expect("示例输出应为 UTF-8")
+}
+
+#[test]
+fn every_chapter_writes_to_the_sink() {
+    for chapter in chapters::all() {
+        let mut buf = Vec::new();
+        chapter.write_to(&mut buf).unwrap();
+        assert!(!buf.is_empty(), "第{}章没有任何输出", chapter.id);
+    }
+}
+
+#[test]
+fn helper_functions_write_to_the_sink() {
+    let memory = capture(examples::memory_management_to);
+    assert!(memory.contains("      ✨ 在最深层函数中"));
+
+    let ownership = capture(examples::ownership_to);
+    assert!(ownership.contains("函数获得所有权: function"));
+
+    let traits = capture(examples::trait_objects_to);
+    assert!(traits.contains("绘制按钮: 'OK' (50x10)"));
+
+    let macros = capture(examples::macros_to);
+    assert!(macros.contains("  Hello, Rust!"));
+    assert!(macros.contains("  函数 bar 被调用了"));
+}
+
+#[test]
+fn thread_output_is_captured() {
+    let output = capture(examples::concurrency_to);
+    for i in 1..=3 {
+        assert!(output.contains(&format!("  子线程: {}", i)));
+    }
+    assert!(output.contains("  收到消息: Hello from thread"));
+}
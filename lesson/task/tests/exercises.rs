@@ -0,0 +1,225 @@
+// 改错练习：用例格式、按说明编译失败的检查、练习目录和 exercise 命令
+use std::fs;
+use std::io::{Cursor, ErrorKind};
+use std::path::PathBuf;
+use std::process::Command;
+
+use task::exercises::{self, CaseOutcome, Workspace};
+use task::explain::Diagnostic;
+use task::progress::{FixedClock, ProgressStore, ResultKind};
+use task::session::Session;
+use task::verify::VerifyOptions;
+
+const CASE: &str = "\
+// title: 移动之后再使用
+// chapter: 6
+// error: E0382
+// message: borrow of moved value: `s1`
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let s1 = String::from(\"hello\");
+    let s2 = s1;
+    println!(\"{} {}\", s1, s2);
+}
+";
+
+const FIXED: &str = "fn main() {\n    let s1 = String::from(\"hello\");\n    let s2 = s1.clone();\n    println!(\"{} {}\", s1, s2);\n}\n";
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("task-exercises-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// 没有 rustc 时跳过需要编译的检查
+fn rustc_available() -> bool {
+    let available = Command::new(VerifyOptions::default().rustc).arg("--version").output().is_ok();
+    if !available {
+        eprintln!("跳过：找不到 rustc");
+    }
+    available
+}
+
+fn diagnostic(code: &str, message: &str) -> Diagnostic {
+    Diagnostic {
+        message: message.to_string(),
+        code: Some(code.to_string()),
+        location: None,
+        label: None,
+    }
+}
+
+#[test]
+fn embedded_exercises_are_well_formed() {
+    assert_eq!(exercises::all().len(), exercises::embedded().len());
+    for (file, text) in exercises::embedded() {
+        let exercise = exercises::parse_exercise(file, text).unwrap_or_else(|err| panic!("{}", err));
+        assert!(task::explain::lookup(&exercise.error).is_some(), "{} 的错误码没有讲解", file);
+        assert!(exercise.hint.is_some(), "{} 缺少提示", file);
+        assert!(exercise.code().starts_with("fn ") || exercise.code().starts_with('#'), "{}", file);
+        assert_eq!(exercises::get(&exercise.id).map(|found| found.id.as_str()), Some(exercise.id.as_str()));
+    }
+
+    let exercise = exercises::get("use_after_move").unwrap();
+    assert_eq!((exercise.id.as_str(), exercise.chapter, exercise.error.as_str()), ("06_use_after_move", 6, "E0382"));
+    assert!(exercise.code().starts_with("fn main() {\n"));
+    assert!(exercises::for_chapter(7).all(|exercise| exercise.id.starts_with("07_")));
+    assert!(exercises::get("missing").is_none());
+}
+
+#[test]
+fn reports_header_errors() {
+    for (text, line, message) in [
+        ("// title: a\n// chapter: 6\n// error: E0382\nfn main() {}\n", 0, "缺少字段 message"),
+        ("// title: a\n// title: b\n", 2, "字段 title 重复"),
+        ("// author: me\n", 1, "未知字段 author"),
+        ("// chapter: 99\n", 1, "未知章节 99"),
+        ("// error: 382\n", 1, "382 不是 E0000 形式的错误码"),
+        ("// hint:\n", 1, "字段 hint 为空"),
+    ] {
+        let err = exercises::parse_exercise("bad.rs", text).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (line, message), "{:?}", text);
+    }
+    // 代码开始之后的注释不是说明字段
+    let text = format!("{}// note: 这里不是字段\n", CASE);
+    assert_eq!(exercises::parse_exercise("case.rs", &text).unwrap().id, "case");
+}
+
+#[test]
+fn classifies_compile_errors_against_the_header() {
+    let exercise = exercises::parse_exercise("case.rs", CASE).unwrap();
+    let expected = diagnostic("E0382", "borrow of moved value: `s1`");
+    assert_eq!(exercises::classify(&exercise, vec![]), CaseOutcome::UnexpectedSuccess);
+    assert!(exercises::classify(&exercise, vec![expected.clone()]).passed());
+    // 错误码对但信息不符，或者多出其他错误码，都算不符合说明
+    let other_message = diagnostic("E0382", "use of moved value: `s2`");
+    assert!(!exercises::classify(&exercise, vec![other_message]).passed());
+    let outcome = exercises::classify(&exercise, vec![expected, diagnostic("E0308", "mismatched types")]);
+    assert!(outcome.to_string().contains("error[E0308]: mismatched types"), "{}", outcome);
+}
+
+#[test]
+fn embedded_cases_fail_as_declared() {
+    let options = VerifyOptions::default();
+    for exercise in exercises::all() {
+        let outcome = match exercises::check_case(exercise, &options) {
+            Ok(outcome) => outcome,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                eprintln!("跳过：{}", err);
+                return;
+            }
+            Err(err) => panic!("{}", err),
+        };
+        assert!(outcome.passed(), "{}：{}", exercise.id, outcome);
+    }
+}
+
+#[test]
+fn menu_rechecks_until_the_exercise_compiles() {
+    if !rustc_available() {
+        return;
+    }
+    let dir = temp_dir("menu");
+    let store = ProgressStore::new(&dir);
+    let run = |script: &str| {
+        let mut session = Session::scripted(Cursor::new(script.to_string()), Vec::new())
+            .with_progress(store.clone())
+            .with_clock(FixedClock(1_700_000_000));
+        session.run().unwrap();
+        String::from_utf8(session.into_output()).unwrap()
+    };
+
+    let transcript = run("exercise\nexercise use_after_move\nagain\nq\nexercise nope\nq\n");
+    for expected in [
+        "🧩 改错练习（共 10 个）",
+        "  ○ 06_use_after_move 移动之后再使用（E0382）",
+        "🚫 这段代码无法通过编译：error[E0382]: borrow of moved value: `s1`",
+        "  │ 3 │     let s2 = s1;",
+        "🔧 还有 1 个编译错误",
+        "❌ error[E0382]: borrow of moved value: `s1`",
+        "💡 需要两个独立的字符串时用 clone 复制一份",
+        "❌ 没有练习 nope",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+    assert_eq!(transcript.matches("🔧 还有 1 个编译错误").count(), 2, "{}", transcript);
+
+    // 修改后的文件保留在练习目录中，再次进入时直接通过
+    let path = dir.join("exercises/06_use_after_move.rs");
+    assert_eq!(fs::read_to_string(&path).unwrap(), exercises::get("use_after_move").unwrap().source);
+    fs::write(&path, FIXED).unwrap();
+    let transcript = run("exercise 06_use_after_move\nexercise\nq\n");
+    assert!(transcript.contains("🎉 编译通过，完成练习「移动之后再使用」！"), "{}", transcript);
+    assert!(transcript.contains("  ✓ 06_use_after_move"), "{}", transcript);
+
+    let (progress, _) = store.load().unwrap();
+    assert!(progress.is_solved("06_use_after_move"));
+    let result = progress.latest_result(ResultKind::Exercise, 6).unwrap();
+    assert_eq!((result.score, result.total, result.at), (1, 5, 1_700_000_000));
+    assert!(progress.to_text().contains("solved 06_use_after_move 1700000000\n"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn binary_exercise_command() {
+    let dir = temp_dir("binary");
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .arg("--profile-dir")
+            .arg(&dir)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["exercise"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("  ○ 12_not_static"), "{}", stdout);
+    let (code, stdout) = run(&["exercise", "use_after_move"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("💡 修改后运行 task exercise check 06_use_after_move 重新检查"), "{}", stdout);
+    assert_eq!(run(&["exercise", "nope"]).0, Some(2));
+    if !rustc_available() {
+        fs::remove_dir_all(&dir).unwrap();
+        return;
+    }
+
+    let workspace = Workspace::new(dir.join("exercises"));
+    let exercise = exercises::get("use_after_move").unwrap();
+    assert_eq!(run(&["exercise", "check", "use_after_move"]).0, Some(1));
+    fs::write(workspace.path(exercise), FIXED).unwrap();
+    let (code, stdout) = run(&["exercise", "check", "use_after_move"]);
+    assert_eq!(code, Some(0), "{}", stdout);
+    assert!(run(&["exercise"]).1.contains("  ✓ 06_use_after_move"));
+    assert_eq!(run(&["exercise", "reset", "use_after_move"]).0, Some(0));
+    assert_eq!(fs::read_to_string(workspace.path(exercise)).unwrap(), exercise.source);
+
+    let (code, stdout) = run(&["exercise", "verify"]);
+    assert_eq!(code, Some(0), "{}", stdout);
+    assert!(stdout.contains("🧪 检查了 10 个练习用例，0 个与说明不符"), "{}", stdout);
+
+    // 自定义目录中编译通过和缺少字段的用例都算失败
+    let cases = dir.join("cases");
+    fs::create_dir_all(&cases).unwrap();
+    fs::write(cases.join("01_moved.rs"), CASE).unwrap();
+    fs::write(cases.join("02_compiles.rs"), CASE.replace("let s2 = s1;", "let s2 = s1.clone();")).unwrap();
+    fs::write(cases.join("03_no_header.rs"), FIXED).unwrap();
+    fs::write(cases.join("notes.txt"), "不是用例").unwrap();
+    let (code, stdout) = run(&["exercise", "verify", cases.to_str().unwrap()]);
+    assert_eq!(code, Some(1), "{}", stdout);
+    for expected in [
+        "✅ 01_moved (E0382) 按预期编译失败",
+        "❌ 02_compiles (E0382) 应当编译失败，但编译通过了",
+        "❌ 03_no_header.rs: 缺少字段 title",
+        "🧪 检查了 3 个练习用例，2 个与说明不符",
+    ] {
+        assert!(stdout.contains(expected), "缺少 {:?}：\n{}", expected, stdout);
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,234 @@
+// 编译错误讲解：JSON 读取、诊断解析、错误码示例和 explain 命令
+use std::fs;
+use std::io::{Cursor, ErrorKind};
+use std::process::Command;
+use std::time::Duration;
+
+use task::explain::{self, Diagnostic, Location};
+use task::i18n::{self, Locale};
+use task::json::{self, Json};
+use task::knowledge::KnowledgeBase;
+use task::session::Session;
+use task::verify::{self, VerifyOptions};
+
+/// rustc 1.95 对一个含两处错误的文件输出的诊断（节选字段）
+const RUSTC_JSON: &str = r#"{"$message_type":"diagnostic","message":"borrow of moved value: `s1`","code":{"code":"E0382","explanation":"A variable was used after its contents have been moved elsewhere.\n"},"level":"error","spans":[{"file_name":"src/main.rs","byte_start":50,"byte_end":52,"line_start":3,"line_end":3,"column_start":14,"column_end":16,"is_primary":false,"text":[],"label":"value moved here","suggested_replacement":null,"suggestion_applicability":null,"expansion":null},{"file_name":"src/main.rs","byte_start":77,"byte_end":79,"line_start":4,"line_end":4,"column_start":23,"column_end":25,"is_primary":true,"text":[],"label":"value borrowed here after move","suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[],"rendered":"error[E0382]: borrow of moved value: `s1`\n"}
+{"$message_type":"diagnostic","message":"variable `x` is assigned to, but never used","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":90,"byte_end":91,"line_start":5,"line_end":5,"column_start":9,"column_end":10,"is_primary":true,"text":[],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[],"rendered":"warning: unused\n"}
+{"$message_type":"diagnostic","message":"cannot assign twice to immutable variable `x`","code":{"code":"E0384","explanation":null},"level":"error","spans":[{"file_name":"src/main.rs","byte_start":97,"byte_end":102,"line_start":6,"line_end":6,"column_start":5,"column_end":10,"is_primary":true,"text":[],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[],"rendered":"error[E0384]\n"}
+{"$message_type":"diagnostic","message":"aborting due to 2 previous errors; 1 warning emitted","code":null,"level":"error","spans":[],"children":[],"rendered":"error: aborting due to 2 previous errors\n"}
+{"$message_type":"diagnostic","message":"For more information about an error, try `rustc --explain E0382`.","code":null,"level":"failure-note","spans":[],"children":[],"rendered":"For more information\n"}
+"#;
+
+#[test]
+fn json_reader_parses_values() {
+    let value = json::parse(r#" {"a": [1, -2.5e2, true, null], "b": {"c": "x\"\\\/\n\u4e2d\ud83e\udd80"}, "a": 0} "#).unwrap();
+    assert_eq!(
+        value.get("a"),
+        Some(&Json::Array(vec![Json::Number(1.0), Json::Number(-250.0), Json::Bool(true), Json::Null]))
+    );
+    assert_eq!(value.get("b").and_then(|b| b.get("c")).and_then(Json::as_str), Some("x\"\\/\n中🦀"));
+    assert_eq!(value.get("a").unwrap().items()[0].as_u64(), Some(1));
+    assert_eq!(value.get("a").unwrap().items()[1].as_u64(), None);
+    assert_eq!(json::parse("[]"), Ok(Json::Array(Vec::new())));
+    assert_eq!(json::parse("\"所有权\""), Ok(Json::String("所有权".to_string())));
+}
+
+#[test]
+fn json_reader_reports_errors() {
+    for (text, offset) in [
+        ("", 0),
+        ("{\"a\" 1}", 5),
+        ("[1 2]", 3),
+        ("\"abc", 4),
+        ("01", 1),
+        ("1.", 2),
+        ("tru", 0),
+        ("\"\\x\"", 2),
+        ("\"\\ud83e\"", 7),
+        ("{1: 2}", 1),
+        ("[1] x", 4),
+        ("\"a\nb\"", 2),
+    ] {
+        let err = json::parse(text).unwrap_err();
+        assert_eq!(err.offset, offset, "{:?}: {}", text, err);
+    }
+    let deep = format!("{}{}", "[".repeat(200), "]".repeat(200));
+    assert_eq!(json::parse(&deep).unwrap_err().message, "嵌套层数过多");
+}
+
+#[test]
+fn parses_rustc_json_diagnostics() {
+    let diagnostics = explain::parse_diagnostics(RUSTC_JSON);
+    assert_eq!(
+        diagnostics,
+        [
+            Diagnostic {
+                message: "borrow of moved value: `s1`".to_string(),
+                code: Some("E0382".to_string()),
+                location: Some(Location {
+                    file: "src/main.rs".to_string(),
+                    line: 4,
+                    column: 23,
+                }),
+                label: Some("value borrowed here after move".to_string()),
+            },
+            Diagnostic {
+                message: "cannot assign twice to immutable variable `x`".to_string(),
+                code: Some("E0384".to_string()),
+                location: Some(Location {
+                    file: "src/main.rs".to_string(),
+                    line: 6,
+                    column: 5,
+                }),
+                label: None,
+            },
+        ]
+    );
+    // 夹杂其他输出和坏行时只取能解析的诊断
+    let mixed = format!("   Compiling demo v0.1.0\n{{\"level\": \n{}", RUSTC_JSON);
+    assert_eq!(explain::parse_diagnostics(&mixed).len(), 2);
+}
+
+#[test]
+fn parses_pasted_terminal_output() {
+    let text = "\
+error[E0502]: cannot borrow `v` as mutable because it is also borrowed as immutable
+ --> src/main.rs:4:5
+  |
+3 |     let first = &v[0];
+  |                  - immutable borrow occurs here
+4 |     v.push(4);
+  |     ^^^^^^^^^ mutable borrow occurs here
+
+error: expected one of `.`, `;`, `?`, `}`, or an operator, found `x`
+ --> C:\\demo\\main.rs:9:1
+
+warning: unused variable: `y`
+ --> src/main.rs:2:9
+
+error: aborting due to 2 previous errors
+";
+    let diagnostics = explain::parse_diagnostics(text);
+    assert_eq!(diagnostics.len(), 2, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("E0502"));
+    assert_eq!(diagnostics[0].location.as_ref().unwrap().to_string(), "src/main.rs:4:5");
+    assert_eq!(diagnostics[1].code, None);
+    assert_eq!(diagnostics[1].location.as_ref().unwrap().file, "C:\\demo\\main.rs");
+}
+
+#[test]
+fn error_table_is_complete() {
+    assert!(explain::is_error_code("E0382") && explain::is_error_code("e0106"));
+    assert!(!explain::is_error_code("E382") && !explain::is_error_code("main.rs"));
+    assert_eq!(explain::lookup("e0499").map(|info| info.chapter), Some(7));
+
+    let zh = KnowledgeBase::embedded();
+    let en = KnowledgeBase::embedded_for(Locale::En);
+    for (index, info) in explain::ERRORS.iter().enumerate() {
+        assert!(explain::ERRORS[..index].iter().all(|other| other.code != info.code), "{} 重复", info.code);
+        assert!(task::chapters::get(info.chapter).is_some(), "{}", info.code);
+        for key in [info.title, info.fix] {
+            assert!(i18n::lookup(key).is_some(), "缺少 {}", key);
+        }
+        if let Some(point) = info.point {
+            for base in [&zh, &en] {
+                let points = base.chapter(info.chapter).unwrap_or_default();
+                assert!(point >= 1 && point <= points.len(), "{} 的知识点 {} 不存在", info.code, point);
+            }
+        }
+    }
+}
+
+#[test]
+fn error_examples_fail_and_pass_as_documented() {
+    let options = VerifyOptions::default();
+    for info in explain::ERRORS {
+        for example in info.examples() {
+            let outcome = match verify::verify_example(&example, &options) {
+                Ok(outcome) => outcome,
+                Err(err) if err.kind() == ErrorKind::NotFound => {
+                    eprintln!("跳过：{}", err);
+                    return;
+                }
+                Err(err) => panic!("{}", err),
+            };
+            assert!(outcome.passed(), "{} {}：{}", info.code, example.title, outcome);
+        }
+    }
+}
+
+#[test]
+fn compile_gives_up_after_the_timeout() {
+    let dir = std::env::temp_dir().join(format!("task-explain-timeout-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("main.rs");
+    fs::write(&source, "fn main() {}\n").unwrap();
+    let options = VerifyOptions {
+        timeout: Duration::from_millis(1),
+        ..VerifyOptions::default()
+    };
+    match explain::compile(&source, &options) {
+        Err(err) if err.kind() == ErrorKind::NotFound => eprintln!("跳过：{}", err),
+        Err(err) => assert_eq!(err.kind(), ErrorKind::TimedOut, "{}", err),
+        Ok(diagnostics) => panic!("1 毫秒内不可能编译完：{:?}", diagnostics),
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn explains_codes_in_the_menu() {
+    let mut session = Session::scripted(Cursor::new("explain E0382\nexplain e9999\nexplain\nq\n"), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    for expected in [
+        "🩺 E0382：使用了已经移动的值",
+        "📚 相关章节：第6章：所有权（knowledge 6）",
+        "📖 知识点：所有权规则",
+        "  🚫 这段代码无法通过编译（E0382）",
+        "  │ 2 │ let s2 = s1.clone();",
+        "❔ 讲解列表中还没有 E9999",
+        "❌ 用法：explain <错误码>，已收录：E0384 E0308 E0382",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+}
+
+#[test]
+fn binary_explain_command() {
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["explain", "E0106"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("📚 相关章节：第12章：生命周期"), "{}", stdout);
+    assert_eq!(run(&["explain", "E9999"]).0, Some(1));
+
+    let dir = std::env::temp_dir().join(format!("task-explain-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let log = dir.join("errors.json");
+    fs::write(&log, RUSTC_JSON).unwrap();
+    let (code, stdout) = run(&["explain", log.to_str().unwrap()]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("🩺 发现 2 个编译错误"), "{}", stdout);
+    assert!(stdout.contains("   --> src/main.rs:4:23（value borrowed here after move）"), "{}", stdout);
+    assert_eq!(stdout.matches("🩺 E0").count(), 2, "{}", stdout);
+
+    let source = dir.join("main.rs");
+    fs::write(&source, "fn main() {\n    let v = vec![1];\n    let w = v;\n    println!(\"{:?} {:?}\", v, w);\n}\n").unwrap();
+    let (code, stdout) = run(&["explain", source.to_str().unwrap()]);
+    // 没有 rustc 时命令失败，跳过对输出的检查
+    if code == Some(0) {
+        assert!(stdout.contains("❌ error[E0382]: borrow of moved value: `v`"), "{}", stdout);
+        assert!(stdout.contains("main.rs:4:"), "{}", stdout);
+    }
+    assert_eq!(run(&["explain", dir.join("missing.rs").to_str().unwrap()]).0, Some(1));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,197 @@
+// 抽认卡：SM-2 调度、卡片编号、进度文件中的复习记录和 review 命令
+use std::fs;
+use std::io::Cursor;
+
+use task::flashcards::{self, CardId, CardKind, CardState, Quality, INITIAL_EASE, MAX_EASE, MAX_INTERVAL, MIN_EASE, NEW_CARDS_PER_DAY};
+use task::progress::{FixedClock, Progress, ProgressStore};
+use task::session::Session;
+
+const DAY: u64 = flashcards::SECONDS_PER_DAY;
+
+fn quality(value: u8) -> Quality {
+    Quality::new(value).unwrap()
+}
+
+/// 依次复习，返回每次之后的 (间隔, 难度系数)
+fn schedule(grades: &[u8]) -> Vec<(u32, u32)> {
+    let mut state = CardState::new(0);
+    let mut today = 0;
+    grades
+        .iter()
+        .map(|grade| {
+            state.review(quality(*grade), today);
+            assert_eq!(state.due, today + u64::from(state.interval));
+            today = state.due;
+            (state.interval, state.ease)
+        })
+        .collect()
+}
+
+#[test]
+fn sm2_intervals_grow_with_the_ease_factor() {
+    assert_eq!(schedule(&[5, 5, 5, 5]), [(1, 260), (6, 270), (16, 280), (45, 290)]);
+    // 4 分不改变难度系数，3 分降低 0.14
+    assert_eq!(schedule(&[4, 4, 4]), [(1, 250), (6, 250), (15, 250)]);
+    assert_eq!(schedule(&[3, 3, 3]), [(1, 236), (6, 222), (13, 208)]);
+}
+
+#[test]
+fn sm2_lapse_restarts_without_changing_ease() {
+    let mut state = CardState::new(100);
+    for grade in [5, 5, 5] {
+        let today = state.due;
+        state.review(quality(grade), today);
+    }
+    assert_eq!((state.repetitions, state.interval, state.ease), (3, 16, 280));
+
+    state.review(quality(1), 130);
+    assert_eq!((state.repetitions, state.interval, state.ease, state.due), (0, 1, 280, 131));
+    assert_eq!(state.reviewed, 130);
+    assert_eq!(state.added, 100);
+    state.review(quality(5), 131);
+    assert_eq!((state.repetitions, state.interval), (1, 1));
+}
+
+#[test]
+fn sm2_ease_never_drops_below_minimum() {
+    let last = *schedule(&[3; 12]).last().unwrap();
+    assert_eq!(last.1, MIN_EASE);
+    assert!(Quality::new(6).is_none());
+    assert!(quality(3).is_pass() && !quality(2).is_pass());
+    assert_eq!(CardState::new(7).ease, INITIAL_EASE);
+}
+
+#[test]
+fn sm2_interval_and_ease_are_capped() {
+    let last = *schedule(&[5; 100]).last().unwrap();
+    assert_eq!(last, (MAX_INTERVAL, MAX_EASE));
+    // 超出范围的状态也不会溢出
+    let mut state = CardState { repetitions: u32::MAX, interval: u32::MAX, ease: u32::MAX, ..CardState::new(0) };
+    state.review(quality(5), 10);
+    assert_eq!((state.repetitions, state.interval, state.ease), (u32::MAX, MAX_INTERVAL, MAX_EASE));
+}
+
+#[test]
+fn card_ids_round_trip() {
+    let id = CardId {
+        chapter: 6,
+        point: 1,
+        kind: CardKind::Mistake,
+        item: 2,
+    };
+    assert_eq!(id.to_string(), "6.1.m2");
+    assert_eq!("6.1.m2".parse::<CardId>(), Ok(id));
+    for bad in ["6.1", "6.0.c1", "6.1.c0", "6.1.x1", "6.1.c", "6.1.c1.2", "a.1.c1"] {
+        assert!(bad.parse::<CardId>().is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn cards_come_from_concepts_and_mistakes() {
+    let cards = flashcards::cards_for_chapter(6);
+    let point = &task::knowledge::get_chapter_knowledge(6).unwrap()[0];
+    assert_eq!(cards.len(), point.key_concepts.len() + point.common_mistakes.len());
+    assert_eq!(cards[0].id.to_string(), "6.1.c1");
+    assert_eq!(cards[0].back, point.key_concepts[0]);
+    assert!(cards[0].front.contains(&point.title), "{}", cards[0].front);
+    let mistake = cards.iter().find(|card| card.id.kind == CardKind::Mistake).unwrap();
+    assert_eq!(mistake.back, point.common_mistakes[0]);
+    assert!(flashcards::cards_for_chapter(20).is_empty());
+}
+
+#[test]
+fn card_states_persist_in_the_progress_file() {
+    let mut progress = Progress::default();
+    let id: CardId = "6.1.c2".parse().unwrap();
+    let state = flashcards::record_review(&mut progress, id, quality(5), 20_000);
+    assert_eq!(state.due, 20_001);
+    let text = progress.to_text();
+    assert!(text.contains("card 6.1.c2 20000 20000 1 1 260 20001\n"), "{}", text);
+
+    let (parsed, warnings) = Progress::parse(&text);
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(parsed, progress);
+
+    let (parsed, warnings) = Progress::parse("version 1\ncard 6.1.c2 1 2 3\ncard 6.x 1 1 1 1 250 2\n");
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(parsed.is_empty());
+
+    // 间隔和难度系数超出范围的记录视为损坏
+    let (parsed, warnings) = Progress::parse("version 1\ncard 6.1.c2 1 1 1 4000000000 250 2\ncard 6.1.c3 1 1 1 1 50 2\ncard 6.1.c4 1 1 1 1 99999 2\n");
+    assert_eq!(warnings.len(), 3, "{:?}", warnings);
+    assert!(parsed.is_empty());
+}
+
+#[test]
+fn queue_holds_due_and_limited_new_cards() {
+    let today = 1_000;
+    let mut progress = Progress::default();
+    assert!(flashcards::review_queue(&progress, today).is_empty());
+
+    for chapter in 1..=8 {
+        progress.record_run(chapter, today * DAY);
+    }
+    let queue = flashcards::review_queue(&progress, today);
+    assert!(queue.due.is_empty());
+    assert_eq!(queue.new.len(), NEW_CARDS_PER_DAY);
+    assert_eq!(queue.new[0].id.to_string(), "1.1.c1");
+
+    // 今天已经引入的新卡片占用当天的名额
+    let first = queue.new[0].id;
+    let second = queue.new[1].id;
+    flashcards::record_review(&mut progress, first, quality(5), today);
+    flashcards::record_review(&mut progress, second, quality(1), today);
+    let queue = flashcards::review_queue(&progress, today);
+    assert_eq!(queue.new.len(), NEW_CARDS_PER_DAY - 2);
+    assert!(queue.cards().all(|card| card.id != first && card.id != second));
+
+    // 第二天两张都到期，新卡片名额恢复
+    let queue = flashcards::review_queue(&progress, today + 1);
+    let due: Vec<CardId> = queue.due.iter().map(|card| card.id).collect();
+    assert_eq!(due, [first, second]);
+    assert_eq!(queue.new.len(), NEW_CARDS_PER_DAY);
+}
+
+#[test]
+fn review_command_schedules_cards_with_a_fixed_clock() {
+    let dir = std::env::temp_dir().join(format!("task-flashcards-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let store = ProgressStore::new(&dir);
+    let day = 20_000;
+    let run = |script: &str, at: u64| {
+        let mut session = Session::scripted(Cursor::new(script.to_string()), Vec::new())
+            .with_progress(store.clone())
+            .with_clock(FixedClock(at));
+        session.run().unwrap();
+        String::from_utf8(session.into_output()).unwrap()
+    };
+
+    let transcript = run("review\nq\n", day * DAY);
+    assert!(transcript.contains("📭 还没有学过的章节"), "{}", transcript);
+
+    let transcript = run("6\nreview\n5\n7\n2\nq\nq\n", day * DAY + 3600);
+    for expected in [
+        "🗂️  今日复习：到期 0 张，新卡片 6 张",
+        "🃏 第1/6张 · 第6章：所有权 · 核心概念",
+        "❓ 「所有权规则」的第1个核心概念是什么？（共4个）",
+        "💡 每个值都有一个所有者",
+        "5\n📅 1天后再复习",
+        "❌ 请输入 0-5 的数字",
+        "✅ 本次复习了 2 张卡片，还剩 4 张",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+    let (progress, _) = store.load().unwrap();
+    assert_eq!(progress.cards().count(), 2);
+
+    // 第二天：两张旧卡片到期，剩下的新卡片继续引入
+    let transcript = run("review\n5\n4\n", (day + 1) * DAY);
+    assert!(transcript.contains("🗂️  今日复习：到期 2 张，新卡片 4 张"), "{}", transcript);
+    let (progress, _) = store.load().unwrap();
+    let state = progress.card("6.1.c1".parse().unwrap()).unwrap();
+    assert_eq!((state.repetitions, state.interval, state.due), (2, 6, day + 7));
+
+    let transcript = run("review\nq\n", (day + 2) * DAY);
+    assert!(transcript.contains("到期 1 张"), "{}", transcript);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,197 @@
+// 练习评分：评分文件格式、接口检查、参考答案和出错提交的评分结果，以及 grade 命令
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use task::grader::{self, GradeReport, TestOutcome};
+use task::verify::VerifyOptions;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("task-grader-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Smartcontractcode 目录中的参考答案
+fn reference(id: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../Smartcontractcode").join(format!("{}.rs", id))
+}
+
+/// 评分一个提交；没有 rustc 时返回 None 跳过检查
+fn grade(assignment: &grader::Assignment, submission: &Path) -> Option<GradeReport> {
+    match grader::grade(assignment, submission, &VerifyOptions::default()) {
+        Ok(report) => Some(report),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            eprintln!("跳过：{}", err);
+            None
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn embedded_assignments_are_well_formed() {
+    assert_eq!(grader::all().len(), grader::embedded().len());
+    for (file, text) in grader::embedded() {
+        let assignment = grader::parse_assignment(file, text).unwrap_or_else(|err| panic!("{}", err));
+        assert!(text.contains("#[test]"), "{} 没有测试", file);
+        assert!(reference(&assignment.id).exists(), "{} 没有对应的练习", file);
+        assert_eq!(grader::get(&assignment.id.to_uppercase()).map(|found| &found.id), Some(&assignment.id));
+    }
+
+    let assignment = grader::get("rs-062.rs").unwrap();
+    assert_eq!(assignment.api, ["pub fn par_sum(v: &Vec<u32>) -> u32"]);
+    assert_eq!(assignment.timeout.as_secs(), 10);
+    assert_eq!(grader::get("rs-031").unwrap().timeout, grader::DEFAULT_TIMEOUT);
+    assert!(grader::get("rs-017").is_none());
+}
+
+#[test]
+fn every_exercise_is_graded_or_explained() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../Smartcontractcode");
+    let mut ids: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy()[..6].to_string())
+        .collect();
+    ids.sort();
+    assert_eq!(ids.len(), 69);
+    for id in &ids {
+        assert!(grader::get(id).is_some() != grader::ungraded(id).is_some(), "{} 应该有评分文件或无法评分的原因", id);
+    }
+    assert_eq!(grader::all().len() + grader::ungraded_ids().count(), ids.len());
+    assert_eq!(grader::ungraded("RS-067.rs"), Some("grade.ungraded.tokio"));
+}
+
+#[test]
+fn reports_header_errors() {
+    for (text, line, message) in [
+        ("// chapter: 6\n// api: pub fn f()\n", 0, "缺少字段 title"),
+        ("// title: a\n// chapter: 6\n", 0, "至少需要一个 api 字段"),
+        ("// title: a\n// chapter: 99\n// api: pub fn f()\n", 2, "未知章节 99"),
+        ("// title: a\n// title: b\n", 2, "字段 title 重复"),
+        ("// timeout: 0\n// title: a\n// chapter: 6\n// api: pub fn f()\n", 1, "时间限制 0 不是正数秒数"),
+        ("// error: E0382\n", 1, "未知字段 error"),
+    ] {
+        let err = grader::parse_assignment("bad.rs", text).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (line, message), "{:?}", text);
+    }
+    let text = "// title: a\n// chapter: 6\n// api: pub fn f()\n// api: pub trait T\n// timeout: 0.5\n";
+    let assignment = grader::parse_assignment("case.rs", text).unwrap();
+    assert_eq!((assignment.api.len(), assignment.timeout.as_millis()), (2, 500));
+}
+
+#[test]
+fn api_check_ignores_formatting() {
+    let api = vec!["pub fn add(a: u32, b: u32) -> u32".to_string(), "pub trait Money".to_string()];
+    let source = "pub fn add(\n    a: u32,\n    b: u32,\n) -> u32 { a + b }\npub trait Money {}\n";
+    assert!(grader::missing_api(source, &api).is_empty());
+    assert_eq!(grader::missing_api("fn add(a: u32, b: u32) -> u32 { a + b }", &api), api.iter().map(String::as_str).collect::<Vec<_>>());
+    assert!(grader::missing_api("pub fn add(a: u32, /* 和 */ b: u32) -> u32 { a + b }\npub trait Money {}", &api).is_empty());
+
+    // 只在记号边界上匹配，注释和字符串中的文字不算
+    let api = vec!["pub fn foo".to_string()];
+    for source in ["pub fn foobar() {}", "// pub fn foo() {}", "/* pub fn foo */", "const S: &str = \"pub fn foo\";", "pub fn\nfoo_bar() {}"] {
+        assert_eq!(grader::missing_api(source, &api), ["pub fn foo"], "{:?}", source);
+    }
+    assert!(grader::missing_api("pub fn foo() {}", &api).is_empty());
+}
+
+#[test]
+fn reference_solutions_pass() {
+    for assignment in grader::all() {
+        let Some(report) = grade(assignment, &reference(&assignment.id)) else {
+            return;
+        };
+        assert!(report.passed(), "{}：{:?}", assignment.id, report);
+    }
+}
+
+#[test]
+fn reports_failures_compile_errors_and_timeouts() {
+    let dir = temp_dir("report");
+    let assignment = grader::get("rs-062").unwrap();
+    let submission = dir.join("sum.rs");
+
+    // 缺少接口时不编译
+    fs::write(&submission, "pub fn sum(v: &Vec<u32>) -> u32 { v.iter().sum() }\n").unwrap();
+    let report = grader::grade(assignment, &submission, &VerifyOptions::default()).unwrap();
+    assert_eq!(report.missing_api, ["pub fn par_sum(v: &Vec<u32>) -> u32"]);
+
+    fs::write(&submission, "pub fn par_sum(v: &Vec<u32>) -> u32 { v.len() }\n").unwrap();
+    let Some(report) = grade(assignment, &submission) else {
+        return;
+    };
+    let error = &report.compile_errors[0];
+    assert_eq!(error.code.as_deref(), Some("E0308"));
+    let location = error.location.as_ref().unwrap();
+    assert_eq!((location.file.as_str(), location.line), (submission.to_str().unwrap(), 1));
+
+    fs::write(&submission, "pub fn par_sum(v: &Vec<u32>) -> u32 { v.iter().sum::<u32>() + 1 }\n").unwrap();
+    let report = grade(assignment, &submission).unwrap();
+    assert!(!report.passed());
+    let failed = report.tests.iter().find_map(|test| match &test.outcome {
+        TestOutcome::Failed(message) => Some(message),
+        _ => None,
+    });
+    assert!(failed.is_some_and(|message| message.contains("assertion `left == right` failed")), "{:?}", report);
+
+    let assignment = grader::parse_assignment("loop.rs", "// title: 死循环\n// chapter: 3\n// api: pub fn spin()\n// timeout: 0.5\n\n#[test]\nfn spins() {\n    spin();\n}\n").unwrap();
+    fs::write(&submission, "pub fn spin() {\n    loop {}\n}\n").unwrap();
+    let report = grade(&assignment, &submission).unwrap();
+    assert_eq!(report.tests.len(), 1);
+    assert_eq!((report.tests[0].name.as_str(), &report.tests[0].outcome), ("spins", &TestOutcome::TimedOut));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn report_hints_knowledge_only_for_chapters_that_have_it() {
+    let report = |id: &str| {
+        let mut out = Vec::new();
+        grader::write_report(&mut out, grader::get(id).unwrap(), &GradeReport::default()).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    assert!(report("rs-003").contains("（knowledge 1）"), "{}", report("rs-003"));
+    let text = report("rs-062");
+    assert!(text.contains("📚 相关章节：第19章") && !text.contains("knowledge"), "{}", text);
+}
+
+#[test]
+fn binary_grade_command() {
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["grade", "list"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("  rs-062  作用域线程求和"), "{}", stdout);
+    assert!(stdout.contains("  rs-067  依赖 tokio"), "{}", stdout);
+    assert_eq!(run(&["grade", "rs-999", "a.rs"]).0, Some(2));
+    assert_eq!(run(&["grade", "rs-017", "a.rs"]).0, Some(2));
+    assert_eq!(run(&["grade", "rs-062"]).0, Some(2));
+    assert_eq!(run(&["grade", "rs-062", "no/such/file.rs"]).0, Some(1));
+
+    let dir = temp_dir("binary");
+    let submission = dir.join("rs-062.rs");
+    fs::write(&submission, "pub fn par_sum(v: &Vec<u32>) -> u32 { 0 }\n").unwrap();
+    let (code, stdout) = run(&["grade", "rs-062", submission.to_str().unwrap()]);
+    if stdout.is_empty() {
+        fs::remove_dir_all(&dir).unwrap();
+        return;
+    }
+    assert_eq!(code, Some(1), "{}", stdout);
+    assert!(stdout.contains("📝 rs-062 作用域线程求和"), "{}", stdout);
+    assert!(stdout.contains("  ❌ "), "{}", stdout);
+
+    let (code, stdout) = run(&["grade", "rs-062", reference("rs-062").to_str().unwrap()]);
+    assert_eq!(code, Some(0), "{}", stdout);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,144 @@
+// 章节前置关系：注册表无环、拓扑顺序、下一步推荐和 DOT 导出
+use std::fs;
+use std::io::Cursor;
+use std::process::Command;
+
+use task::chapters;
+use task::graph::{self, Graph};
+use task::progress::{Progress, ProgressStore};
+use task::session::Session;
+
+fn ids(chapters: &[&chapters::Chapter]) -> Vec<u8> {
+    chapters.iter().map(|chapter| chapter.id).collect()
+}
+
+#[test]
+fn registry_prerequisites_form_a_dag() {
+    let graph = graph::graph();
+    assert_eq!(graph.find_cycle(), None);
+    assert!(graph.unknown_prerequisites().is_empty(), "{:?}", graph.unknown_prerequisites());
+    // 注册表本身就是学习顺序，每章都排在它的前置之后
+    let order: Vec<u8> = chapters::all().iter().map(|chapter| chapter.id).collect();
+    for (index, chapter) in chapters::all().iter().enumerate() {
+        for pre in chapter.prerequisites {
+            let position = order.iter().position(|id| id == pre).unwrap();
+            assert!(position < index, "第{}章的前置第{}章排在它后面", chapter.id, pre);
+        }
+    }
+    assert_eq!(graph.topological_order(), Ok(order));
+}
+
+#[test]
+fn detects_cycles_and_unknown_prerequisites() {
+    let graph = Graph::new([(1, vec![]), (3, vec![1, 5]), (5, vec![3]), (7, vec![5, 99])]);
+    assert_eq!(graph.find_cycle(), Some(vec![3, 5, 3]));
+    assert_eq!(graph.topological_order(), Err(vec![3, 5, 3]));
+    assert_eq!(graph.unknown_prerequisites(), [(7, 99)]);
+
+    let graph = Graph::new([(2, vec![2])]);
+    assert_eq!(graph.find_cycle(), Some(vec![2, 2]));
+}
+
+#[test]
+fn orders_and_stages_a_small_graph() {
+    let graph = Graph::new([(4, vec![2, 3]), (3, vec![1]), (2, vec![1]), (1, vec![]), (5, vec![])]);
+    assert_eq!(graph.topological_order(), Ok(vec![1, 3, 2, 4, 5]));
+    assert_eq!(graph.stages(), Ok(vec![vec![1, 5], vec![3, 2], vec![4]]));
+    assert_eq!(graph.ancestors(4), [1, 3, 2]);
+    assert_eq!(graph.dependents(1), [3, 2]);
+    assert_eq!(graph.ready(|id| id == 1, Some(1)), [3, 2, 5]);
+}
+
+#[test]
+fn recommends_chapters_unlocked_by_recent_progress() {
+    let mut progress = Progress::default();
+    assert_eq!(graph::recommend(&progress).map(|chapter| chapter.id), Some(1));
+
+    progress.record_run(1, 10);
+    progress.record_run(2, 20);
+    progress.record_run(3, 30);
+    // 第3章刚学完，它解锁的第4、13章排在前面
+    assert_eq!(ids(&graph::ready_chapters(&progress)), [4, 13, 5]);
+
+    for chapter in chapters::all() {
+        progress.record_run(chapter.id, 100);
+    }
+    assert!(graph::recommend(&progress).is_none());
+}
+
+#[test]
+fn learning_path_lists_every_prerequisite() {
+    let path = graph::learning_path(chapters::get(7).unwrap());
+    assert_eq!(ids(&path), [1, 2, 3, 5, 6, 7]);
+    assert_eq!(ids(&graph::learning_path(chapters::get(1).unwrap())), [1]);
+}
+
+#[test]
+fn writes_graphviz_dot() {
+    let mut progress = Progress::default();
+    progress.record_run(1, 10);
+    let mut out = Vec::new();
+    graph::write_dot(&mut out, Some(&progress)).unwrap();
+    let dot = String::from_utf8(out).unwrap();
+    assert!(dot.starts_with("digraph chapters {\n") && dot.ends_with("}\n"), "{}", dot);
+    assert!(dot.contains("    subgraph cluster_basic {\n"), "{}", dot);
+    assert!(dot.contains("    c6 -> c7;\n"), "{}", dot);
+    assert!(dot.contains("c1 [label=\"1. 变量与常量\", style=\"rounded,filled\", fillcolor=palegreen];"), "{}", dot);
+    assert!(dot.contains("c2 [label=\"2. 数据类型\", style=\"rounded,filled\", fillcolor=gold];"), "{}", dot);
+    assert!(dot.contains("[style=dashed, color=gray, dir=none, constraint=false];"), "{}", dot);
+    // 相关主题不重复已有的前置关系
+    for (from, to) in graph::related_edges() {
+        assert!(!dot.contains(&format!("    c{} -> c{};\n", from, to)), "{} {}", from, to);
+    }
+}
+
+#[test]
+fn session_shows_recommendation_and_route() {
+    let dir = std::env::temp_dir().join(format!("task-graph-session-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let mut session = Session::scripted(Cursor::new("1\nnext\npath 7\npath 99\nq\n"), Vec::new())
+        .with_progress(ProgressStore::new(&dir));
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    for expected in [
+        "▶️  建议下一章：第2章：数据类型",
+        "🔓 前置章节已学完：1. 变量与常量",
+        "📋 也可以开始：3. 函数",
+        "🗺️  学到第7章：借用机制的路线",
+        "   1. ✓ 第1章：变量与常量\n   2. ○ 第2章：数据类型\n",
+        "📌 还需学习 5 章（○ 表示未学）",
+        "❌ 用法：path <章节>，章节范围 1-25",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+}
+
+#[test]
+fn binary_graph_and_next_commands() {
+    let run = |args: &[&str]| {
+        let dir = std::env::temp_dir().join(format!("task-graph-{}", std::process::id()));
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .arg("--profile-dir")
+            .arg(&dir)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["graph"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.starts_with("digraph chapters {"), "{}", stdout);
+    assert!(!stdout.contains("fillcolor"), "{}", stdout);
+
+    let (code, stdout) = run(&["next"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("▶️  建议下一章：第1章：变量与常量"), "{}", stdout);
+
+    let (_, stdout) = run(&["path", "4"]);
+    assert!(stdout.contains("📌 还需学习 4 章"), "{}", stdout);
+}
@@ -0,0 +1,284 @@
+// 界面文字的本地化：消息目录的完整性和英文界面
+use std::io::Cursor;
+use std::process::Command;
+use std::sync::Mutex;
+
+use task::chapters;
+use task::exercises::{self, CaseOutcome};
+use task::explain::{self, Diagnostic, Location};
+use task::grader::{self, TestOutcome};
+use task::i18n::{self, Catalog, Locale};
+use task::knowledge::{self, KnowledgeBase};
+use task::playground::Playground;
+use task::progress::Progress;
+use task::session::Session;
+use task::verify::{Outcome, VerifyOptions};
+
+/// 当前语言是进程级的设置，切换语言的测试需要串行执行
+static LOCALE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 在指定语言下执行 `f`，结束后恢复中文
+fn with_locale<T>(locale: Locale, f: impl FnOnce() -> T) -> T {
+    let _guard = LOCALE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    i18n::set_locale(locale);
+    let result = f();
+    i18n::set_locale(Locale::Zh);
+    result
+}
+
+#[test]
+fn catalogs_parse_cleanly() {
+    for locale in Locale::ALL {
+        let (catalog, warnings) = i18n::catalog(locale);
+        assert!(warnings.is_empty(), "{}: {:?}", locale, warnings);
+        assert!(!catalog.keys().is_empty());
+    }
+}
+
+#[test]
+fn catalog_format() {
+    let text = "# 注释\na = 一\n\nb = \"\"\"\n  第一行\n\n# 块中的 # 不是注释\n\"\"\"\nbad line\na = 重复\n";
+    let (catalog, warnings) = Catalog::parse(text);
+    assert_eq!(catalog.get("a"), Some("一"));
+    assert_eq!(catalog.get("b"), Some("  第一行\n\n# 块中的 # 不是注释"));
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings[0].starts_with("第9行"));
+    assert!(warnings[1].contains("重复"));
+}
+
+#[test]
+fn placeholders_are_filled() {
+    assert_eq!(i18n::fill("第{id}章：{title}", &[("id", &6), ("title", &"所有权")]), "第6章：所有权");
+    assert_eq!(i18n::fill("{unknown} {", &[("id", &1)]), "{unknown} {");
+    assert_eq!(i18n::placeholders("{a} {b_2} {} {中}"), vec!["a", "b_2"]);
+}
+
+#[test]
+fn locale_tags_are_recognized() {
+    assert_eq!(Locale::from_tag("en"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("en_US.UTF-8"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("EN-gb"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("zh_CN.UTF-8"), Some(Locale::Zh));
+    assert_eq!(Locale::from_tag("C"), None);
+    assert_eq!(Locale::from_tag("fr_FR"), None);
+}
+
+/// 列出每种语言未翻译的键；有遗漏时测试失败，输出就是待翻译清单
+#[test]
+fn reports_untranslated_keys_per_locale() {
+    let zh_packs = KnowledgeBase::embedded();
+    let mut report = Vec::new();
+    for locale in Locale::ALL {
+        let mut missing = i18n::missing_keys(locale);
+        if locale != Locale::Zh {
+            let translated: Vec<u8> = knowledge::embedded_packs(locale)
+                .iter()
+                .filter_map(|(file, text)| knowledge::parse_pack(file, text).ok())
+                .map(|pack| pack.chapter)
+                .collect();
+            for id in zh_packs.chapters().filter(|id| !translated.contains(id)) {
+                let chapter = chapters::get(id).unwrap();
+                missing.push(format!("knowledge/{}/{:02}_{}.md", locale, id, chapter.bin));
+            }
+        }
+        eprintln!("[{}] 未翻译 {} 项", locale, missing.len());
+        for key in &missing {
+            eprintln!("  {}", key);
+        }
+        if !missing.is_empty() {
+            report.push(format!("{}: {}", locale, missing.join(", ")));
+        }
+    }
+    assert!(report.is_empty(), "未翻译的键：\n{}", report.join("\n"));
+}
+
+#[test]
+fn translations_keep_placeholders_and_known_keys() {
+    let zh = &i18n::catalog(Locale::Zh).0;
+    for locale in Locale::ALL {
+        let catalog = &i18n::catalog(locale).0;
+        for key in catalog.keys() {
+            let value = catalog.get(key).unwrap();
+            match zh.get(key) {
+                Some(original) => {
+                    let mut expected = i18n::placeholders(original);
+                    let mut actual = i18n::placeholders(value);
+                    expected.sort_unstable();
+                    actual.sort_unstable();
+                    assert_eq!(actual, expected, "[{}] {} 的占位符与原文不一致", locale, key);
+                }
+                None => {
+                    // 中文目录之外只允许出现章节字段
+                    let known = chapters::all().iter().any(|chapter| {
+                        i18n::CHAPTER_FIELDS
+                            .iter()
+                            .any(|field| i18n::chapter_key(chapter.id, field) == key)
+                    });
+                    assert!(known, "[{}] 未知的键 {}", locale, key);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn translated_chapter_lists_match_the_original() {
+    with_locale(Locale::En, || {
+        for chapter in chapters::all() {
+            assert_eq!(chapter.localized_goals().len(), chapter.goals.len(), "{}", chapter.title);
+            assert_eq!(chapter.localized_concepts().len(), chapter.concepts.len(), "{}", chapter.title);
+            assert_eq!(chapter.localized_summary().len(), chapter.summary.len(), "{}", chapter.title);
+        }
+    });
+}
+
+#[test]
+fn translated_knowledge_packs_match_the_original() {
+    let zh = KnowledgeBase::embedded();
+    let en = KnowledgeBase::embedded_for(Locale::En);
+    assert!(en.errors().is_empty(), "{:?}", en.errors());
+
+    // 相关主题在译文中也要跳转到同一章
+    let targets = |locale: Locale, base: &KnowledgeBase| -> Vec<Vec<Option<u8>>> {
+        with_locale(locale, || {
+            base.chapters()
+                .flat_map(|id| base.chapter(id).unwrap())
+                .map(|point| point.related_topics.iter().map(|topic| knowledge::resolve_topic(topic)).collect())
+                .collect()
+        })
+    };
+    assert_eq!(targets(Locale::En, &en), targets(Locale::Zh, &zh));
+
+    for id in zh.chapters() {
+        let (original, translated) = (zh.chapter(id).unwrap(), en.chapter(id).unwrap());
+        assert_eq!(original.len(), translated.len(), "第{}章", id);
+        for (a, b) in original.iter().zip(translated) {
+            assert_ne!(a.title, b.title, "第{}章的知识点没有翻译", id);
+            assert_eq!(a.code_examples.len(), b.code_examples.len(), "{}", a.title);
+            for (x, y) in a.code_examples.iter().zip(&b.code_examples) {
+                assert_eq!(x.expect, y.expect, "{}", x.title);
+            }
+        }
+    }
+}
+
+#[test]
+fn english_session_uses_the_catalog() {
+    let transcript = with_locale(Locale::En, || {
+        let script = "1\nknowledge 6\ngoto Borrowing\nknowledge 20\nq\n";
+        let mut session = Session::scripted(Cursor::new(script), Vec::new());
+        session.run().unwrap();
+        String::from_utf8(session.into_output()).unwrap()
+    });
+    for expected in [
+        "🦀 Welcome to the interactive Rust tutorial!",
+        "│               🦀 Rust Tutorial Menu                │",
+        "1. Variables & Constants  2. Data Types",
+        "🎯 Learning goals:",
+        "  • Master mutability (mut)",
+        "✅ Chapter 1: Variables & Constants example finished!",
+        "  📚 Chapter 2 Data Types: Scalar and compound data types",
+        "📖 Chapter 6: Ownership: knowledge points (1)",
+        "📚 Ownership rules",
+        "   → Borrowing (Chapter 7: Borrowing, goto 7)",
+        "  🚫 This code does not compile (E0382)",
+        "📚 References and the borrowing rules",
+        // 没有译文的章节回退到中文内容
+        "📭 No detailed knowledge points for Chapter 20: Unsafe Rust yet",
+        "👋 Thanks for using the Rust tutorial!",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+}
+
+#[test]
+fn verify_and_progress_messages_are_translated() {
+    let (outcome, warnings) = with_locale(Locale::En, || {
+        let outcome = Outcome::WrongErrorCode { expected: "E0382".into(), actual: Vec::new() }.to_string();
+        (outcome, Progress::parse("run x\nfoo 1\n").1)
+    });
+    assert_eq!(outcome, "expected error E0382, got (no error code)");
+    assert_eq!(
+        warnings,
+        ["line 1: malformed record, ignored: run x", "line 2: unknown record type foo, kept as is", "no version line, reading it as version 1"]
+    );
+}
+
+#[test]
+fn grader_messages_are_translated() {
+    let (outcomes, error) = with_locale(Locale::En, || {
+        let outcomes = [TestOutcome::Passed, TestOutcome::Failed("boom".into()), TestOutcome::TimedOut].map(|outcome| outcome.to_string());
+        (outcomes, grader::parse_assignment("bad.rs", "// title: a\n// chapter: 6\n").unwrap_err().message)
+    });
+    assert_eq!(outcomes, ["passed", "failed: boom", "timed out"]);
+    assert_eq!(error, "at least one api field is required");
+}
+
+#[test]
+fn exercise_messages_are_translated() {
+    let (outcomes, error) = with_locale(Locale::En, || {
+        let diagnostic = Diagnostic { message: "boom".into(), code: Some("E0499".into()), location: None, label: None };
+        let outcomes = [CaseOutcome::FailsAsDeclared, CaseOutcome::UnexpectedSuccess, CaseOutcome::WrongErrors(vec![diagnostic])]
+            .map(|outcome| outcome.to_string());
+        (outcomes, exercises::parse_exercise("bad.rs", "// error: 382\n").unwrap_err().message)
+    });
+    assert_eq!(
+        outcomes,
+        ["fails to compile as declared", "should fail to compile, but it compiled", "compile errors do not match the header: error[E0499]: boom"]
+    );
+    assert_eq!(error, "382 is not an error code like E0000");
+}
+
+#[test]
+fn compile_error_lists_are_translated() {
+    let text = with_locale(Locale::En, || {
+        let location = Location { file: "main.rs".into(), line: 3, column: 20 };
+        let diagnostic = Diagnostic {
+            message: "borrow of moved value: `s1`".into(),
+            code: Some("E0382".into()),
+            location: Some(location),
+            label: Some("value borrowed here after move".into()),
+        };
+        let mut out = Vec::new();
+        explain::write_errors(&mut out, &[diagnostic]).unwrap();
+        String::from_utf8(out).unwrap()
+    });
+    assert_eq!(text, "\n❌ error[E0382]: borrow of moved value: `s1`\n   --> main.rs:3:20 (value borrowed here after move)\n");
+}
+
+#[test]
+fn playground_errors_are_translated() {
+    let error = with_locale(Locale::En, || {
+        let mut playground = Playground::new(VerifyOptions { rustc: "/nonexistent/rustc".into(), ..VerifyOptions::default() });
+        playground.push_line("let x = 1;");
+        playground.run().unwrap_err().to_string()
+    });
+    assert!(error.starts_with("cannot run \"/nonexistent/rustc\": "), "{}", error);
+}
+
+#[test]
+fn binary_selects_language_from_flag_and_environment() {
+    let run = |args: &[&str], lang: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .env("LANG", lang)
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["summary", "6"], "en_US.UTF-8");
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("📖 Chapter 6: Ownership"), "{}", stdout);
+
+    let (_, stdout) = run(&["--lang", "en", "list"], "zh_CN.UTF-8");
+    assert!(stdout.contains("🔰 Basics (basic)"), "{}", stdout);
+
+    let (_, stdout) = run(&["list", "--lang", "zh"], "en_US.UTF-8");
+    assert!(stdout.contains("🔰 基础教程 (basic)"), "{}", stdout);
+
+    let (code, _) = run(&["--lang", "fr", "list"], "C");
+    assert_eq!(code, Some(task::cli::EXIT_USAGE));
+}
@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::Path;
+
+use task::chapters;
+use task::i18n::Locale;
+use task::knowledge::{self, parse_pack, Expectation, KnowledgeBase};
+
+fn knowledge_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../tutorial/knowledge"))
+}
+
+const SAMPLE: &str = "---
+chapter: 6
+---
+
+# 所有权
+
+第一段
+接着第一段
+
+第二段
+- 概述里的列表保留为文字
+
+## 核心概念
+- 每个值都有一个所有者
+- 同一时间只能有一个所有者
+
+## 示例：移动
+
+移动之后不能再使用。
+
+```rust
+let a = String::new();
+let b = a;
+```
+
+## 示例：输出
+
+```rust
+println!(\"hi\");
+```
+
+```text
+hi
+```
+";
+
+#[test]
+fn parses_sample_pack() {
+    let pack = parse_pack("sample.md", SAMPLE).unwrap();
+    assert_eq!(pack.chapter, 6);
+    let point = &pack.points[0];
+    assert_eq!(point.title, "所有权");
+    assert_eq!(
+        point.description,
+        "第一段\n接着第一段\n\n第二段\n- 概述里的列表保留为文字"
+    );
+    assert_eq!(point.key_concepts.len(), 2);
+    assert_eq!(point.code_examples.len(), 2);
+    assert_eq!(point.code_examples[0].explanation, "移动之后不能再使用。");
+    assert_eq!(point.code_examples[0].code, "let a = String::new();\nlet b = a;");
+    assert_eq!(point.code_examples[0].output, None);
+    assert_eq!(point.code_examples[1].output.as_deref(), Some("hi"));
+    assert_eq!(point.code_examples[1].expect, Expectation::Runs);
+
+    let pack = parse_pack(
+        "fail.md",
+        "---\nchapter: 6\n---\n# 移动\n概述\n## 示例：错误\n```rust, compile_fail, E0382\nlet b = a;\n```\n",
+    )
+    .unwrap();
+    assert_eq!(
+        pack.points[0].code_examples[0].expect,
+        Expectation::CompileFail {
+            code: Some("E0382".to_string())
+        }
+    );
+}
+
+#[test]
+fn errors_report_file_line_and_field() {
+    let cases = [
+        ("# 标题\n", 1, "头部"),
+        ("---\nchapter: 99\n---\n", 2, "chapter"),
+        ("---\nauthor: me\n---\n", 2, "author"),
+        ("---\n---\n# 标题\n概述\n", 2, "chapter"),
+        ("---\nchapter: 1\n---\n正文\n", 4, "正文"),
+        ("---\nchapter: 1\n---\n# 标题\n\n## 核心概念\n", 4, "标题"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 练习\n", 6, "练习"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 常见错误\n忘了写短横线\n", 7, "常见错误"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 示例：空\n说明\n", 6, "示例：空"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 示例：坏\n```rust\nlet x = 1;\n", 7, "示例：坏"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 示例：倒序\n```text\n1\n```\n", 7, "示例：倒序"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n```rust\nlet x = 1;\n```\n", 6, "标题"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 示例：属性\n```rust,no_run\n```\n", 7, "示例：属性"),
+        ("---\nchapter: 1\n---\n# 标题\n概述\n## 示例：错误码\n```rust,compile_fail,382\n```\n", 7, "示例：错误码"),
+        (
+            "---\nchapter: 1\n---\n# 标题\n概述\n## 示例：多余输出\n```rust,compile_fail\nx\n```\n```text\n1\n```\n",
+            10,
+            "示例：多余输出",
+        ),
+    ];
+    for (text, line, field) in cases {
+        let err = parse_pack("bad.md", text).expect_err(text);
+        assert_eq!((err.line, err.field.as_str()), (line, field), "{}\n{}", text, err);
+        assert!(err.to_string().starts_with(&format!("bad.md:{}: [{}]", line, field)));
+    }
+}
+
+#[test]
+fn embedded_packs_load_cleanly() {
+    let base = KnowledgeBase::embedded();
+    assert!(base.errors().is_empty(), "{:?}", base.errors());
+    for id in 1..=8 {
+        let points = base.chapter(id).unwrap_or_else(|| panic!("第{}章缺少知识点", id));
+        assert!(!points.is_empty());
+    }
+    assert_eq!(
+        knowledge::get_chapter_knowledge(1).unwrap()[0].title,
+        "变量声明与可变性"
+    );
+}
+
+#[test]
+fn every_pack_file_is_embedded_and_named_after_its_chapter() {
+    for locale in Locale::ALL {
+        // 中文在目录顶层，译本在以语言代码命名的子目录中
+        let dir = match locale {
+            Locale::Zh => knowledge_dir().to_path_buf(),
+            other => knowledge_dir().join(other.code()),
+        };
+        let embedded = knowledge::embedded_packs(locale);
+        let mut files = 0;
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_str().unwrap().to_string();
+            if path.is_dir() || name == "README.md" {
+                continue;
+            }
+            files += 1;
+            let pack = parse_pack(&name, &fs::read_to_string(&path).unwrap()).unwrap();
+            let chapter = chapters::get(pack.chapter).unwrap();
+            assert_eq!(name, format!("{:02}_{}.md", chapter.id, chapter.bin));
+            assert!(
+                embedded.iter().any(|(file, _)| Path::new(file).file_name() == Some(name.as_ref())),
+                "{} 没有加入 {} 的内置知识点",
+                name,
+                locale
+            );
+        }
+        assert_eq!(files, embedded.len(), "{}", locale);
+    }
+}
+
+#[test]
+fn directory_overrides_replace_chapters() {
+    let dir = std::env::temp_dir().join(format!("task-knowledge-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("06_ownership.md"), SAMPLE).unwrap();
+    fs::write(dir.join("07_borrowing.md"), "---\nchapter: 7\n---\n# 只有标题\n").unwrap();
+    fs::write(dir.join("README.md"), "说明文件不是知识点").unwrap();
+
+    let base = KnowledgeBase::embedded().with_overrides(&dir);
+    assert_eq!(base.chapter(6).unwrap()[0].title, "所有权");
+    // 出错的文件保留内置内容
+    assert_eq!(
+        base.chapter(7).unwrap()[0].title,
+        KnowledgeBase::embedded().chapter(7).unwrap()[0].title
+    );
+    assert_eq!(base.errors().len(), 1);
+    assert!(base.errors()[0].file.ends_with("07_borrowing.md"));
+
+    let missing = KnowledgeBase::embedded().with_overrides(&dir.join("missing"));
+    assert_eq!(missing.errors().len(), 1);
+    assert!(missing.chapter(1).is_some());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn renders_every_section() {
+    let mut pack = parse_pack("sample.md", SAMPLE).unwrap();
+    let point = &mut pack.points[0];
+    point.common_mistakes.push("移动后继续使用原变量".to_string());
+    point.best_practices.push("只读时传引用".to_string());
+    point.related_topics = vec!["借用机制".to_string(), "引用与借用规则".to_string(), "火星".to_string()];
+
+    let mut out = Vec::new();
+    knowledge::write_knowledge_point(&mut out, point).unwrap();
+    let text = String::from_utf8(out).unwrap();
+
+    for expected in [
+        "📚 所有权",
+        "   第一段\n   接着第一段\n\n   第二段",
+        "   • 每个值都有一个所有者",
+        "  示例 1：移动",
+        "  │ 1 │ let a = String::new();\n  │ 2 │ let b = a;",
+        "  💡 移动之后不能再使用。",
+        "  示例 2：输出",
+        "  📤 预期输出：\n  ▏ hi",
+        "   ✗ 移动后继续使用原变量",
+        "   ✓ 只读时传引用",
+        "   → 借用机制（第7章：借用机制，goto 7）",
+        "   → 引用与借用规则（第7章：借用机制，goto 7）",
+        "   → 火星\n",
+    ] {
+        assert!(text.contains(expected), "缺少 {:?}：\n{}", expected, text);
+    }
+    // 第一个示例没有预期输出
+    assert_eq!(text.matches("📤 预期输出").count(), 1);
+}
+
+#[test]
+fn topics_resolve_to_chapters() {
+    assert_eq!(knowledge::resolve_topic("所有权"), Some(6));
+    assert_eq!(knowledge::resolve_topic("栈与堆"), Some(5));
+    assert_eq!(knowledge::resolve_topic("宏系统"), Some(21));
+    // "特征" 同时出现在多个章节标题中，无法确定
+    assert_eq!(knowledge::resolve_topic("特征"), None);
+    assert_eq!(knowledge::resolve_topic("火星"), None);
+}
+
+#[test]
+fn session_follows_cross_references() {
+    use std::io::Cursor;
+    use task::session::Session;
+
+    let mut session = Session::scripted(Cursor::new("knowledge 7\ngoto 6\ngoto 所有权\nknowledge 20\ngoto 火星\nq\n"), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("📚 引用与借用规则"));
+    assert!(transcript.contains("→ 所有权（第6章：所有权，goto 6）"));
+    assert_eq!(transcript.matches("📚 所有权规则").count(), 2);
+    assert!(transcript.contains("📭 第20章：Unsafe Rust 暂无详细知识点"));
+    assert!(transcript.contains("❌ 找不到要跳转的章节：火星"));
+}
@@ -0,0 +1,111 @@
+// 按显示宽度排版：宽字符和表情符号的列宽、截断、折行、菜单方框和章节表格
+use std::io::Cursor;
+
+use task::i18n::{self, Locale};
+use task::layout::{self, Align, Panel, Table};
+use task::session::Session;
+use task::term::Theme;
+
+#[test]
+fn measures_display_width() {
+    assert_eq!(layout::width("Rust"), 4);
+    assert_eq!(layout::width("所有权"), 6);
+    assert_eq!(layout::width("（全角）"), 8);
+    assert_eq!(layout::width("🦀 Rust"), 7);
+    assert_eq!(layout::width("✅✓"), 3);
+    // 表情样式的变体、肤色修饰、零宽连接的家庭和国旗都算一个字素
+    assert_eq!(layout::width("⚠️"), 2);
+    assert_eq!(layout::width("👍🏽"), 2);
+    assert_eq!(layout::width("👨‍👩‍👧"), 2);
+    assert_eq!(layout::width("🇨🇳🇺🇸"), 4);
+    assert_eq!(layout::width("e\u{301}"), 1);
+    // 颜色转义序列不占列宽
+    assert_eq!(layout::width("\x1b[32m✅\x1b[0m 通过"), 7);
+}
+
+#[test]
+fn truncates_and_pads_by_columns() {
+    assert_eq!(layout::truncate("变量与常量", 10), "变量与常量");
+    assert_eq!(layout::truncate("变量与常量", 9), "变量与常…");
+    assert_eq!(layout::truncate("变量与常量", 8), "变量与…");
+    assert_eq!(layout::truncate("👨‍👩‍👧 family", 4), "👨‍👩‍👧 …");
+    assert_eq!(layout::truncate("abc", 0), "");
+
+    assert_eq!(layout::pad("所有权", 8, Align::Left), "所有权  ");
+    assert_eq!(layout::pad("所有权", 8, Align::Right), "  所有权");
+    assert_eq!(layout::pad("🦀", 5, Align::Center), " 🦀  ");
+    assert_eq!(layout::pad("太长的标题", 4, Align::Left), "太长的标题");
+}
+
+#[test]
+fn wraps_mixed_text() {
+    assert_eq!(layout::wrap("the borrow checker rejects this", 12), ["the borrow", "checker", "rejects this"]);
+    // 汉字之间可以断开，全角标点不放在行首
+    assert_eq!(layout::wrap("借用检查器拒绝了这段代码，因为", 12), ["借用检查器拒", "绝了这段代", "码，因为"]);
+    assert_eq!(layout::wrap("使用 clone 复制一份", 10), ["使用 clone", "复制一份"]);
+    assert_eq!(layout::wrap("supercalifragilistic", 8), ["supercal", "ifragili", "stic"]);
+    assert_eq!(layout::wrap("第一行\n\n  缩进", 20), ["第一行", "", "  缩进"]);
+    for line in layout::wrap("所有权规则：每个值都有一个所有者；值在任一时刻只能有一个所有者。When the owner goes out of scope, the value is dropped.", 16) {
+        assert!(layout::width(&line) <= 16, "{:?}", line);
+    }
+
+    let items: Vec<String> = ["1. 变量与常量", "2. 数据类型", "3. 函数"].map(String::from).to_vec();
+    assert_eq!(layout::flow(&items, 30, 2), ["1. 变量与常量  2. 数据类型", "3. 函数"]);
+}
+
+#[test]
+fn panel_lines_have_equal_width() {
+    for theme in Theme::ALL {
+        let panel = Panel::with_theme(30, theme);
+        let mut lines = vec![panel.top(), panel.centered("🦀 Rust 教程菜单"), panel.divider(), panel.line("一个放不下的非常非常长的标题🎉")];
+        lines.extend(panel.paragraph("search 关键词: 搜索教程、知识点和示例代码", 1));
+        lines.extend(panel.items(&["1. 变量与常量✓".to_string(), "2. 数据类型".to_string(), "3. 函数".to_string()], 1));
+        lines.push(panel.bottom());
+        for line in &lines {
+            assert_eq!(layout::width(line), 30, "{:?} {:?}", theme, lines);
+        }
+        let expected = match theme {
+            Theme::Unicode => ["│ 一个放不下的非常非常长的…  │", "│  知识点和示例代码          │"],
+            Theme::Ascii => ["│ 一个放不下的非常非常长...  │", "│  知识点和示例代码          │"],
+        };
+        assert!(lines.contains(&expected[0].to_string()), "{:?}", lines);
+        assert!(lines.contains(&expected[1].to_string()), "{:?}", lines);
+    }
+}
+
+#[test]
+fn table_aligns_columns_and_wraps_the_last_one() {
+    let mut table = Table::new(&[Align::Right, Align::Left, Align::Left]).indent(2).max_width(Some(40)).theme(Theme::Unicode);
+    table.heading("🔰 基础教程");
+    table.row(&["1.", "变量与常量", "变量声明、可变性和常量"]);
+    table.row(&["10.", "Enums", "枚举定义、模式匹配和 Option 类型的用法"]);
+    table.row(&["3.", "函数", ""]);
+    assert_eq!(
+        table.lines(),
+        [
+            "🔰 基础教程",
+            "   1. 变量与常量 变量声明、可变性和常量",
+            "  10. Enums      枚举定义、模式匹配和",
+            "                 Option 类型的用法",
+            "   3. 函数",
+        ]
+    );
+}
+
+/// 菜单方框每一行的宽度都相同
+#[test]
+fn menu_box_is_aligned_in_every_locale() {
+    for locale in Locale::ALL {
+        let previous = i18n::locale();
+        i18n::set_locale(locale);
+        let mut session = Session::scripted(Cursor::new("q\n"), Vec::new());
+        session.run().unwrap();
+        i18n::set_locale(previous);
+        let transcript = String::from_utf8(session.into_output()).unwrap();
+        let menu: Vec<&str> = transcript.lines().skip_while(|line| !line.starts_with('┌')).take_while(|line| !line.starts_with("🎓")).collect();
+        assert!(menu.len() > 20, "{}", transcript);
+        for line in &menu {
+            assert_eq!(layout::width(line), layout::MENU_WIDTH, "{:?}：{:?}", locale, line);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+// Rust 词法分析与语法高亮：记号种类、字节范围，以及任意输入都能切分且拼回原文
+use task::highlight;
+use task::lexer::{self, Token, TokenKind};
+use task::quiz::Rng;
+use task::source;
+
+/// 去掉空白后的 (种类, 文字)
+fn kinds(source: &str) -> Vec<(TokenKind, &str)> {
+    lexer::tokenize(source)
+        .into_iter()
+        .filter(|token| token.kind != TokenKind::Whitespace)
+        .map(|token| (token.kind, &source[token.span]))
+        .collect()
+}
+
+/// 记号首尾相接、不为空、落在字符边界上，拼起来是原文
+fn assert_round_trip(source: &str) {
+    let tokens = lexer::tokenize(source);
+    let mut end = 0;
+    for Token { span, .. } in &tokens {
+        assert_eq!(span.start, end, "{:?}", source);
+        assert!(span.end > span.start, "{:?}", source);
+        assert!(source.is_char_boundary(span.end), "{:?}", source);
+        end = span.end;
+    }
+    assert_eq!(end, source.len(), "{:?}", source);
+    assert_eq!(strip_ansi(&highlight::ansi(source)), source);
+    assert_eq!(unescape_html(&highlight::html(source)), source);
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+fn unescape_html(html: &str) -> String {
+    let mut plain = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        plain.push_str(&rest[..start]);
+        rest = &rest[start + rest[start..].find('>').unwrap() + 1..];
+    }
+    plain.push_str(rest);
+    plain.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&amp;", "&")
+}
+
+#[test]
+fn keywords_idents_and_macros() {
+    let source = "pub fn r#type(self) -> Self { println!(\"x\"); a != b }";
+    assert_eq!(
+        kinds(source)[..6],
+        [
+            (TokenKind::Keyword, "pub"),
+            (TokenKind::Keyword, "fn"),
+            (TokenKind::Ident, "r#type"),
+            (TokenKind::Punct, "("),
+            (TokenKind::Keyword, "self"),
+            (TokenKind::Punct, ")"),
+        ]
+    );
+    // break 以 br 开头、crate 以 cr 开头，都不是原始字符串
+    assert_eq!(kinds("break crate bar"), [(TokenKind::Keyword, "break"), (TokenKind::Keyword, "crate"), (TokenKind::Ident, "bar")]);
+    let html = highlight::html(source);
+    assert!(html.contains("<span class=\"macro\">println</span>!"), "{}", html);
+    assert!(html.contains("<span class=\"str\">&quot;x&quot;</span>"), "{}", html);
+    assert!(html.contains("a != b"), "{}", html);
+}
+
+#[test]
+fn chars_and_lifetimes() {
+    let source = "fn f<'a>(x: &'a str) -> char { let c = 'a'; let n = '\\n'; let q = '\\''; let u = '中'; b'x' } &'static str 'r#fn";
+    let found: Vec<(TokenKind, &str)> = kinds(source)
+        .into_iter()
+        .filter(|(kind, _)| matches!(kind, TokenKind::Char { .. } | TokenKind::Lifetime))
+        .collect();
+    let char = TokenKind::Char { terminated: true };
+    assert_eq!(
+        found,
+        [
+            (TokenKind::Lifetime, "'a"),
+            (TokenKind::Lifetime, "'a"),
+            (char, "'a'"),
+            (char, "'\\n'"),
+            (char, "'\\''"),
+            (char, "'中'"),
+            (char, "b'x'"),
+            (TokenKind::Lifetime, "'static"),
+            (TokenKind::Lifetime, "'r#fn"),
+        ]
+    );
+    // 没闭合的字符在行尾结束，不吞掉下一行
+    assert_eq!(kinds("' x\nlet")[..2], [(TokenKind::Char { terminated: false }, "' x"), (TokenKind::Keyword, "let")]);
+}
+
+#[test]
+fn strings_and_raw_strings() {
+    let source = r####""a\"b" b"bytes" c"c" r"C:\dir" r#"say "hi""# br##"a"#b"## r#"open"####;
+    assert_eq!(
+        kinds(source),
+        [
+            (TokenKind::Str { terminated: true }, r#""a\"b""#),
+            (TokenKind::Str { terminated: true }, r#"b"bytes""#),
+            (TokenKind::Str { terminated: true }, r#"c"c""#),
+            (TokenKind::RawStr { terminated: true }, r#"r"C:\dir""#),
+            (TokenKind::RawStr { terminated: true }, r##"r#"say "hi""#"##),
+            (TokenKind::RawStr { terminated: true }, r###"br##"a"#b"##"###),
+            (TokenKind::RawStr { terminated: false }, r##"r#"open"##),
+        ]
+    );
+    assert_eq!(kinds("\"line\nnext"), [(TokenKind::Str { terminated: false }, "\"line\nnext")]);
+}
+
+#[test]
+fn nested_block_comments() {
+    let source = "/* a /* b */ still comment */ x // end\n/** doc */ /* open /* */";
+    assert_eq!(
+        kinds(source),
+        [
+            (TokenKind::BlockComment { terminated: true }, "/* a /* b */ still comment */"),
+            (TokenKind::Ident, "x"),
+            (TokenKind::LineComment, "// end"),
+            (TokenKind::BlockComment { terminated: true }, "/** doc */"),
+            (TokenKind::BlockComment { terminated: false }, "/* open /* */"),
+        ]
+    );
+}
+
+#[test]
+fn numbers_with_suffixes() {
+    fn number(source: &str) -> (&str, usize) {
+        match lexer::tokenize(source).swap_remove(0) {
+            Token { kind: TokenKind::Number { suffix }, span } => (&source[span], suffix),
+            other => panic!("{:?}", other),
+        }
+    }
+    assert_eq!(number("42u8"), ("42u8", 2));
+    assert_eq!(number("1_000_000"), ("1_000_000", 0));
+    assert_eq!(number("0xffu8"), ("0xffu8", 2));
+    assert_eq!(number("0b1010_i32"), ("0b1010_i32", 3));
+    assert_eq!(number("0o77"), ("0o77", 0));
+    assert_eq!(number("2.5f32"), ("2.5f32", 3));
+    assert_eq!(number("1e10"), ("1e10", 0));
+    assert_eq!(number("6.02E+23_f64"), ("6.02E+23_f64", 3));
+    assert_eq!(number("1."), ("1.", 0));
+    // 范围、方法调用和元组字段中的点不属于数字
+    assert_eq!(number("1..10"), ("1", 0));
+    assert_eq!(number("1.max(2)"), ("1", 0));
+    assert_eq!(kinds("t.0")[2], (TokenKind::Number { suffix: 0 }, "0"));
+}
+
+#[test]
+fn ansi_colors_each_line_separately() {
+    let colored = highlight::ansi("/* a\nb */ let");
+    assert_eq!(colored, "\x1b[90m/* a\x1b[0m\n\x1b[90mb */\x1b[0m \x1b[35mlet\x1b[0m");
+}
+
+#[test]
+fn embedded_sources_round_trip() {
+    for path in ["src/examples.rs", "src/bin/macros.rs", "src/bin/procedural_macros.rs", "src/bin/lifetimes.rs"] {
+        let (_, text) = source::embedded(path).unwrap();
+        assert_round_trip(text);
+        let tokens = lexer::tokenize(text);
+        assert!(tokens.iter().all(|token| !matches!(
+            token.kind,
+            TokenKind::Str { terminated: false } | TokenKind::RawStr { terminated: false } | TokenKind::BlockComment { terminated: false }
+        )), "{}", path);
+    }
+}
+
+#[test]
+fn random_inputs_never_panic_and_round_trip() {
+    // 容易出错的片段随机拼接，加上真实源码的任意前缀
+    const PIECES: &[&str] = &[
+        "'", "\"", "\\", "r", "#", "b", "c", "br", "/*", "*/", "//", "/", "*", "\n", " ", "0x", "0b", "1", ".", "..", "e", "E", "+", "_",
+        "u8", "a", "fn", "'a", "中", "é", "🦀", "!", "=", "{", "}", "\t", "\r\n", "\u{200B}",
+    ];
+    let mut rng = Rng::new(2024);
+    for _ in 0..5000 {
+        let length = rng.below(16);
+        let input: String = (0..length).map(|_| PIECES[rng.below(PIECES.len())]).collect();
+        assert_round_trip(&input);
+    }
+    let (_, text) = source::embedded("src/bin/lifetimes.rs").unwrap();
+    let text = &text[..text.char_indices().nth(3000).map_or(text.len(), |(offset, _)| offset)];
+    for end in (0..=text.len()).filter(|&end| text.is_char_boundary(end)).step_by(7) {
+        assert_round_trip(&text[..end]);
+    }
+}
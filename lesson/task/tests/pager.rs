@@ -0,0 +1,161 @@
+// 分页查看：命令解析、翻页、搜索、小节跳转，以及菜单中超过一屏的章节输出
+use std::io::Cursor;
+
+use task::chapters;
+use task::pager::{self, Pager, PagerCommand, Step};
+use task::session::{self, Session, SessionOptions};
+
+/// 第 N 行的内容是 `line N`，第 5、12、20 行是小节标题
+fn numbered(count: usize) -> String {
+    (1..=count)
+        .map(|number| match number {
+            5 | 12 => format!("📍 {}. 小节", number),
+            20 => "🧵 线程基础：".to_string(),
+            _ => format!("line {}", number),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn parses_commands() {
+    assert_eq!(pager::parse_command("\n"), PagerCommand::NextPage);
+    assert_eq!(pager::parse_command("b"), PagerCommand::PreviousPage);
+    assert_eq!(pager::parse_command("+5"), PagerCommand::Down(5));
+    assert_eq!(pager::parse_command("-"), PagerCommand::Up(1));
+    assert_eq!(pager::parse_command("/ 所有权 "), PagerCommand::Search("所有权".to_string()));
+    assert_eq!(pager::parse_command("n"), PagerCommand::NextMatch);
+    assert_eq!(pager::parse_command("N"), PagerCommand::PreviousMatch);
+    assert_eq!(pager::parse_command("G"), PagerCommand::Bottom);
+    assert_eq!(pager::parse_command("s"), PagerCommand::Sections);
+    assert_eq!(pager::parse_command("s 3"), PagerCommand::Section(3));
+    assert_eq!(pager::parse_command("+x"), PagerCommand::Unknown("+x".to_string()));
+}
+
+#[test]
+fn detects_section_headers() {
+    assert!(pager::is_section_header("📍 4.1 引用与借用规则"));
+    assert!(pager::is_section_header("\n📍 1. 宏与函数的区别".trim_start()));
+    assert!(pager::is_section_header("🧵 线程基础："));
+    assert!(pager::is_section_header("🎯 Learning goals:"));
+    assert!(!pager::is_section_header("  • 线程创建和同步"));
+    assert!(!pager::is_section_header("  📚 学习第2章数据类型："));
+    assert!(!pager::is_section_header("所有权规则："));
+    assert!(!pager::is_section_header("✅ 第1章：变量与常量 示例运行完成！"));
+
+    // 章节运行的输出按标题分成小节
+    let chapter = chapters::get(19).unwrap();
+    let mut output = Vec::new();
+    session::show_chapter_run(&mut output, chapter).unwrap();
+    let pager = Pager::new(&String::from_utf8(output).unwrap(), 10);
+    let headers: Vec<&str> = pager.sections().iter().map(|&line| pager.lines()[line].as_str()).collect();
+    for expected in ["🧵 线程基础：", "📨 消息传递："] {
+        assert!(headers.contains(&expected), "{:?}", headers);
+    }
+}
+
+#[test]
+fn pages_scrolls_and_stops_at_the_end() {
+    let mut pager = Pager::new(&numbered(25), 10);
+    assert!(!pager.fits());
+    assert_eq!(pager.visible()[0], "line 1");
+    assert_eq!(pager.apply(PagerCommand::NextPage), Step::Page);
+    assert_eq!(pager.top(), 10);
+    // 最后一页显示完整的一屏
+    assert_eq!(pager.apply(PagerCommand::NextPage), Step::Page);
+    assert_eq!((pager.top(), pager.visible().last().unwrap().as_str()), (15, "line 25"));
+    assert_eq!(pager.apply(PagerCommand::NextPage), Step::Quit);
+
+    pager.apply(PagerCommand::PreviousPage);
+    assert_eq!(pager.top(), 5);
+    pager.apply(PagerCommand::Up(100));
+    assert_eq!(pager.top(), 0);
+    pager.apply(PagerCommand::Down(3));
+    assert_eq!(pager.top(), 3);
+    pager.apply(PagerCommand::Bottom);
+    assert_eq!(pager.top(), 15);
+    pager.apply(PagerCommand::Top);
+    assert_eq!(pager.top(), 0);
+    assert_eq!(pager.apply(PagerCommand::Quit), Step::Quit);
+    assert!(Pager::new(&numbered(10), 10).fits());
+}
+
+#[test]
+fn searches_forward_and_backward() {
+    let mut pager = Pager::new(&numbered(40), 10);
+    pager.apply(PagerCommand::Search("LINE 3".to_string()));
+    assert_eq!(pager.top(), 2);
+    pager.apply(PagerCommand::NextMatch);
+    assert_eq!(pager.top(), 29);
+    pager.apply(PagerCommand::NextMatch);
+    assert_eq!(pager.top(), 30);
+    pager.apply(PagerCommand::PreviousMatch);
+    assert_eq!(pager.top(), 29);
+
+    pager.apply(PagerCommand::Search("没有的词".to_string()));
+    assert_eq!(pager.top(), 29);
+    let mut page = Vec::new();
+    pager.write_page(&mut page).unwrap();
+    let page = String::from_utf8(page).unwrap();
+    assert!(page.contains("── 第 30-39 行，共 40 行（97%）"), "{}", page);
+    assert!(page.contains("🔍 没有找到更多的“没有的词”"), "{}", page);
+}
+
+#[test]
+fn jumps_between_sections() {
+    let mut pager = Pager::new(&numbered(40), 10);
+    assert_eq!(pager.sections(), [4, 11, 19]);
+    pager.apply(PagerCommand::NextSection);
+    assert_eq!(pager.top(), 4);
+    pager.apply(PagerCommand::NextSection);
+    assert_eq!(pager.top(), 11);
+    pager.apply(PagerCommand::PreviousSection);
+    assert_eq!(pager.top(), 4);
+    pager.apply(PagerCommand::Section(3));
+    assert_eq!(pager.top(), 19);
+
+    let Step::Text(list) = pager.apply(PagerCommand::Sections) else {
+        panic!("应当列出小节");
+    };
+    assert!(list.contains("s1   📍 5. 小节") && list.contains("s3   🧵 线程基础："), "{}", list);
+    pager.apply(PagerCommand::Section(9));
+    assert_eq!(pager.top(), 19);
+}
+
+#[test]
+fn run_reads_commands_until_quit() {
+    let mut pager = Pager::new(&numbered(30), 10);
+    let mut output = Vec::new();
+    pager::run(&mut Cursor::new("s2\nh\n\nxyz\nq\nnot read\n"), &mut output, &mut pager).unwrap();
+    let transcript = String::from_utf8(output).unwrap();
+    // s2 跳到第 12 行，看完帮助后回车回到这一页，不翻页
+    assert_eq!(pager.top(), 11);
+    assert!(transcript.contains("line 1\n"), "{}", transcript);
+    assert!(transcript.contains("📖 分页命令"), "{}", transcript);
+    assert!(transcript.contains("❌ 未知命令 xyz，输入 h 查看帮助"), "{}", transcript);
+}
+
+#[test]
+fn menu_pages_long_chapter_output() {
+    let options = SessionOptions {
+        pause: true,
+        clear_screen: false,
+    };
+    let run = |height: usize, input: &str| {
+        let mut session = Session::with_options(Cursor::new(input.to_string()), Vec::new(), options).with_pager(height);
+        session.run().unwrap();
+        String::from_utf8(session.into_output()).unwrap()
+    };
+
+    let transcript = run(8, "19\n/线程基础\n]\nq\nq\n");
+    assert!(transcript.contains("── 第 1-8 行，共"), "{}", transcript);
+    assert!(transcript.contains("📨 消息传递："), "{}", transcript);
+    // 分页结束后直接回到菜单，不再等待回车
+    assert!(!transcript.contains("⏎ 按回车键继续"), "{}", transcript);
+    assert_eq!(transcript.matches("┌─").count(), 2);
+
+    // 一屏放得下时和以前一样直接显示
+    let transcript = run(1000, "1\n\nq\n");
+    assert!(!transcript.contains("── 第 "), "{}", transcript);
+    assert_eq!(transcript.matches("⏎ 按回车键继续...").count(), 1);
+}
@@ -0,0 +1,206 @@
+// 代码试验场：命令解析、定义与语句的拆分、行号换算、编译运行和菜单中的 playground 模式
+use std::io::{Cursor, ErrorKind};
+use std::process::Command;
+use std::time::Duration;
+
+use task::playground::{self, Line, Playground, PlaygroundCommand, RunOutcome, RunReport};
+use task::session::Session;
+use task::verify::VerifyOptions;
+
+fn lines(text: &str) -> Vec<Line> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| Line {
+            number: index + 1,
+            text: line.to_string(),
+        })
+        .collect()
+}
+
+fn numbers(lines: &[Line]) -> Vec<usize> {
+    lines.iter().map(|line| line.number).collect()
+}
+
+fn playground_with(text: &str) -> Playground {
+    let mut playground = Playground::new(VerifyOptions::default()).with_run_timeout(Duration::from_millis(500));
+    for line in text.lines() {
+        playground.push_line(line);
+    }
+    playground
+}
+
+/// 编译运行；没有 rustc 时返回 None 跳过检查
+fn run(playground: &mut Playground) -> Option<RunReport> {
+    match playground.run() {
+        Ok(report) => Some(report),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            eprintln!("跳过：{}", err);
+            None
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn stdout(report: &RunReport) -> &str {
+    match &report.outcome {
+        RunOutcome::Finished { success: true, stdout, .. } => stdout,
+        other => panic!("运行失败：{:?}", other),
+    }
+}
+
+#[test]
+fn parses_commands() {
+    assert_eq!(playground::parse_command(":run"), Some(PlaygroundCommand::Run));
+    assert_eq!(playground::parse_command("  :LOAD 6.2 "), Some(PlaygroundCommand::Load("6.2".to_string())));
+    assert_eq!(playground::parse_command(":q"), Some(PlaygroundCommand::Quit));
+    assert_eq!(playground::parse_command(":nope"), Some(PlaygroundCommand::Unknown(":nope".to_string())));
+    assert_eq!(playground::parse_command("::std::mem::drop(v);"), None);
+    assert_eq!(playground::parse_command("let x = 1;"), None);
+}
+
+#[test]
+fn splits_items_from_statements() {
+    let entry = lines(
+        "#[derive(Debug)]
+struct Point {
+    x: i32,
+}
+
+let p = Point { x: 1 };
+impl Point
+{
+    fn show(&self) -> String { format!(\"{{{}}}\", self.x) }
+}
+let text = \"}\";
+let raw = r#\"{\"#;
+fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() > b.len() { a } else { b }
+}
+let c = '{';
+println!(\"{:?} {}\", p, text); // }
+use std::fmt;",
+    );
+    let (items, statements) = playground::split_entry(&entry);
+    let items: Vec<Vec<usize>> = items.iter().map(|item| numbers(item)).collect();
+    assert_eq!(items, [vec![1, 2, 3, 4], vec![7, 8, 9, 10], vec![13, 14, 15], vec![18]]);
+    assert_eq!(numbers(&statements), [5, 6, 11, 12, 16, 17]);
+
+    // fn main 的函数体按语句处理，保持原来的列号
+    let (items, statements) = playground::split_entry(&lines("fn helper() {}\n\nfn main() { let x = 1;\n    helper();\n}"));
+    assert_eq!(items.len(), 1);
+    assert_eq!(numbers(&statements), [3, 4]);
+    assert_eq!(statements[0].text, "            let x = 1;");
+
+    // 转义的单引号字符、跨行的字符串和行尾注释都不影响判断
+    let (items, statements) = playground::split_entry(&lines("let q = ['\\'','{'];\nfn after() {}\nlet s = \"a\n{\";\nstruct B; // {"));
+    let items: Vec<Vec<usize>> = items.iter().map(|item| numbers(item)).collect();
+    assert_eq!(items, [vec![2], vec![5]]);
+    assert_eq!(numbers(&statements), [1, 3, 4]);
+}
+
+#[test]
+fn maps_program_lines_back_to_input() {
+    let playground = playground_with("struct A;\nlet a = A;\n  let b = 2;");
+    let program = playground.program();
+    assert_eq!(program.source, "#![allow(unused)]\nstruct A;\nfn main() {\n    let a = A;\n      let b = 2;\n}\n");
+    assert_eq!(program.origin(2, 1), Some((1, 1)));
+    assert_eq!(program.origin(5, 7), Some((3, 3)));
+    assert_eq!(program.origin(3, 1), None);
+    assert_eq!(program.origin(99, 1), None);
+}
+
+#[test]
+fn keeps_items_that_compile() {
+    let mut playground = playground_with("fn double(x: i32) -> i32 {\n    x * 2\n}\nlet a = double(21);\nprintln!(\"{}\", a);");
+    let Some(report) = run(&mut playground) else {
+        return;
+    };
+    assert_eq!((stdout(&report), report.kept), ("42\n", 1));
+    assert!(playground.buffer().is_empty());
+
+    // 之前的语句不再运行，定义还能继续使用
+    playground.push_line("println!(\"{}\", double(4));");
+    let report = run(&mut playground).unwrap();
+    assert_eq!((stdout(&report), report.kept), ("8\n", 0));
+
+    // 编译失败时不保留新的定义，行号换算回输入
+    assert_eq!(playground.next_line(), 7);
+    for line in ["fn broken() -> u8 { 1 }", "let s: String = double(1);"] {
+        playground.push_line(line);
+    }
+    let report = run(&mut playground).unwrap();
+    let RunOutcome::CompileErrors(errors) = &report.outcome else {
+        panic!("{:?}", report);
+    };
+    assert_eq!(errors[0].code.as_deref(), Some("E0308"));
+    let location = errors[0].location.as_ref().unwrap();
+    assert_eq!((location.file.as_str(), location.line, location.column), ("playground", 8, 17));
+    assert_eq!((report.kept, playground.items().len()), (0, 1));
+
+    playground.clear();
+    assert_eq!((playground.next_line(), playground.items().len()), (1, 0));
+    assert_eq!(run(&mut playground).unwrap().outcome, RunOutcome::Empty);
+}
+
+#[test]
+fn reports_panics_and_timeouts() {
+    let mut playground = playground_with("let v: Vec<i32> = Vec::new();\nprintln!(\"开始\");\nlet x = v[3];");
+    let Some(report) = run(&mut playground) else {
+        return;
+    };
+    let RunOutcome::Finished { success: false, stdout, stderr } = &report.outcome else {
+        panic!("{:?}", report);
+    };
+    assert_eq!(stdout, "开始\n");
+    assert!(stderr.contains("panicked at playground:3:"), "{}", stderr);
+    assert!(!stderr.contains("RUST_BACKTRACE"), "{}", stderr);
+
+    playground.push_line("loop {}");
+    assert_eq!(run(&mut playground).unwrap().outcome, RunOutcome::TimedOut);
+}
+
+#[test]
+fn loads_chapter_examples() {
+    let examples = playground::chapter_examples(6);
+    assert!(!examples.is_empty());
+    assert_eq!(playground::find_example("6.1").map(|example| &example.title), Some(&examples[0].title));
+    assert_eq!(playground::find_example("6 1").map(|example| &example.title), Some(&examples[0].title));
+    assert!(playground::find_example("6.0").is_none());
+    assert!(playground::find_example("6").is_none());
+
+    let mut playground = playground_with("let stale = 1;");
+    playground.load(examples[0]);
+    let code: Vec<&str> = examples[0].code.lines().skip_while(|line| line.trim().is_empty()).collect();
+    let loaded: Vec<&str> = playground.buffer().iter().map(|line| line.text.as_str()).collect();
+    assert_eq!(loaded, code);
+    assert_eq!(playground.buffer()[0].number, 2);
+}
+
+#[test]
+fn menu_playground_mode() {
+    let script = "playground\n:load\n:load 6\nstruct Counter { n: u32 }\n:run\nlet c = Counter { n: 3 };\nprintln!(\"n = {}\", c.n);\n:show\n:run\nlet s: u8 = \"x\";\n:run\n:what\n:q\nq\n";
+    let mut session = Session::scripted(Cursor::new(script), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    for expected in [
+        "🧪 代码试验场",
+        "  1│ struct Counter { n: u32 }",
+        "❌ 用法：:load <章节>.<编号>",
+        "  6.1 ",
+        "✏️  当前输入：\n  2│ let c = Counter { n: 3 };\n  3│ println!(\"n = {}\", c.n);",
+        "❌ 未知命令 :what，输入 :help 查看命令",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+    if Command::new(VerifyOptions::default().rustc).arg("--version").output().is_ok() {
+        for expected in [
+            "📦 保留了 1 个定义",
+            "n = 3",
+            "❌ error[E0308]: mismatched types\n   --> playground:4:13",
+            "💡 这次输入没有保留",
+        ] {
+            assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+        }
+    }
+    assert!(transcript.contains("🎓 请选择章节"), "{}", transcript);
+}
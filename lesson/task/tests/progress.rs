@@ -0,0 +1,202 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::process::Command;
+
+use task::chapters;
+use task::progress::{ActivityResult, Progress, ProgressStore, ResultKind, FILE_NAME};
+use task::session::Session;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("task-progress-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// 以中文界面运行 task，不受终端语言设置影响
+fn task_command() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_task"));
+    command.env("LANG", "zh_CN.UTF-8").env_remove("LC_ALL").env_remove("LC_MESSAGES");
+    command
+}
+
+#[test]
+fn round_trips_through_text() {
+    let mut progress = Progress::default();
+    progress.record_run(1, 100);
+    progress.record_run(1, 200);
+    progress.record_run(6, 150);
+    progress.record_result(ActivityResult {
+        kind: ResultKind::Quiz,
+        chapter: 6,
+        score: 4,
+        total: 5,
+        at: 160,
+    });
+
+    let (parsed, warnings) = Progress::parse(&progress.to_text());
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(parsed, progress);
+
+    let run = parsed.chapter(1).unwrap();
+    assert_eq!((run.runs, run.first_run, run.last_run), (2, 100, 200));
+    assert_eq!(parsed.latest_result(ResultKind::Quiz, 6).unwrap().score, 4);
+}
+
+#[test]
+fn corrupted_lines_are_skipped_with_warnings() {
+    let text = "version 1\nrun 1 2 100 200\nrun 3 abc\nbogus line\nresult quiz 1 3 5\nrun 2 1 50 50";
+    let (progress, warnings) = Progress::parse(text);
+    assert!(progress.is_completed(1));
+    assert!(progress.is_completed(2));
+    assert!(!progress.is_completed(3));
+    assert!(progress.results().is_empty());
+    assert_eq!(warnings.len(), 3, "{:?}", warnings);
+    assert!(warnings[0].starts_with("第3行"));
+}
+
+#[test]
+fn unknown_record_types_survive_a_save() {
+    // 更新的版本加入的记录类型，旧版本保存时不能丢掉
+    let (mut progress, warnings) = Progress::parse("version 1\nrun 1 1 10 10\nbadge gold 42\n");
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    progress.record_run(2, 20);
+    let text = progress.to_text();
+    assert!(text.contains("\nbadge gold 42\n"), "{}", text);
+    let (parsed, _) = Progress::parse(&text);
+    assert_eq!(parsed, progress);
+}
+
+#[test]
+fn newer_or_missing_version_is_reported() {
+    let (progress, warnings) = Progress::parse("version 9\nrun 4 1 10 10\nbadge gold\n");
+    assert!(progress.is_completed(4));
+    assert!(warnings.iter().any(|warning| warning.contains("版本 9")));
+
+    let (_, warnings) = Progress::parse("run 4 1 10 10\n");
+    assert!(warnings.iter().any(|warning| warning.contains("缺少版本号")));
+
+    let (progress, warnings) = Progress::parse("");
+    assert!(progress.is_empty());
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn continue_with_follows_learning_order() {
+    let all = chapters::all();
+    let mut progress = Progress::default();
+    assert_eq!(progress.continue_with().unwrap().id, all[0].id);
+
+    progress.record_run(all[0].id, 10);
+    progress.record_run(all[1].id, 20);
+    assert_eq!(progress.continue_with().unwrap().id, all[2].id);
+
+    // 最近复习了前面的章节时，跳过后面已经学过的章节
+    progress.record_run(all[2].id, 30);
+    progress.record_run(all[0].id, 40);
+    assert_eq!(progress.continue_with().unwrap().id, all[3].id);
+
+    for (i, chapter) in all.iter().enumerate() {
+        progress.record_run(chapter.id, 100 + i as u64);
+    }
+    assert_eq!(progress.completed_count(), all.len());
+    assert!(progress.continue_with().is_none());
+}
+
+#[test]
+fn store_saves_and_loads() {
+    let dir = scratch_dir("store");
+    let store = ProgressStore::new(&dir);
+    let (empty, warnings) = store.load().unwrap();
+    assert!(empty.is_empty() && warnings.is_empty());
+
+    let mut progress = Progress::default();
+    progress.record_run(5, 42);
+    store.save(&progress).unwrap();
+    assert_eq!(store.load().unwrap().0, progress);
+    assert!(!dir.join(format!("{}.tmp", FILE_NAME)).exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn store_does_not_overwrite_newer_schema() {
+    let dir = scratch_dir("newer");
+    let store = ProgressStore::new(&dir);
+    let text = "version 9\nrun 4 1 10 10\nbadge gold\n";
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(store.path(), text).unwrap();
+    let (mut progress, _) = store.load().unwrap();
+    progress.record_run(5, 42);
+    let err = store.save(&progress).unwrap_err();
+    assert!(err.to_string().contains("版本 9"), "{}", err);
+    assert_eq!(fs::read_to_string(store.path()).unwrap(), text, "不认识的记录要保留");
+
+    fs::write(store.path(), "version 1\nrun 4 1 10 10\n").unwrap();
+    store.save(&progress).unwrap();
+    assert_eq!(store.load().unwrap().0, progress);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn session_marks_completed_chapters_and_resumes() {
+    let dir = scratch_dir("session");
+    let store = ProgressStore::new(&dir);
+    let first = &chapters::all()[0];
+    let second = &chapters::all()[1];
+
+    let mut session = Session::scripted(Cursor::new(format!("{}\nq\n", first.id)), Vec::new())
+        .with_progress(store.clone());
+    session.run().unwrap();
+    assert!(session.progress().is_completed(first.id));
+    assert!(store.load().unwrap().0.is_completed(first.id));
+
+    let mut session = Session::scripted(Cursor::new("c\nq\n"), Vec::new()).with_progress(store.clone());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains(&format!("已完成 1/{} 章", chapters::all().len())));
+    assert!(transcript.contains(&format!("输入 c 继续学习{}", second.display_name())));
+    assert!(transcript.contains(&format!("{}. {}✓", first.id, first.title)));
+    assert!(transcript.contains(&format!("✅ {} 示例运行完成！", second.display_name())));
+    assert!(store.load().unwrap().0.is_completed(second.id));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn session_reports_corrupted_progress_file() {
+    let dir = scratch_dir("corrupted");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(FILE_NAME), "version 1\nrun x y\n").unwrap();
+
+    let mut session = Session::scripted(Cursor::new("q\n"), Vec::new()).with_progress(ProgressStore::new(&dir));
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("⚠️  学习进度：第2行：记录格式错误"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn binary_records_runs_in_profile_dir() {
+    let dir = scratch_dir("binary");
+    let run = task_command()
+        .args(["run", "3", "--profile-dir"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(run.status.success());
+
+    let show = task_command()
+        .arg("progress")
+        .arg("--profile-dir")
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(show.status.success());
+    let stdout = String::from_utf8_lossy(&show.stdout);
+    assert!(stdout.contains("已完成 1/"), "{}", stdout);
+    assert!(stdout.contains("✓ 运行 1 次"), "{}", stdout);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
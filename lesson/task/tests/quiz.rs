@@ -0,0 +1,231 @@
+use std::fs;
+use std::io::Cursor;
+use std::process::Command;
+
+use task::chapters;
+use task::knowledge;
+use task::progress::{ProgressStore, ResultKind};
+use task::quiz::{self, Question, QuestionKind, Rng, Verdict, QUESTIONS};
+use task::session::Session;
+
+/// 按题型给出一个正确答案
+fn correct_answer(question: &Question) -> String {
+    match question.kind {
+        QuestionKind::MultipleChoice { answer, .. } => ((b'a' + answer as u8) as char).to_string(),
+        QuestionKind::TrueFalse { answer } => if answer { "t" } else { "f" }.to_string(),
+        QuestionKind::PredictOutput { output, .. } => output.replace('\n', " "),
+        QuestionKind::FailingLine { line, .. } => line.to_string(),
+    }
+}
+
+/// 用同一个种子重现出题顺序，生成答案文件内容
+fn answers_for(chapter: u8, seed: u64, correct: usize) -> String {
+    let mut questions = quiz::questions_for(chapter);
+    Rng::new(seed).shuffle(&mut questions);
+    questions
+        .iter()
+        .enumerate()
+        .map(|(i, question)| if i < correct { correct_answer(question) } else { "?".to_string() })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 以中文界面运行 task，不受终端语言设置影响
+fn task_command() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_task"));
+    command.env("LANG", "zh_CN.UTF-8").env_remove("LC_ALL").env_remove("LC_MESSAGES");
+    command
+}
+
+#[test]
+fn rng_is_deterministic_per_seed() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+    let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+    let third: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+
+    let mut items: Vec<u32> = (0..20).collect();
+    Rng::new(7).shuffle(&mut items);
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn answers_are_checked_per_kind() {
+    // 选项在消息目录中：第1章第4题有四个选项，b 正确
+    let choice = *QUESTIONS.iter().find(|question| question.key == "quiz.1.4").unwrap();
+    assert_eq!(choice.options().len(), 4);
+    assert_eq!(choice.check("b"), Verdict::Correct);
+    assert_eq!(choice.check(" B \n"), Verdict::Correct);
+    assert_eq!(choice.check("2"), Verdict::Correct);
+    assert_eq!(choice.check("a"), Verdict::Wrong);
+    assert_eq!(choice.check("e"), Verdict::Invalid);
+    assert_eq!(choice.check("0"), Verdict::Invalid);
+
+    let truth = Question {
+        kind: QuestionKind::TrueFalse { answer: false },
+        ..choice
+    };
+    assert_eq!(truth.check("错"), Verdict::Correct);
+    assert_eq!(truth.check("true"), Verdict::Wrong);
+    assert_eq!(truth.check("maybe"), Verdict::Invalid);
+
+    let output = Question {
+        kind: QuestionKind::PredictOutput {
+            code: "",
+            output: "12\n6",
+        },
+        ..choice
+    };
+    assert_eq!(output.check("12   6"), Verdict::Correct);
+    assert_eq!(output.check("12"), Verdict::Wrong);
+    assert_eq!(output.check(""), Verdict::Invalid);
+
+    let failing = Question {
+        kind: QuestionKind::FailingLine {
+            code: "a\nb\nc",
+            line: 2,
+        },
+        ..choice
+    };
+    assert_eq!(failing.check("2"), Verdict::Correct);
+    assert_eq!(failing.check("3"), Verdict::Wrong);
+    assert_eq!(failing.check("4"), Verdict::Invalid);
+}
+
+#[test]
+fn questions_are_well_formed() {
+    for (index, question) in QUESTIONS.iter().enumerate() {
+        assert!(chapters::get(question.chapter).is_some(), "{}", question.key);
+        assert!(question.key.starts_with(&format!("quiz.{}.", question.chapter)), "{}", question.key);
+        assert!(QUESTIONS[..index].iter().all(|other| other.key != question.key), "{} 重复", question.key);
+        // 题干和解释都在消息目录中，找不到时会显示键名
+        assert_ne!(question.prompt(), question.key);
+        assert_ne!(question.explanation(), question.key);
+        assert_eq!(question.check(&correct_answer(question)), Verdict::Correct, "{}", question.key);
+        if let Some(point) = question.point {
+            let points = knowledge::get_chapter_knowledge(question.chapter).unwrap_or_default();
+            assert!(point < points.len(), "第{}章没有第{}个知识点", question.chapter, point + 1);
+        }
+        match question.kind {
+            QuestionKind::MultipleChoice { answer } => assert!(answer < question.options().len() && question.options().len() >= 2, "{}", question.key),
+            QuestionKind::FailingLine { code, line } => {
+                assert!((1..=code.lines().count()).contains(&line), "{}", code)
+            }
+            _ => {}
+        }
+    }
+    assert!(!quiz::chapters_with_quiz().is_empty());
+}
+
+#[test]
+fn answers_from_input_are_scored() {
+    let total = quiz::questions_for(1).len() as u32;
+    let answers = answers_for(1, 9, 2);
+    let mut out = Vec::new();
+    let report = quiz::run_quiz(&mut Cursor::new(answers), &mut out, 1, &mut Rng::new(9), true).unwrap();
+    assert!(report.complete);
+    assert_eq!((report.score, report.total), (2, total));
+    assert_eq!(report.missed.len(), total as usize - 2);
+
+    let transcript = String::from_utf8(out).unwrap();
+    assert!(transcript.contains(&format!("📊 得分：2/{}", total)));
+    assert!(transcript.contains("⚠️  无法识别的答案"));
+}
+
+#[test]
+fn input_ending_early_counts_as_unanswered() {
+    let answers = answers_for(2, 1, 1);
+    let first_line = answers.lines().next().unwrap().to_string();
+    let mut out = Vec::new();
+    let report = quiz::run_quiz(&mut Cursor::new(first_line), &mut out, 2, &mut Rng::new(1), true).unwrap();
+    assert!(!report.complete);
+    assert_eq!(report.score, 1);
+    assert_eq!(report.missed.len(), report.total as usize - 1);
+}
+
+#[test]
+fn session_quiz_command_records_result() {
+    let dir = std::env::temp_dir().join(format!("task-quiz-session-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let store = ProgressStore::new(&dir);
+    let total = quiz::questions_for(1).len();
+
+    // 全部答错：与出题顺序无关
+    let script = format!("quiz 1\n{}q\n", "?\n".repeat(total));
+    let mut session = Session::scripted(Cursor::new(script), Vec::new()).with_progress(store.clone());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains(&format!("📊 得分：0/{}", total)));
+    assert!(transcript.contains("📖 建议复习：变量声明与可变性"));
+    assert!(transcript.contains("👋 感谢使用"));
+
+    let saved = store.load().unwrap().0;
+    let result = saved.latest_result(ResultKind::Quiz, 1).unwrap();
+    assert_eq!((result.score, result.total), (0, total as u32));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn binary_reads_answers_file() {
+    let dir = std::env::temp_dir().join(format!("task-quiz-binary-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let answers = dir.join("answers.txt");
+    fs::write(&answers, answers_for(6, 3, usize::MAX)).unwrap();
+
+    let output = task_command()
+        .args(["quiz", "6", "--seed", "3", "--answers"])
+        .arg(&answers)
+        .arg("--profile-dir")
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let total = quiz::questions_for(6).len();
+    assert!(stdout.contains(&format!("📊 得分：{0}/{0} (100%)", total)), "{}", stdout);
+
+    let saved = ProgressStore::new(&dir).load().unwrap().0;
+    assert!(saved.latest_result(ResultKind::Quiz, 6).is_some());
+
+    let missing = task_command()
+        .args(["quiz", "20", "--profile-dir"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert_eq!(missing.status.code(), Some(task::cli::EXIT_FAILURE));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn english_quiz_is_fully_translated() {
+    let dir = std::env::temp_dir().join(format!("task-quiz-english-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let answers = dir.join("answers.txt");
+    fs::write(&answers, answers_for(1, 1, 1)).unwrap();
+
+    let output = task_command()
+        .args(["--lang", "en", "quiz", "1", "--seed", "1", "--answers"])
+        .arg(&answers)
+        .arg("--profile-dir")
+        .arg(&dir)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(&format!("📊 Score: 1/{}", quiz::questions_for(1).len())), "{}", stdout);
+    // 按知识点序号关联，英文知识点标题与中文不同也能给出复习建议
+    assert!(stdout.contains("📖 Review: "), "{}", stdout);
+    let chinese: Vec<&str> = stdout.lines().filter(|line| line.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c))).collect();
+    assert!(chinese.is_empty(), "{:?}", chinese);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,199 @@
+// 全文搜索：分词、排序、摘要和菜单跳转
+use std::fs;
+use std::io::Cursor;
+use std::process::Command;
+
+use task::search::{self, Document, Index, Query, Source, TokenKind};
+use task::session::Session;
+
+fn terms(text: &str, kind: TokenKind) -> Vec<String> {
+    search::tokenize(text)
+        .into_iter()
+        .filter(|token| token.kind == kind)
+        .map(|token| token.term)
+        .collect()
+}
+
+fn document(source: Source, chapter: Option<u8>, title: &str, text: &str) -> Document {
+    Document {
+        source,
+        chapter,
+        title: title.to_string(),
+        path: None,
+        line: 0,
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn tokenizes_rust_identifiers_and_paths() {
+    let text = "let weak = Rc::downgrade(&rc); read_to_string(HashMap::new())";
+    assert_eq!(
+        terms(text, TokenKind::Word),
+        ["let", "weak", "rc", "downgrade", "rc", "read_to_string", "hashmap", "new"]
+    );
+    assert_eq!(terms(text, TokenKind::Path), ["rc::downgrade", "hashmap::new"]);
+    assert_eq!(terms(text, TokenKind::Part), ["read", "to", "string"]);
+
+    let tokens = search::tokenize("Rc::downgrade");
+    let path = tokens.iter().find(|token| token.kind == TokenKind::Path).unwrap();
+    assert_eq!(path.span, 0..13);
+}
+
+#[test]
+fn tokenizes_cjk_into_unigrams_and_bigrams() {
+    let text = "转移所有权，Box<T>";
+    assert_eq!(terms(text, TokenKind::Unigram), ["转", "移", "所", "有", "权"]);
+    assert_eq!(terms(text, TokenKind::Bigram), ["转移", "移所", "所有", "有权"]);
+    assert_eq!(terms(text, TokenKind::Word), ["box", "t"]);
+    // 标点把中文切成不同的段，不会跨段组成两字词
+    assert_eq!(terms("借用，规则", TokenKind::Bigram), ["借用", "规则"]);
+}
+
+#[test]
+fn query_requires_words_and_bigrams() {
+    let query = Query::parse("Rc::downgrade 弱引用");
+    assert_eq!(query.required, ["rc", "downgrade", "弱引", "引用"]);
+    assert_eq!(query.optional, ["rc::downgrade"]);
+    // 只有一个字时按单字查找
+    assert_eq!(Query::parse("锁").required, ["锁"]);
+    assert!(Query::parse("，。!?").required.is_empty());
+}
+
+#[test]
+fn ranks_documents_containing_every_term() {
+    let index = Index::build(vec![
+        document(Source::Book, None, "杂谈", "Rc 是引用计数指针。downgrade 在别处。"),
+        document(Source::Tutorial, Some(18), "弱引用", "调用 Rc::downgrade(&rc) 得到 Weak<T>。"),
+        document(Source::Tutorial, Some(6), "所有权", "每个值都有一个所有者。"),
+        document(Source::Knowledge, Some(18), "Weak 与 Rc::downgrade", "打破循环引用。\nlet w = Rc::downgrade(&a);"),
+    ]);
+
+    let hits = index.search("rc::downgrade");
+    let titles: Vec<&str> = hits.iter().map(|hit| hit.document.title.as_str()).collect();
+    assert_eq!(titles, ["Weak 与 Rc::downgrade", "弱引用", "杂谈"]);
+    assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+    assert!(index.search("downgrade 所有者").is_empty());
+    assert!(index.search("不存在的词").is_empty());
+    assert_eq!(index.search("所有者")[0].document.chapter, Some(6));
+}
+
+#[test]
+fn snippets_highlight_the_best_line() {
+    let index = Index::build(vec![document(
+        Source::Knowledge,
+        Some(18),
+        "弱引用",
+        "第一行没有命中\n    let w = Rc::downgrade(&rc);\n只提到 rc",
+    )]);
+    let hit = &index.search("Rc::downgrade")[0];
+    assert_eq!(hit.snippet.line, 1);
+    assert_eq!(hit.snippet.text, "let w = Rc::downgrade(&rc);");
+    assert_eq!(hit.snippet.highlighted("【{text}】"), "let w = 【Rc::downgrade】(&【rc】);");
+
+    // 过长的行截取命中词附近的部分
+    let long = format!("{}所有权{}", "前".repeat(100), "后".repeat(100));
+    let index = Index::build(vec![document(Source::Book, None, "长文", &long)]);
+    let snippet = &index.search("所有权")[0].snippet;
+    assert!(snippet.text.starts_with('…') && snippet.text.ends_with('…'), "{}", snippet.text);
+    assert_eq!(snippet.text.chars().count(), 62);
+    assert_eq!(&snippet.text[snippet.highlights[0].clone()], "所有权");
+}
+
+#[test]
+fn collects_markdown_sections_and_example_sources() {
+    let root = std::env::temp_dir().join(format!("task-search-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("tutorial")).unwrap();
+    fs::create_dir_all(root.join("src/05_concurrency")).unwrap();
+    fs::write(
+        root.join("tutorial/03_ownership.md"),
+        "# 所有权\n\n开头\n\n## 移动\n\n```rust\n# 隐藏行\nlet b = a;\n```\n",
+    )
+    .unwrap();
+    fs::write(root.join("tutorial/SUMMARY.md"), "# 目录\n- 所有权\n").unwrap();
+    fs::write(root.join("src/05_concurrency/04_rc.md"), "前言\n## Weak\nRc::downgrade\n").unwrap();
+
+    let documents = search::collect_documents(Some(&root));
+    let files: Vec<(&str, &str, usize, Option<u8>)> = documents
+        .iter()
+        .filter_map(|doc| Some((doc.path.as_deref()?, doc.title.as_str(), doc.line, doc.chapter)))
+        .collect();
+    assert_eq!(
+        files,
+        [
+            ("tutorial/03_ownership.md", "所有权", 2, Some(6)),
+            ("tutorial/03_ownership.md", "移动", 6, Some(6)),
+            ("src/05_concurrency/04_rc.md", "04_rc", 1, None),
+            ("src/05_concurrency/04_rc.md", "Weak", 3, None),
+        ]
+    );
+    // 代码块里的 # 不是标题
+    assert!(documents.iter().any(|doc| doc.title == "移动" && doc.text.contains("# 隐藏行")));
+
+    let index = Index::build(documents);
+    let hit = index.search("rc::downgrade").into_iter().find(|hit| hit.document.path.is_some());
+    assert_eq!(hit.unwrap().location().as_deref(), Some("src/05_concurrency/04_rc.md:3"));
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn searches_the_whole_repository() {
+    let index = search::index();
+    let hits = index.search("Rc::downgrade");
+    assert!(hits.iter().any(|hit| hit.document.source == Source::Tutorial && hit.document.chapter == Some(18)));
+    assert!(hits.iter().any(|hit| hit.document.source == Source::Book));
+    assert!(hits.iter().any(|hit| hit.document.source == Source::Example));
+
+    let top = &index.search("所有权")[0];
+    assert_eq!((top.document.source, top.document.chapter), (Source::Chapter, Some(6)));
+}
+
+#[test]
+fn menu_search_jumps_to_a_result() {
+    let mut session = Session::scripted(Cursor::new("search 所有权规则\n1\nq\n"), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("🔍 「所有权规则」共找到"), "{}", transcript);
+    assert!(transcript.contains(". [知识点] 所有权规则\n"), "{}", transcript);
+    assert!(transcript.contains("📚 第6章：所有权（goto 6）"), "{}", transcript);
+    assert!(transcript.contains("💡 输入结果编号跳转到对应章节，或输入其他菜单命令: 1\n"), "{}", transcript);
+    assert!(transcript.contains("📖 第6章：所有权 详细知识点"), "{}", transcript);
+
+    // 不是编号的输入按菜单命令处理
+    let mut session = Session::scripted(Cursor::new("search 所有权\nq\n"), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.ends_with("📚 继续学习：https://doc.rust-lang.org/book/\n"), "{}", transcript);
+    assert_eq!(transcript.matches("👋 感谢使用").count(), 1);
+
+    let mut session = Session::scripted(Cursor::new("search\nsearch zzzqqq\n"), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("❌ 用法：search <关键词>"), "{}", transcript);
+    assert!(transcript.contains("📭 没有找到与「zzzqqq」相关的内容"), "{}", transcript);
+}
+
+#[test]
+fn binary_search_command() {
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let (code, stdout) = run(&["search", "移动", "语义", "--limit", "2", "--open"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.contains("\n 2. ") && !stdout.contains("\n 3. "), "{}", stdout);
+    assert!(stdout.contains("详细知识点"), "{}", stdout);
+
+    let (code, stdout) = run(&["search", "zzzqqq"]);
+    assert_eq!(code, Some(1));
+    assert!(stdout.contains("📭"), "{}", stdout);
+}
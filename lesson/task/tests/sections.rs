@@ -0,0 +1,140 @@
+// 章节小节：注册表、按关键词查找、命令行参数，以及单独运行和逐节运行
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use std::process::{Command as Process, Stdio};
+
+use task::chapters;
+use task::cli::{self, CliError, Command, RunTarget};
+use task::progress::PROFILE_DIR_VAR;
+use task::sections;
+use task::session::Session;
+
+fn parse(args: &[&str]) -> Result<Command, CliError> {
+    cli::parse(args.iter().map(|arg| arg.to_string())).map(|invocation| invocation.command)
+}
+
+/// 运行 task 二进制，`input` 写入标准输入
+fn run_binary(args: &[&str], input: &str) -> (i32, String) {
+    let mut child = Process::new(env!("CARGO_BIN_EXE_task"))
+        .env("LANG", "zh_CN.UTF-8")
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env(PROFILE_DIR_VAR, std::env::temp_dir().join(format!("task-sections-profile-{}", std::process::id())))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("无法启动 task 二进制");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    (output.status.code().unwrap_or(-1), String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+fn sections_are_demo_functions_of_the_chapter_bin() {
+    for chapter in chapters::all() {
+        let sections = chapter.sections();
+        let source = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(chapter.source_path())).unwrap();
+        for (index, section) in sections.iter().enumerate() {
+            assert!(!section.title.is_empty(), "{}", section.id);
+            assert!(source.contains(&format!("pub(crate) fn {}()", section.id)), "{} {}", chapter.bin, section.id);
+            assert!(sections[..index].iter().all(|other| other.id != section.id), "{} 重复", section.id);
+        }
+    }
+    assert_eq!(chapters::get(21).unwrap().sections().len(), 12);
+    assert_eq!(chapters::get(21).unwrap().sections()[4].title, "HashMap 创建宏");
+    // main 中没有拆成函数的章节不分小节
+    assert!(chapters::get(10).unwrap().sections().is_empty());
+}
+
+#[test]
+fn finds_sections_by_number_prefix_or_title() {
+    let concurrency = chapters::get(19).unwrap();
+    let macros = chapters::get(21).unwrap();
+    let id = |chapter, query| sections::find(chapter, query).ok().map(|section| section.id);
+    assert_eq!(id(concurrency, "thread_pool"), Some("thread_pool_example"));
+    assert_eq!(id(concurrency, "Thread-Pool"), Some("thread_pool_example"));
+    assert_eq!(id(concurrency, "线程池"), Some("thread_pool_example"));
+    assert_eq!(id(concurrency, "2"), Some("message_passing"));
+    assert_eq!(id(macros, "hashmap"), Some("hashmap_creation_demo"));
+    // 完整标识优先于包含关键词的其他小节
+    assert_eq!(id(chapters::get(8).unwrap(), "memory_layout"), Some("memory_layout"));
+
+    let candidates = sections::find(macros, "demo").unwrap_err();
+    assert_eq!(candidates.len(), 12);
+    assert!(sections::find(macros, "99").unwrap_err().is_empty());
+    assert!(sections::find(chapters::get(10).unwrap(), "1").unwrap_err().is_empty());
+}
+
+#[test]
+fn parses_section_arguments() {
+    let section = |chapter, section| Ok(Command::Run(RunTarget::Section { chapter, section }));
+    assert_eq!(parse(&["run", "19", "thread_pool"]), section(19, "thread_pool_example"));
+    assert_eq!(parse(&["run", "21", "--section", "hashmap"]), section(21, "hashmap_creation_demo"));
+    assert_eq!(parse(&["run", "21", "-s", "5"]), section(21, "hashmap_creation_demo"));
+    assert_eq!(parse(&["run", "21", "--step"]), Ok(Command::Run(RunTarget::Step(21))));
+    assert_eq!(parse(&["sections", "21"]), Ok(Command::Sections(21)));
+
+    assert_eq!(parse(&["run", "21", "--section"]), Err(CliError::MissingArgument("cli.argument.section")));
+    assert_eq!(parse(&["run", "21", "--fast"]), Err(CliError::UnexpectedArgument("--fast".into())));
+    assert_eq!(
+        parse(&["run", "10", "x"]),
+        Err(CliError::UnknownSection { chapter: 10, section: "x".into() })
+    );
+    let Err(CliError::AmbiguousSection { candidates, .. }) = parse(&["run", "19", "e"]) else {
+        panic!("应当有多个候选");
+    };
+    assert!(candidates.contains(&"thread_basics"), "{:?}", candidates);
+    assert_eq!(parse(&["run", "19", "thread_pool", "extra"]), Err(CliError::UnexpectedArgument("extra".into())));
+}
+
+#[test]
+fn binary_runs_one_section() {
+    let (code, stdout) = run_binary(&["run", "21", "--section", "hashmap"], "");
+    assert_eq!(code, cli::EXIT_OK);
+    assert!(stdout.contains("🧩 [5/12] 第21章：宏系统 · HashMap 创建宏（hashmap_creation_demo）"), "{}", stdout);
+    assert!(stdout.contains("📍 5. HashMap 创建宏"), "{}", stdout);
+    assert!(!stdout.contains("📍 4. 重复模式宏"), "{}", stdout);
+
+    let (code, stdout) = run_binary(&["sections", "19"], "");
+    assert_eq!(code, cli::EXIT_OK);
+    assert!(stdout.contains("7. thread_pool_example     实际应用：线程池"), "{}", stdout);
+    let (_, stdout) = run_binary(&["sections", "10"], "");
+    assert!(stdout.contains("📭 第10章：枚举 的示例没有拆分成小节"), "{}", stdout);
+}
+
+#[test]
+fn binary_steps_through_sections_until_q() {
+    // 运行第一节后回车，第二节后输入 q
+    let (code, stdout) = run_binary(&["run", "6", "--step"], "\nq\n");
+    assert_eq!(code, cli::EXIT_OK);
+    assert!(stdout.contains("🧩 [2/3] 第6章：所有权 · 所有权规则（ownership_rules）"), "{}", stdout);
+    assert!(!stdout.contains("[3/3]"), "{}", stdout);
+    assert!(stdout.contains("⏹️  已运行 2/3 节"), "{}", stdout);
+    assert_eq!(stdout.matches("⏭️").count(), 2);
+
+    // 输入结束后不再暂停
+    let (_, stdout) = run_binary(&["run", "6", "--step"], "");
+    assert!(stdout.contains("✅ 第6章：所有权 的 3 个小节运行完成"), "{}", stdout);
+}
+
+#[test]
+fn menu_lists_and_runs_sections() {
+    let input = "sections 21\nrun 21 hashmap\nrun 21 nothing\nrun 6 --step\nrun 10 --step\nq\n";
+    let mut session = Session::scripted(Cursor::new(input), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("🧩 第21章：宏系统 的小节："), "{}", transcript);
+    assert!(transcript.contains("🧩 [5/12] 第21章：宏系统 · HashMap 创建宏"), "{}", transcript);
+    // 演示函数的输出也写入会话，在下一条命令的输出之前
+    let demo = transcript.find("📍 5. HashMap 创建宏").expect("小节输出应写入会话");
+    assert!(demo < transcript.find("❌ 第21章没有小节").unwrap(), "{}", transcript);
+    assert!(transcript.contains("❌ 第21章没有小节“nothing”"), "{}", transcript);
+    // 回放脚本时逐节运行不暂停
+    assert!(transcript.contains("✅ 第6章：所有权 的 3 个小节运行完成"), "{}", transcript);
+    assert!(!transcript.contains("⏭️"), "{}", transcript);
+    // 没有小节的章节整章运行，示例写入会话输出
+    assert!(transcript.contains("📭 第10章：枚举 的示例没有拆分成小节，下面运行整章"), "{}", transcript);
+    assert!(transcript.contains("🌐 IP 地址枚举："), "{}", transcript);
+}
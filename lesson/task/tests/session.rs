@@ -0,0 +1,118 @@
+use std::io::Cursor;
+use std::process::Command;
+
+use task::progress::PROFILE_DIR_VAR;
+use task::session::{Session, SessionOptions};
+
+/// 让二进制把进度写到临时目录，不碰真实的用户目录
+fn scratch_profile() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("task-session-profile-{}", std::process::id()))
+}
+
+/// 不清屏的交互会话，回车停顿仍然会读取输入
+fn run_interactive(input: &str) -> String {
+    let options = SessionOptions {
+        pause: true,
+        clear_screen: false,
+    };
+    let mut session = Session::with_options(Cursor::new(input), Vec::new(), options);
+    session.run().expect("会话不应出错");
+    String::from_utf8(session.into_output()).unwrap()
+}
+
+fn run_scripted(script: &str) -> String {
+    let mut session = Session::scripted(Cursor::new(script), Vec::new());
+    session.run().expect("会话不应出错");
+    String::from_utf8(session.into_output()).unwrap()
+}
+
+/// 以中文界面运行 task，不受终端语言设置影响
+fn task_command() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_task"));
+    command.env("LANG", "zh_CN.UTF-8").env_remove("LC_ALL").env_remove("LC_MESSAGES");
+    command
+}
+
+#[test]
+fn chapter_then_enter_then_quit() {
+    let transcript = run_interactive("1\n\nq\n");
+    assert!(transcript.starts_with("🦀 欢迎来到 Rust 基础教程交互式示例！"));
+    assert!(transcript.contains("🔸 正在运行：第1章：变量与常量"));
+    assert!(transcript.contains("不可变变量 x = 5"));
+    assert!(transcript.contains("✅ 第1章：变量与常量 示例运行完成！"));
+    assert!(transcript.contains("📚 学习第2章数据类型"));
+    assert_eq!(transcript.matches("⏎ 按回车键继续...").count(), 1);
+    assert_eq!(transcript.matches("┌─").count(), 2);
+    assert!(transcript.trim_end().ends_with("📚 继续学习：https://doc.rust-lang.org/book/"));
+}
+
+#[test]
+fn empty_input_exits_cleanly() {
+    let transcript = run_interactive("");
+    assert_eq!(transcript.matches("┌─").count(), 1);
+    assert!(transcript.contains("👋 感谢使用 Rust 基础教程！"));
+}
+
+#[test]
+fn eof_while_waiting_for_enter_exits() {
+    let transcript = run_interactive("99");
+    assert!(transcript.contains("❌ 章节编号超出范围！"));
+    assert_eq!(transcript.matches("👋 感谢使用").count(), 1);
+}
+
+#[test]
+fn script_skips_pauses_comments_and_blank_lines() {
+    let transcript = run_scripted("# 先看第6章\n6\n\nhelp\nfoo\n");
+    assert!(!transcript.contains("⏎ 按回车键继续"));
+    assert!(!transcript.contains("先看第6章"));
+    assert!(transcript.contains("🔸 正在运行：第6章：所有权"));
+    assert!(transcript.contains("📖 Rust 基础教程 - 完整功能说明"));
+    assert!(transcript.contains("❌ 无效选择：foo！"));
+    // 欢迎页之后每条命令一个菜单，输入结束时再一个
+    assert_eq!(transcript.matches("┌─").count(), 4);
+    assert!(transcript.contains("👋 感谢使用 Rust 基础教程！"));
+}
+
+#[test]
+fn command_name_ignores_case_but_argument_keeps_it() {
+    let transcript = run_scripted("SEARCH Rc::downgrade\nFoo Bar\nQ\n1\n");
+    assert!(transcript.contains("🔍 「Rc::downgrade」共找到"), "{}", transcript);
+    assert!(transcript.contains("❌ 无效选择：foo Bar！"), "{}", transcript);
+    assert!(!transcript.contains("正在运行"), "{}", transcript);
+}
+
+#[test]
+fn script_quit_stops_replay() {
+    let transcript = run_scripted("q\n1\n");
+    assert!(!transcript.contains("正在运行"));
+    assert_eq!(transcript.matches("👋 感谢使用").count(), 1);
+}
+
+#[test]
+fn binary_replays_script_file() {
+    let script = std::env::temp_dir().join(format!("task-script-{}.txt", std::process::id()));
+    std::fs::write(&script, "demo\nq\n").unwrap();
+    let output = task_command()
+        .arg("--script")
+        .arg(&script)
+        .env(PROFILE_DIR_VAR, scratch_profile())
+        .output()
+        .unwrap();
+    std::fs::remove_file(&script).unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("🎉 交互式演示完成！"));
+}
+
+#[test]
+fn binary_with_closed_stdin_terminates() {
+    let output = task_command()
+        .stdin(std::process::Stdio::null())
+        .env(PROFILE_DIR_VAR, scratch_profile())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("👋 感谢使用 Rust 基础教程！"));
+}
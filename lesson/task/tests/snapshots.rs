@@ -0,0 +1,188 @@
+// 章节示例的快照测试
+// 每章的输出与 tests/snapshots/ 下的快照文件逐行比较。
+// 修改示例后运行 `TASK_BLESS=1 cargo test --test snapshots` 更新快照。
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use task::chapters::{self, Chapter};
+
+/// 设置为 1 时把实际输出写回快照文件
+const BLESS_VAR: &str = "TASK_BLESS";
+
+/// 以这些前缀开头的连续行顺序不确定（线程交错、HashMap 遍历），比较前先排序
+const UNORDERED_PREFIXES: &[&str] = &["  子线程: ", "  主线程: ", "队伍 "];
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+}
+
+fn snapshot_path(chapter: &Chapter) -> PathBuf {
+    snapshot_dir().join(format!("{:02}_{}.txt", chapter.id, chapter.bin))
+}
+
+fn blessing() -> bool {
+    env::var(BLESS_VAR).map(|value| value == "1").unwrap_or(false)
+}
+
+/// 把独立的 `0x` 十六进制地址替换为 `0x[addr]`，`50x10` 这样的尺寸不受影响
+fn redact_addresses(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(pos) = rest.find("0x") {
+        result.push_str(&rest[..pos]);
+        let standalone = !result
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric());
+        let digits = rest[pos + 2..]
+            .bytes()
+            .take_while(|b| b.is_ascii_hexdigit())
+            .count();
+        if standalone && digits > 0 {
+            result.push_str("0x[addr]");
+        } else {
+            result.push_str(&rest[pos..pos + 2 + digits]);
+        }
+        rest = &rest[pos + 2 + digits..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 规范化输出：去掉行尾空白、隐去地址、为顺序不确定的行块排序
+fn normalize(output: &str) -> String {
+    let mut lines: Vec<String> = output
+        .lines()
+        .map(|line| redact_addresses(line.trim_end()))
+        .collect();
+
+    let is_unordered = |line: &str| UNORDERED_PREFIXES.iter().any(|prefix| line.starts_with(prefix));
+    let mut start = 0;
+    while start < lines.len() {
+        if !is_unordered(&lines[start]) {
+            start += 1;
+            continue;
+        }
+        let end = (start..lines.len())
+            .find(|&i| !is_unordered(&lines[i]))
+            .unwrap_or(lines.len());
+        lines[start..end].sort();
+        start = end;
+    }
+
+    let mut normalized = lines.join("\n");
+    normalized.push('\n');
+    normalized
+}
+
+/// 基于最长公共子序列的逐行差异，`-` 为快照，`+` 为实际输出
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("  {:>4} - {}\n", i + 1, old[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("  {:>4} + {}\n", j + 1, new[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+fn capture(chapter: &Chapter) -> String {
+    let mut buf = Vec::new();
+    chapter.write_to(&mut buf).expect("写入内存缓冲区不应失败");
+    String::from_utf8(buf).expect("示例输出应为 UTF-8")
+}
+
+#[test]
+fn chapter_outputs_match_snapshots() {
+    let bless = blessing();
+    if bless {
+        fs::create_dir_all(snapshot_dir()).unwrap();
+    }
+
+    let mut failures = Vec::new();
+    for chapter in chapters::all() {
+        let path = snapshot_path(chapter);
+        let actual = normalize(&capture(chapter));
+
+        if bless {
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "{} 与快照 {} 不一致：\n{}",
+                chapter.display_name(),
+                path.display(),
+                line_diff(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!(
+                "{} 缺少快照 {}",
+                chapter.display_name(),
+                path.display()
+            )),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\n确认输出变化符合预期后，运行 `{}=1 cargo test --test snapshots` 更新快照",
+        failures.join("\n"),
+        BLESS_VAR
+    );
+}
+
+#[test]
+fn no_orphan_snapshots() {
+    let expected: Vec<PathBuf> = chapters::all().iter().map(snapshot_path).collect();
+    for entry in fs::read_dir(snapshot_dir()).unwrap() {
+        let path = entry.unwrap().path();
+        assert!(
+            expected.contains(&path),
+            "快照 {} 不对应任何已登记的章节",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn normalization_is_stable() {
+    assert_eq!(redact_addresses("地址: 0x7ffd1234abcd."), "地址: 0x[addr].");
+    assert_eq!(redact_addresses("0x 不是地址"), "0x 不是地址");
+    assert_eq!(redact_addresses("按钮 (50x10)"), "按钮 (50x10)");
+
+    let first = normalize("开始\n  主线程: 1\n  子线程: 1\n  子线程: 2\n结束  \n");
+    let second = normalize("开始\n  子线程: 1\n  主线程: 1\n  子线程: 2\n结束\n");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn diff_marks_changed_lines() {
+    let diff = line_diff("a\nb\nc\n", "a\nx\nc\n");
+    assert_eq!(diff, "     2 - b\n     2 + x\n");
+}
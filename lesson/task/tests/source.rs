@@ -0,0 +1,128 @@
+// 示例源码查看：按函数定位、语法高亮、命令行参数和菜单命令
+use std::io::Cursor;
+use std::process::Command as Process;
+
+use task::chapters;
+use task::highlight;
+use task::cli::{self, CliError, Command};
+use task::progress::PROFILE_DIR_VAR;
+use task::session::Session;
+use task::source;
+
+fn parse(args: &[&str]) -> Result<Command, CliError> {
+    cli::parse(args.iter().map(|arg| arg.to_string())).map(|invocation| invocation.command)
+}
+
+#[test]
+fn every_chapter_and_section_has_its_function() {
+    for chapter in chapters::all() {
+        let snippet = source::chapter_snippet(chapter).unwrap_or_else(|| panic!("{} 没有示例函数", chapter.id));
+        assert_eq!(snippet.path, source::EXAMPLES_PATH);
+        assert_eq!(snippet.name, format!("{}_to", chapter.example.name));
+        for section in chapter.sections() {
+            let snippet = source::section_snippet(chapter, section).unwrap_or_else(|| panic!("{} 没有源码", section.id));
+            assert_eq!(snippet.path, chapter.source_path());
+            // 片段从函数定义（或其上方的注释）开始，到函数结尾的右括号结束
+            assert!(snippet.lines.iter().any(|line| line.starts_with(&format!("pub(crate) fn {}()", section.id))), "{}", section.id);
+            assert_eq!(snippet.lines.last().unwrap().trim_end(), "}", "{}", section.id);
+        }
+    }
+}
+
+#[test]
+fn function_range_matches_file_lines() {
+    let (path, text) = source::embedded("src/bin/ownership.rs").unwrap();
+    let snippet = source::find_function(path, text, "core_concepts").unwrap();
+    let file: Vec<&str> = text.lines().collect();
+    assert_eq!(snippet.lines, file[snippet.first_line - 1..snippet.last_line()]);
+    assert_eq!(snippet.lines[0], "// 3.1 目的与核心思想");
+    // 字符串和注释中的括号不影响函数结尾的判断
+    let (path, text) = source::embedded("src/bin/macros.rs").unwrap();
+    let snippet = source::find_function(path, text, "hashmap_creation_demo").unwrap();
+    assert!(snippet.text().contains("hashmap!"));
+    assert!(source::find_function(path, text, "no_such_function").is_none());
+    assert!(source::embedded("src/bin/missing.rs").is_none());
+}
+
+#[test]
+fn semicolons_inside_signature_brackets_do_not_end_the_function() {
+    const TEXT: &str = "fn first(bytes: [u8; 4],\n         pair: Vec<[i32; 2]>) -> impl Fn() -> u8 {\n    let _ = (bytes, pair);\n    || 0\n}\nfn declared(x: [u8; 2]);\nfn after() {}\n";
+    let snippet = source::find_function("lib.rs", TEXT, "first").unwrap();
+    assert_eq!(snippet.lines.len(), 5, "{:?}", snippet.lines);
+    assert_eq!(snippet.lines.last().unwrap(), &"}");
+    let snippet = source::find_function("lib.rs", TEXT, "declared").unwrap();
+    assert_eq!(snippet.lines, ["fn declared(x: [u8; 2]);"]);
+}
+
+#[test]
+fn highlight_keeps_text_and_closes_colors_per_line() {
+    let code = "// 注释 { \nfn main() {\n    let s = \"}\\\"\"; let c = '{';\n    println!(\"{}\", 42u8); /* 块\n注释 */ &'a x\n}";
+    let colored = highlight::ansi(code);
+    let plain: String = strip_ansi(&colored);
+    assert_eq!(plain, code);
+    for line in colored.lines() {
+        assert_eq!(line.matches("\x1b[0m").count(), line.matches("\x1b[3").count() + line.matches("\x1b[9").count(), "{:?}", line);
+    }
+    assert!(colored.contains("\x1b[35mfn\x1b[0m"), "{:?}", colored);
+    assert!(colored.contains("\x1b[36mprintln\x1b[0m!"), "{:?}", colored);
+    assert!(colored.contains("\x1b[33m42u8\x1b[0m"), "{:?}", colored);
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[test]
+fn parses_source_arguments() {
+    assert_eq!(parse(&["source", "6"]), Ok(Command::Source { chapter: 6, section: None }));
+    assert_eq!(
+        parse(&["source", "19", "线程池"]),
+        Ok(Command::Source { chapter: 19, section: Some("thread_pool_example") })
+    );
+    assert_eq!(parse(&["source"]), Err(CliError::MissingArgument("cli.argument.chapter")));
+    assert_eq!(parse(&["source", "10", "x"]), Err(CliError::UnknownSection { chapter: 10, section: "x".into() }));
+}
+
+#[test]
+fn binary_shows_numbered_source_without_color_in_pipe() {
+    let output = Process::new(env!("CARGO_BIN_EXE_task"))
+        .env("LANG", "zh_CN.UTF-8")
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env(PROFILE_DIR_VAR, std::env::temp_dir().join(format!("task-source-profile-{}", std::process::id())))
+        .args(["source", "19", "thread_pool"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(cli::EXIT_OK));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("📄 src/bin/concurrency.rs:"), "{}", stdout);
+    assert!(stdout.contains(" │ pub(crate) fn thread_pool_example() {"), "{}", stdout);
+    assert!(!stdout.contains('\x1b'), "{}", stdout);
+}
+
+#[test]
+fn menu_shows_source_and_runs_section_after_it() {
+    let input = "source 6\nsource 6 rules\nsource 6 ownership_rules\nsource 99\nrun 6 1\nq\n";
+    let mut session = Session::scripted(Cursor::new(input), Vec::new());
+    session.run().unwrap();
+    let transcript = String::from_utf8(session.into_output()).unwrap();
+    assert!(transcript.contains("📄 src/examples.rs:"), "{}", transcript);
+    assert!(transcript.contains("💡 完整的独立示例在 src/bin/ownership.rs"), "{}", transcript);
+    assert!(transcript.contains(" │ pub(crate) fn ownership_rules() {"), "{}", transcript);
+    assert!(transcript.contains("❌ 用法：source <章节> [小节]"), "{}", transcript);
+    assert!(transcript.contains("❌ 小节“rules”不唯一，可能是：ownership_rules, verify_rules"), "{}", transcript);
+    // 单独运行一节时源码在运行结果之前
+    let header = transcript.find("🧩 [1/3] 第6章：所有权").unwrap();
+    let code = transcript[header..].find(" │ pub(crate) fn core_concepts() {").unwrap();
+    let output = transcript[header..].find("▶️  运行结果：").unwrap();
+    assert!(code < output, "{}", transcript);
+}
@@ -0,0 +1,121 @@
+// 终端输出：能力检测、ASCII 主题、状态符号上色和 --theme 选项
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::Command;
+
+use task::term::{self, Color, Renderer, Terminal, Theme};
+
+fn detect(is_terminal: bool, vars: &[(&str, &str)]) -> Terminal {
+    let vars: HashMap<String, String> = vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect();
+    Terminal::detect(is_terminal, |name| vars.get(name).cloned())
+}
+
+/// 除了汉字和全角标点只有 ASCII
+fn is_plain_text(text: &str) -> bool {
+    text.chars()
+        .all(|c| c.is_ascii() || matches!(c, '\u{3000}'..='\u{303F}' | '\u{4E00}'..='\u{9FFF}' | '\u{FF00}'..='\u{FFEF}'))
+}
+
+const ASCII: Terminal = Terminal {
+    theme: Theme::Ascii,
+    color: false,
+    ansi: false,
+};
+
+#[test]
+fn detects_terminal_capabilities() {
+    let full = Terminal {
+        theme: Theme::Unicode,
+        color: true,
+        ansi: true,
+    };
+    assert_eq!(detect(true, &[("TERM", "xterm-256color")]), full);
+    // 输出被重定向时不写任何转义序列
+    assert_eq!(detect(false, &[("TERM", "xterm-256color")]), Terminal::default());
+    // NO_COLOR 为空时不生效
+    assert_eq!(detect(true, &[("NO_COLOR", "")]), full);
+    assert_eq!(detect(true, &[("NO_COLOR", "1")]), Terminal { color: false, ..full });
+    assert_eq!(detect(true, &[("TERM", "dumb")]), ASCII);
+    assert_eq!(detect(true, &[("TERM", "dumb"), ("TASK_THEME", "unicode")]), Terminal::default());
+    assert_eq!(detect(false, &[("TASK_THEME", "Plain")]), ASCII);
+    assert_eq!(detect(false, &[("TASK_THEME", "neon")]), Terminal::default());
+}
+
+#[test]
+fn ascii_theme_replaces_emoji_and_box_drawing() {
+    let text = "┌─────┐\n│ 🦀 Rust 基础 ✓ │\n└─────┘\n✅ 编译通过 ❌ 失败 ⚠️  注意 • 下一章 → 所有权…\n👨‍👩‍👧 “引号”";
+    let rendered = term::render(text, ASCII);
+    assert_eq!(
+        rendered,
+        "+-----+\n| * Rust 基础 * |\n+-----+\n[OK] 编译通过 [X] 失败 [!]  注意 - 下一章 -> 所有权...\n*** \"引号\""
+    );
+    assert!(is_plain_text(&rendered), "{}", rendered);
+    assert_eq!(term::render("第6章：所有权 · 所有权规则", ASCII), "第6章：所有权 - 所有权规则");
+
+    // 默认主题原样输出
+    assert_eq!(term::render(text, Terminal::default()), text);
+}
+
+#[test]
+fn colors_status_marks() {
+    let color = Terminal {
+        color: true,
+        ..Terminal::default()
+    };
+    assert_eq!(term::render("✅ 通过", color), "\x1b[32m✅\x1b[0m 通过");
+    assert_eq!(term::render("❌ 失败", Terminal { theme: Theme::Ascii, ..color }), "\x1b[31m[X]\x1b[0m 失败");
+    assert_eq!(term::render("普通文字 │", color), "普通文字 │");
+    assert_eq!(term::paint("注意", Color::Yellow), "\x1b[33m注意\x1b[0m");
+}
+
+#[test]
+fn renderer_handles_split_characters() {
+    let mut renderer = Renderer::new(Vec::new(), ASCII);
+    let bytes = "✅ 完成\n".as_bytes();
+    // 在多字节字符中间断开写入
+    for chunk in bytes.chunks(2) {
+        renderer.write_all(chunk).unwrap();
+    }
+    let (mark, chapter) = ("🎉", 3);
+    write!(renderer, "{} 第 {} 章", mark, chapter).unwrap();
+    assert_eq!(String::from_utf8(renderer.into_inner()).unwrap(), "[OK] 完成\n* 第 3 章");
+}
+
+#[test]
+fn binary_theme_option() {
+    let run = |args: &[&str], vars: &[(&str, &str)]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .env_remove("TASK_THEME")
+            .env_remove("TERM")
+            .envs(vars.iter().copied())
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned(), String::from_utf8_lossy(&output.stderr).into_owned())
+    };
+
+    let (code, unicode, _) = run(&["list"], &[]);
+    assert_eq!(code, Some(0));
+    assert!(unicode.contains("🔰 基础教程"), "{}", unicode);
+
+    for (args, vars) in [(&["list", "--theme", "ascii"][..], &[][..]), (&["list"], &[("TASK_THEME", "ascii")]), (&["list"], &[("TERM", "dumb")])] {
+        let (code, stdout, _) = run(args, vars);
+        assert_eq!(code, Some(0));
+        assert!(stdout.contains("变量与常量"), "{}", stdout);
+        assert!(stdout.contains("* 基础教程"), "{}", stdout);
+        assert!(is_plain_text(&stdout), "{}", stdout);
+        // 输出不是终端，不写转义序列
+        assert!(!stdout.contains('\x1b'), "{}", stdout);
+    }
+
+    // 解析出错时还不知道 --theme，按环境变量选择主题
+    let (code, _, stderr) = run(&["bogus"], &[("TASK_THEME", "ascii")]);
+    assert_eq!(code, Some(2));
+    assert!(stderr.starts_with("[X] 未知命令：bogus"), "{}", stderr);
+    let (code, _, stderr) = run(&["--theme", "neon"], &[]);
+    assert_eq!(code, Some(2));
+    assert!(stderr.contains("未知主题：neon"), "{}", stderr);
+}
@@ -0,0 +1,129 @@
+// 用本机 rustc 验证知识点中的代码示例；找不到 rustc 时跳过
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use task::i18n::Locale;
+use task::knowledge::{CodeExample, Expectation, KnowledgeBase};
+use task::verify::{self, Outcome, VerifyOptions};
+
+fn example(code: &str, output: Option<&str>, expect: Expectation) -> CodeExample {
+    CodeExample {
+        title: "测试".to_string(),
+        code: code.to_string(),
+        explanation: String::new(),
+        output: output.map(str::to_string),
+        expect,
+    }
+}
+
+/// rustc 不可用时返回 None
+fn check(example: &CodeExample, options: &VerifyOptions) -> Option<Outcome> {
+    match verify::verify_example(example, options) {
+        Ok(outcome) => Some(outcome),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            eprintln!("跳过：{}", err);
+            None
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn all_knowledge_examples_pass() {
+    let options = VerifyOptions::default();
+    for locale in Locale::ALL {
+        let base = KnowledgeBase::embedded_for(locale);
+        assert!(base.errors().is_empty(), "{:?}", base.errors());
+        for chapter in base.chapters() {
+            for point in base.chapter(chapter).unwrap() {
+                for example in &point.code_examples {
+                    let Some(outcome) = check(example, &options) else {
+                        return;
+                    };
+                    assert!(
+                        outcome.passed(),
+                        "[{}] 第{}章 {} / {}：{}",
+                        locale,
+                        chapter,
+                        point.title,
+                        example.title,
+                        outcome
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn failures_are_classified() {
+    let options = VerifyOptions {
+        timeout: Duration::from_secs(20),
+        ..VerifyOptions::default()
+    };
+    let compile_fail = |code: Option<&str>| Expectation::CompileFail {
+        code: code.map(str::to_string),
+    };
+
+    let cases = [
+        (example("println!(\"1\");", Some("2"), Expectation::Runs), "mismatch"),
+        (example("let x: i32 = \"a\";", None, Expectation::Runs), "compile"),
+        (example("panic!(\"boom\");", None, Expectation::Runs), "runtime"),
+        (example("let x = 1;", None, compile_fail(None)), "success"),
+        (example("let x: i32 = \"a\";", None, compile_fail(Some("E0382"))), "code"),
+        (example("let x: i32 = \"a\";", None, compile_fail(Some("E0308"))), "passed"),
+        (example("print!(\"a  \\n\\n\");", Some("a"), Expectation::Runs), "passed"),
+    ];
+    for (example, expected) in cases {
+        let Some(outcome) = check(&example, &options) else {
+            return;
+        };
+        let kind = match &outcome {
+            Outcome::Passed => "passed",
+            Outcome::OutputMismatch { .. } => "mismatch",
+            Outcome::CompileError(_) => "compile",
+            Outcome::RuntimeError(stderr) => {
+                assert!(stderr.contains("boom"));
+                "runtime"
+            }
+            Outcome::UnexpectedSuccess => "success",
+            Outcome::WrongErrorCode { actual, .. } => {
+                assert_eq!(actual, &["E0308"]);
+                "code"
+            }
+            Outcome::Timeout => "timeout",
+        };
+        assert_eq!(kind, expected, "{}：{}", example.code, outcome);
+    }
+}
+
+#[test]
+fn runaway_programs_time_out() {
+    let options = VerifyOptions {
+        timeout: Duration::from_millis(500),
+        ..VerifyOptions::default()
+    };
+    let Some(outcome) = check(&example("loop {}", None, Expectation::Runs), &options) else {
+        return;
+    };
+    // 编译在慢机器上也可能超时，两种情况都算超时
+    assert_eq!(outcome, Outcome::Timeout);
+}
+
+#[test]
+fn snippets_are_wrapped_in_main() {
+    assert_eq!(verify::wrap_snippet("let x = 1;\n\nprintln!(\"{}\", x);"), "fn main() {\n    let x = 1;\n\n    println!(\"{}\", x);\n}\n");
+    let full = "fn main() {}";
+    assert_eq!(verify::wrap_snippet(full), "fn main() {}\n");
+    assert_eq!(verify::wrap_snippet("/// 入口\npub fn main ( ) {}"), "/// 入口\npub fn main ( ) {}\n");
+    // 注释、字符串、别的名字和函数体内的 main 都不算顶层的 fn main
+    for code in ["// fn main() 之前的准备\nlet x = 1;", "println!(\"fn main()\");", "fn main_helper() {}", "{ fn main() {} }"] {
+        assert!(verify::wrap_snippet(code).starts_with("fn main() {\n    "), "{}", code);
+    }
+}
+
+#[test]
+fn error_codes_are_extracted() {
+    let stderr = "error[E0382]: borrow of moved value\nerror[E0499]: second\nerror[E0382]: again\nerror: aborting";
+    assert_eq!(verify::error_codes(stderr), ["E0382", "E0499"]);
+}
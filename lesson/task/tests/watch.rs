@@ -0,0 +1,276 @@
+// 监视模式：修改时间的检测、连续保存的合并、通过后切换到下一个练习、出错后继续监视，以及 watch 命令
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use task::exercises;
+use task::verify::VerifyOptions;
+use task::watch::{self, Target, WatchOptions, Watcher};
+
+const FIXED: &str = "fn main() {\n    let s1 = String::from(\"hello\");\n    let s2 = s1.clone();\n    println!(\"{} {}\", s1, s2);\n}\n";
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("task-watch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn reference(id: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../Smartcontractcode").join(format!("{}.rs", id))
+}
+
+fn rustc_available() -> bool {
+    let available = Command::new(VerifyOptions::default().rustc).arg("--version").output().is_ok();
+    if !available {
+        eprintln!("跳过：找不到 rustc");
+    }
+    available
+}
+
+/// 监视线程写入、测试线程读取的输出
+#[derive(Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl SharedOutput {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+
+    /// 等到输出中 `expected` 出现 `count` 次
+    fn wait_for(&self, expected: &str, count: usize) {
+        for _ in 0..600 {
+            if self.text().matches(expected).count() >= count {
+                return;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        panic!("等待 {:?} 超时：\n{}", expected, self.text());
+    }
+}
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn fast() -> WatchOptions {
+    WatchOptions {
+        interval: Duration::from_millis(20),
+        debounce: Duration::from_millis(100),
+        ..WatchOptions::default()
+    }
+}
+
+#[test]
+fn scan_reports_new_and_modified_files() {
+    let dir = temp_dir("scan");
+    fs::write(dir.join("b.rs"), "fn main() {}\n").unwrap();
+    fs::write(dir.join("a.rs"), "fn main() {}\n").unwrap();
+    fs::write(dir.join("notes.txt"), "不是代码").unwrap();
+    let mut watcher = Watcher::new(&dir);
+    assert_eq!(watcher.scan().unwrap(), [dir.join("a.rs"), dir.join("b.rs")]);
+    assert!(watcher.scan().unwrap().is_empty());
+
+    fs::write(dir.join("b.rs"), "fn main() { println!(); }\n").unwrap();
+    fs::write(dir.join("notes.txt"), "还不是代码").unwrap();
+    assert_eq!(watcher.scan().unwrap(), [dir.join("b.rs")]);
+
+    // 删除的文件也算变化，之后不再出现
+    fs::remove_file(dir.join("a.rs")).unwrap();
+    assert_eq!(watcher.scan().unwrap(), [dir.join("a.rs")]);
+    assert!(watcher.scan().unwrap().is_empty());
+
+    assert!(matches!(Target::from_path(Path::new("x/06_use_after_move.rs")), Some(Target::Exercise(_))));
+    assert!(matches!(Target::from_path(Path::new("rs-062.rs")), Some(Target::Assignment(_))));
+    assert!(Target::from_path(Path::new("use_after_move.rs")).is_none());
+    assert!(Target::from_path(Path::new("rs-062.txt")).is_none());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn wait_merges_a_burst_of_saves() {
+    let dir = temp_dir("burst");
+    let mut watcher = Watcher::new(&dir);
+    assert!(watcher.scan().unwrap().is_empty());
+
+    let writer = {
+        let dir = dir.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            fs::write(dir.join("a.rs"), "fn main() {}\n").unwrap();
+            thread::sleep(Duration::from_millis(40));
+            fs::write(dir.join("b.rs"), "fn main() {}\n").unwrap();
+        })
+    };
+    let options = WatchOptions {
+        interval: Duration::from_millis(10),
+        debounce: Duration::from_millis(300),
+        ..WatchOptions::default()
+    };
+    assert_eq!(watcher.wait(&options).unwrap(), [dir.join("a.rs"), dir.join("b.rs")]);
+    writer.join().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn advances_to_the_next_unsolved_exercise() {
+    if !rustc_available() {
+        return;
+    }
+    let dir = temp_dir("advance");
+    let exercise = exercises::get("use_after_move").unwrap();
+    fs::write(dir.join("06_use_after_move.rs"), &exercise.source).unwrap();
+    fs::write(dir.join("rs-062.rs"), "pub fn par_sum(v: &Vec<u32>) -> u32 {\n    0\n}\n").unwrap();
+
+    let output = SharedOutput::default();
+    let (sender, solved) = mpsc::channel();
+    let watching = {
+        let (dir, mut output) = (dir.clone(), output.clone());
+        thread::spawn(move || {
+            let mut watcher = Watcher::new(dir);
+            watch::run(&mut output, &mut watcher, &fast(), |target| sender.send(target.id().to_string()).unwrap())
+        })
+    };
+
+    output.wait_for("⏳ 等待文件保存", 1);
+    fs::write(dir.join("06_use_after_move.rs"), FIXED).unwrap();
+    output.wait_for("⏳ 等待文件保存", 2);
+    fs::copy(reference("rs-062"), dir.join("rs-062.rs")).unwrap();
+    assert!(watching.join().unwrap().unwrap());
+
+    let transcript = output.text();
+    for expected in [
+        "👀 正在监视",
+        "  06_use_after_move    🔧 1 个编译错误",
+        "  rs-062               ❌ 通过 0/1 个测试",
+        "👉 当前练习：06_use_after_move 移动之后再使用",
+        "❌ error[E0382]: borrow of moved value: `s1`",
+        "💡 需要两个独立的字符串时用 clone 复制一份",
+        "  06_use_after_move    ✅ 编译通过",
+        "👉 当前练习：rs-062 作用域线程求和",
+        "     ❌ par_sum_matches_the_sequential_sum",
+        "  rs-062               ✅ 通过 1/1 个测试",
+        "🎉 目录中的 2 个练习全部完成！",
+    ] {
+        assert!(transcript.contains(expected), "缺少 {:?}：\n{}", expected, transcript);
+    }
+    assert_eq!(solved.try_iter().collect::<Vec<_>>(), ["06_use_after_move", "rs-062"]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn check_and_scan_errors_keep_the_session_running() {
+    let dir = temp_dir("errors");
+    fs::write(dir.join("rs-062.rs"), "pub fn par_sum(v: &Vec<u32>) -> u32 {\n    0\n}\n").unwrap();
+    let options = WatchOptions {
+        verify: VerifyOptions {
+            rustc: "/no/such/rustc".into(),
+            ..VerifyOptions::default()
+        },
+        ..fast()
+    };
+
+    let output = SharedOutput::default();
+    {
+        let (dir, mut output) = (dir.clone(), output.clone());
+        thread::spawn(move || watch::run(&mut output, &mut Watcher::new(dir), &options, |_| {}));
+    }
+    output.wait_for("⏳ 等待文件保存", 1);
+    assert!(output.text().contains("  rs-062               ⚠️  无法检查："), "{}", output.text());
+
+    // 目录暂时消失时显示一次错误，恢复后继续检查
+    fs::remove_dir_all(&dir).unwrap();
+    output.wait_for("⚠️  无法读取", 1);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("rs-062.rs"), "pub fn par_sum(v: &Vec<u32>) -> u32 {\n    1\n}\n").unwrap();
+    output.wait_for("⏳ 等待文件保存", 2);
+    assert_eq!(output.text().matches("⚠️  无法读取").count(), 1, "{}", output.text());
+    assert_eq!(output.text().matches("⚠️  无法检查").count(), 2, "{}", output.text());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn deleted_exercises_are_dropped() {
+    let dir = temp_dir("deleted");
+    let exercise = exercises::get("use_after_move").unwrap();
+    fs::write(dir.join("06_use_after_move.rs"), &exercise.source).unwrap();
+    fs::write(dir.join("rs-062.rs"), "pub fn par_sum(v: &Vec<u32>) -> u32 {\n    0\n}\n").unwrap();
+    // 检查都会失败，两个练习都没有完成
+    let options = WatchOptions {
+        verify: VerifyOptions {
+            rustc: "/no/such/rustc".into(),
+            ..VerifyOptions::default()
+        },
+        ..fast()
+    };
+
+    let output = SharedOutput::default();
+    let watching = {
+        let (dir, mut output) = (dir.clone(), output.clone());
+        thread::spawn(move || watch::run(&mut output, &mut Watcher::new(dir), &options, |_| {}))
+    };
+    output.wait_for("⏳ 等待文件保存", 1);
+    assert!(output.text().contains("👉 当前练习：06_use_after_move"), "{}", output.text());
+
+    // 删除当前练习后换到下一个，全部删除后结束
+    fs::remove_file(dir.join("06_use_after_move.rs")).unwrap();
+    output.wait_for("👉 当前练习：rs-062", 1);
+    fs::remove_file(dir.join("rs-062.rs")).unwrap();
+    assert!(!watching.join().unwrap().unwrap());
+    assert!(output.text().contains("中没有练习文件"), "{}", output.text());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn binary_watch_command() {
+    let run = |args: &[&str], profile: &Path| {
+        let output = Command::new(env!("CARGO_BIN_EXE_task"))
+            .args(args)
+            .arg("--profile-dir")
+            .arg(profile)
+            .env("LANG", "zh_CN.UTF-8")
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .output()
+            .unwrap();
+        (output.status.code(), String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let profile = temp_dir("binary");
+    let dir = profile.join("exercises");
+    assert_eq!(run(&["watch"], &profile).0, Some(1));
+    fs::create_dir_all(&dir).unwrap();
+    let (code, stdout) = run(&["watch", dir.to_str().unwrap()], &profile);
+    assert_eq!(code, Some(1));
+    assert!(stdout.contains("中没有练习文件"), "{}", stdout);
+    if !rustc_available() {
+        fs::remove_dir_all(&profile).unwrap();
+        return;
+    }
+
+    // 练习已经全部通过时检查一遍就退出，并记入学习进度
+    fs::write(dir.join("06_use_after_move.rs"), FIXED).unwrap();
+    let (code, stdout) = run(&["watch"], &profile);
+    assert_eq!(code, Some(0), "{}", stdout);
+    assert!(stdout.contains("🎉 目录中的 1 个练习全部完成！"), "{}", stdout);
+    let progress = fs::read_to_string(profile.join("progress.txt")).unwrap();
+    assert!(progress.contains("solved 06_use_after_move "), "{}", progress);
+
+    // 再次启动时已经记录过的练习不再重复记录
+    let (code, _) = run(&["watch"], &profile);
+    assert_eq!(code, Some(0));
+    let progress = fs::read_to_string(profile.join("progress.txt")).unwrap();
+    assert_eq!(progress.matches("result exercise ").count(), 1, "{}", progress);
+    fs::remove_dir_all(&profile).unwrap();
+}
@@ -0,0 +1,14 @@
+// title: 移动之后再释放
+// chapter: 6
+// error: E0382
+// message: use of moved value: `s1`
+// hint: 赋值把值的所有权从 s1 移动到了 s2，之后只有 s2 能释放它
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("s2: {}", s2);
+    drop(s1);
+}
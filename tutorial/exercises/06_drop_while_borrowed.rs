@@ -0,0 +1,14 @@
+// title: 借用期间释放
+// chapter: 6
+// error: E0505
+// message: cannot move out of `s` because it is borrowed
+// hint: 引用 r 之后还要使用，s 必须活到那之后；先用完 r 再释放 s
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let s = String::from("world");
+    let r = &s;
+    drop(s);
+    println!("r: {}", r);
+}
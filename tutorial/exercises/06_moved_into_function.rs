@@ -0,0 +1,17 @@
+// title: 传给函数之后再使用
+// chapter: 6
+// error: E0382
+// message: borrow of moved value: `s2`
+// hint: 按值传参会移动所有权；使用函数返回的值，或者让函数接收引用
+//
+// 修改下面的代码，让它通过编译。
+
+fn take_and_return(s: String) -> String {
+    s
+}
+
+fn main() {
+    let s2 = String::from("world");
+    let s3 = take_and_return(s2);
+    println!("{} {}", s2, s3);
+}
@@ -0,0 +1,23 @@
+// title: 部分移动的结构体
+// chapter: 6
+// error: E0382
+// message: borrow of partially moved value: `person`
+// hint: name 字段已经移出，结构体不能再整体使用；借用字段 &person.name，或者只打印仍然有效的字段
+//
+// 修改下面的代码，让它通过编译。
+
+#[derive(Debug)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("Alice"),
+        age: 30,
+    };
+    let name = person.name;
+    println!("{} {}", name, person.age);
+    println!("{:?}", person);
+}
@@ -0,0 +1,13 @@
+// title: 移动之后再使用
+// chapter: 6
+// error: E0382
+// message: borrow of moved value: `s1`
+// hint: 需要两个独立的字符串时用 clone 复制一份，或者只借用 &s1
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{} {}", s1, s2);
+}
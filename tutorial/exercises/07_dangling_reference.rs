@@ -0,0 +1,16 @@
+// title: 悬垂引用
+// chapter: 7
+// error: E0597
+// message: `x` does not live long enough
+// hint: x 在内部作用域结束时被释放，r 却还要在外面使用；让 x 活得比 r 更久
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let r;
+    {
+        let x = 5;
+        r = &x;
+    }
+    println!("r: {}", r);
+}
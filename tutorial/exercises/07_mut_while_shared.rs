@@ -0,0 +1,17 @@
+// title: 不可变引用还在使用时创建可变引用
+// chapter: 7
+// error: E0502
+// message: cannot borrow `s` as mutable because it is also borrowed as immutable
+// hint: 不可变引用最后一次使用之后，才能创建可变引用
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &mut s;
+    println!("{} and {}", r1, r2);
+    r3.push_str(" world");
+    println!("{}", r3);
+}
@@ -0,0 +1,16 @@
+// title: 同时存在两个可变引用
+// chapter: 7
+// error: E0499
+// message: cannot borrow `s` as mutable more than once at a time
+// hint: 同一时间只能有一个可变引用；用完第一个再创建第二个
+//
+// 修改下面的代码，让它通过编译。
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &mut s;
+    let r2 = &mut s;
+    r1.push_str(" world");
+    r2.push('!');
+    println!("{}", s);
+}
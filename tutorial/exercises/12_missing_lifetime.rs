@@ -0,0 +1,19 @@
+// title: 返回引用却没有生命周期
+// chapter: 12
+// error: E0106
+// message: missing lifetime specifier
+// hint: 返回值借用自 x 或 y，用生命周期参数 'a 把它们联系起来
+//
+// 修改下面的代码，让它通过编译。
+
+fn longest(x: &str, y: &str) -> &str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+fn main() {
+    println!("{}", longest("Rust", "Go"));
+}
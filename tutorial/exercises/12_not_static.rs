@@ -0,0 +1,17 @@
+// title: 需要 'static 的参数
+// chapter: 12
+// error: E0597
+// message: `dynamic` does not live long enough
+// hint: 'static 引用要在整个程序运行期间有效；传入字符串字面量，或者让函数接收普通的 &str
+//
+// 修改下面的代码，让它通过编译。
+
+fn needs_static(s: &'static str) {
+    println!("需要静态生命周期: {}", s);
+}
+
+fn main() {
+    needs_static("字面量可以传递");
+    let dynamic = String::from("动态字符串");
+    needs_static(&dynamic);
+}